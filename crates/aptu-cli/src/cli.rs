@@ -67,6 +67,14 @@ pub enum OutputFormat {
     /// GitHub Actions annotation output (`::error file=...,line=...,title=...::message`)
     #[value(name = "github-annotations")]
     GithubAnnotations,
+    /// Code Climate JSON for GitLab Code Quality (security findings only)
+    #[value(name = "codeclimate")]
+    CodeClimate,
+    /// GitLab SAST report JSON (security findings only)
+    #[value(name = "gitlab-sast")]
+    GitlabSast,
+    /// `JUnit` XML for CI test dashboards (security findings only)
+    Junit,
 }
 
 /// Issue state filter for triage operations.
@@ -96,7 +104,8 @@ pub struct OutputContext {
 
 impl OutputContext {
     /// Creates an `OutputContext` from CLI arguments.
-    /// Quiet mode is automatically enabled for structured formats (Json, Yaml, Markdown, Sarif).
+    /// Quiet mode is automatically enabled for structured formats (Json, Yaml, Markdown, Sarif,
+    /// and the security report formats).
     pub fn from_cli(format: OutputFormat, verbose: bool) -> Self {
         let quiet = matches!(
             format,
@@ -105,6 +114,9 @@ impl OutputContext {
                 | OutputFormat::Markdown
                 | OutputFormat::Sarif
                 | OutputFormat::GithubAnnotations
+                | OutputFormat::CodeClimate
+                | OutputFormat::GitlabSast
+                | OutputFormat::Junit
         );
         Self {
             format,
@@ -167,7 +179,7 @@ pub fn parse_date_to_rfc3339(date_str: &str) -> anyhow::Result<String> {
 #[command(version, about, long_about = None)]
 #[command(arg_required_else_help = true)]
 pub struct Cli {
-    /// Output format (text, json, yaml, sarif, github-annotations)
+    /// Output format (text, json, yaml, sarif, github-annotations, codeclimate, gitlab-sast, junit)
     #[arg(long, short = 'o', global = true, value_enum, default_value = "text")]
     pub output: OutputFormat,
 
@@ -222,23 +234,204 @@ pub enum Commands {
     #[command(subcommand)]
     Completion(CompletionCommand),
 
+    /// Query and maintain the structural code graph
+    #[command(subcommand)]
+    Graph(GraphCommand),
+
+    /// Select tests affected by a change
+    #[command(subcommand)]
+    Tests(TestsCommand),
+
+    /// Work with deterministic triage rules
+    #[command(subcommand)]
+    Triage(TriageCommand),
+
     /// Scan a file or directory for security issues
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
     ScanSecurity {
+        /// Git hook management
+        #[command(subcommand)]
+        command: Option<ScanSecurityCommand>,
         /// Path to scan (file or directory)
-        #[arg(required_unless_present = "diff")]
+        #[arg(required_unless_present_any = ["diff", "staged", "since"])]
         path: Option<std::path::PathBuf>,
         /// Read unified diff from FILE or - for stdin
-        #[arg(long, conflicts_with = "path", value_name = "FILE")]
+        #[arg(long, conflicts_with_all = ["path", "staged", "since"], value_name = "FILE")]
         diff: Option<std::path::PathBuf>,
+        /// Scan changes staged for commit (git diff --cached)
+        #[arg(long, conflicts_with_all = ["path", "since"])]
+        staged: bool,
+        /// Scan every commit in REV..HEAD, including secrets later removed
+        #[arg(long, conflicts_with = "path", value_name = "REV")]
+        since: Option<String>,
         /// Fail with exit code 1 if findings match these severities (comma-separated: critical,high,medium,low)
         #[arg(long, value_delimiter = ',')]
         fail_on: Vec<String>,
         /// Exclude paths matching this prefix (repeatable)
         #[arg(long)]
         exclude: Vec<String>,
+        /// Skip files larger than this many bytes
+        #[arg(long, value_name = "BYTES", default_value_t = aptu_core::security::walk::DEFAULT_MAX_FILE_SIZE)]
+        max_file_size: u64,
+        /// Scan files ignored by .gitignore (.aptuignore is still honoured)
+        #[arg(long)]
+        no_gitignore: bool,
         /// Write SARIF output to this file
         #[arg(long, value_name = "PATH")]
         sarif_output: Option<std::path::PathBuf>,
+        /// Validate findings with the configured AI model to filter false positives
+        #[arg(long)]
+        validate: bool,
+    },
+}
+
+/// Scan-security subcommands
+#[derive(Subcommand)]
+pub enum ScanSecurityCommand {
+    /// Manage the git pre-commit hook
+    #[command(subcommand)]
+    Hook(HookCommand),
+}
+
+/// Git hook subcommands
+#[derive(Subcommand)]
+pub enum HookCommand {
+    /// Install a pre-commit hook that scans staged changes
+    Install {
+        /// Severities that block the commit (comma-separated)
+        #[arg(long, value_delimiter = ',', default_value = "critical,high")]
+        fail_on: Vec<String>,
+
+        /// Overwrite an existing pre-commit hook
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// Alternative output for graph queries
+#[derive(Clone, Copy, ValueEnum)]
+pub enum GraphExport {
+    /// Graphviz DOT
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+}
+
+/// Structural graph subcommands
+#[derive(Subcommand)]
+pub enum GraphCommand {
+    /// List the functions that call SYMBOL directly
+    Callers {
+        /// Function name
+        symbol: String,
+        /// Repository root to analyze
+        #[arg(long, value_name = "PATH", default_value = ".")]
+        repo_path: std::path::PathBuf,
+        /// Emit the result as a DOT or Mermaid graph instead of --output
+        #[arg(long, value_enum)]
+        export: Option<GraphExport>,
+    },
+
+    /// List the functions SYMBOL calls directly
+    Callees {
+        /// Function name
+        symbol: String,
+        /// Repository root to analyze
+        #[arg(long, value_name = "PATH", default_value = ".")]
+        repo_path: std::path::PathBuf,
+        /// Emit the result as a DOT or Mermaid graph instead of --output
+        #[arg(long, value_enum)]
+        export: Option<GraphExport>,
+    },
+
+    /// Show the blast radius of a file or symbol
+    Impact {
+        /// File path (relative to the repository root) or symbol name
+        target: String,
+        /// Maximum number of hops from the target
+        #[arg(long, default_value_t = 2)]
+        depth: usize,
+        /// Repository root to analyze
+        #[arg(long, value_name = "PATH", default_value = ".")]
+        repo_path: std::path::PathBuf,
+        /// Emit the result as a DOT or Mermaid graph instead of --output
+        #[arg(long, value_enum)]
+        export: Option<GraphExport>,
+    },
+
+    /// Find a shortest dependency path from FROM to TO
+    Path {
+        /// Starting symbol
+        from: String,
+        /// Destination symbol
+        to: String,
+        /// Repository root to analyze
+        #[arg(long, value_name = "PATH", default_value = ".")]
+        repo_path: std::path::PathBuf,
+        /// Emit the result as a DOT or Mermaid graph instead of --output
+        #[arg(long, value_enum)]
+        export: Option<GraphExport>,
+    },
+
+    /// Remove cached per-commit graphs older than the configured cache TTL
+    Gc {
+        /// List what would be removed without deleting anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+/// Test runner filter emitted instead of --output
+#[derive(Clone, Copy, ValueEnum)]
+pub enum TestFilter {
+    /// `cargo test -- <names>`
+    Cargo,
+    /// `pytest -k "<a> or <b>"`
+    Pytest,
+}
+
+/// Test selection subcommands
+#[derive(Subcommand)]
+pub enum TestsCommand {
+    /// List the tests that transitively exercise symbols changed by a diff
+    Affected {
+        /// Read unified diff from FILE or - for stdin (default: git diff HEAD)
+        #[arg(long, conflicts_with = "since", value_name = "FILE")]
+        diff: Option<std::path::PathBuf>,
+        /// Use the changes between REV and the working tree (git diff REV)
+        #[arg(long, value_name = "REV")]
+        since: Option<String>,
+        /// Repository root to analyze
+        #[arg(long, value_name = "PATH", default_value = ".")]
+        repo_path: std::path::PathBuf,
+        /// Print the tests as a test runner filter instead of --output
+        #[arg(long, value_enum)]
+        filter: Option<TestFilter>,
+    },
+}
+
+/// Triage subcommands
+#[derive(Subcommand)]
+pub enum TriageCommand {
+    /// Inspect triage rules (~/.config/aptu/triage-rules.toml)
+    #[command(subcommand)]
+    Rules(TriageRulesCommand),
+}
+
+/// Triage rules subcommands
+#[derive(Subcommand)]
+pub enum TriageRulesCommand {
+    /// Show which rules match an issue and the labels and milestone they produce
+    ///
+    /// The issue is GitHub REST API JSON (e.g. from `gh api repos/OWNER/REPO/issues/N`).
+    /// Add `suggested_labels` and `suggested_milestone` keys to simulate the model.
+    Test {
+        /// Issue JSON file
+        #[arg(value_name = "ISSUE_JSON")]
+        file: std::path::PathBuf,
+        /// Rules file to test instead of the configured one
+        #[arg(long, value_name = "FILE")]
+        rules: Option<std::path::PathBuf>,
     },
 }
 
@@ -329,6 +522,14 @@ pub enum IssueCommand {
         #[arg(long, short = 's', default_value = "open")]
         state: IssueState,
 
+        /// Maximum number of issues to triage when using --since
+        #[arg(long, requires = "since")]
+        limit: Option<usize>,
+
+        /// Discard the saved --since checkpoint and start the sweep from the beginning
+        #[arg(long, requires = "since")]
+        restart: bool,
+
         /// Preview triage without posting to GitHub
         #[arg(long)]
         dry_run: bool,
@@ -341,6 +542,10 @@ pub enum IssueCommand {
         #[arg(long)]
         no_comment: bool,
 
+        /// Assign the top suggested assignee (from CODEOWNERS and commit history)
+        #[arg(long)]
+        assign: bool,
+
         /// Bypass 'already triaged' detection
         #[arg(short, long)]
         force: bool,
@@ -383,6 +588,92 @@ pub enum IssueCommand {
         #[arg(long)]
         dry_run: bool,
     },
+
+    /// Nudge, label, and close issues with no recent activity (dry-run unless --apply)
+    Stale {
+        /// Repository (OWNER/REPO) to sweep
+        #[arg(long, short = 'r')]
+        repo: Option<String>,
+
+        /// Days without activity before an issue is stale (overrides `stale.days_until_stale`)
+        #[arg(long)]
+        days: Option<u32>,
+
+        /// Days after the nudge before closing; 0 never closes (overrides `stale.days_until_close`)
+        #[arg(long)]
+        close_after: Option<u32>,
+
+        /// Maximum number of issues to act on
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Post nudges, apply labels, and close issues (default is a dry run)
+        #[arg(long)]
+        apply: bool,
+    },
+
+    /// Find clusters of duplicate open issues and close them
+    Dedupe {
+        /// Repository (OWNER/REPO) to sweep
+        #[arg(long, short = 'r')]
+        repo: Option<String>,
+
+        /// Minimum embedding similarity for issues to be clustered (0.0-1.0;
+        /// default: 0.6 with an embedding model, 0.7 with the lexical fallback)
+        #[arg(long)]
+        threshold: Option<f32>,
+
+        /// Maximum number of clusters to review with AI
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Close duplicates without asking for confirmation
+        #[arg(long, conflicts_with = "dry_run")]
+        apply: bool,
+
+        /// List proposed clusters without closing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Break a large issue into linked sub-issues
+    Split {
+        /// Issue reference (URL, owner/repo#number, or number)
+        #[arg(value_name = "ISSUE")]
+        reference: String,
+
+        /// Repository for bare issue numbers (e.g., "block/goose")
+        #[arg(long, short = 'r')]
+        repo: Option<String>,
+
+        /// Create the sub-issues without asking for confirmation
+        #[arg(long, conflicts_with = "dry_run")]
+        apply: bool,
+
+        /// Preview the breakdown without creating anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Plan an implementation of an issue using the local code
+    Plan {
+        /// Issue reference (URL, owner/repo#number, or number)
+        #[arg(value_name = "ISSUE")]
+        reference: String,
+
+        /// Repository for bare issue numbers (e.g., "block/goose")
+        #[arg(long, short = 'r')]
+        repo: Option<String>,
+
+        /// Local checkout used to locate the code the issue concerns
+        #[arg(long, value_name = "PATH", default_value = ".")]
+        repo_path: std::path::PathBuf,
+
+        /// Also write a unified diff implementing the plan to FILE
+        /// (for `aptu pr create --diff`)
+        #[arg(long, value_name = "FILE")]
+        diff: Option<std::path::PathBuf>,
+    },
 }
 
 /// Completion subcommands
@@ -528,6 +819,29 @@ pub enum PrCommand {
         limit: u32,
     },
 
+    /// Nudge, label, and close pull requests with no recent activity (dry-run unless --apply)
+    Stale {
+        /// Repository (OWNER/REPO) to sweep
+        #[arg(long, short = 'r')]
+        repo: Option<String>,
+
+        /// Days without activity before a pull request is stale (overrides `stale.days_until_stale`)
+        #[arg(long)]
+        days: Option<u32>,
+
+        /// Days after the nudge before closing; 0 never closes (overrides `stale.days_until_close`)
+        #[arg(long)]
+        close_after: Option<u32>,
+
+        /// Maximum number of pull requests to act on
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Post nudges, apply labels, and close pull requests (default is a dry run)
+        #[arg(long)]
+        apply: bool,
+    },
+
     /// Revert all comments and labels posted by aptu on a PR
     Revert {
         /// PR reference (URL, owner/repo#number, or number)
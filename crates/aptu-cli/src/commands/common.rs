@@ -2,12 +2,18 @@
 
 //! Shared helpers for CLI command handlers.
 
+use std::io::Read;
+use std::path::Path;
 use std::time::Duration;
 
+use anyhow::Result;
 use indicatif::{ProgressBar, ProgressStyle};
 
 use crate::cli::OutputContext;
 
+/// Maximum allowed size for a diff input (5 MiB).
+const DIFF_SIZE_LIMIT: usize = 5_242_880;
+
 /// Creates a styled spinner (only if interactive).
 pub fn maybe_spinner(ctx: &OutputContext, message: &str) -> Option<ProgressBar> {
     if ctx.is_interactive() {
@@ -24,3 +30,35 @@ pub fn maybe_spinner(ctx: &OutputContext, message: &str) -> Option<ProgressBar>
         None
     }
 }
+
+/// Reads a unified diff from a file path or stdin (`-`), enforcing a 5 MiB limit.
+pub fn read_diff_input(diff_path: &Path) -> Result<String> {
+    let content = if diff_path == Path::new("-") {
+        let mut buf = String::new();
+        std::io::stdin()
+            .take((DIFF_SIZE_LIMIT + 1) as u64)
+            .read_to_string(&mut buf)
+            .map_err(|e| anyhow::anyhow!("Failed to read stdin: {e}"))?;
+        buf
+    } else {
+        let meta = std::fs::metadata(diff_path)
+            .map_err(|e| anyhow::anyhow!("Cannot stat '{}': {e}", diff_path.display()))?;
+        if meta.len() > DIFF_SIZE_LIMIT as u64 {
+            return Err(anyhow::anyhow!(
+                "Diff file '{}' exceeds the 5 MiB limit ({} bytes)",
+                diff_path.display(),
+                meta.len()
+            ));
+        }
+        std::fs::read_to_string(diff_path)
+            .map_err(|e| anyhow::anyhow!("Failed to read '{}': {e}", diff_path.display()))?
+    };
+
+    if content.len() > DIFF_SIZE_LIMIT {
+        return Err(anyhow::anyhow!(
+            "Diff input exceeds the 5 MiB limit ({} bytes)",
+            content.len()
+        ));
+    }
+    Ok(content)
+}
@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Find and close duplicate issues command.
+//!
+//! Clusters a repository's open issues by embedding similarity, has the AI
+//! pick a canonical issue per cluster, and closes the rest as duplicates
+//! after confirmation (or immediately with `--apply`).
+
+use anyhow::{Context, Result};
+use console::style;
+use dialoguer::Confirm;
+use tracing::{info, instrument, warn};
+
+use super::common::maybe_spinner;
+use super::types::DedupeResult;
+use crate::cli::OutputContext;
+use crate::provider::CliTokenProvider;
+use aptu_core::facade::DuplicateCluster;
+use aptu_core::history::ContributionStatus;
+
+/// Find duplicate issue clusters and close the duplicates.
+///
+/// Without `--apply`, each cluster is confirmed interactively; in
+/// non-interactive sessions the clusters are only listed.
+///
+/// # Arguments
+///
+/// * `owner` / `repo` - Repository to sweep
+/// * `threshold` - Minimum embedding similarity for clustering (default depends on the embedder)
+/// * `limit` - Maximum number of clusters to review with AI
+/// * `apply` - Close duplicates without asking
+/// * `dry_run` - List clusters without closing anything
+#[instrument(skip_all, fields(owner = %owner, repo = %repo, threshold, apply, dry_run))]
+#[allow(clippy::too_many_arguments)]
+pub async fn run(
+    ctx: &OutputContext,
+    config: &aptu_core::AppConfig,
+    owner: &str,
+    repo: &str,
+    threshold: Option<f32>,
+    limit: Option<usize>,
+    apply: bool,
+    dry_run: bool,
+) -> Result<DedupeResult> {
+    let provider = CliTokenProvider;
+
+    let spinner = maybe_spinner(ctx, "Finding duplicate issues...");
+    let proposals = aptu_core::facade::find_duplicate_clusters(
+        &provider, owner, repo, threshold, limit, &config.ai,
+    )
+    .await
+    .context("Failed to find duplicate issues")?;
+    if let Some(s) = spinner {
+        s.finish_and_clear();
+    }
+
+    info!(
+        candidate_clusters = proposals.candidate_clusters,
+        confirmed_clusters = proposals.clusters.len(),
+        "Duplicate review complete"
+    );
+
+    let mut closed = Vec::new();
+    if !dry_run && (apply || ctx.is_interactive()) {
+        for cluster in &proposals.clusters {
+            if !apply && !confirm_cluster(cluster)? {
+                continue;
+            }
+            for duplicate in &cluster.duplicates {
+                match aptu_core::facade::close_duplicate(
+                    &provider,
+                    owner,
+                    repo,
+                    duplicate.number,
+                    cluster.canonical.number,
+                    &cluster.rationale,
+                )
+                .await
+                {
+                    Ok(record) => {
+                        aptu_core::history::add_contribution(aptu_core::history::Contribution {
+                            id: uuid::Uuid::new_v4(),
+                            repo: format!("{owner}/{repo}"),
+                            issue: duplicate.number,
+                            action: "dedupe".to_string(),
+                            timestamp: chrono::Utc::now(),
+                            comment_url: record.comment_url,
+                            status: ContributionStatus::default(),
+                            ai_stats: None,
+                        })
+                        .ok();
+                        closed.push(duplicate.number);
+                    }
+                    Err(e) => {
+                        warn!(number = duplicate.number, error = %e, "Failed to close duplicate");
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(DedupeResult {
+        repo: format!("{owner}/{repo}"),
+        candidate_clusters: proposals.candidate_clusters,
+        clusters: proposals.clusters,
+        closed,
+        dry_run,
+    })
+}
+
+/// Show a proposed cluster and ask whether to close its duplicates.
+fn confirm_cluster(cluster: &DuplicateCluster) -> Result<bool> {
+    println!();
+    println!(
+        "{} #{} {}",
+        style("Canonical").green().bold(),
+        cluster.canonical.number,
+        cluster.canonical.title
+    );
+    for duplicate in &cluster.duplicates {
+        println!(
+            "  {} #{} {} (similarity {:.2})",
+            style("duplicate").yellow(),
+            duplicate.number,
+            duplicate.title,
+            duplicate.similarity
+        );
+    }
+    if !cluster.rationale.is_empty() {
+        println!("  {}", style(&cluster.rationale).dim());
+    }
+    Confirm::new()
+        .with_prompt(format!(
+            "Close {} issue(s) as duplicates of #{}?",
+            cluster.duplicates.len(),
+            cluster.canonical.number
+        ))
+        .default(false)
+        .interact()
+        .context("Failed to get user confirmation")
+}
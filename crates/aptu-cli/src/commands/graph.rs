@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Structural graph commands.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use aptu_core::AppConfig;
+use aptu_core::graph::{GraphDb, cache, export, query, repo};
+
+use super::types::{GraphEdgeSummary, GraphGcResult, GraphNodeSummary, GraphQueryResult};
+
+/// A graph query to run against a local repository.
+pub enum GraphQuery {
+    /// Direct callers of a symbol.
+    Callers(String),
+    /// Direct callees of a symbol.
+    Callees(String),
+    /// Blast radius of a file or symbol, up to `depth` hops.
+    Impact { target: String, depth: usize },
+    /// Shortest dependency path between two symbols.
+    Path { from: String, to: String },
+}
+
+/// Run a graph query against the repository at `repo_path`.
+///
+/// The graph is assembled from the cached per-file index for this checkout,
+/// re-parsing only files that changed since the last query.
+pub fn run_query(
+    graph_query: &GraphQuery,
+    repo_path: &Path,
+    config: &AppConfig,
+) -> Result<GraphQueryResult> {
+    if !repo_path.is_dir() {
+        bail!("Repository path {} is not a directory", repo_path.display());
+    }
+    let graph = repo::load_local_graph(repo_path);
+
+    let resolve = |target: &str| {
+        let nodes = query::resolve_target(&graph, target);
+        if nodes.is_empty() {
+            bail!(
+                "'{target}' not found in the graph of {}",
+                repo_path.display()
+            );
+        }
+        Ok(nodes)
+    };
+
+    let (label, seeds, subgraph, path) = match graph_query {
+        GraphQuery::Callers(symbol) => {
+            let seeds = resolve(symbol)?;
+            let subgraph = query::direct_callers(&graph, &seeds);
+            (format!("callers {symbol}"), seeds, subgraph, None)
+        }
+        GraphQuery::Callees(symbol) => {
+            let seeds = resolve(symbol)?;
+            let subgraph = query::direct_callees(&graph, &seeds);
+            (format!("callees {symbol}"), seeds, subgraph, None)
+        }
+        GraphQuery::Impact { target, depth } => {
+            let seeds = resolve(target)?;
+            let subgraph = query::blast_radius(&graph, &seeds, config.graph.max_nodes, *depth);
+            (
+                format!("impact {target} --depth {depth}"),
+                seeds,
+                subgraph,
+                None,
+            )
+        }
+        GraphQuery::Path { from, to } => {
+            let found = query::shortest_path(&graph, &resolve(from)?, &resolve(to)?);
+            let subgraph = found
+                .as_deref()
+                .map_or_else(GraphDb::new, |p| query::path_subgraph(&graph, p));
+            let path = Some(
+                found
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|&idx| summarize_node(&graph[idx]))
+                    .collect(),
+            );
+            (format!("path {from} {to}"), Vec::new(), subgraph, path)
+        }
+    };
+
+    Ok(GraphQueryResult {
+        query: label,
+        nodes: subgraph
+            .node_indices()
+            .map(|idx| summarize_node(&subgraph[idx]))
+            .collect(),
+        edges: subgraph
+            .edge_indices()
+            .filter_map(|idx| {
+                let (from, to) = subgraph.edge_endpoints(idx)?;
+                Some(GraphEdgeSummary {
+                    from: from.index(),
+                    to: to.index(),
+                    kind: export::edge_label(subgraph[idx]).to_string(),
+                })
+            })
+            .collect(),
+        path,
+        seeds: seeds.iter().map(|&idx| graph[idx].clone()).collect(),
+        subgraph,
+    })
+}
+
+/// Summarize a graph node for output.
+pub(crate) fn summarize_node(node: &aptu_core::graph::Node) -> GraphNodeSummary {
+    GraphNodeSummary {
+        kind: export::node_kind(node).to_string(),
+        name: node.name().to_string(),
+        path: node.path().to_string(),
+    }
+}
+
+/// Prune cached per-commit graphs older than the configured TTL.
+pub fn run_gc(config: &AppConfig, dry_run: bool) -> Result<GraphGcResult> {
+    let report = cache::gc(&config.graph, dry_run).with_context(|| {
+        format!(
+            "Failed to prune graph cache at {}",
+            cache::cache_root().display()
+        )
+    })?;
+    Ok(GraphGcResult {
+        dry_run,
+        removed: report
+            .removed
+            .iter()
+            .map(|p| p.display().to_string())
+            .collect(),
+        bytes_freed: report.bytes_freed,
+        kept: report.kept,
+    })
+}
@@ -6,15 +6,23 @@ pub mod auth;
 pub mod common;
 pub mod completion;
 pub mod create;
+pub mod dedupe;
+pub mod graph;
 pub mod history;
 pub mod issue;
 pub mod models;
+pub mod plan;
 pub mod pr;
 pub mod repo;
 pub mod scan_security;
+pub mod split;
+pub mod stale;
+pub mod test_impact;
 pub mod triage;
+pub mod triage_rules;
 pub mod types;
 
+use std::fmt::Write as _;
 use std::time::Instant;
 
 use anyhow::{Context, Result};
@@ -24,13 +32,16 @@ use rayon::prelude::*;
 use tracing::debug;
 
 use crate::cli::{
-    AuthCommand, Commands, CompletionCommand, IssueCommand, IssueState, OutputContext,
-    OutputFormat, PrCommand, RepoCommand,
+    AuthCommand, Commands, CompletionCommand, GraphCommand, GraphExport, HookCommand, IssueCommand,
+    IssueState, OutputContext, OutputFormat, PrCommand, RepoCommand, ScanSecurityCommand,
+    TestsCommand, TriageCommand, TriageRulesCommand,
 };
 use crate::commands::common::maybe_spinner;
 use crate::commands::types::{BulkPrReviewResult, PrReviewResult, SinglePrReviewOutcome};
 use crate::output;
-use aptu_core::{AppConfig, State, check_already_triaged, history::ContributionStatus};
+use aptu_core::{
+    AppConfig, State, check_already_triaged, history::ContributionStatus, prior_triage,
+};
 
 /// Options for PR review behavior.
 #[allow(clippy::struct_excessive_bools)]
@@ -75,11 +86,18 @@ fn show_triage_success(
         return;
     }
     if let Some(url) = comment_url {
+        let message = if result.retriaged {
+            "Triage comment updated successfully!"
+        } else {
+            "Comment posted successfully!"
+        };
         println!();
-        println!("{}", style("Comment posted successfully!").green().bold());
+        println!("{}", style(message).green().bold());
         println!("  {}", style(url).cyan().underlined());
     }
-    if !no_apply && (!result.applied_labels.is_empty() || result.applied_milestone.is_some()) {
+    if (!no_apply && (!result.applied_labels.is_empty() || result.applied_milestone.is_some()))
+        || !result.applied_assignees.is_empty()
+    {
         println!();
         println!("{}", style("Applied to issue:").green());
         if !result.applied_labels.is_empty() {
@@ -88,12 +106,15 @@ fn show_triage_success(
         if let Some(milestone) = &result.applied_milestone {
             println!("  Milestone: {milestone}");
         }
-        if !result.apply_warnings.is_empty() {
-            println!();
-            println!("{}", style("Warnings:").yellow());
-            for warning in &result.apply_warnings {
-                println!("  - {warning}");
-            }
+        if !result.applied_assignees.is_empty() {
+            println!("  Assignees: {}", result.applied_assignees.join(", "));
+        }
+    }
+    if !result.apply_warnings.is_empty() {
+        println!();
+        println!("{}", style("Warnings:").yellow());
+        for warning in &result.apply_warnings {
+            println!("  - {warning}");
         }
     }
 }
@@ -106,6 +127,7 @@ struct TriageConfig<'a> {
     dry_run: bool,
     no_apply: bool,
     no_comment: bool,
+    assign: bool,
     force: bool,
     ctx: &'a OutputContext,
     config: &'a AppConfig,
@@ -119,6 +141,7 @@ async fn triage_single_issue(
     dry_run: bool,
     no_apply: bool,
     no_comment: bool,
+    assign: bool,
     force: bool,
     ctx: &OutputContext,
     config: &AppConfig,
@@ -129,6 +152,7 @@ async fn triage_single_issue(
         dry_run,
         no_apply,
         no_comment,
+        assign,
         force,
         ctx,
         config,
@@ -141,7 +165,7 @@ async fn triage_single_issue_impl(cfg: &TriageConfig<'_>) -> Result<Option<types
     // Phase 1a: Fetch issue
     let spinner = maybe_spinner(cfg.ctx, "Fetching issue...");
     let fetch_start = Instant::now();
-    let issue_details = triage::fetch(cfg.reference, cfg.repo_context).await?;
+    let mut issue_details = triage::fetch(cfg.reference, cfg.repo_context).await?;
     let fetch_elapsed = fetch_start.elapsed();
     if let Some(s) = spinner {
         s.finish_and_clear();
@@ -150,10 +174,19 @@ async fn triage_single_issue_impl(cfg: &TriageConfig<'_>) -> Result<Option<types
     // Phase 1a.5: Display issue preview (title and labels) immediately after fetch
     crate::output::common::show_preview(cfg.ctx, &issue_details.title, &issue_details.labels);
 
-    // Phase 1b: Check if already triaged (unless force or dry_run is true)
-    if !cfg.force && !cfg.dry_run {
+    // Phase 1b: Check if already triaged (unless force is true). An issue that changed
+    // since the last aptu triage is re-triaged, revising that comment in place.
+    if !cfg.force {
         let triage_status = check_already_triaged(&issue_details);
-        if triage_status.is_triaged() {
+        if let Some(reason) = triage_status.retriage {
+            if matches!(cfg.ctx.format, OutputFormat::Text) {
+                println!(
+                    "{}",
+                    style(format!("Re-triaging ({reason} since last triage)")).yellow()
+                );
+            }
+            issue_details.prior_triage = prior_triage(&issue_details);
+        } else if triage_status.is_triaged() && !cfg.dry_run {
             if matches!(cfg.ctx.format, OutputFormat::Text) {
                 println!("{}", style("Already triaged (skipping)").yellow());
             }
@@ -198,7 +231,9 @@ async fn triage_single_issue_impl(cfg: &TriageConfig<'_>) -> Result<Option<types
         applied_labels: Vec::new(),
         applied_milestone: None,
         apply_warnings: Vec::new(),
+        applied_assignees: Vec::new(),
         is_maintainer,
+        retriaged: issue_details.prior_triage.is_some(),
     };
 
     // Render triage FIRST (before asking for confirmation)
@@ -247,13 +282,40 @@ async fn triage_single_issue_impl(cfg: &TriageConfig<'_>) -> Result<Option<types
         result.apply_warnings.clone_from(&apply_result.warnings);
     }
 
+    // Phase 3b: Assign the top suggested assignee if requested
+    if cfg.assign {
+        if let Some(top) = analyze_result.triage.suggested_assignees.first() {
+            let spinner = maybe_spinner(cfg.ctx, "Assigning issue...");
+            result.applied_assignees =
+                triage::assign(&issue_details, std::slice::from_ref(&top.login)).await?;
+            if let Some(s) = spinner {
+                s.finish_and_clear();
+            }
+            if result.applied_assignees.is_empty() {
+                result.apply_warnings.push(format!(
+                    "Could not assign {}: not assignable in this repository",
+                    top.login
+                ));
+            }
+        } else {
+            result
+                .apply_warnings
+                .push("No assignee suggested; nothing to assign".to_string());
+        }
+    }
+
     // Record to history only if comment was posted
     if let Some(url) = &comment_url {
         let contribution = aptu_core::history::Contribution {
             id: uuid::Uuid::new_v4(),
             repo: format!("{}/{}", issue_details.owner, issue_details.repo),
             issue: issue_details.number,
-            action: "triage".to_string(),
+            action: if result.retriaged {
+                "retriage"
+            } else {
+                "triage"
+            }
+            .to_string(),
             timestamp: chrono::Utc::now(),
             comment_url: url.clone(),
             status: aptu_core::history::ContributionStatus::Pending,
@@ -307,6 +369,13 @@ async fn review_single_pr(
         );
     }
 
+    if context_record.ai_skipped {
+        eprintln!(
+            "info: AI review skipped -- all {} file(s) are renames, moves, formatting, or comment changes",
+            context_record.files_total
+        );
+    }
+
     // Log metrics (fire-and-forget)
     aptu_core::metrics::append_jsonl(&ai_stats);
     aptu_core::metrics::write_context_jsonl(&context_record);
@@ -471,69 +540,289 @@ async fn run_repo_command(repo_cmd: RepoCommand, ctx: OutputContext) -> Result<(
     }
 }
 
-/// Resolve issue references from --since flag.
-async fn resolve_triage_refs(
-    since: Option<String>,
+/// A `--since` triage sweep over one repository.
+struct TriageSweep<'a> {
+    repo_context: &'a str,
+    since: &'a str,
     state: IssueState,
-    repo_context: Option<&str>,
+    limit: Option<usize>,
+    restart: bool,
+}
+
+/// Triage every issue needing triage since a date, one GraphQL page at a time.
+///
+/// Issues are fetched oldest first and each page is triaged before the next is
+/// fetched. After each page the cursor of the last triaged issue is saved to a
+/// per-repository checkpoint, so re-running the same sweep after an
+/// interruption resumes where it stopped (`--restart` starts over). Dry runs
+/// neither read nor write the checkpoint. The checkpoint is removed once the
+/// last page has been triaged.
+#[allow(clippy::fn_params_excessive_bools)]
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_lines)]
+async fn run_triage_sweep(
+    sweep: &TriageSweep<'_>,
+    dry_run: bool,
+    no_apply: bool,
+    no_comment: bool,
+    assign: bool,
     force: bool,
     ctx: &OutputContext,
-) -> Result<Vec<String>> {
-    if let Some(since_date) = since {
-        // Fetch untriaged issues since the specified date
-        let repo_context = repo_context.ok_or_else(|| {
-            anyhow::anyhow!(
-                "--since requires --repo or default_repo config when no references provided"
-            )
-        })?;
-        let (owner, repo_name) = repo_context
-            .split_once('/')
-            .context("Invalid repo format, expected 'owner/repo'")?;
-
-        // Parse the date to RFC3339 format
-        let rfc3339_date = crate::cli::parse_date_to_rfc3339(&since_date)?;
-
-        // Convert IssueState to octocrab::params::State
-        let octocrab_state = match state {
-            IssueState::Open => State::Open,
-            IssueState::Closed => State::Closed,
-            IssueState::All => State::All,
-        };
+    config: &AppConfig,
+) -> Result<types::BulkTriageResult> {
+    let (owner, repo_name) = sweep
+        .repo_context
+        .split_once('/')
+        .context("Invalid repo format, expected 'owner/repo'")?;
+
+    // Parse the date to RFC3339 format
+    let rfc3339_date = crate::cli::parse_date_to_rfc3339(sweep.since)?;
+
+    // Convert IssueState to octocrab::params::State
+    let (octocrab_state, state_name) = match sweep.state {
+        IssueState::Open => (State::Open, "open"),
+        IssueState::Closed => (State::Closed, "closed"),
+        IssueState::All => (State::All, "all"),
+    };
 
+    let mut checkpoint = aptu_core::github::issues::TriageCheckpoint {
+        since: rfc3339_date.clone(),
+        state: state_name.to_string(),
+        force,
+        cursor: None,
+        processed: 0,
+        failed: Vec::new(),
+    };
+    if sweep.restart {
+        aptu_core::github::issues::clear_triage_checkpoint(owner, repo_name);
+    } else if !dry_run
+        && let Some(saved) = aptu_core::github::issues::load_triage_checkpoint(owner, repo_name)
+        && saved.same_sweep(&checkpoint)
+    {
+        if matches!(ctx.format, OutputFormat::Text) {
+            println!(
+                "{}",
+                style(format!(
+                    "Resuming triage sweep after {} issues (use --restart to start over)",
+                    saved.processed
+                ))
+                .yellow()
+            );
+        }
+        checkpoint = saved;
+    }
+
+    let client =
+        aptu_core::github::auth::create_client().context("Failed to create GitHub client")?;
+    let mut bulk_result = types::BulkTriageResult::default();
+    let mut remaining = sweep.limit.unwrap_or(usize::MAX);
+    let mut rate_limit_checked = false;
+
+    // Retry issues that failed in an earlier run before moving past them
+    if !checkpoint.failed.is_empty() {
+        warn_if_rate_limit_low(ctx).await?;
+        rate_limit_checked = true;
+        let retry: Vec<u64> = checkpoint.failed.iter().copied().take(remaining).collect();
+        let issue_refs: Vec<String> = retry
+            .iter()
+            .map(|number| format!("{}#{number}", sweep.repo_context))
+            .collect();
+        let retry_result = triage_bulk(
+            &issue_refs,
+            Some(sweep.repo_context),
+            dry_run,
+            no_apply,
+            no_comment,
+            assign,
+            force,
+            ctx,
+            config,
+        )
+        .await;
+        let still_failing = failed_issue_numbers(&retry_result);
+        checkpoint
+            .failed
+            .retain(|number| !retry.contains(number) || still_failing.contains(number));
+        remaining -= retry.len();
+        bulk_result.merge(retry_result);
+        aptu_core::github::issues::save_triage_checkpoint(owner, repo_name, &checkpoint)?;
+    }
+
+    while remaining > 0 {
         let spinner = maybe_spinner(ctx, "Fetching issues needing triage...");
-        let client =
-            aptu_core::github::auth::create_client().context("Failed to create GitHub client")?;
-        let untriaged_issues = aptu_core::github::issues::fetch_issues_needing_triage(
+        let page = aptu_core::github::graphql::fetch_triage_page(
             &client,
             owner,
             repo_name,
             Some(&rfc3339_date),
             force,
             octocrab_state,
+            checkpoint.cursor.as_deref(),
         )
         .await?;
         if let Some(s) = spinner {
             s.finish_and_clear();
         }
 
-        // Warn if pagination limit hit
-        if untriaged_issues.len() == 100 && matches!(ctx.format, OutputFormat::Text) {
-            println!(
-                "{}",
-                style(
-                    "Warning: Fetched 100 issues (pagination limit). There may be more untriaged issues."
-                )
-                    .yellow()
-            );
+        let truncated = page.issues.len() > remaining;
+        let batch: Vec<(String, aptu_core::github::issues::UntriagedIssue)> =
+            page.issues.into_iter().take(remaining).collect();
+        if !batch.is_empty() {
+            if !rate_limit_checked {
+                warn_if_rate_limit_low(ctx).await?;
+                rate_limit_checked = true;
+            }
+            let issue_refs: Vec<String> = batch
+                .iter()
+                .map(|(_, issue)| format!("{}#{}", sweep.repo_context, issue.number))
+                .collect();
+            let page_result = triage_bulk(
+                &issue_refs,
+                Some(sweep.repo_context),
+                dry_run,
+                no_apply,
+                no_comment,
+                assign,
+                force,
+                ctx,
+                config,
+            )
+            .await;
+            checkpoint.failed.extend(failed_issue_numbers(&page_result));
+            bulk_result.merge(page_result);
         }
 
-        Ok(untriaged_issues
-            .into_iter()
-            .map(|issue| format!("{}#{}", repo_context, issue.number))
-            .collect())
-    } else {
-        Ok(Vec::new())
+        remaining -= batch.len();
+        checkpoint.processed += batch.len();
+        checkpoint.cursor = if truncated {
+            batch.last().map(|(cursor, _)| cursor.clone())
+        } else {
+            page.end_cursor.or(checkpoint.cursor)
+        };
+
+        let finished = !truncated && !page.has_next_page;
+        if !dry_run {
+            if finished && checkpoint.failed.is_empty() {
+                aptu_core::github::issues::clear_triage_checkpoint(owner, repo_name);
+            } else {
+                // Failed issues keep the checkpoint so the next run retries them
+                aptu_core::github::issues::save_triage_checkpoint(owner, repo_name, &checkpoint)?;
+            }
+        }
+        if finished {
+            break;
+        }
     }
+
+    Ok(bulk_result)
+}
+
+/// Issue numbers whose triage failed in `result`.
+fn failed_issue_numbers(result: &types::BulkTriageResult) -> Vec<u64> {
+    result
+        .outcomes
+        .iter()
+        .filter(|(_, outcome)| matches!(outcome, types::SingleTriageOutcome::Failed(_)))
+        .filter_map(|(issue_ref, _)| issue_ref.rsplit_once('#')?.1.parse().ok())
+        .collect()
+}
+
+/// Warn when the GitHub rate limit is running low (only when authenticated).
+async fn warn_if_rate_limit_low(ctx: &OutputContext) -> Result<()> {
+    if !aptu_core::github::auth::is_authenticated() {
+        return Ok(());
+    }
+    let spinner = maybe_spinner(ctx, "Checking GitHub rate limit...");
+    let gh_client =
+        aptu_core::github::auth::create_client().context("Failed to create GitHub client")?;
+    let rate_limit = aptu_core::check_rate_limit(&gh_client).await?;
+    if let Some(s) = spinner {
+        s.finish_and_clear();
+    }
+
+    if rate_limit.is_low() && matches!(ctx.format, OutputFormat::Text) {
+        println!(
+            "{}",
+            style(format!("Warning: {}", rate_limit.message())).yellow()
+        );
+    }
+    Ok(())
+}
+
+/// Triage `issue_refs` concurrently and collect the outcomes.
+#[allow(clippy::fn_params_excessive_bools)]
+#[allow(clippy::too_many_arguments)]
+async fn triage_bulk(
+    issue_refs: &[String],
+    repo_context: Option<&str>,
+    dry_run: bool,
+    no_apply: bool,
+    no_comment: bool,
+    assign: bool,
+    force: bool,
+    ctx: &OutputContext,
+    config: &AppConfig,
+) -> types::BulkTriageResult {
+    // Bulk triage using core processor
+    let items: Vec<(String, ())> = issue_refs.iter().map(|r| (r.clone(), ())).collect();
+
+    let ctx_for_processor = ctx.clone();
+    let ctx_for_progress = ctx.clone();
+    let repo_context_owned = repo_context.map(std::string::ToString::to_string);
+    let config_clone = config.clone();
+
+    let core_result = aptu_core::process_bulk(
+        items,
+        move |(issue_ref, ())| {
+            let ctx = ctx_for_processor.clone();
+            let repo_context = repo_context_owned.clone();
+            let config = config_clone.clone();
+            async move {
+                triage_single_issue(
+                    &issue_ref,
+                    repo_context.as_deref(),
+                    dry_run,
+                    no_apply,
+                    no_comment,
+                    assign,
+                    force,
+                    &ctx,
+                    &config,
+                )
+                .await
+            }
+        },
+        move |current, total, action| {
+            crate::output::common::show_progress(&ctx_for_progress, current, total, action);
+        },
+    )
+    .await;
+
+    // Convert core BulkResult to CLI BulkTriageResult
+    let mut bulk_result = types::BulkTriageResult {
+        succeeded: core_result.succeeded,
+        failed: core_result.failed,
+        skipped: core_result.skipped,
+        outcomes: Vec::new(),
+    };
+
+    for (issue_ref, outcome) in core_result.outcomes {
+        let cli_outcome = match outcome {
+            aptu_core::BulkOutcome::Success(triage_result) => {
+                types::SingleTriageOutcome::Success(Box::new(triage_result))
+            }
+            aptu_core::BulkOutcome::Skipped(msg) => types::SingleTriageOutcome::Skipped(msg),
+            aptu_core::BulkOutcome::Failed(err) => {
+                if matches!(ctx.format, OutputFormat::Text) {
+                    println!("  {}", style(format!("Error: {err}")).red());
+                }
+                types::SingleTriageOutcome::Failed(err)
+            }
+        };
+        bulk_result.outcomes.push((issue_ref, cli_outcome));
+    }
+
+    bulk_result
 }
 
 /// Run the issue command.
@@ -559,9 +848,12 @@ async fn run_issue_command(
             repo,
             since,
             state,
+            limit,
+            restart,
             dry_run,
             no_apply,
             no_comment,
+            assign,
             force,
         } => {
             // Determine repo context: --repo flag > inferred_repo > default_repo config
@@ -570,100 +862,54 @@ async fn run_issue_command(
                 .or(inferred_repo.as_deref())
                 .or(config.user.default_repo.as_deref());
 
-            // Resolve issue numbers from references or --since flag
-            let issue_refs = if references.is_empty() {
-                resolve_triage_refs(since, state, repo_context, force, &ctx).await?
-            } else {
-                references
+            let bulk_result = match since {
+                // Sweep issues needing triage from the --since date
+                Some(since) if references.is_empty() => {
+                    let repo_context = repo_context.ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "--since requires --repo or default_repo config when no references provided"
+                        )
+                    })?;
+                    let sweep = TriageSweep {
+                        repo_context,
+                        since: &since,
+                        state,
+                        limit,
+                        restart,
+                    };
+                    run_triage_sweep(
+                        &sweep, dry_run, no_apply, no_comment, assign, force, &ctx, config,
+                    )
+                    .await?
+                }
+                _ if references.is_empty() => types::BulkTriageResult::default(),
+                _ => {
+                    // Check GitHub rate limit before triaging (only when we have issues)
+                    warn_if_rate_limit_low(&ctx).await?;
+                    triage_bulk(
+                        &references,
+                        repo_context,
+                        dry_run,
+                        no_apply,
+                        no_comment,
+                        assign,
+                        force,
+                        &ctx,
+                        config,
+                    )
+                    .await
+                }
             };
 
-            if issue_refs.is_empty() {
+            if bulk_result.outcomes.is_empty() {
                 if matches!(ctx.format, OutputFormat::Text) {
                     println!("{}", style("No issues to triage.").yellow());
                 }
                 return Ok(());
             }
 
-            // Check GitHub rate limit before triaging (only when we have issues)
-            if aptu_core::github::auth::is_authenticated() {
-                let spinner = maybe_spinner(&ctx, "Checking GitHub rate limit...");
-                let gh_client = aptu_core::github::auth::create_client()
-                    .context("Failed to create GitHub client")?;
-                let rate_limit = aptu_core::check_rate_limit(&gh_client).await?;
-                if let Some(s) = spinner {
-                    s.finish_and_clear();
-                }
-
-                if rate_limit.is_low() && matches!(ctx.format, OutputFormat::Text) {
-                    println!(
-                        "{}",
-                        style(format!("Warning: {}", rate_limit.message())).yellow()
-                    );
-                }
-            }
-
-            // Bulk triage using core processor
-            let items: Vec<(String, ())> = issue_refs.iter().map(|r| (r.clone(), ())).collect();
-
-            let ctx_for_processor = ctx.clone();
-            let ctx_for_progress = ctx.clone();
-            let repo_context_owned = repo_context.map(std::string::ToString::to_string);
-            let config_clone = config.clone();
-
-            let core_result = aptu_core::process_bulk(
-                items,
-                move |(issue_ref, ())| {
-                    let ctx = ctx_for_processor.clone();
-                    let repo_context = repo_context_owned.clone();
-                    let config = config_clone.clone();
-                    async move {
-                        triage_single_issue(
-                            &issue_ref,
-                            repo_context.as_deref(),
-                            dry_run,
-                            no_apply,
-                            no_comment,
-                            force,
-                            &ctx,
-                            &config,
-                        )
-                        .await
-                    }
-                },
-                move |current, total, action| {
-                    crate::output::common::show_progress(&ctx_for_progress, current, total, action);
-                },
-            )
-            .await;
-
-            // Convert core BulkResult to CLI BulkTriageResult
-            let mut bulk_result = types::BulkTriageResult {
-                succeeded: core_result.succeeded,
-                failed: core_result.failed,
-                skipped: core_result.skipped,
-                outcomes: Vec::new(),
-            };
-
-            for (issue_ref, outcome) in core_result.outcomes {
-                let cli_outcome = match outcome {
-                    aptu_core::BulkOutcome::Success(triage_result) => {
-                        types::SingleTriageOutcome::Success(Box::new(triage_result))
-                    }
-                    aptu_core::BulkOutcome::Skipped(msg) => {
-                        types::SingleTriageOutcome::Skipped(msg)
-                    }
-                    aptu_core::BulkOutcome::Failed(err) => {
-                        if matches!(ctx.format, OutputFormat::Text) {
-                            println!("  {}", style(format!("Error: {err}")).red());
-                        }
-                        types::SingleTriageOutcome::Failed(err)
-                    }
-                };
-                bulk_result.outcomes.push((issue_ref, cli_outcome));
-            }
-
             // Render bulk summary (only for multiple issues)
-            if issue_refs.len() > 1 {
+            if bulk_result.outcomes.len() > 1 {
                 output::render(&bulk_result, &ctx)?;
             }
 
@@ -687,6 +933,108 @@ async fn run_issue_command(
             output::render(&result, &ctx)?;
             Ok(())
         }
+        IssueCommand::Stale {
+            repo,
+            days,
+            close_after,
+            limit,
+            apply,
+        } => {
+            // Determine repo context: --repo flag > inferred_repo > default_repo config
+            let repo_context = repo
+                .as_deref()
+                .or(inferred_repo.as_deref())
+                .or(config.user.default_repo.as_deref());
+
+            let repo_str = repo_context.ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Could not determine owner/repo; use --repo or set default_repo in config"
+                )
+            })?;
+            let (owner, repo_name) = aptu_core::github::parse_owner_repo(repo_str)?;
+
+            let stale_config = stale::with_overrides(&config.stale, days, close_after);
+            let result = stale::run(
+                &ctx,
+                config,
+                &owner,
+                &repo_name,
+                aptu_core::github::stale::StaleKind::Issue,
+                stale_config,
+                limit,
+                apply,
+            )
+            .await?;
+            output::render(&result, &ctx)?;
+            Ok(())
+        }
+        IssueCommand::Dedupe {
+            repo,
+            threshold,
+            limit,
+            apply,
+            dry_run,
+        } => {
+            // Determine repo context: --repo flag > inferred_repo > default_repo config
+            let repo_context = repo
+                .as_deref()
+                .or(inferred_repo.as_deref())
+                .or(config.user.default_repo.as_deref());
+
+            let repo_str = repo_context.ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Could not determine owner/repo; use --repo or set default_repo in config"
+                )
+            })?;
+            let (owner, repo_name) = aptu_core::github::parse_owner_repo(repo_str)?;
+
+            let result = dedupe::run(
+                &ctx, config, &owner, &repo_name, threshold, limit, apply, dry_run,
+            )
+            .await?;
+            output::render(&result, &ctx)?;
+            Ok(())
+        }
+        IssueCommand::Split {
+            reference,
+            repo,
+            apply,
+            dry_run,
+        } => {
+            // Determine repo context: --repo flag > inferred_repo > default_repo config
+            let repo_context = repo
+                .as_deref()
+                .or(inferred_repo.as_deref())
+                .or(config.user.default_repo.as_deref());
+
+            let result = split::run(&ctx, config, &reference, repo_context, apply, dry_run).await?;
+            output::render(&result, &ctx)?;
+            Ok(())
+        }
+        IssueCommand::Plan {
+            reference,
+            repo,
+            repo_path,
+            diff,
+        } => {
+            // Determine repo context: --repo flag > inferred_repo > default_repo config
+            let repo_context = repo
+                .as_deref()
+                .or(inferred_repo.as_deref())
+                .or(config.user.default_repo.as_deref());
+
+            let result = plan::run(
+                &ctx,
+                config,
+                &reference,
+                repo_context,
+                &repo_path,
+                diff.as_deref(),
+            )
+            .await?;
+            output::render(&result, &ctx)?;
+            Ok(())
+        }
         IssueCommand::Revert {
             issue,
             repo,
@@ -744,22 +1092,40 @@ async fn run_issue_command(
 
             // Format result
             let comments_count = outcome.comment_ids.len();
+            let mut extra = String::new();
+            if outcome.reopened {
+                extra.push_str(if dry_run {
+                    " and reopen it"
+                } else {
+                    " and reopened it"
+                });
+            }
+            if !outcome.closed_sub_issues.is_empty() {
+                let _ = write!(
+                    extra,
+                    " and {} {} sub-issue(s)",
+                    if dry_run { "close" } else { "closed" },
+                    outcome.closed_sub_issues.len()
+                );
+            }
             let result = types::RevertResult {
                 dry_run,
                 labels_removed: outcome.labels_removed.clone(),
                 comments_removed: comments_count,
                 comment_ids: outcome.comment_ids,
+                reopened: outcome.reopened,
+                closed_sub_issues: outcome.closed_sub_issues,
                 summary: if dry_run {
                     format!(
-                        "Would remove {} comments and {} labels from issue #{issue_number}",
+                        "Would remove {} comments and {} labels from issue #{issue_number}{extra}",
                         comments_count,
-                        outcome.labels_removed.len()
+                        outcome.labels_removed.len(),
                     )
                 } else {
                     format!(
-                        "Removed {} comments and {} labels from issue #{issue_number}",
+                        "Removed {} comments and {} labels from issue #{issue_number}{extra}",
                         comments_count,
-                        outcome.labels_removed.len()
+                        outcome.labels_removed.len(),
                     )
                 },
             };
@@ -965,6 +1331,41 @@ async fn run_pr_command(
             output::render(&result, &ctx)?;
             Ok(())
         }
+        PrCommand::Stale {
+            repo,
+            days,
+            close_after,
+            limit,
+            apply,
+        } => {
+            // Determine repo context: --repo flag > inferred_repo > default_repo config
+            let repo_context = repo
+                .as_deref()
+                .or(inferred_repo.as_deref())
+                .or(config.user.default_repo.as_deref());
+
+            let repo_str = repo_context.ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Could not determine owner/repo; use --repo or set default_repo in config"
+                )
+            })?;
+            let (owner, repo_name) = aptu_core::github::parse_owner_repo(repo_str)?;
+
+            let stale_config = stale::with_overrides(&config.stale, days, close_after);
+            let result = stale::run(
+                &ctx,
+                config,
+                &owner,
+                &repo_name,
+                aptu_core::github::stale::StaleKind::PullRequest,
+                stale_config,
+                limit,
+                apply,
+            )
+            .await?;
+            output::render(&result, &ctx)?;
+            Ok(())
+        }
         PrCommand::Revert { pr, repo, dry_run } => {
             let spinner = maybe_spinner(&ctx, "Reverting PR...");
 
@@ -1018,6 +1419,8 @@ async fn run_pr_command(
                 labels_removed: outcome.labels_removed.clone(),
                 comments_removed: comments_count,
                 comment_ids: outcome.comment_ids,
+                reopened: false,
+                closed_sub_issues: Vec::new(),
                 summary: if dry_run {
                     format!(
                         "Would remove {} comments and {} labels from PR #{pr_number}",
@@ -1081,6 +1484,94 @@ fn run_completion_command(completion_cmd: &CompletionCommand, _ctx: OutputContex
     }
 }
 
+/// Run a graph command.
+fn run_graph_command(
+    graph_cmd: GraphCommand,
+    ctx: &OutputContext,
+    config: &AppConfig,
+) -> Result<()> {
+    let (graph_query, repo_path, export) = match graph_cmd {
+        GraphCommand::Gc { dry_run } => {
+            let result = graph::run_gc(config, dry_run)?;
+            return output::render(&result, ctx);
+        }
+        GraphCommand::Callers {
+            symbol,
+            repo_path,
+            export,
+        } => (graph::GraphQuery::Callers(symbol), repo_path, export),
+        GraphCommand::Callees {
+            symbol,
+            repo_path,
+            export,
+        } => (graph::GraphQuery::Callees(symbol), repo_path, export),
+        GraphCommand::Impact {
+            target,
+            depth,
+            repo_path,
+            export,
+        } => (
+            graph::GraphQuery::Impact { target, depth },
+            repo_path,
+            export,
+        ),
+        GraphCommand::Path {
+            from,
+            to,
+            repo_path,
+            export,
+        } => (graph::GraphQuery::Path { from, to }, repo_path, export),
+    };
+
+    let spinner = maybe_spinner(ctx, "Building code graph...");
+    let result = graph::run_query(&graph_query, &repo_path, config);
+    if let Some(s) = spinner {
+        s.finish_and_clear();
+    }
+    let result = result?;
+
+    match export {
+        Some(GraphExport::Dot) => print!("{}", aptu_core::graph::export::to_dot(&result.subgraph)),
+        Some(GraphExport::Mermaid) => {
+            print!("{}", aptu_core::graph::export::to_mermaid(&result.subgraph));
+        }
+        None => output::render(&result, ctx)?,
+    }
+    Ok(())
+}
+
+/// Run a tests command.
+fn run_tests_command(tests_cmd: TestsCommand, ctx: &OutputContext) -> Result<()> {
+    let TestsCommand::Affected {
+        diff,
+        since,
+        repo_path,
+        filter,
+    } = tests_cmd;
+    let source = match (diff, since) {
+        (Some(path), _) => test_impact::DiffSource::File(path),
+        (None, Some(rev)) => test_impact::DiffSource::Since(rev),
+        (None, None) => test_impact::DiffSource::WorkingTree,
+    };
+
+    let spinner = maybe_spinner(ctx, "Building code graph...");
+    let result = test_impact::run_affected(&source, &repo_path);
+    if let Some(s) = spinner {
+        s.finish_and_clear();
+    }
+    let result = result?;
+
+    match filter {
+        Some(filter) => {
+            if let Some(command) = test_impact::runner_filter(&result, filter) {
+                println!("{command}");
+            }
+        }
+        None => output::render(&result, ctx)?,
+    }
+    Ok(())
+}
+
 pub async fn run(
     command: Commands,
     ctx: OutputContext,
@@ -1101,20 +1592,48 @@ pub async fn run(
         Commands::Pr(pr_cmd) => run_pr_command(pr_cmd, ctx, config, inferred_repo).await,
         Commands::Models(models_cmd) => run_models_command(models_cmd, ctx).await,
         Commands::Completion(completion_cmd) => run_completion_command(&completion_cmd, ctx),
+        Commands::Graph(graph_cmd) => run_graph_command(graph_cmd, &ctx, config),
+        Commands::Tests(tests_cmd) => run_tests_command(tests_cmd, &ctx),
+        Commands::Triage(TriageCommand::Rules(TriageRulesCommand::Test { file, rules })) => {
+            let result = triage_rules::run_test(&file, rules.as_deref())?;
+            output::render(&result, &ctx)
+        }
+        Commands::ScanSecurity {
+            command: Some(ScanSecurityCommand::Hook(HookCommand::Install { fail_on, force })),
+            ..
+        } => scan_security::run_hook_install(&fail_on, force),
         Commands::ScanSecurity {
+            command: None,
             path,
             diff,
+            staged,
+            since,
             fail_on,
             exclude,
+            max_file_size,
+            no_gitignore,
             sarif_output,
+            validate,
         } => {
+            let options = aptu_core::security::ScanOptions {
+                max_file_size,
+                respect_gitignore: !no_gitignore,
+                exclude,
+            };
+            let git = if staged {
+                Some(scan_security::GitScan::Staged)
+            } else {
+                since.map(scan_security::GitScan::Since)
+            };
             scan_security::run_scan_security_command(
                 path,
                 diff,
+                git,
                 fail_on,
-                exclude,
+                options,
                 ctx.format,
                 sarif_output,
+                validate,
                 config,
             )
             .await
@@ -1127,6 +1646,33 @@ mod tests {
     use crate::cli::{OutputContext, OutputFormat};
     use crate::commands::types::{AuthActionResult, RepoMutateResult};
 
+    #[test]
+    fn test_failed_issue_numbers_come_from_failed_outcomes() {
+        use crate::commands::types::{BulkTriageResult, SingleTriageOutcome};
+
+        let result = BulkTriageResult {
+            succeeded: 0,
+            failed: 2,
+            skipped: 1,
+            outcomes: vec![
+                (
+                    "octo/repo#7".to_string(),
+                    SingleTriageOutcome::Failed("rate limited".to_string()),
+                ),
+                (
+                    "octo/repo#8".to_string(),
+                    SingleTriageOutcome::Skipped("already triaged".to_string()),
+                ),
+                (
+                    "octo/repo#12".to_string(),
+                    SingleTriageOutcome::Failed("timeout".to_string()),
+                ),
+            ],
+        };
+
+        assert_eq!(super::failed_issue_numbers(&result), vec![7, 12]);
+    }
+
     // UX-006/007: AuthActionResult renders correct text
     #[test]
     fn test_auth_action_result_render_text() {
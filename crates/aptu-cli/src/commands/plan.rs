@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Plan an issue's implementation command.
+//!
+//! Locates the code an issue concerns in a local checkout, asks the AI for a
+//! step plan naming concrete files and functions, and optionally writes a
+//! unified diff for `aptu pr create --diff`.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use tracing::{info, instrument};
+
+use super::common::maybe_spinner;
+use super::types::PlanResult;
+use crate::cli::OutputContext;
+use crate::provider::CliTokenProvider;
+
+/// Plan the implementation of an issue.
+///
+/// # Arguments
+///
+/// * `reference` - Issue reference (URL, owner/repo#number, or number)
+/// * `repo_context` - Repository for bare issue numbers
+/// * `repo_path` - Local checkout of the repository
+/// * `diff_path` - Where to write a diff implementing the plan, if requested
+#[instrument(skip_all, fields(reference = %reference, repo_path = %repo_path.display()))]
+pub async fn run(
+    ctx: &OutputContext,
+    config: &aptu_core::AppConfig,
+    reference: &str,
+    repo_context: Option<&str>,
+    repo_path: &Path,
+    diff_path: Option<&Path>,
+) -> Result<PlanResult> {
+    if !repo_path.is_dir() {
+        bail!(
+            "Repository path is not a directory: {}",
+            repo_path.display()
+        );
+    }
+    let provider = CliTokenProvider;
+
+    let spinner = maybe_spinner(ctx, "Fetching issue...");
+    let issue = aptu_core::fetch_issue_for_plan(&provider, reference, repo_context)
+        .await
+        .context("Failed to fetch issue")?;
+    if let Some(s) = spinner {
+        s.finish_and_clear();
+    }
+
+    let spinner = maybe_spinner(ctx, "Planning implementation...");
+    let (plan, symbols, _ai_stats) = aptu_core::facade::plan_issue(
+        &provider,
+        &issue,
+        repo_path,
+        &config.ai,
+        diff_path.is_some(),
+    )
+    .await
+    .context("Failed to plan implementation")?;
+    if let Some(s) = spinner {
+        s.finish_and_clear();
+    }
+    info!(
+        steps = plan.steps.len(),
+        symbols = symbols.len(),
+        "Implementation plan ready"
+    );
+
+    if let Some(path) = diff_path {
+        std::fs::write(path, &plan.diff)
+            .with_context(|| format!("Failed to write diff: {}", path.display()))?;
+    }
+
+    Ok(PlanResult {
+        repo: format!("{}/{}", issue.owner, issue.repo),
+        issue_number: issue.number,
+        issue_title: issue.title,
+        summary: plan.summary,
+        steps: plan.steps,
+        symbols,
+        diff_path: diff_path.map(|p| p.display().to_string()),
+    })
+}
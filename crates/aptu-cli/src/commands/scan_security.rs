@@ -2,115 +2,117 @@
 
 //! `scan-security` subcommand: scan a local file or directory for security issues.
 
-use std::io::Read;
-use std::path::{Path, PathBuf};
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 use anyhow::{Context, Result};
-use aptu_core::{AppConfig, Finding, PatternEngine, SarifReport, SecurityScanner};
-use walkdir::WalkDir;
+use aptu_core::security::sarif::SarifInvocation;
+use aptu_core::security::{CommitFindings, ScanOptions, ScanStats, ValidatedFinding};
+use aptu_core::{
+    AppConfig, Finding, FindingCache, PatternEngine, SarifReport, SecurityScanner, Severity,
+};
+use chrono::{DateTime, Utc};
 
+use super::common::read_diff_input;
 use crate::cli::OutputFormat;
+use crate::output::render_findings_report;
+use crate::provider::CliTokenProvider;
 
-/// Maximum allowed size for a diff input (5 MiB).
-const DIFF_SIZE_LIMIT: usize = 5_242_880;
+/// Marker line identifying a pre-commit hook written by `aptu`.
+const HOOK_MARKER: &str = "# Installed by aptu scan-security hook install";
+
+/// Git-backed scan modes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitScan {
+    /// Scan changes staged in the index.
+    Staged,
+    /// Scan every commit in `REV..HEAD`.
+    Since(String),
+}
 
 /// Run the `scan-security` subcommand.
 ///
 /// When `diff` is provided, reads a unified diff from a file path or stdin (`-`),
 /// enforces a 5 MiB size limit, and calls `scanner.scan_diff()`.
-/// When `path` is provided, walks the file or directory and calls `scanner.scan_file()`.
+/// When `git` is provided, scans staged changes or each commit in a range of
+/// the repository in the current directory.
+/// When `path` is provided, scans the file or directory in parallel with
+/// `scanner.scan_path()`, honouring `.gitignore`/`.aptuignore` and the size and
+/// exclusion limits in `options`.
+///
+/// When `validate` is set, findings are checked by the configured AI model
+/// (verdicts are cached across runs). Findings judged false positives stay in
+/// JSON, YAML, SARIF, and text output with their verdict, but are dropped from
+/// the other formats and do not count towards `--fail-on`.
 ///
 /// Findings are emitted in the requested `output_format`. When `sarif_output` is
 /// provided, a SARIF report is additionally written to that file (before the
 /// `--fail-on` exit evaluation) so the report survives a non-zero exit.
-#[allow(clippy::unused_async)]
+#[allow(clippy::too_many_arguments)]
 pub async fn run_scan_security_command(
     path: Option<PathBuf>,
     diff: Option<PathBuf>,
+    git: Option<GitScan>,
     fail_on: Vec<String>,
-    exclude: Vec<String>,
+    options: ScanOptions,
     output_format: OutputFormat,
     sarif_output: Option<PathBuf>,
-    _config: &AppConfig,
+    validate: bool,
+    config: &AppConfig,
 ) -> Result<()> {
+    let started = Utc::now();
     let scanner = SecurityScanner::default();
     let mut findings: Vec<Finding> = Vec::new();
+    let mut commits: Option<Vec<CommitFindings>> = None;
 
     if let Some(diff_path) = diff {
-        // Diff mode: read from file or stdin
-        let content = if diff_path == Path::new("-") {
-            let mut buf = String::new();
-            std::io::stdin()
-                .take((DIFF_SIZE_LIMIT + 1) as u64)
-                .read_to_string(&mut buf)
-                .map_err(|e| anyhow::anyhow!("Failed to read stdin: {e}"))?;
-            buf
-        } else {
-            let meta = std::fs::metadata(&diff_path)
-                .map_err(|e| anyhow::anyhow!("Cannot stat '{}': {e}", diff_path.display()))?;
-            if meta.len() > DIFF_SIZE_LIMIT as u64 {
-                return Err(anyhow::anyhow!(
-                    "Diff file '{}' exceeds the 5 MiB limit ({} bytes)",
-                    diff_path.display(),
-                    meta.len()
-                ));
+        let content = read_diff_input(&diff_path)?;
+        findings.extend(scanner.scan_diff(&content));
+    } else if let Some(git) = git {
+        let cwd = std::env::current_dir().context("Failed to get current directory")?;
+        match git {
+            GitScan::Staged => findings = scanner.scan_staged(&cwd)?,
+            GitScan::Since(rev) => {
+                let results = scanner.scan_commit_range(&cwd, &rev)?;
+                findings = results
+                    .iter()
+                    .flat_map(|c| c.findings.iter().cloned())
+                    .collect();
+                commits = Some(results);
             }
-            std::fs::read_to_string(&diff_path)
-                .map_err(|e| anyhow::anyhow!("Failed to read '{}': {e}", diff_path.display()))?
-        };
-
-        if content.len() > DIFF_SIZE_LIMIT {
-            return Err(anyhow::anyhow!(
-                "Diff input exceeds the 5 MiB limit ({} bytes)",
-                content.len()
-            ));
         }
-
-        findings.extend(scanner.scan_diff(&content));
     } else {
         // Walk mode: path is guaranteed present by Clap (required_unless_present = "diff")
         let scan_path = path
             .ok_or_else(|| anyhow::anyhow!("internal: path required when --diff not provided"))?;
 
-        for entry in WalkDir::new(&scan_path)
-            .follow_links(false)
-            .into_iter()
-            .filter_map(std::result::Result::ok)
-        {
-            if entry.file_type().is_dir() {
-                continue;
-            }
-
-            let file_path = entry.path();
-            let file_path_str = file_path.to_string_lossy();
-
-            // Apply --exclude prefix filter
-            if exclude
-                .iter()
-                .any(|prefix| file_path_str.starts_with(prefix.as_str()))
-            {
-                continue;
-            }
-
-            // Read file content; skip files that cannot be read (binary, permission denied, etc.)
-            let Ok(content) = std::fs::read_to_string(file_path) else {
-                continue;
-            };
-
-            let file_findings = scanner.scan_file(&content, &file_path_str);
-            findings.extend(file_findings);
-        }
+        let scan = scanner.scan_path(&scan_path, &options);
+        report_stats(&scan.stats, output_format);
+        findings = scan.findings;
     }
 
+    let validations = if validate && !findings.is_empty() {
+        Some(validate_findings(&findings, config).await?)
+    } else {
+        None
+    };
+
     // Emit findings in the requested format; SARIF report file is written
     // before the --fail-on evaluation below so the report survives a non-zero exit.
-    emit_output(output_format, sarif_output, &findings)?;
+    emit_output(
+        output_format,
+        sarif_output,
+        &findings,
+        commits.as_deref(),
+        validations.as_deref(),
+        started,
+    )?;
 
     // Exit 1 if any finding severity matches --fail-on list
     if !fail_on.is_empty() {
         let fail_severities: Vec<String> = fail_on.iter().map(|s| s.to_lowercase()).collect();
 
-        let should_fail = findings
+        let should_fail = confirmed_findings(&findings, validations.as_deref())
             .iter()
             .any(|f| fail_severities.iter().any(|s| s == f.severity.as_str()));
 
@@ -122,19 +124,119 @@ pub async fn run_scan_security_command(
     Ok(())
 }
 
+/// Validate findings with the configured AI model, reusing cached verdicts.
+///
+/// Files referenced by findings are read from the working tree for context;
+/// files that no longer exist (e.g. in history scans) are validated without it.
+async fn validate_findings(
+    findings: &[Finding],
+    config: &AppConfig,
+) -> Result<Vec<ValidatedFinding>> {
+    let mut file_contents = HashMap::new();
+    for finding in findings {
+        if !file_contents.contains_key(&finding.file_path)
+            && let Ok(content) = std::fs::read_to_string(&finding.file_path)
+        {
+            file_contents.insert(finding.file_path.clone(), content);
+        }
+    }
+
+    let cache = FindingCache::new();
+    let validated = aptu_core::validate_security_findings(
+        &CliTokenProvider,
+        findings,
+        &file_contents,
+        &config.ai,
+        Some(&cache),
+    )
+    .await?;
+
+    let dismissed = validated.iter().filter(|v| !v.is_valid).count();
+    tracing::info!(
+        validated = validated.len(),
+        dismissed,
+        "Security findings validated"
+    );
+    Ok(validated)
+}
+
+/// Findings that count as real issues: all findings, or only those the
+/// validator confirmed when validation ran.
+fn confirmed_findings(
+    findings: &[Finding],
+    validations: Option<&[ValidatedFinding]>,
+) -> Vec<Finding> {
+    match validations {
+        Some(validations) => validations
+            .iter()
+            .filter(|v| v.is_valid)
+            .map(|v| v.finding.clone())
+            .collect(),
+        None => findings.to_vec(),
+    }
+}
+
+/// Report directory scan statistics.
+///
+/// Text output prints a summary line to stderr; structured formats only log it
+/// so stdout stays machine-readable.
+fn report_stats(stats: &ScanStats, output_format: OutputFormat) {
+    tracing::info!(
+        files_scanned = stats.files_scanned,
+        skipped_binary = stats.files_skipped_binary,
+        skipped_size = stats.files_skipped_size,
+        skipped_unreadable = stats.files_skipped_unreadable,
+        bytes_scanned = stats.bytes_scanned,
+        elapsed_ms = stats.elapsed.as_millis(),
+        "Security scan complete"
+    );
+    if matches!(output_format, OutputFormat::Text | OutputFormat::Markdown) {
+        let skipped =
+            stats.files_skipped_binary + stats.files_skipped_size + stats.files_skipped_unreadable;
+        eprintln!(
+            "Scanned {} file{} in {:.2}s ({} skipped: {} binary, {} too large, {} unreadable)",
+            stats.files_scanned,
+            if stats.files_scanned == 1 { "" } else { "s" },
+            stats.elapsed.as_secs_f64(),
+            skipped,
+            stats.files_skipped_binary,
+            stats.files_skipped_size,
+            stats.files_skipped_unreadable
+        );
+    }
+}
+
 /// Emit findings in the requested output format and write a SARIF report
 /// to `sarif_output` if provided.
+///
+/// The SARIF run records `started` and the current time as its invocation
+/// window and resolves relative finding paths against the working directory.
+/// When `commits` is provided (history scans), text output is grouped by commit
+/// and JSON/YAML output lists findings per commit.
+/// When `validations` is provided, JSON/YAML list validated findings, SARIF
+/// results carry the verdict as properties, text output shows the verdict under
+/// each finding, and the remaining formats include confirmed findings only.
 fn emit_output(
     output_format: OutputFormat,
     sarif_output: Option<PathBuf>,
     findings: &[Finding],
+    commits: Option<&[CommitFindings]>,
+    validations: Option<&[ValidatedFinding]>,
+    started: DateTime<Utc>,
 ) -> Result<()> {
     // Build the SARIF report exactly once, whether it is the requested
     // output format or only written to the `sarif_output` file.
     let sarif_json = if matches!(output_format, OutputFormat::Sarif) || sarif_output.is_some() {
         let engine = PatternEngine::from_embedded_json()?;
         let patterns = engine.definitions();
-        let report = SarifReport::with_rules(findings.to_vec(), &patterns);
+        let mut report = SarifReport::with_rules(findings.to_vec(), &patterns)
+            .with_invocation(&SarifInvocation::completed(started, Utc::now()));
+        if let Some(validations) = validations {
+            report = report.with_validations(validations);
+        }
+        if let Ok(cwd) = std::env::current_dir() {
+            report = report.with_source_root(&cwd);
+        }
         Some(
             serde_json::to_string_pretty(&report)
                 .map_err(|e| anyhow::anyhow!("Failed to serialize SARIF: {e}"))?,
@@ -143,6 +245,8 @@ fn emit_output(
         None
     };
 
+    let verdict = |i: usize| validations.and_then(|v| v.get(i));
+
     match output_format {
         OutputFormat::Sarif => {
             // Guaranteed present because output_format is Sarif.
@@ -151,7 +255,7 @@ fn emit_output(
             }
         }
         OutputFormat::GithubAnnotations => {
-            for f in findings {
+            for f in &confirmed_findings(findings, validations) {
                 println!(
                     "::error file={},line={},title={}::{}",
                     f.file_path, f.line_number, f.pattern_id, f.description
@@ -159,29 +263,52 @@ fn emit_output(
             }
         }
         OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(findings)
-                .map_err(|e| anyhow::anyhow!("Failed to serialize findings to JSON: {e}"))?;
+            let json = match (validations, commits) {
+                (Some(validations), _) => serde_json::to_string_pretty(validations),
+                (None, Some(commits)) => serde_json::to_string_pretty(commits),
+                (None, None) => serde_json::to_string_pretty(findings),
+            }
+            .map_err(|e| anyhow::anyhow!("Failed to serialize findings to JSON: {e}"))?;
             println!("{json}");
         }
         OutputFormat::Yaml => {
-            let yaml = serde_saphyr::to_string(&findings.to_vec())
-                .map_err(|e| anyhow::anyhow!("Failed to serialize findings to YAML: {e}"))?;
+            let yaml = match (validations, commits) {
+                (Some(validations), _) => serde_saphyr::to_string(&validations.to_vec()),
+                (None, Some(commits)) => serde_saphyr::to_string(&commits.to_vec()),
+                (None, None) => serde_saphyr::to_string(&findings.to_vec()),
+            }
+            .map_err(|e| anyhow::anyhow!("Failed to serialize findings to YAML: {e}"))?;
             println!("{yaml}");
         }
+        OutputFormat::CodeClimate | OutputFormat::GitlabSast | OutputFormat::Junit => {
+            let confirmed = confirmed_findings(findings, validations);
+            if let Some(report) = render_findings_report(output_format, &confirmed, started)? {
+                println!("{report}");
+            }
+        }
         OutputFormat::Text | OutputFormat::Markdown => {
             if findings.is_empty() {
                 println!("No security findings.");
+            } else if let Some(commits) = commits {
+                println!(
+                    "Security findings ({}) in {} commit{}:",
+                    findings.len(),
+                    commits.len(),
+                    if commits.len() == 1 { "" } else { "s" }
+                );
+                let mut index = 0;
+                for commit in commits {
+                    let short = commit.commit.get(..12).unwrap_or(&commit.commit);
+                    println!("  {short} {}", commit.summary);
+                    for f in &commit.findings {
+                        print_finding_line("    ", f, verdict(index));
+                        index += 1;
+                    }
+                }
             } else {
                 println!("Security findings ({}):", findings.len());
-                for f in findings {
-                    println!(
-                        "  [{}] {} ({}:{}): {}",
-                        f.severity.as_str().to_uppercase(),
-                        f.pattern_id,
-                        f.file_path,
-                        f.line_number,
-                        f.description
-                    );
+                for (i, f) in findings.iter().enumerate() {
+                    print_finding_line("  ", f, verdict(i));
                 }
             }
         }
@@ -199,6 +326,105 @@ fn emit_output(
     Ok(())
 }
 
+/// Print one finding as a text line, followed by its validation verdict if any.
+fn print_finding_line(indent: &str, f: &Finding, verdict: Option<&ValidatedFinding>) {
+    println!(
+        "{indent}[{}] {} ({}:{}): {}",
+        f.severity.as_str().to_uppercase(),
+        f.pattern_id,
+        f.file_path,
+        f.line_number,
+        f.description
+    );
+    if let Some(v) = verdict {
+        let label = if v.is_valid {
+            "confirmed"
+        } else {
+            "likely false positive"
+        };
+        println!("{indent}    AI: {label}: {}", v.reasoning);
+    }
+}
+
+/// Render the pre-commit hook script.
+///
+/// `fail_on` is interpolated into a shell script, so each value must be a
+/// known severity name (case-insensitive); anything else is rejected.
+fn pre_commit_hook_script(fail_on: &[String]) -> Result<String> {
+    const SEVERITIES: [Severity; 4] = [
+        Severity::Critical,
+        Severity::High,
+        Severity::Medium,
+        Severity::Low,
+    ];
+
+    let severities = fail_on
+        .iter()
+        .map(|value| {
+            let value = value.trim().to_lowercase();
+            SEVERITIES
+                .iter()
+                .map(Severity::as_str)
+                .find(|name| *name == value)
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Invalid --fail-on severity '{value}' (expected one of: critical, high, medium, low)"
+                    )
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let fail_on = if severities.is_empty() {
+        "critical,high".to_string()
+    } else {
+        severities.join(",")
+    };
+    Ok(format!(
+        "#!/bin/sh\n{HOOK_MARKER}\n# Blocks commits that add {fail_on} security findings.\n# Bypass once with: git commit --no-verify\nexec aptu scan-security --staged --fail-on {fail_on}\n"
+    ))
+}
+
+/// Install a git pre-commit hook that runs `aptu scan-security --staged`.
+///
+/// The hook is written to the repository's hooks directory (honouring
+/// `core.hooksPath`). An existing hook not written by `aptu` is only replaced
+/// with `force`.
+pub fn run_hook_install(fail_on: &[String], force: bool) -> Result<()> {
+    let script = pre_commit_hook_script(fail_on)?;
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "--git-path", "hooks"])
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        anyhow::bail!("Not inside a git repository");
+    }
+    let hooks_dir = PathBuf::from(String::from_utf8_lossy(&output.stdout).trim());
+    let hook_path = hooks_dir.join("pre-commit");
+
+    if let Ok(existing) = std::fs::read_to_string(&hook_path)
+        && !existing.contains(HOOK_MARKER)
+        && !force
+    {
+        anyhow::bail!(
+            "A pre-commit hook already exists at {}. Re-run with --force to replace it.",
+            hook_path.display()
+        );
+    }
+
+    std::fs::create_dir_all(&hooks_dir)
+        .with_context(|| format!("Failed to create {}", hooks_dir.display()))?;
+    std::fs::write(&hook_path, script)
+        .with_context(|| format!("Failed to write {}", hook_path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&hook_path, std::fs::Permissions::from_mode(0o755))
+            .with_context(|| format!("Failed to make {} executable", hook_path.display()))?;
+    }
+
+    println!("Installed pre-commit hook at {}", hook_path.display());
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,12 +436,14 @@ mod tests {
     async fn run_scan_security_errors_when_path_and_diff_missing() {
         // Arrange / Act: no diff and no path supplied
         let result = run_scan_security_command(
+            None,
             None,
             None,
             Vec::new(),
-            Vec::new(),
+            ScanOptions::default(),
             OutputFormat::Text,
             None,
+            false,
             &AppConfig::default(),
         )
         .await;
@@ -228,4 +456,54 @@ mod tests {
             "unexpected error: {err}"
         );
     }
+
+    #[test]
+    fn pre_commit_hook_script_runs_staged_scan() {
+        let script = pre_commit_hook_script(&["critical".to_string(), "High".to_string()]).unwrap();
+
+        assert!(script.starts_with("#!/bin/sh\n"));
+        assert!(script.contains(HOOK_MARKER));
+        assert!(script.contains("exec aptu scan-security --staged --fail-on critical,high\n"));
+    }
+
+    #[test]
+    fn pre_commit_hook_script_rejects_unknown_severities() {
+        let err = pre_commit_hook_script(&["high".to_string(), "low; rm -rf ~".to_string()])
+            .expect_err("expected an invalid severity error");
+
+        assert!(
+            err.to_string().contains("Invalid --fail-on severity"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn confirmed_findings_drops_dismissed_verdicts() {
+        let real = Finding {
+            pattern_id: "real".to_string(),
+            ..Finding::default()
+        };
+        let dismissed = Finding {
+            pattern_id: "dismissed".to_string(),
+            ..Finding::default()
+        };
+        let validations = vec![
+            ValidatedFinding {
+                finding: real.clone(),
+                is_valid: true,
+                ..ValidatedFinding::default()
+            },
+            ValidatedFinding {
+                finding: dismissed.clone(),
+                is_valid: false,
+                ..ValidatedFinding::default()
+            },
+        ];
+        let findings = vec![real, dismissed];
+
+        let confirmed = confirmed_findings(&findings, Some(&validations));
+        assert_eq!(confirmed.len(), 1);
+        assert_eq!(confirmed[0].pattern_id, "real");
+        assert_eq!(confirmed_findings(&findings, None).len(), 2);
+    }
 }
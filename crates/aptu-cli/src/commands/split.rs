@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Split an issue into sub-issues command.
+//!
+//! Asks the AI for a task breakdown of a large issue, previews it, and after
+//! confirmation (or immediately with `--apply`) creates the child issues and
+//! links them to the parent.
+
+use anyhow::{Context, Result};
+use console::style;
+use dialoguer::Confirm;
+use tracing::{info, instrument};
+
+use super::common::maybe_spinner;
+use super::types::SplitResult;
+use crate::cli::OutputContext;
+use crate::provider::CliTokenProvider;
+use aptu_core::IssueSplitResponse;
+use aptu_core::history::ContributionStatus;
+
+/// Split an issue into linked sub-issues.
+///
+/// Without `--apply`, the breakdown is confirmed interactively; in
+/// non-interactive sessions it is only previewed.
+///
+/// # Arguments
+///
+/// * `reference` - Issue reference (URL, owner/repo#number, or number)
+/// * `repo_context` - Repository for bare issue numbers
+/// * `apply` - Create the sub-issues without asking
+/// * `dry_run` - Preview the breakdown without creating anything
+#[instrument(skip_all, fields(reference = %reference, apply, dry_run))]
+pub async fn run(
+    ctx: &OutputContext,
+    config: &aptu_core::AppConfig,
+    reference: &str,
+    repo_context: Option<&str>,
+    apply: bool,
+    dry_run: bool,
+) -> Result<SplitResult> {
+    let provider = CliTokenProvider;
+
+    let spinner = maybe_spinner(ctx, "Fetching issue...");
+    let mut issue = aptu_core::fetch_issue_for_triage(&provider, reference, repo_context)
+        .await
+        .context("Failed to fetch issue")?;
+    issue.prior_triage = aptu_core::prior_triage(&issue);
+    if let Some(s) = spinner {
+        s.finish_and_clear();
+    }
+
+    let spinner = maybe_spinner(ctx, "Planning sub-issues...");
+    let (plan, ai_stats) = aptu_core::facade::plan_issue_split(&provider, &issue, &config.ai)
+        .await
+        .context("Failed to plan sub-issues")?;
+    if let Some(s) = spinner {
+        s.finish_and_clear();
+    }
+    info!(tasks = plan.tasks.len(), "Sub-issue breakdown ready");
+
+    let mut result = SplitResult {
+        repo: format!("{}/{}", issue.owner, issue.repo),
+        issue_number: issue.number,
+        issue_title: issue.title.clone(),
+        tasks: plan.tasks.clone(),
+        rationale: plan.rationale.clone(),
+        created: Vec::new(),
+        linked: false,
+        tasklist_url: None,
+        warnings: Vec::new(),
+        dry_run,
+    };
+
+    let proceed =
+        !dry_run && (apply || (ctx.is_interactive() && confirm_split(issue.number, &plan)?));
+    if !proceed {
+        return Ok(result);
+    }
+
+    let spinner = maybe_spinner(ctx, "Creating sub-issues...");
+    let outcome = aptu_core::facade::create_sub_issues(
+        &provider,
+        &issue.owner,
+        &issue.repo,
+        issue.number,
+        &plan,
+    )
+    .await
+    .context("Failed to create sub-issues")?;
+    if let Some(s) = spinner {
+        s.finish_and_clear();
+    }
+
+    for child in &outcome.created {
+        aptu_core::history::add_contribution(aptu_core::history::Contribution {
+            id: uuid::Uuid::new_v4(),
+            repo: result.repo.clone(),
+            issue: child.number,
+            action: "split".to_string(),
+            timestamp: chrono::Utc::now(),
+            comment_url: child.url.clone(),
+            status: ContributionStatus::default(),
+            ai_stats: Some(ai_stats.clone()),
+        })
+        .ok();
+    }
+
+    result.created = outcome.created;
+    result.linked = outcome.linked;
+    result.tasklist_url = outcome.tasklist_url;
+    result.warnings = outcome.warnings;
+    Ok(result)
+}
+
+/// Show the proposed breakdown and ask whether to create it.
+fn confirm_split(parent: u64, plan: &IssueSplitResponse) -> Result<bool> {
+    println!();
+    for (index, task) in plan.tasks.iter().enumerate() {
+        println!(
+            "{} {}",
+            style(format!("{}.", index + 1)).green().bold(),
+            task.title
+        );
+        if !task.depends_on.is_empty() {
+            let deps: Vec<String> = task.depends_on.iter().map(ToString::to_string).collect();
+            println!("   {}", style(format!("after {}", deps.join(", "))).dim());
+        }
+    }
+    if !plan.rationale.is_empty() {
+        println!("{}", style(&plan.rationale).dim());
+    }
+    Confirm::new()
+        .with_prompt(format!(
+            "Create {} sub-issue(s) of #{parent}?",
+            plan.tasks.len()
+        ))
+        .default(false)
+        .interact()
+        .context("Failed to get user confirmation")
+}
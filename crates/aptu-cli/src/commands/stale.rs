@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Stale issue and PR sweeper command.
+//!
+//! Finds items inactive past their configured window and, with `--apply`,
+//! posts AI-written nudges, applies the stale label, closes items whose grace
+//! period has passed, and unmarks items that saw activity. Without `--apply`
+//! the proposed actions are only listed.
+
+use anyhow::{Context, Result};
+use aptu_core::config::StaleConfig;
+use aptu_core::facade::StaleCandidate;
+use aptu_core::github::stale::{StaleAction, StaleKind};
+use aptu_core::history::ContributionStatus;
+use tracing::{info, instrument};
+
+use super::common::maybe_spinner;
+use super::types::{StaleOutcome, StaleSweepResult};
+use crate::cli::OutputContext;
+use crate::provider::CliTokenProvider;
+
+/// Sweep a repository's issues or pull requests for stale items.
+///
+/// # Arguments
+///
+/// * `owner` / `repo` - Repository to sweep
+/// * `kind` - Issues or pull requests
+/// * `stale_config` - Stale settings with any CLI overrides applied
+/// * `limit` - Maximum number of items to act on
+/// * `apply` - Act on items instead of listing them
+#[instrument(skip_all, fields(owner = %owner, repo = %repo, kind = ?kind, apply))]
+#[allow(clippy::too_many_arguments)]
+pub async fn run(
+    ctx: &OutputContext,
+    config: &aptu_core::AppConfig,
+    owner: &str,
+    repo: &str,
+    kind: StaleKind,
+    stale_config: StaleConfig,
+    limit: Option<usize>,
+    apply: bool,
+) -> Result<StaleSweepResult> {
+    let provider = CliTokenProvider;
+
+    let spinner = maybe_spinner(ctx, &format!("Finding stale {}s...", kind.noun()));
+    let mut candidates =
+        aptu_core::facade::find_stale_items(&provider, owner, repo, kind, &stale_config)
+            .await
+            .context("Failed to find stale items")?;
+    if let Some(s) = spinner {
+        s.finish_and_clear();
+    }
+    if let Some(limit) = limit {
+        candidates.truncate(limit);
+    }
+    info!(count = candidates.len(), "Found stale candidates");
+
+    let mut result = StaleSweepResult {
+        repo: format!("{owner}/{repo}"),
+        kind,
+        dry_run: !apply,
+        succeeded: 0,
+        failed: 0,
+        outcomes: Vec::new(),
+    };
+
+    if !apply {
+        result.outcomes = candidates
+            .into_iter()
+            .map(|c| outcome(&c, None, None))
+            .collect();
+        return Ok(result);
+    }
+
+    let items: Vec<(String, StaleCandidate)> = candidates
+        .iter()
+        .map(|c| (format!("#{}", c.item.number), c.clone()))
+        .collect();
+
+    let ctx_for_progress = ctx.clone();
+    let owner_owned = owner.to_string();
+    let repo_owned = repo.to_string();
+    let ai_config = config.ai.clone();
+
+    let core_result = aptu_core::process_bulk(
+        items,
+        move |(_, candidate)| {
+            let owner = owner_owned.clone();
+            let repo = repo_owned.clone();
+            let stale_config = stale_config.clone();
+            let ai_config = ai_config.clone();
+            async move {
+                let applied = aptu_core::facade::apply_stale_action(
+                    &CliTokenProvider,
+                    &owner,
+                    &repo,
+                    kind,
+                    &candidate,
+                    &stale_config,
+                    &ai_config,
+                )
+                .await?;
+
+                aptu_core::history::add_contribution(aptu_core::history::Contribution {
+                    id: uuid::Uuid::new_v4(),
+                    repo: format!("{owner}/{repo}"),
+                    issue: candidate.item.number,
+                    action: action_name(candidate.action).to_string(),
+                    timestamp: chrono::Utc::now(),
+                    comment_url: applied.comment_url.clone().unwrap_or_default(),
+                    status: ContributionStatus::default(),
+                    ai_stats: applied.ai_stats,
+                })
+                .ok();
+
+                Ok(Some(outcome(&candidate, applied.comment_url, None)))
+            }
+        },
+        move |current, total, action| {
+            crate::output::common::show_progress(&ctx_for_progress, current, total, action);
+        },
+    )
+    .await;
+
+    result.succeeded = core_result.succeeded;
+    result.failed = core_result.failed;
+    // Report outcomes in candidate order; bulk processing completes out of order
+    let mut outcomes: Vec<(usize, StaleOutcome)> = core_result
+        .outcomes
+        .into_iter()
+        .filter_map(|(id, bulk_outcome)| {
+            let idx = candidates
+                .iter()
+                .position(|c| format!("#{}", c.item.number) == id)?;
+            match bulk_outcome {
+                aptu_core::BulkOutcome::Success(outcome) => Some((idx, outcome)),
+                aptu_core::BulkOutcome::Skipped(_) => None,
+                aptu_core::BulkOutcome::Failed(err) => {
+                    Some((idx, outcome(&candidates[idx], None, Some(err))))
+                }
+            }
+        })
+        .collect();
+    outcomes.sort_by_key(|(idx, _)| *idx);
+    result.outcomes = outcomes.into_iter().map(|(_, outcome)| outcome).collect();
+
+    Ok(result)
+}
+
+/// Applies `--days` and `--close-after` overrides to the configured stale settings.
+pub fn with_overrides(
+    config: &StaleConfig,
+    days: Option<u32>,
+    close_after: Option<u32>,
+) -> StaleConfig {
+    let mut config = config.clone();
+    if let Some(days) = days {
+        config.days_until_stale = days;
+    }
+    if let Some(close_after) = close_after {
+        config.days_until_close = close_after;
+    }
+    config
+}
+
+/// History action name for a stale action.
+fn action_name(action: StaleAction) -> &'static str {
+    match action {
+        StaleAction::Nudge { .. } => "stale-nudge",
+        StaleAction::Close { .. } => "stale-close",
+        StaleAction::Unmark => "stale-unmark",
+    }
+}
+
+/// Builds the reported outcome for a candidate.
+fn outcome(
+    candidate: &StaleCandidate,
+    comment_url: Option<String>,
+    error: Option<String>,
+) -> StaleOutcome {
+    StaleOutcome {
+        number: candidate.item.number,
+        title: candidate.item.title.clone(),
+        url: candidate.item.url.clone(),
+        action: candidate.action,
+        comment_url,
+        error,
+    }
+}
@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! `tests affected` subcommand: select the tests a diff exercises.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use aptu_core::graph::{query, repo};
+
+use super::common::read_diff_input;
+use super::graph::summarize_node;
+use super::types::AffectedTestsResult;
+use crate::cli::TestFilter;
+
+/// Where the diff to analyze comes from.
+pub enum DiffSource {
+    /// A unified diff file, or `-` for stdin.
+    File(std::path::PathBuf),
+    /// `git diff <rev>` in the repository.
+    Since(String),
+    /// `git diff HEAD` in the repository (uncommitted changes).
+    WorkingTree,
+}
+
+/// List the tests that transitively exercise the symbols a diff touches.
+///
+/// Symbols are resolved against the working tree at `repo_path`, and the
+/// graph is assembled from the cached per-file index for this checkout.
+pub fn run_affected(source: &DiffSource, repo_path: &Path) -> Result<AffectedTestsResult> {
+    if !repo_path.is_dir() {
+        bail!("Repository path {} is not a directory", repo_path.display());
+    }
+    let diff = match source {
+        DiffSource::File(path) => read_diff_input(path)?,
+        DiffSource::Since(rev) => git_diff(repo_path, rev)?,
+        DiffSource::WorkingTree => git_diff(repo_path, "HEAD")?,
+    };
+
+    let symbols = repo::diff_symbols(repo_path, &diff);
+    let mut graph = repo::load_local_graph(repo_path);
+    let names: Vec<&str> = symbols.iter().map(String::as_str).collect();
+    let modified = query::find_modified_nodes(&mut graph, &names);
+
+    Ok(AffectedTestsResult {
+        tests: query::affected_tests(&graph, &modified)
+            .into_iter()
+            .map(|idx| summarize_node(&graph[idx]))
+            .collect(),
+        symbols,
+    })
+}
+
+/// Formats affected tests as a test runner invocation, or `None` if there are
+/// no tests to run.
+///
+/// Test methods are named `Type::method` in the graph; pytest's `-k` only
+/// accepts Python identifiers, so they are reduced to the method name.
+#[must_use]
+pub fn runner_filter(result: &AffectedTestsResult, filter: TestFilter) -> Option<String> {
+    let mut names: Vec<&str> = Vec::new();
+    for test in &result.tests {
+        let name = match filter {
+            TestFilter::Cargo => test.name.as_str(),
+            TestFilter::Pytest => test.name.rsplit("::").next().unwrap_or(&test.name),
+        };
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    if names.is_empty() {
+        return None;
+    }
+    Some(match filter {
+        TestFilter::Cargo => format!("cargo test -- {}", names.join(" ")),
+        TestFilter::Pytest => format!("pytest -k \"{}\"", names.join(" or ")),
+    })
+}
+
+/// Run `git diff <rev>` in `repo_path` and return its output.
+fn git_diff(repo_path: &Path, rev: &str) -> Result<String> {
+    let output = std::process::Command::new("git")
+        .args(["diff", "--no-color", "--no-ext-diff", rev, "--"])
+        .current_dir(repo_path)
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        bail!(
+            "git diff {rev} failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::types::GraphNodeSummary;
+
+    fn result(names: &[&str]) -> AffectedTestsResult {
+        AffectedTestsResult {
+            symbols: vec!["target".to_string()],
+            tests: names
+                .iter()
+                .map(|name| GraphNodeSummary {
+                    kind: "fn".to_string(),
+                    name: (*name).to_string(),
+                    path: "tests/it.rs".to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_runner_filter_formats_cargo_and_pytest() {
+        let affected = result(&["test_a", "test_b"]);
+
+        assert_eq!(
+            runner_filter(&affected, TestFilter::Cargo).as_deref(),
+            Some("cargo test -- test_a test_b")
+        );
+        assert_eq!(
+            runner_filter(&affected, TestFilter::Pytest).as_deref(),
+            Some("pytest -k \"test_a or test_b\"")
+        );
+        assert_eq!(runner_filter(&result(&[]), TestFilter::Cargo), None);
+    }
+
+    #[test]
+    fn test_runner_filter_reduces_pytest_methods_to_their_name() {
+        let affected = result(&["TestParser::test_empty", "test_a", "TestLexer::test_a"]);
+
+        assert_eq!(
+            runner_filter(&affected, TestFilter::Pytest).as_deref(),
+            Some("pytest -k \"test_empty or test_a\"")
+        );
+    }
+}
@@ -135,3 +135,22 @@ pub async fn apply(
 
     Ok(result)
 }
+
+/// Assign users to an issue.
+///
+/// Returns the logins GitHub actually assigned.
+///
+/// # Arguments
+///
+/// * `issue_details` - Issue details (owner, repo, number)
+/// * `assignees` - Logins to assign
+#[instrument(skip_all, fields(owner = %issue_details.owner, repo = %issue_details.repo, number = issue_details.number))]
+pub async fn assign(issue_details: &IssueDetails, assignees: &[String]) -> Result<Vec<String>> {
+    // Create CLI token provider
+    let provider = CliTokenProvider;
+
+    let assigned = aptu_core::assign_issue(&provider, issue_details, assignees).await?;
+
+    info!(assignees = ?assigned, "Issue assigned");
+    Ok(assigned)
+}
@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! `triage rules test` subcommand: dry-run triage rules against an issue.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use aptu_core::triage_rules::{TriageRules, mentioned_paths, rules_file_path};
+use aptu_core::{IssueDetails, TriageResponse};
+use serde::Deserialize;
+
+use super::types::RulesTestResult;
+
+/// Issue fixture: GitHub REST issue JSON plus optional simulated model output.
+#[derive(Debug, Deserialize)]
+struct IssueFixture {
+    #[serde(default)]
+    number: u64,
+    title: String,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default, alias = "authorAssociation")]
+    author_association: Option<String>,
+    /// `https://api.github.com/repos/OWNER/REPO`, used for `repos` conditions.
+    #[serde(default)]
+    repository_url: Option<String>,
+    #[serde(default)]
+    html_url: Option<String>,
+    #[serde(default)]
+    suggested_labels: Vec<String>,
+    #[serde(default)]
+    suggested_milestone: Option<String>,
+}
+
+impl IssueFixture {
+    /// Splits the fixture into issue details and the simulated model triage.
+    fn into_parts(self) -> (IssueDetails, TriageResponse) {
+        let (owner, repo) = self
+            .repository_url
+            .as_deref()
+            .and_then(|url| {
+                let mut segments = url.trim_end_matches('/').rsplit('/');
+                let repo = segments.next()?;
+                let owner = segments.next()?;
+                Some((owner.to_string(), repo.to_string()))
+            })
+            .unwrap_or_default();
+        let mut issue = IssueDetails::builder()
+            .owner(owner)
+            .repo(repo)
+            .number(self.number)
+            .title(self.title)
+            .body(self.body.unwrap_or_default())
+            .url(self.html_url.unwrap_or_default())
+            .build();
+        issue.author_association = self.author_association;
+        let triage = TriageResponse {
+            suggested_labels: self.suggested_labels,
+            suggested_milestone: self.suggested_milestone,
+            ..Default::default()
+        };
+        (issue, triage)
+    }
+}
+
+/// Evaluate triage rules against an issue fixture without calling GitHub or
+/// the model.
+///
+/// Uses `rules_path` if given, otherwise the configured rules file.
+pub fn run_test(issue_path: &Path, rules_path: Option<&Path>) -> Result<RulesTestResult> {
+    let (rules_file, rules) = match rules_path {
+        Some(path) => {
+            let content = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read '{}'", path.display()))?;
+            (path.to_path_buf(), TriageRules::parse(&content)?)
+        }
+        None => (rules_file_path(), TriageRules::load()?),
+    };
+
+    let content = std::fs::read_to_string(issue_path)
+        .with_context(|| format!("Failed to read '{}'", issue_path.display()))?;
+    let fixture: IssueFixture = serde_json::from_str(&content)
+        .with_context(|| format!("'{}' is not issue JSON", issue_path.display()))?;
+    let (issue, model_triage) = fixture.into_parts();
+
+    let skip_ai = rules.skip_ai_triage(&issue);
+    let mut triage = skip_ai.clone().unwrap_or(model_triage);
+    rules.apply(&issue, &mut triage);
+
+    Ok(RulesTestResult {
+        rules_file: rules_file.display().to_string(),
+        rule_count: rules.rules.len(),
+        mentioned_paths: mentioned_paths(&issue.body),
+        matched_rules: triage.applied_rules,
+        skip_ai: skip_ai.is_some(),
+        summary: skip_ai.map(|t| t.summary),
+        labels: triage.suggested_labels,
+        forced_labels: triage.forced_labels,
+        milestone: triage.suggested_milestone,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixture_reads_rest_issue_json() {
+        let fixture: IssueFixture = serde_json::from_str(
+            r#"{
+                "number": 7,
+                "title": "Bump serde",
+                "body": null,
+                "author_association": "NONE",
+                "repository_url": "https://api.github.com/repos/block/goose",
+                "html_url": "https://github.com/block/goose/issues/7",
+                "user": {"login": "dependabot[bot]"},
+                "suggested_labels": ["dependencies"]
+            }"#,
+        )
+        .unwrap();
+        let (issue, triage) = fixture.into_parts();
+        assert_eq!(issue.owner, "block");
+        assert_eq!(issue.repo, "goose");
+        assert_eq!(issue.number, 7);
+        assert_eq!(issue.author_association.as_deref(), Some("NONE"));
+        assert!(issue.body.is_empty());
+        assert_eq!(triage.suggested_labels, vec!["dependencies"]);
+    }
+}
@@ -54,6 +54,7 @@ pub struct IssuesResult {
 /// Result from the triage command.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "snake_case")]
+#[allow(clippy::struct_excessive_bools)]
 pub struct TriageResult {
     /// Issue title (for display).
     pub issue_title: String,
@@ -75,8 +76,12 @@ pub struct TriageResult {
     pub applied_milestone: Option<String>,
     /// Warnings from applying labels/milestone.
     pub apply_warnings: Vec<String>,
+    /// Users assigned to the issue (with `--assign`).
+    pub applied_assignees: Vec<String>,
     /// Whether the user is a maintainer (has write/maintain/admin permission).
     pub is_maintainer: bool,
+    /// Whether this revises an earlier aptu triage after the issue changed.
+    pub retriaged: bool,
 }
 
 /// Outcome of a single triage operation in a bulk operation.
@@ -103,7 +108,7 @@ impl SingleTriageOutcome {
 }
 
 /// Result from a bulk triage operation.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct BulkTriageResult {
     /// Number of issues successfully triaged.
@@ -125,6 +130,14 @@ impl BulkTriageResult {
                 .is_some_and(|result| result.dry_run)
         })
     }
+
+    /// Adds the counts and outcomes of `other` to `self`.
+    pub fn merge(&mut self, other: Self) {
+        self.succeeded += other.succeeded;
+        self.failed += other.failed;
+        self.skipped += other.skipped;
+        self.outcomes.extend(other.outcomes);
+    }
 }
 
 /// Result from the history command.
@@ -250,6 +263,75 @@ pub struct DiscoverResult {
     pub repos: Vec<DiscoveredRepo>,
 }
 
+/// A node in a graph query result.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct GraphNodeSummary {
+    /// Node kind (`fn`, `struct`, `file`, ...).
+    pub kind: String,
+    /// Symbol or file name.
+    pub name: String,
+    /// File path relative to the repository root.
+    pub path: String,
+}
+
+/// An edge in a graph query result, referencing `nodes` by position.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct GraphEdgeSummary {
+    /// Index of the source node.
+    pub from: usize,
+    /// Index of the target node.
+    pub to: usize,
+    /// Edge kind (`calls`, `implements`, ...).
+    pub kind: String,
+}
+
+/// Result from the graph callers, callees, impact, and path commands.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct GraphQueryResult {
+    /// The query that was run, e.g. `callers parse_config`.
+    pub query: String,
+    /// Nodes in the result subgraph.
+    pub nodes: Vec<GraphNodeSummary>,
+    /// Edges between `nodes`.
+    pub edges: Vec<GraphEdgeSummary>,
+    /// For path queries, the nodes along the path in order (`None` if unreachable).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<Vec<GraphNodeSummary>>,
+    /// The queried symbols, which the text output never flags as untested.
+    #[serde(skip)]
+    pub seeds: Vec<aptu_core::graph::Node>,
+    /// The result subgraph, used for DOT and Mermaid export.
+    #[serde(skip)]
+    pub subgraph: aptu_core::graph::GraphDb,
+}
+
+/// Result from the tests affected command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AffectedTestsResult {
+    /// Symbols the diff touches.
+    pub symbols: Vec<String>,
+    /// Tests that transitively exercise those symbols.
+    pub tests: Vec<GraphNodeSummary>,
+}
+
+/// Result from the graph gc command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct GraphGcResult {
+    /// Whether files were only listed, not deleted.
+    pub dry_run: bool,
+    /// Cache files removed (or that would be removed).
+    pub removed: Vec<String>,
+    /// Total size of the removed files in bytes.
+    pub bytes_freed: u64,
+    /// Cache files still within the TTL.
+    pub kept: usize,
+}
+
 /// Result from auth login or logout actions.
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -272,6 +354,104 @@ pub struct RepoMutateResult {
     pub message: String,
 }
 
+/// Outcome of a stale action on a single issue or PR.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct StaleOutcome {
+    /// Issue or PR number.
+    pub number: u64,
+    /// Title.
+    pub title: String,
+    /// URL.
+    pub url: String,
+    /// Action taken (or proposed, in a dry run).
+    pub action: aptu_core::github::stale::StaleAction,
+    /// URL of the posted comment, if any.
+    pub comment_url: Option<String>,
+    /// Error message if the action failed.
+    pub error: Option<String>,
+}
+
+/// Result from the issue and PR stale commands.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct StaleSweepResult {
+    /// Repository swept (e.g., "owner/name").
+    pub repo: String,
+    /// Kind of item swept.
+    pub kind: aptu_core::github::stale::StaleKind,
+    /// Whether this was a dry-run (nothing posted or closed).
+    pub dry_run: bool,
+    /// Number of items acted on successfully.
+    pub succeeded: usize,
+    /// Number of items that failed.
+    pub failed: usize,
+    /// Individual outcomes, oldest activity first.
+    pub outcomes: Vec<StaleOutcome>,
+}
+
+/// Result from the issue dedupe command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct DedupeResult {
+    /// Repository swept (e.g., "owner/name").
+    pub repo: String,
+    /// Number of similarity clusters found before AI review.
+    pub candidate_clusters: usize,
+    /// Clusters the model confirmed contain duplicates.
+    pub clusters: Vec<aptu_core::facade::DuplicateCluster>,
+    /// Issue numbers that were closed as duplicates.
+    pub closed: Vec<u64>,
+    /// Whether this was a dry-run (nothing closed).
+    pub dry_run: bool,
+}
+
+/// Result from the issue split command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SplitResult {
+    /// Repository (e.g., "owner/name").
+    pub repo: String,
+    /// Parent issue number.
+    pub issue_number: u64,
+    /// Parent issue title.
+    pub issue_title: String,
+    /// Proposed child issues, in task order.
+    pub tasks: Vec<aptu_core::SubIssueTask>,
+    /// Model-written rationale for the breakdown.
+    pub rationale: String,
+    /// Child issues that were created (empty unless applied).
+    pub created: Vec<aptu_core::facade::CreatedSubIssue>,
+    /// Whether the children were linked with GitHub's sub-issues API.
+    pub linked: bool,
+    /// URL of the fallback task list comment on the parent, if posted.
+    pub tasklist_url: Option<String>,
+    /// Non-fatal problems while creating the children.
+    pub warnings: Vec<String>,
+    /// Whether this was a dry-run (nothing created).
+    pub dry_run: bool,
+}
+
+/// Result from the issue plan command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PlanResult {
+    /// Repository (e.g., "owner/name").
+    pub repo: String,
+    /// Issue number.
+    pub issue_number: u64,
+    /// Issue title.
+    pub issue_title: String,
+    /// Summary of the approach.
+    pub summary: String,
+    /// Ordered implementation steps.
+    pub steps: Vec<aptu_core::PlanStep>,
+    /// Symbols located in the local checkout, best first.
+    pub symbols: Vec<aptu_core::CodeSymbol>,
+    /// Path the diff was written to, if requested.
+    pub diff_path: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct RevertResult {
@@ -283,6 +463,35 @@ pub struct RevertResult {
     pub comments_removed: usize,
     /// IDs of removed comments.
     pub comment_ids: Vec<u64>,
+    /// Whether the issue was (or would be) reopened after a dedupe close.
+    pub reopened: bool,
+    /// Child issues closed (or that would be closed) after a split.
+    pub closed_sub_issues: Vec<u64>,
     /// Summary message describing what was removed.
     pub summary: String,
 }
+
+/// Result from the triage rules test command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct RulesTestResult {
+    /// Rules file that was evaluated.
+    pub rules_file: String,
+    /// Number of rules in the file.
+    pub rule_count: usize,
+    /// File paths found in the issue body, for `paths` conditions.
+    pub mentioned_paths: Vec<String>,
+    /// Names of the rules that matched, in file order.
+    pub matched_rules: Vec<String>,
+    /// Whether a rule would skip the AI call.
+    pub skip_ai: bool,
+    /// Summary a `skip_ai` rule would post.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// Model-suggested labels after mapping and forbidding.
+    pub labels: Vec<String>,
+    /// Labels forced by rules.
+    pub forced_labels: Vec<String>,
+    /// Resulting milestone, if any.
+    pub milestone: Option<String>,
+}
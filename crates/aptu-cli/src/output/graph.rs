@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use console::style;
+use std::io::{self, Write};
+
+use crate::cli::OutputContext;
+use crate::commands::types::{AffectedTestsResult, GraphGcResult, GraphQueryResult};
+
+use super::Renderable;
+
+impl Renderable for GraphGcResult {
+    fn render_text(&self, w: &mut dyn Write, ctx: &OutputContext) -> io::Result<()> {
+        if ctx.is_verbose() || self.dry_run {
+            for path in &self.removed {
+                writeln!(w, "  {path}")?;
+            }
+        }
+        let verb = if self.dry_run {
+            "Would remove"
+        } else {
+            "Removed"
+        };
+        writeln!(
+            w,
+            "{} {verb} {} cached graph(s), {} bytes; {} kept",
+            style("*").green().bold(),
+            self.removed.len(),
+            self.bytes_freed,
+            self.kept
+        )?;
+        Ok(())
+    }
+}
+
+impl Renderable for GraphQueryResult {
+    fn render_text(&self, w: &mut dyn Write, _ctx: &OutputContext) -> io::Result<()> {
+        if let Some(path) = &self.path {
+            if path.is_empty() {
+                writeln!(
+                    w,
+                    "{} No dependency path ({})",
+                    style("!").yellow().bold(),
+                    self.query
+                )?;
+                return Ok(());
+            }
+            for (i, node) in path.iter().enumerate() {
+                let arrow = if i == 0 { "  " } else { "-> " };
+                writeln!(
+                    w,
+                    "{arrow}{} {} {}",
+                    node.kind,
+                    style(&node.name).cyan(),
+                    style(&node.path).dim()
+                )?;
+            }
+            return Ok(());
+        }
+
+        let seeds: Vec<&aptu_core::graph::Node> = self.seeds.iter().collect();
+        let text = aptu_core::graph::query::render_subgraph_text(&self.subgraph, &seeds);
+        if text.is_empty() {
+            writeln!(
+                w,
+                "{} No results ({})",
+                style("!").yellow().bold(),
+                self.query
+            )?;
+        } else {
+            writeln!(w, "{text}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Renderable for AffectedTestsResult {
+    fn render_text(&self, w: &mut dyn Write, ctx: &OutputContext) -> io::Result<()> {
+        if ctx.is_verbose() {
+            writeln!(w, "Changed symbols: {}", self.symbols.join(", "))?;
+        }
+        if self.tests.is_empty() {
+            writeln!(
+                w,
+                "{} No affected tests ({} changed symbol(s))",
+                style("!").yellow().bold(),
+                self.symbols.len()
+            )?;
+            return Ok(());
+        }
+        for test in &self.tests {
+            writeln!(
+                w,
+                "{} {}",
+                style(&test.name).cyan(),
+                style(&test.path).dim()
+            )?;
+        }
+        Ok(())
+    }
+}
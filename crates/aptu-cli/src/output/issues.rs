@@ -6,7 +6,7 @@ use console::style;
 use std::io::{self, Write};
 
 use crate::cli::{OutputContext, OutputFormat};
-use crate::commands::types::{IssuesResult, RevertResult};
+use crate::commands::types::{DedupeResult, IssuesResult, PlanResult, RevertResult, SplitResult};
 
 use super::Renderable;
 
@@ -121,7 +121,10 @@ impl IssuesResult {
                             .context("Failed to serialize empty SARIF report")?;
                         println!("{json}");
                     }
-                    OutputFormat::GithubAnnotations => {
+                    OutputFormat::GithubAnnotations
+                    | OutputFormat::CodeClimate
+                    | OutputFormat::GitlabSast
+                    | OutputFormat::Junit => {
                         // No findings for issues list; emit nothing.
                     }
                     OutputFormat::Markdown => {
@@ -175,6 +178,320 @@ impl IssuesResult {
     }
 }
 
+impl Renderable for DedupeResult {
+    fn render_text(&self, w: &mut dyn Write, _ctx: &OutputContext) -> io::Result<()> {
+        writeln!(w)?;
+        if self.clusters.is_empty() {
+            writeln!(
+                w,
+                "{}",
+                style(format!("No duplicate issues found in {}.", self.repo)).yellow()
+            )?;
+            return Ok(());
+        }
+
+        writeln!(
+            w,
+            "{}",
+            style(format!(
+                "{} duplicate cluster(s) in {} ({} candidate cluster(s) reviewed)",
+                self.clusters.len(),
+                self.repo,
+                self.candidate_clusters
+            ))
+            .bold()
+        )?;
+
+        for cluster in &self.clusters {
+            writeln!(w)?;
+            writeln!(
+                w,
+                "{} #{} {}",
+                style("Canonical").green().bold(),
+                cluster.canonical.number,
+                cluster.canonical.title
+            )?;
+            for duplicate in &cluster.duplicates {
+                let status = if self.closed.contains(&duplicate.number) {
+                    style("closed").red()
+                } else {
+                    style("duplicate").yellow()
+                };
+                writeln!(
+                    w,
+                    "  {status} #{} {} (similarity {:.2})",
+                    duplicate.number, duplicate.title, duplicate.similarity
+                )?;
+            }
+            if !cluster.rationale.is_empty() {
+                writeln!(w, "  {}", style(&cluster.rationale).dim())?;
+            }
+        }
+
+        writeln!(w)?;
+        if self.dry_run {
+            writeln!(
+                w,
+                "{}",
+                style("(dry-run: no issues were closed)").italic().dim()
+            )?;
+        } else {
+            writeln!(w, "Closed {} issue(s) as duplicates.", self.closed.len())?;
+        }
+        Ok(())
+    }
+
+    fn render_markdown(&self, w: &mut dyn Write, _ctx: &OutputContext) -> io::Result<()> {
+        writeln!(w, "## Duplicate Issues in {}", self.repo)?;
+        writeln!(w)?;
+        if self.clusters.is_empty() {
+            writeln!(w, "No duplicate issues found.")?;
+            return Ok(());
+        }
+
+        for cluster in &self.clusters {
+            writeln!(
+                w,
+                "### #{} {}",
+                cluster.canonical.number, cluster.canonical.title
+            )?;
+            writeln!(w)?;
+            for duplicate in &cluster.duplicates {
+                let closed = if self.closed.contains(&duplicate.number) {
+                    " (closed)"
+                } else {
+                    ""
+                };
+                writeln!(
+                    w,
+                    "- #{} {} (similarity {:.2}){closed}",
+                    duplicate.number, duplicate.title, duplicate.similarity
+                )?;
+            }
+            if !cluster.rationale.is_empty() {
+                writeln!(w)?;
+                writeln!(w, "{}", cluster.rationale)?;
+            }
+            writeln!(w)?;
+        }
+
+        if self.dry_run {
+            writeln!(w, "**(dry-run: no issues were closed)**")?;
+        }
+        Ok(())
+    }
+}
+
+impl Renderable for SplitResult {
+    fn render_text(&self, w: &mut dyn Write, _ctx: &OutputContext) -> io::Result<()> {
+        writeln!(w)?;
+        writeln!(
+            w,
+            "{}",
+            style(format!(
+                "Split {}#{} {} into {} sub-issue(s)",
+                self.repo,
+                self.issue_number,
+                self.issue_title,
+                self.tasks.len()
+            ))
+            .bold()
+        )?;
+        writeln!(w)?;
+
+        for (index, task) in self.tasks.iter().enumerate() {
+            let created = self.created.get(index);
+            let marker = match created {
+                Some(child) => style(format!("#{}", child.number)).green().bold(),
+                None => style(format!("{}.", index + 1)).cyan().bold(),
+            };
+            writeln!(w, "{marker} {}", task.title)?;
+            if !task.labels.is_empty() {
+                writeln!(w, "   {}", style(task.labels.join(", ")).dim())?;
+            }
+            if !task.depends_on.is_empty() {
+                let deps: Vec<String> = task
+                    .depends_on
+                    .iter()
+                    .map(|&dep| match self.created.get(dep - 1) {
+                        Some(child) => format!("#{}", child.number),
+                        None => dep.to_string(),
+                    })
+                    .collect();
+                writeln!(
+                    w,
+                    "   {}",
+                    style(format!("after {}", deps.join(", "))).dim()
+                )?;
+            }
+        }
+        if !self.rationale.is_empty() {
+            writeln!(w)?;
+            writeln!(w, "{}", style(&self.rationale).dim())?;
+        }
+
+        for warning in &self.warnings {
+            writeln!(w, "{} {warning}", style("Warning:").yellow())?;
+        }
+
+        writeln!(w)?;
+        if self.created.is_empty() {
+            writeln!(
+                w,
+                "{}",
+                style("(no sub-issues were created)").italic().dim()
+            )?;
+        } else if self.linked {
+            writeln!(
+                w,
+                "Created {} sub-issue(s) of #{}.",
+                self.created.len(),
+                self.issue_number
+            )?;
+        } else if let Some(url) = &self.tasklist_url {
+            writeln!(
+                w,
+                "Created {} issue(s); sub-issues are unavailable, linked them in {url}",
+                self.created.len()
+            )?;
+        } else {
+            writeln!(w, "Created {} issue(s).", self.created.len())?;
+        }
+        Ok(())
+    }
+
+    fn render_markdown(&self, w: &mut dyn Write, _ctx: &OutputContext) -> io::Result<()> {
+        writeln!(
+            w,
+            "## Sub-issues of {}#{} {}",
+            self.repo, self.issue_number, self.issue_title
+        )?;
+        writeln!(w)?;
+        for (index, task) in self.tasks.iter().enumerate() {
+            match self.created.get(index) {
+                Some(child) => writeln!(w, "- [ ] #{} {}", child.number, task.title)?,
+                None => writeln!(w, "{}. {}", index + 1, task.title)?,
+            }
+        }
+        if !self.rationale.is_empty() {
+            writeln!(w)?;
+            writeln!(w, "{}", self.rationale)?;
+        }
+        writeln!(w)?;
+
+        if self.created.is_empty() {
+            writeln!(w, "**(no sub-issues were created)**")?;
+        }
+        Ok(())
+    }
+}
+
+impl Renderable for PlanResult {
+    fn render_text(&self, w: &mut dyn Write, _ctx: &OutputContext) -> io::Result<()> {
+        writeln!(w)?;
+        writeln!(
+            w,
+            "{}",
+            style(format!(
+                "Plan for {}#{} {}",
+                self.repo, self.issue_number, self.issue_title
+            ))
+            .bold()
+        )?;
+        writeln!(w)?;
+        writeln!(w, "{}", self.summary)?;
+        writeln!(w)?;
+
+        for (index, step) in self.steps.iter().enumerate() {
+            writeln!(
+                w,
+                "{} {}",
+                style(format!("{}.", index + 1)).cyan().bold(),
+                step.description
+            )?;
+            if !step.file.is_empty() {
+                let mut location = step.file.clone();
+                if !step.symbols.is_empty() {
+                    location = format!("{location}: {}", step.symbols.join(", "));
+                }
+                writeln!(w, "   {}", style(location).dim())?;
+            }
+        }
+
+        writeln!(w)?;
+        if self.symbols.is_empty() {
+            writeln!(
+                w,
+                "{}",
+                style("(no matching symbols found; planned from the repository tree)")
+                    .italic()
+                    .dim()
+            )?;
+        } else {
+            writeln!(w, "{}:", style("Relevant code").cyan())?;
+            for symbol in &self.symbols {
+                writeln!(
+                    w,
+                    "  - {} {} {}",
+                    symbol.kind,
+                    symbol.name,
+                    style(&symbol.path).dim()
+                )?;
+            }
+        }
+
+        if let Some(path) = &self.diff_path {
+            writeln!(w)?;
+            writeln!(
+                w,
+                "Wrote diff to {path}; open a PR with `aptu pr create --diff {path}`."
+            )?;
+        }
+        Ok(())
+    }
+
+    fn render_markdown(&self, w: &mut dyn Write, _ctx: &OutputContext) -> io::Result<()> {
+        writeln!(
+            w,
+            "## Plan for {}#{} {}",
+            self.repo, self.issue_number, self.issue_title
+        )?;
+        writeln!(w)?;
+        writeln!(w, "{}", self.summary)?;
+        writeln!(w)?;
+        for (index, step) in self.steps.iter().enumerate() {
+            write!(w, "{}. {}", index + 1, step.description)?;
+            if !step.file.is_empty() {
+                write!(w, " (`{}`", step.file)?;
+                for symbol in &step.symbols {
+                    write!(w, ", `{symbol}`")?;
+                }
+                write!(w, ")")?;
+            }
+            writeln!(w)?;
+        }
+
+        if !self.symbols.is_empty() {
+            writeln!(w)?;
+            writeln!(w, "### Relevant code")?;
+            writeln!(w)?;
+            for symbol in &self.symbols {
+                writeln!(
+                    w,
+                    "- {} `{}` in `{}`",
+                    symbol.kind, symbol.name, symbol.path
+                )?;
+            }
+        }
+
+        if let Some(path) = &self.diff_path {
+            writeln!(w)?;
+            writeln!(w, "Diff written to `{path}`.")?;
+        }
+        Ok(())
+    }
+}
+
 impl Renderable for RevertResult {
     fn render_text(&self, w: &mut dyn Write, _ctx: &OutputContext) -> io::Result<()> {
         writeln!(w)?;
@@ -195,6 +512,13 @@ impl Renderable for RevertResult {
             }
         }
 
+        if !self.closed_sub_issues.is_empty() {
+            writeln!(w, "{}:", style("Sub-issues closed").cyan())?;
+            for number in &self.closed_sub_issues {
+                writeln!(w, "  - #{number}")?;
+            }
+        }
+
         if self.dry_run {
             writeln!(
                 w,
@@ -229,6 +553,15 @@ impl Renderable for RevertResult {
             writeln!(w)?;
         }
 
+        if !self.closed_sub_issues.is_empty() {
+            writeln!(w, "### Sub-issues Closed")?;
+            writeln!(w)?;
+            for number in &self.closed_sub_issues {
+                writeln!(w, "- #{number}")?;
+            }
+            writeln!(w)?;
+        }
+
         if self.dry_run {
             writeln!(w, "**(dry-run: no changes were made)**")?;
         }
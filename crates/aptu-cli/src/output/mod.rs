@@ -6,6 +6,8 @@
 //! Command handlers return data; this module handles presentation.
 
 use anyhow::{Context, Result};
+use aptu_core::{CodeClimateReport, Finding, GitLabSastReport, JUnitReport};
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 use std::io::{self, Write};
 
@@ -42,9 +44,12 @@ pub fn render<T: Renderable>(result: &T, ctx: &OutputContext) -> Result<()> {
                 .context("Failed to serialize empty SARIF report")?;
             println!("{json}");
         }
-        OutputFormat::GithubAnnotations => {
-            // GitHub annotation output is handled per-command (scan_security.rs).
-            // For non-scan commands, emit nothing meaningful in this format.
+        OutputFormat::GithubAnnotations
+        | OutputFormat::CodeClimate
+        | OutputFormat::GitlabSast
+        | OutputFormat::Junit => {
+            // Security report formats are handled per-command (scan_security.rs,
+            // render_pr_review). For other commands, emit nothing meaningful.
         }
         OutputFormat::Markdown => {
             result
@@ -78,6 +83,12 @@ pub fn render_pr_review(
         return Ok(());
     }
 
+    let findings = result.security_findings.as_deref().unwrap_or_default();
+    if let Some(report) = render_findings_report(ctx.format, findings, Utc::now())? {
+        println!("{report}");
+        return Ok(());
+    }
+
     if matches!(ctx.format, OutputFormat::Sarif) {
         // Convert security findings to SARIF format
         if let Some(findings) = &result.security_findings {
@@ -99,13 +110,49 @@ pub fn render_pr_review(
     render(result, ctx)
 }
 
+/// Serialize security findings as a Code Climate, GitLab SAST, or `JUnit` report.
+///
+/// Pattern metadata (remediation, references) comes from the embedded pattern
+/// database. `started` is recorded as the scan start time where the format
+/// supports it. Returns `None` for formats that are not security report formats.
+pub fn render_findings_report(
+    format: OutputFormat,
+    findings: &[Finding],
+    started: DateTime<Utc>,
+) -> Result<Option<String>> {
+    let patterns = aptu_core::PatternEngine::global().definitions();
+    let report = match format {
+        OutputFormat::CodeClimate => {
+            let report = CodeClimateReport::with_rules(findings.to_vec(), &patterns);
+            serde_json::to_string_pretty(&report)
+                .context("Failed to serialize CodeClimate report")?
+        }
+        OutputFormat::GitlabSast => {
+            let report = GitLabSastReport::with_rules(findings.to_vec(), &patterns)
+                .with_scan_times(started, Utc::now());
+            serde_json::to_string_pretty(&report)
+                .context("Failed to serialize GitLab SAST report")?
+        }
+        OutputFormat::Junit => {
+            let elapsed = (Utc::now() - started).to_std().unwrap_or_default();
+            JUnitReport::with_rules(findings.to_vec(), &patterns)
+                .with_duration(elapsed)
+                .to_xml()
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(report))
+}
+
 mod auth;
 mod bulk;
 pub mod common;
 mod create;
+mod graph;
 mod history;
 mod issues;
 mod models;
 pub mod pr;
 mod repos;
+mod stale;
 mod triage;
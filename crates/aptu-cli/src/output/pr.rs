@@ -692,6 +692,7 @@ mod tests {
             confidence: aptu_core::Confidence::High,
             file_path: "src/main.rs".to_string(),
             line_number: 42,
+            column: None,
             matched_text: "unsafe { }".to_string(),
             cwe: Some("CWE-123".to_string()),
         };
@@ -719,6 +720,7 @@ mod tests {
             confidence: aptu_core::Confidence::High,
             file_path: "src/main.rs".to_string(),
             line_number: 42,
+            column: None,
             matched_text: "unsafe { }".to_string(),
             cwe: Some("CWE-123".to_string()),
         };
@@ -743,6 +745,7 @@ mod tests {
             confidence: aptu_core::Confidence::High,
             file_path: "src/main.rs".to_string(),
             line_number: 42,
+            column: None,
             matched_text: "unsafe { }".to_string(),
             cwe: Some("CWE-123".to_string()),
         };
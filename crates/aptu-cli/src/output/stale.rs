@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Stale sweep output rendering.
+
+use std::io::{self, Write};
+
+use aptu_core::github::stale::StaleAction;
+use comfy_table::{ContentArrangement, Table};
+use console::style;
+
+use crate::cli::OutputContext;
+use crate::commands::types::StaleSweepResult;
+use crate::output::Renderable;
+
+/// Maximum title length in characters for table output.
+const STALE_TITLE_MAX_CHARS: usize = 60;
+
+/// Describe a stale action for display.
+fn describe_action(action: StaleAction) -> String {
+    match action {
+        StaleAction::Nudge { inactive_days } => format!("nudge ({inactive_days}d inactive)"),
+        StaleAction::Close { stale_days } => format!("close ({stale_days}d stale)"),
+        StaleAction::Unmark => "unmark (active again)".to_string(),
+    }
+}
+
+impl Renderable for StaleSweepResult {
+    fn render_text(&self, w: &mut dyn Write, _ctx: &OutputContext) -> io::Result<()> {
+        writeln!(w)?;
+        if self.outcomes.is_empty() {
+            writeln!(
+                w,
+                "{}",
+                style(format!("No stale {}s in {}.", self.kind.noun(), self.repo)).yellow()
+            )?;
+            return Ok(());
+        }
+
+        let heading = if self.dry_run {
+            format!("Proposed stale actions for {} (dry run)", self.repo)
+        } else {
+            format!("Stale actions for {}", self.repo)
+        };
+        writeln!(w, "{}", style(heading).bold())?;
+        writeln!(w)?;
+
+        let mut table = Table::new();
+        table
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_header(vec!["#", "Title", "Action", "Result"]);
+        for outcome in &self.outcomes {
+            let result = match (&outcome.error, self.dry_run) {
+                (Some(err), _) => format!("failed: {err}"),
+                (None, true) => "-".to_string(),
+                (None, false) => "done".to_string(),
+            };
+            table.add_row(vec![
+                format!("#{}", outcome.number),
+                aptu_core::utils::truncate(&outcome.title, STALE_TITLE_MAX_CHARS),
+                describe_action(outcome.action),
+                result,
+            ]);
+        }
+        writeln!(w, "{table}")?;
+        writeln!(w)?;
+
+        if self.dry_run {
+            writeln!(
+                w,
+                "{}",
+                style("(dry-run: nothing was posted; rerun with --apply)")
+                    .italic()
+                    .dim()
+            )?;
+        } else {
+            writeln!(
+                w,
+                "  Succeeded: {}  Failed: {}",
+                style(self.succeeded).green(),
+                style(self.failed).red()
+            )?;
+        }
+        Ok(())
+    }
+
+    fn render_markdown(&self, w: &mut dyn Write, _ctx: &OutputContext) -> io::Result<()> {
+        writeln!(w, "## Stale {}s in {}", self.kind.noun(), self.repo)?;
+        writeln!(w)?;
+        if self.outcomes.is_empty() {
+            writeln!(w, "No stale {}s found.", self.kind.noun())?;
+            return Ok(());
+        }
+
+        writeln!(w, "| # | Title | Action | Result |")?;
+        writeln!(w, "|---|-------|--------|--------|")?;
+        for outcome in &self.outcomes {
+            let result = match (&outcome.error, self.dry_run) {
+                (Some(err), _) => format!("failed: {err}"),
+                (None, true) => "dry run".to_string(),
+                (None, false) => outcome
+                    .comment_url
+                    .as_ref()
+                    .map_or_else(|| "done".to_string(), |url| format!("[comment]({url})")),
+            };
+            writeln!(
+                w,
+                "| #{} | {} | {} | {} |",
+                outcome.number,
+                aptu_core::utils::truncate(&outcome.title, STALE_TITLE_MAX_CHARS),
+                describe_action(outcome.action),
+                result
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -5,7 +5,7 @@ use console::style;
 use std::io::{self, Write};
 
 use crate::cli::OutputContext;
-use crate::commands::types::TriageResult;
+use crate::commands::types::{RulesTestResult, TriageResult};
 
 use super::Renderable;
 
@@ -62,9 +62,15 @@ pub fn render_triage_content(
 
     // Labels - only show if maintainer
     if is_maintainer {
+        let labels: Vec<String> = triage
+            .suggested_labels
+            .iter()
+            .chain(&triage.forced_labels)
+            .cloned()
+            .collect();
         output.push_str(&render_list_section(
             "Suggested Labels",
-            &triage.suggested_labels,
+            &labels,
             "None",
             false,
         ));
@@ -169,6 +175,16 @@ pub fn render_triage_content(
         output.push('\n');
     }
 
+    // Suggested assignees - only show if maintainer
+    if is_maintainer && !triage.suggested_assignees.is_empty() {
+        let _ = writeln!(output, "{}", style("Suggested Assignees").cyan().bold());
+        for assignee in &triage.suggested_assignees {
+            let _ = writeln!(output, "  @{}", assignee.login);
+            let _ = writeln!(output, "    {}", style(&assignee.reason).dim());
+        }
+        output.push('\n');
+    }
+
     output
 }
 
@@ -235,6 +251,48 @@ impl Renderable for TriageResult {
     }
 }
 
+impl Renderable for RulesTestResult {
+    fn render_text(&self, w: &mut dyn Write, ctx: &OutputContext) -> io::Result<()> {
+        writeln!(
+            w,
+            "{} ({} rules)",
+            style(&self.rules_file).dim(),
+            self.rule_count
+        )?;
+        if ctx.is_verbose() {
+            writeln!(w, "Mentioned paths: {}", self.mentioned_paths.join(", "))?;
+        }
+        if self.matched_rules.is_empty() {
+            writeln!(w, "{} No rules matched", style("!").yellow().bold())?;
+            return Ok(());
+        }
+
+        writeln!(w, "{}", style("Matched rules").cyan().bold())?;
+        for name in &self.matched_rules {
+            writeln!(w, "  - {name}")?;
+        }
+        if let Some(summary) = &self.summary {
+            writeln!(w, "AI call: {}", style("skipped").yellow())?;
+            writeln!(w, "  {summary}")?;
+        }
+        let list = |labels: &[String]| {
+            if labels.is_empty() {
+                "none".to_string()
+            } else {
+                labels.join(", ")
+            }
+        };
+        writeln!(w, "Labels: {}", list(&self.labels))?;
+        writeln!(w, "Forced labels: {}", list(&self.forced_labels))?;
+        writeln!(
+            w,
+            "Milestone: {}",
+            self.milestone.as_deref().unwrap_or("none")
+        )?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
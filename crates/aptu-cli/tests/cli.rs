@@ -211,6 +211,20 @@ fn test_triage_since_requires_repo() {
     );
 }
 
+#[test]
+fn test_triage_limit_requires_since() {
+    // --limit and --restart only apply to a --since sweep
+    let mut cmd = cargo_bin_cmd!("aptu");
+    cmd.arg("issue")
+        .arg("triage")
+        .arg("block/goose#1")
+        .arg("--limit")
+        .arg("10")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("--since"));
+}
+
 #[test]
 fn test_triage_no_comment_flag_recognized() {
     // Test that --no-comment flag is recognized in help
@@ -223,6 +237,103 @@ fn test_triage_no_comment_flag_recognized() {
         .stdout(predicates::str::contains("--no-comment"));
 }
 
+#[test]
+fn test_triage_assign_flag_recognized() {
+    let mut cmd = cargo_bin_cmd!("aptu");
+    cmd.arg("issue")
+        .arg("triage")
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("--assign"));
+}
+
+#[test]
+fn test_dedupe_apply_conflicts_with_dry_run() {
+    let mut cmd = cargo_bin_cmd!("aptu");
+    cmd.arg("issue")
+        .arg("dedupe")
+        .arg("--repo")
+        .arg("block/goose")
+        .arg("--apply")
+        .arg("--dry-run")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_split_apply_conflicts_with_dry_run() {
+    let mut cmd = cargo_bin_cmd!("aptu");
+    cmd.arg("issue")
+        .arg("split")
+        .arg("block/goose#123")
+        .arg("--apply")
+        .arg("--dry-run")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_issue_plan_help_shows_repo_path_and_diff() {
+    let mut cmd = cargo_bin_cmd!("aptu");
+    cmd.arg("issue")
+        .arg("plan")
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("--repo-path"))
+        .stdout(predicates::str::contains("--diff"));
+}
+
+#[test]
+fn test_stale_is_dry_run_unless_apply() {
+    for command in ["issue", "pr"] {
+        let mut cmd = cargo_bin_cmd!("aptu");
+        cmd.arg(command)
+            .arg("stale")
+            .arg("--help")
+            .assert()
+            .success()
+            .stdout(predicates::str::contains("--apply"))
+            .stdout(predicates::str::contains("dry-run"));
+    }
+}
+
+#[test]
+fn test_triage_rules_test_json() {
+    use std::io::Write;
+    let mut rules = tempfile::NamedTempFile::new().unwrap();
+    write!(
+        rules,
+        "[[rule]]\nname = \"bumps\"\ntitle = \"^Bump \"\nskip_ai = true\nadd_labels = [\"dependencies\"]\n"
+    )
+    .unwrap();
+    let mut issue = tempfile::NamedTempFile::new().unwrap();
+    write!(
+        issue,
+        r#"{{"number": 1, "title": "Bump serde", "body": null}}"#
+    )
+    .unwrap();
+
+    let output = cargo_bin_cmd!("aptu")
+        .args(["triage", "rules", "test"])
+        .arg(issue.path())
+        .arg("--rules")
+        .arg(rules.path())
+        .args(["--output", "json"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let parsed: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("output must be valid JSON");
+    assert_eq!(parsed["matched_rules"], serde_json::json!(["bumps"]));
+    assert_eq!(parsed["skip_ai"], true);
+    assert_eq!(parsed["forced_labels"], serde_json::json!(["dependencies"]));
+}
+
 // JSON Output Validation Tests
 
 #[test]
@@ -584,3 +695,284 @@ fn scan_security_sarif_output_written_before_fail_on_exit() {
         "expected at least one SARIF result"
     );
 }
+
+#[test]
+fn scan_security_gitlab_sast_and_junit_output() {
+    use std::io::Write;
+
+    // Arrange: write a diff with a hardcoded API key
+    let diff_content = concat!(
+        "diff --git a/config.py b/config.py\n",
+        "--- a/config.py\n",
+        "+++ b/config.py\n",
+        "@@ -1,2 +1,3 @@\n",
+        " # config\n",
+        "+api_key = \"abcdefghij1234567890xyz\"\n",
+        " pass\n"
+    );
+    let mut tmp = tempfile::NamedTempFile::new().unwrap();
+    write!(tmp, "{diff_content}").unwrap();
+
+    // Act: GitLab SAST report
+    let output = cargo_bin_cmd!("aptu")
+        .arg("scan-security")
+        .arg("--diff")
+        .arg(tmp.path())
+        .arg("--output")
+        .arg("gitlab-sast")
+        .output()
+        .unwrap();
+
+    // Assert: valid JSON with one vulnerability located in config.py
+    assert!(output.status.success(), "expected exit 0");
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("GitLab SAST output must be valid JSON");
+    assert_eq!(report["scan"]["type"], "sast");
+    assert_eq!(
+        report["vulnerabilities"][0]["location"]["file"],
+        "config.py"
+    );
+
+    // Act: JUnit report
+    let output = cargo_bin_cmd!("aptu")
+        .arg("scan-security")
+        .arg("--diff")
+        .arg(tmp.path())
+        .arg("--output")
+        .arg("junit")
+        .output()
+        .unwrap();
+
+    // Assert: one failing test case
+    assert!(output.status.success(), "expected exit 0");
+    let xml = String::from_utf8(output.stdout).unwrap();
+    assert!(xml.starts_with("<?xml"), "expected XML prolog");
+    assert!(xml.contains("<failure"), "expected a failure element");
+}
+
+// --- scan-security git integration tests ---
+
+/// Initialise a git repository with one commit in a temp directory.
+fn init_git_repo() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir.path())
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {args:?} failed");
+    };
+    git(&["init", "-q"]);
+    git(&["config", "user.email", "test@example.com"]);
+    git(&["config", "user.name", "Test"]);
+    git(&["config", "commit.gpgsign", "false"]);
+    std::fs::write(dir.path().join("README.md"), "hello\n").unwrap();
+    git(&["add", "."]);
+    git(&["commit", "-q", "-m", "initial"]);
+    dir
+}
+
+#[test]
+fn scan_security_staged_fails_on_staged_secret() {
+    // Arrange: stage a file containing a hardcoded API key
+    let repo = init_git_repo();
+    std::fs::write(
+        repo.path().join("config.py"),
+        "api_key = \"abcdefghij1234567890xyz\"\n",
+    )
+    .unwrap();
+    let status = std::process::Command::new("git")
+        .args(["add", "config.py"])
+        .current_dir(repo.path())
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    // Act
+    let output = cargo_bin_cmd!("aptu")
+        .current_dir(repo.path())
+        .args(["scan-security", "--staged", "--fail-on", "critical,high"])
+        .output()
+        .unwrap();
+
+    // Assert: findings reported against the staged path, exit non-zero
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!output.status.success(), "expected --fail-on to trip");
+    assert!(
+        stdout.contains("config.py:1"),
+        "unexpected output: {stdout}"
+    );
+}
+
+#[test]
+fn scan_security_hook_install_writes_pre_commit_hook() {
+    // Arrange
+    let repo = init_git_repo();
+
+    // Act
+    cargo_bin_cmd!("aptu")
+        .current_dir(repo.path())
+        .args(["scan-security", "hook", "install"])
+        .assert()
+        .success();
+
+    // Assert: hook calls the staged scan; a second install without --force is allowed
+    let hook = std::fs::read_to_string(repo.path().join(".git/hooks/pre-commit")).unwrap();
+    assert!(hook.contains("aptu scan-security --staged --fail-on critical,high"));
+    cargo_bin_cmd!("aptu")
+        .current_dir(repo.path())
+        .args(["scan-security", "hook", "install"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn scan_security_hook_install_keeps_foreign_hook_without_force() {
+    // Arrange: an existing hook not written by aptu
+    let repo = init_git_repo();
+    let hook_path = repo.path().join(".git/hooks/pre-commit");
+    std::fs::create_dir_all(hook_path.parent().unwrap()).unwrap();
+    std::fs::write(&hook_path, "#!/bin/sh\nexit 0\n").unwrap();
+
+    // Act / Assert
+    cargo_bin_cmd!("aptu")
+        .current_dir(repo.path())
+        .args(["scan-security", "hook", "install"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--force"));
+    assert_eq!(
+        std::fs::read_to_string(&hook_path).unwrap(),
+        "#!/bin/sh\nexit 0\n"
+    );
+}
+
+#[test]
+fn graph_gc_dry_run_keeps_cached_graphs() {
+    // Arrange: an expired cached graph under an isolated data dir
+    let data = tempfile::tempdir().unwrap();
+    let cached = data.path().join("aptu/graph/owner/repo/abc123.bin");
+    std::fs::create_dir_all(cached.parent().unwrap()).unwrap();
+    std::fs::write(&cached, b"graph").unwrap();
+    std::fs::File::options()
+        .write(true)
+        .open(&cached)
+        .unwrap()
+        .set_modified(std::time::SystemTime::UNIX_EPOCH)
+        .unwrap();
+
+    // Act / Assert
+    cargo_bin_cmd!("aptu")
+        .env("XDG_DATA_HOME", data.path())
+        .args(["graph", "gc", "--dry-run", "--output", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("abc123.bin"))
+        .stdout(predicate::str::contains("\"bytes_freed\": 5"));
+    assert!(cached.exists(), "dry run must not delete");
+
+    cargo_bin_cmd!("aptu")
+        .env("XDG_DATA_HOME", data.path())
+        .args(["graph", "gc"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed 1 cached graph(s)"));
+    assert!(!cached.exists());
+}
+
+#[test]
+fn graph_queries_answer_callers_and_paths() {
+    // Arrange: a two-file repository
+    let repo = tempfile::tempdir().unwrap();
+    let data = tempfile::tempdir().unwrap();
+    std::fs::create_dir(repo.path().join("src")).unwrap();
+    std::fs::write(
+        repo.path().join("src/lib.rs"),
+        "pub fn target() -> u32 {\n    leaf()\n}\n\nfn leaf() -> u32 {\n    1\n}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        repo.path().join("src/user.rs"),
+        "pub fn uses_target() -> u32 {\n    target() + 1\n}\n",
+    )
+    .unwrap();
+
+    // Act / Assert
+    cargo_bin_cmd!("aptu")
+        .env("XDG_DATA_HOME", data.path())
+        .args(["graph", "callers", "target", "--repo-path"])
+        .arg(repo.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("fn uses_target [calls: target]"));
+
+    cargo_bin_cmd!("aptu")
+        .env("XDG_DATA_HOME", data.path())
+        .args([
+            "graph",
+            "path",
+            "uses_target",
+            "leaf",
+            "--export",
+            "dot",
+            "--repo-path",
+        ])
+        .arg(repo.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("n0 -> n1 [label=\"calls\"];"))
+        .stdout(predicate::str::contains("n1 -> n2 [label=\"calls\"];"));
+
+    cargo_bin_cmd!("aptu")
+        .env("XDG_DATA_HOME", data.path())
+        .args(["graph", "callers", "missing", "--repo-path"])
+        .arg(repo.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("'missing' not found"));
+}
+
+#[test]
+fn tests_affected_selects_tests_calling_changed_symbols() {
+    // Arrange: a changed function covered by one test, and an unrelated test
+    let repo = tempfile::tempdir().unwrap();
+    let data = tempfile::tempdir().unwrap();
+    std::fs::create_dir(repo.path().join("src")).unwrap();
+    std::fs::write(
+        repo.path().join("src/lib.rs"),
+        "pub fn target() -> u32 {\n    2\n}\n\npub fn other() -> u32 {\n    3\n}\n\n\
+         #[test]\nfn checks_target() {\n    assert_eq!(target(), 2);\n}\n\n\
+         #[test]\nfn checks_other() {\n    assert_eq!(other(), 3);\n}\n",
+    )
+    .unwrap();
+    let diff = repo.path().join("change.diff");
+    std::fs::write(
+        &diff,
+        "diff --git a/src/lib.rs b/src/lib.rs\n--- a/src/lib.rs\n+++ b/src/lib.rs\n\
+         @@ -1,3 +1,3 @@\n pub fn target() -> u32 {\n-    1\n+    2\n }\n",
+    )
+    .unwrap();
+
+    // Act / Assert
+    cargo_bin_cmd!("aptu")
+        .env("XDG_DATA_HOME", data.path())
+        .args(["tests", "affected", "--output", "json", "--diff"])
+        .arg(&diff)
+        .arg("--repo-path")
+        .arg(repo.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"name\": \"checks_target\""))
+        .stdout(predicate::str::contains("checks_other").not());
+
+    cargo_bin_cmd!("aptu")
+        .env("XDG_DATA_HOME", data.path())
+        .args(["tests", "affected", "--filter", "cargo", "--diff"])
+        .arg(&diff)
+        .arg("--repo-path")
+        .arg(repo.path())
+        .assert()
+        .success()
+        .stdout("cargo test -- checks_target\n");
+}
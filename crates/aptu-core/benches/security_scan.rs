@@ -4,7 +4,7 @@
 //!
 //! Validates that pattern matching completes in <10ms for typical code samples.
 
-use aptu_core::security::SecurityScanner;
+use aptu_core::security::{ScanOptions, SecurityScanner};
 use criterion::{Criterion, criterion_group, criterion_main};
 use std::hint::black_box;
 
@@ -107,10 +107,37 @@ diff --git a/src/config.rs b/src/config.rs
     });
 }
 
+fn bench_scan_directory(c: &mut Criterion) {
+    let scanner = SecurityScanner::new();
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let safe = generate_test_code();
+    let vulnerable = generate_vulnerable_code();
+
+    // 200 source files across 10 directories, plus ignored and binary files.
+    for d in 0..10 {
+        let sub = dir.path().join(format!("module_{d}"));
+        std::fs::create_dir(&sub).expect("create module dir");
+        for f in 0..20 {
+            let code = if f % 10 == 0 { &vulnerable } else { &safe };
+            std::fs::write(sub.join(format!("file_{f}.rs")), code).expect("write source");
+        }
+        std::fs::write(sub.join("blob.bin"), [0u8; 4096]).expect("write binary");
+    }
+    std::fs::create_dir(dir.path().join("target")).expect("create target dir");
+    std::fs::write(dir.path().join("target/generated.rs"), &vulnerable).expect("write ignored");
+    std::fs::write(dir.path().join(".gitignore"), "target/\n").expect("write .gitignore");
+
+    let options = ScanOptions::default();
+    c.bench_function("scan_directory_200_files", |b| {
+        b.iter(|| scanner.scan_path(black_box(dir.path()), black_box(&options)));
+    });
+}
+
 criterion_group!(
     benches,
     bench_scan_safe_code,
     bench_scan_vulnerable_code,
-    bench_scan_diff
+    bench_scan_diff,
+    bench_scan_directory
 );
 criterion_main!(benches);
@@ -98,7 +98,7 @@ pub struct ClaudeCredentials {
 /// Creates an HTTP client with timeout. On native targets, the request timeout
 /// is set on the client; on wasm32, the browser's fetch API manages timeouts
 /// independently and reqwest's `timeout()` is unavailable.
-fn build_http_client(timeout_seconds: u64) -> Result<Client> {
+pub(crate) fn build_http_client(timeout_seconds: u64) -> Result<Client> {
     #[cfg(not(target_arch = "wasm32"))]
     let http = Client::builder()
         .timeout(std::time::Duration::from_secs(timeout_seconds))
@@ -474,6 +474,7 @@ mod tests {
             fallback: None,
             custom_guidance: None,
             validation_enabled: true,
+            embedding_model: None,
         }
     }
 
@@ -0,0 +1,868 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Semantic classification of PR file diffs.
+//!
+//! Detects changes that cannot alter behaviour -- consistent identifier renames,
+//! verbatim moves, formatting-only and comment-only edits -- so the review can drop
+//! them from the prompt first and skip the AI call when nothing else changed.
+//!
+//! Each contiguous run of removed and added lines is lexed into a token stream with
+//! whitespace and comments separated out, then the two streams are compared. The
+//! classifier is conservative: unsupported languages, truncated patches, and string
+//! literals spanning lines are always `Behavioural`. Line breaks count in languages
+//! with automatic semicolon insertion, indentation counts in Python, and directive
+//! comments (`//go:build`, `# type:`) count as code.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ai::types::PrFile;
+
+/// Keywords that must never be treated as renamed identifiers.
+const KEYWORDS: &[&str] = &[
+    "and", "as", "async", "await", "break", "case", "catch", "class", "const", "continue", "def",
+    "default", "defer", "do", "elif", "else", "enum", "except", "extern", "false", "final",
+    "finally", "fn", "for", "from", "func", "function", "go", "if", "impl", "import", "in", "is",
+    "lambda", "let", "loop", "match", "mod", "move", "mut", "new", "nil", "None", "not", "null",
+    "or", "pass", "pub", "raise", "ref", "return", "select", "self", "Self", "static", "struct",
+    "super", "switch", "this", "throw", "trait", "true", "try", "type", "unsafe", "use", "var",
+    "where", "while", "with", "yield",
+];
+
+/// Keywords whose following identifier is a declaration.
+const DECLARATION_KEYWORDS: &[&str] = &[
+    "class",
+    "const",
+    "def",
+    "enum",
+    "fn",
+    "func",
+    "function",
+    "interface",
+    "let",
+    "mod",
+    "static",
+    "struct",
+    "trait",
+    "type",
+    "var",
+];
+
+/// Comment prefixes that tools read as directives, so editing them can change
+/// behaviour. Matched against the comment text after the comment marker.
+const DIRECTIVE_PREFIXES: &[&str] = &[
+    "go:",
+    "+build",
+    "export",
+    "line ",
+    "type:",
+    "[",
+    "@ts-",
+    "eslint",
+    "noqa",
+    "nolint",
+    "pylint:",
+    "pyright:",
+    "mypy:",
+    "#region",
+    "#endregion",
+];
+
+/// Declaration keywords that introduce a binding local to a function, which a
+/// rename in another file cannot refer to.
+const LOCAL_DECLARATION_KEYWORDS: &[&str] = &["let", "var"];
+
+/// Line prefixes treated as import bookkeeping when detecting moves.
+const IMPORT_PREFIXES: &[&str] = &["use ", "import ", "from ", "mod ", "package ", "#include"];
+
+/// How a single file's diff affects program behaviour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeClass {
+    /// The diff may change behaviour (the default when nothing weaker can be shown).
+    Behavioural,
+    /// Identifiers renamed consistently across the PR, declaration included.
+    Rename,
+    /// File renamed unchanged, or code moved verbatim to or from another file in the PR.
+    Move,
+    /// Only whitespace, line breaks, or trailing commas changed.
+    Formatting,
+    /// Only comments changed.
+    Comment,
+}
+
+impl ChangeClass {
+    /// Returns true when the change may alter behaviour.
+    #[must_use]
+    pub fn is_behavioural(self) -> bool {
+        self == Self::Behavioural
+    }
+
+    /// Returns the lowercase name used in prompts and metrics.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Behavioural => "behavioural",
+            Self::Rename => "rename",
+            Self::Move => "move",
+            Self::Formatting => "formatting",
+            Self::Comment => "comment",
+        }
+    }
+}
+
+impl fmt::Display for ChangeClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Classifies every file in the PR, returning one class per file in input order.
+///
+/// Renames are validated across the whole PR: every old name must map to a single new
+/// name, its declaration must be renamed to that name in the same file (or, for items
+/// other than local variables, in any file), and the new name must not be referenced
+/// anywhere the diff shows before the change. Moves are matched against the removed
+/// and added lines of the other files.
+#[must_use]
+pub fn classify_files(files: &[PrFile]) -> Vec<ChangeClass> {
+    let analyses: Vec<FileAnalysis> = files.iter().map(analyze_file).collect();
+    let renames_valid = renames_consistent(&analyses);
+    let sides: Vec<(String, String)> = files.iter().map(move_sides).collect();
+
+    analyses
+        .iter()
+        .enumerate()
+        .map(|(idx, analysis)| match analysis.class {
+            ChangeClass::Rename if !renames_valid => ChangeClass::Behavioural,
+            ChangeClass::Behavioural if is_move(files, &sides, idx) => ChangeClass::Move,
+            class => class,
+        })
+        .collect()
+}
+
+/// Which line layout is part of a language's syntax.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Layout {
+    /// Line breaks and indentation are insignificant.
+    Free,
+    /// Line breaks can end statements (automatic semicolon insertion).
+    LineBreaks,
+    /// Indentation delimits blocks.
+    Indentation,
+}
+
+/// Syntax needed to separate code, comments, and string literals.
+#[derive(Clone, Copy)]
+struct Syntax {
+    line_comment: &'static str,
+    block_comments: bool,
+    layout: Layout,
+    lifetimes: bool,
+}
+
+/// Returns the lexer syntax for a file, or `None` when the language is not supported.
+fn syntax_for(filename: &str) -> Option<Syntax> {
+    let c_like = Syntax {
+        line_comment: "//",
+        block_comments: true,
+        layout: Layout::Free,
+        lifetimes: false,
+    };
+    match Path::new(filename).extension()?.to_str()? {
+        "rs" => Some(Syntax {
+            lifetimes: true,
+            ..c_like
+        }),
+        "c" | "h" | "cc" | "cpp" | "cxx" | "hpp" | "cs" | "java" => Some(c_like),
+        "go" | "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" | "kt" | "kts" | "swift" | "scala" => {
+            Some(Syntax {
+                layout: Layout::LineBreaks,
+                ..c_like
+            })
+        }
+        "py" => Some(Syntax {
+            line_comment: "#",
+            block_comments: false,
+            layout: Layout::Indentation,
+            lifetimes: false,
+        }),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Ident(String),
+    Other(String),
+    Comment(String),
+    Indent(usize),
+    Newline,
+}
+
+/// A maximal run of removed and added lines, bounded by context lines or hunk headers.
+#[derive(Debug, Default)]
+struct ChangeBlock {
+    removed: Vec<String>,
+    added: Vec<String>,
+}
+
+impl ChangeBlock {
+    fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty()
+    }
+}
+
+fn parse_blocks(patch: &str) -> Vec<ChangeBlock> {
+    let mut blocks = Vec::new();
+    let mut current = ChangeBlock::default();
+    let mut in_hunk = false;
+    for line in patch.lines() {
+        if let Some(rest) = line.strip_prefix('-').filter(|_| in_hunk) {
+            current.removed.push(rest.to_string());
+        } else if let Some(rest) = line.strip_prefix('+').filter(|_| in_hunk) {
+            current.added.push(rest.to_string());
+        } else if line.starts_with('\\') {
+            // "\ No newline at end of file" does not end the block.
+        } else {
+            in_hunk |= line.starts_with("@@");
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+    blocks
+}
+
+fn starts_with(chars: &[char], at: usize, pattern: &str) -> bool {
+    !pattern.is_empty()
+        && pattern
+            .chars()
+            .enumerate()
+            .all(|(offset, c)| chars.get(at + offset) == Some(&c))
+}
+
+fn normalize_comment(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Returns a comment token, or a code token for directive comments so that
+/// changing them is behavioural.
+fn comment_token(text: &str) -> Token {
+    let normalized = normalize_comment(text);
+    if DIRECTIVE_PREFIXES.iter().any(|p| normalized.starts_with(p)) {
+        Token::Other(normalized)
+    } else {
+        Token::Comment(normalized)
+    }
+}
+
+/// Returns the index of the closing quote of the string literal starting at `start`.
+fn string_end(chars: &[char], start: usize) -> Option<usize> {
+    let quote = chars[start];
+    let mut idx = start + 1;
+    while idx < chars.len() {
+        match chars[idx] {
+            '\\' => idx += 2,
+            c if c == quote => return Some(idx),
+            _ => idx += 1,
+        }
+    }
+    None
+}
+
+/// True for a Rust lifetime or label (`'a`), as opposed to a char literal (`'a'`).
+fn is_lifetime(chars: &[char], at: usize) -> bool {
+    chars
+        .get(at + 1)
+        .is_some_and(|c| c.is_alphabetic() || *c == '_')
+        && chars.get(at + 2) != Some(&'\'')
+}
+
+fn is_operator(c: char) -> bool {
+    !c.is_alphanumeric() && !c.is_whitespace() && !"_()[]{},;\"'`".contains(c)
+}
+
+/// Tokenizes `lines` as one contiguous run of source.
+///
+/// Returns `None` when a string literal is not closed on its line, since whitespace
+/// inside it is significant and cannot be compared token by token.
+fn lex(lines: &[String], syntax: Syntax) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut block_comment: Option<String> = None;
+    for line in lines {
+        let chars: Vec<char> = line.chars().collect();
+        let line_start = tokens.len();
+        let mut idx = 0;
+        while idx < chars.len() {
+            if let Some(text) = block_comment.as_mut() {
+                let end = (idx..chars.len()).find(|&at| starts_with(&chars, at, "*/"));
+                text.push(' ');
+                text.extend(&chars[idx..end.unwrap_or(chars.len())]);
+                if let Some(end) = end {
+                    tokens.push(comment_token(text));
+                    block_comment = None;
+                    idx = end + 2;
+                } else {
+                    idx = chars.len();
+                }
+                continue;
+            }
+            let c = chars[idx];
+            if c.is_whitespace() {
+                idx += 1;
+            } else if starts_with(&chars, idx, syntax.line_comment) {
+                let text: String = chars[idx + syntax.line_comment.len()..].iter().collect();
+                tokens.push(comment_token(&text));
+                idx = chars.len();
+            } else if syntax.block_comments && starts_with(&chars, idx, "/*") {
+                block_comment = Some(String::new());
+                idx += 2;
+            } else if c == '\'' && syntax.lifetimes && is_lifetime(&chars, idx) {
+                tokens.push(Token::Other("'".to_string()));
+                idx += 1;
+            } else if matches!(c, '"' | '\'' | '`') {
+                let end = string_end(&chars, idx)?;
+                tokens.push(Token::Other(chars[idx..=end].iter().collect()));
+                idx = end + 1;
+            } else if c.is_alphanumeric() || c == '_' {
+                let start = idx;
+                while idx < chars.len() && (chars[idx].is_alphanumeric() || chars[idx] == '_') {
+                    idx += 1;
+                }
+                let word: String = chars[start..idx].iter().collect();
+                tokens.push(if c.is_numeric() {
+                    Token::Other(word)
+                } else {
+                    Token::Ident(word)
+                });
+            } else if "()[]{},;".contains(c) {
+                tokens.push(Token::Other(c.to_string()));
+                idx += 1;
+            } else {
+                let start = idx;
+                idx += 1;
+                while idx < chars.len()
+                    && is_operator(chars[idx])
+                    && !starts_with(&chars, idx, syntax.line_comment)
+                    && !(syntax.block_comments && starts_with(&chars, idx, "/*"))
+                {
+                    idx += 1;
+                }
+                tokens.push(Token::Other(chars[start..idx].iter().collect()));
+            }
+        }
+        if syntax.layout == Layout::Indentation
+            && tokens[line_start..]
+                .iter()
+                .any(|t| !matches!(t, Token::Comment(_)))
+        {
+            let indent = chars.iter().take_while(|c| c.is_whitespace()).count();
+            tokens.insert(line_start, Token::Indent(indent));
+        }
+        if syntax.layout == Layout::LineBreaks
+            && block_comment.is_none()
+            && tokens[line_start..]
+                .iter()
+                .any(|t| !matches!(t, Token::Comment(_)))
+        {
+            tokens.push(Token::Newline);
+        }
+    }
+    if let Some(text) = block_comment {
+        tokens.push(comment_token(&text));
+    }
+    Some(tokens)
+}
+
+/// Returns the code tokens, dropping trailing commas where the language ignores them.
+fn code_tokens(tokens: &[Token], syntax: Syntax) -> Vec<&Token> {
+    let code: Vec<&Token> = tokens
+        .iter()
+        .filter(|t| !matches!(t, Token::Comment(_)))
+        .collect();
+    if syntax.layout == Layout::Indentation {
+        // `(x,)` and `(x)` differ in Python.
+        return code;
+    }
+    code.iter()
+        .enumerate()
+        .filter(|(idx, t)| {
+            let closes_next = code.get(idx + 1).is_some_and(
+                |next| matches!(next, Token::Other(s) if matches!(s.as_str(), ")" | "]" | "}")),
+            );
+            !(matches!(t, Token::Other(s) if s == ",") && closes_next)
+        })
+        .map(|(_, t)| *t)
+        .collect()
+}
+
+fn comment_tokens(tokens: &[Token]) -> Vec<&Token> {
+    tokens
+        .iter()
+        .filter(|t| matches!(t, Token::Comment(_)))
+        .collect()
+}
+
+/// An identifier replaced by another at the same position in a change block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RenamedIdent {
+    old: String,
+    new: String,
+    /// `Some(local)` when this position is the name in a declaration; `local` is
+    /// true for local variables (`let`, `var`).
+    declaration: Option<bool>,
+}
+
+enum BlockChange {
+    Formatting,
+    Comment,
+    Rename(Vec<RenamedIdent>),
+    Behavioural,
+}
+
+/// Returns `Some(local)` when `code[index]` is the name introduced by a
+/// declaration keyword (`fn foo`, `let mut x`, `class Bar`).
+fn declaration_at(code: &[&Token], index: usize) -> Option<bool> {
+    let mut previous = code[..index]
+        .iter()
+        .rev()
+        .filter(|t| !matches!(t, Token::Newline | Token::Indent(_)));
+    let mut keyword = previous.next()?;
+    if matches!(keyword, Token::Ident(m) if m == "mut") {
+        keyword = previous.next()?;
+    }
+    match keyword {
+        Token::Ident(keyword) if DECLARATION_KEYWORDS.contains(&keyword.as_str()) => {
+            Some(LOCAL_DECLARATION_KEYWORDS.contains(&keyword.as_str()))
+        }
+        _ => None,
+    }
+}
+
+fn compare_block(before: &[Token], after: &[Token], syntax: Syntax) -> BlockChange {
+    let code_before = code_tokens(before, syntax);
+    let code_after = code_tokens(after, syntax);
+    if code_before.len() != code_after.len() {
+        return BlockChange::Behavioural;
+    }
+
+    let mut renames = Vec::new();
+    for (index, (old, new)) in code_before.iter().zip(&code_after).enumerate() {
+        if old == new {
+            continue;
+        }
+        match (old, new) {
+            (Token::Ident(old), Token::Ident(new))
+                if !KEYWORDS.contains(&old.as_str()) && !KEYWORDS.contains(&new.as_str()) =>
+            {
+                renames.push(RenamedIdent {
+                    old: old.clone(),
+                    new: new.clone(),
+                    declaration: declaration_at(&code_before, index),
+                });
+            }
+            _ => return BlockChange::Behavioural,
+        }
+    }
+
+    if !renames.is_empty() {
+        BlockChange::Rename(renames)
+    } else if comment_tokens(before) == comment_tokens(after) {
+        BlockChange::Formatting
+    } else {
+        BlockChange::Comment
+    }
+}
+
+/// Per-file result before PR-wide rename and move checks.
+struct FileAnalysis {
+    class: ChangeClass,
+    renames: Vec<RenamedIdent>,
+    /// Words on removed and context lines: what the file referenced before.
+    words_before: HashSet<String>,
+    /// Words on added and context lines: what the file references after.
+    words_after: HashSet<String>,
+}
+
+/// Identifier-like words in `line`, including those in comments and strings.
+fn words(line: &str) -> impl Iterator<Item = String> + '_ {
+    line.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+}
+
+fn analyze_file(file: &PrFile) -> FileAnalysis {
+    let mut analysis = FileAnalysis {
+        class: ChangeClass::Behavioural,
+        renames: Vec::new(),
+        words_before: HashSet::new(),
+        words_after: HashSet::new(),
+    };
+    let (Some(syntax), Some(patch)) = (syntax_for(&file.filename), file.patch.as_deref()) else {
+        return analysis;
+    };
+    let blocks = parse_blocks(patch);
+    if file.patch_truncated || blocks.is_empty() {
+        return analysis;
+    }
+    for line in patch.lines().filter(|l| !l.starts_with("@@")) {
+        if let Some(removed) = line.strip_prefix('-') {
+            analysis.words_before.extend(words(removed));
+        } else if let Some(added) = line.strip_prefix('+') {
+            analysis.words_after.extend(words(added));
+        } else {
+            analysis.words_before.extend(words(line));
+            analysis.words_after.extend(words(line));
+        }
+    }
+
+    let mut class = ChangeClass::Formatting;
+    for block in &blocks {
+        let (Some(before), Some(after)) = (lex(&block.removed, syntax), lex(&block.added, syntax))
+        else {
+            return analysis;
+        };
+        match compare_block(&before, &after, syntax) {
+            BlockChange::Comment if class == ChangeClass::Formatting => {
+                class = ChangeClass::Comment;
+            }
+            BlockChange::Formatting | BlockChange::Comment => {}
+            BlockChange::Rename(pairs) => {
+                analysis.renames.extend(pairs);
+                class = ChangeClass::Rename;
+            }
+            BlockChange::Behavioural => return analysis,
+        }
+    }
+    analysis.class = class;
+    analysis
+}
+
+/// True when every rename in the PR is a real rename:
+///
+/// - each old name maps to one new name and each new name to one old name;
+/// - the old name's declaration is renamed to the new name, in the same file for
+///   local variables;
+/// - the new name is not referenced before the change and the old name is not
+///   referenced after it, on any line the diff shows, so no reference can silently
+///   bind to a different declaration.
+fn renames_consistent(analyses: &[FileAnalysis]) -> bool {
+    let renaming = || {
+        analyses
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| a.class == ChangeClass::Rename)
+    };
+    let mut forward: HashMap<&str, &str> = HashMap::new();
+    let mut backward: HashMap<&str, &str> = HashMap::new();
+    for rename in renaming().flat_map(|(_, a)| &a.renames) {
+        if *forward.entry(&rename.old).or_insert(&rename.new) != rename.new.as_str()
+            || *backward.entry(&rename.new).or_insert(&rename.old) != rename.old.as_str()
+        {
+            return false;
+        }
+    }
+
+    renaming().all(|(file, analysis)| {
+        analysis.renames.iter().all(|rename| {
+            let declared = renaming().any(|(other, a)| {
+                a.renames.iter().any(|r| {
+                    r.old == rename.old
+                        && r.new == rename.new
+                        && r.declaration.is_some_and(|local| !local || other == file)
+                })
+            });
+            let fresh = analyses.iter().all(|a| {
+                !a.words_before.contains(&rename.new) && !a.words_after.contains(&rename.old)
+            });
+            declared && fresh
+        })
+    })
+}
+
+/// Normalized removed and added text of a file for move matching: one trimmed line
+/// per row, skipping blank, punctuation-only, and import lines, each block framed by
+/// newlines so matches cannot start or end mid-line.
+fn move_sides(file: &PrFile) -> (String, String) {
+    let Some(patch) = file.patch.as_deref().filter(|_| !file.patch_truncated) else {
+        return (String::new(), String::new());
+    };
+    let keep_indent = keeps_indent(&file.filename);
+    let mut removed = String::new();
+    let mut added = String::new();
+    for block in parse_blocks(patch) {
+        for (side, lines) in [(&mut removed, &block.removed), (&mut added, &block.added)] {
+            let text = move_text(lines, keep_indent);
+            if !text.is_empty() {
+                side.push('\n');
+                side.push_str(&text);
+                side.push('\n');
+            }
+        }
+    }
+    (removed, added)
+}
+
+/// True when indentation is part of the syntax, so moved code must keep it.
+fn keeps_indent(filename: &str) -> bool {
+    syntax_for(filename).is_some_and(|syntax| syntax.layout == Layout::Indentation)
+}
+
+fn move_text(lines: &[String], keep_indent: bool) -> String {
+    lines
+        .iter()
+        .map(|l| if keep_indent { l.trim_end() } else { l.trim() })
+        .filter(|l| l.chars().any(char::is_alphanumeric))
+        .filter(|l| {
+            let l = l.trim_start();
+            let l = l.strip_prefix("pub ").unwrap_or(l);
+            !IMPORT_PREFIXES.iter().any(|p| l.starts_with(*p))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// True when the file was renamed unchanged, or every removed block appears verbatim
+/// in another file's added lines and every added block in another file's removed lines.
+fn is_move(files: &[PrFile], sides: &[(String, String)], index: usize) -> bool {
+    let file = &files[index];
+    if file.status == "renamed" && file.additions == 0 && file.deletions == 0 {
+        return true;
+    }
+    if syntax_for(&file.filename).is_none() {
+        return false;
+    }
+    let Some(patch) = file.patch.as_deref().filter(|_| !file.patch_truncated) else {
+        return false;
+    };
+
+    let others = sides
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| *idx != index)
+        .map(|(_, side)| side);
+    let others_removed: String = others
+        .clone()
+        .map(|(removed, _)| removed.as_str())
+        .collect();
+    let others_added: String = others.map(|(_, added)| added.as_str()).collect();
+
+    let keep_indent = keeps_indent(&file.filename);
+    let mut moved_any = false;
+    for block in parse_blocks(patch) {
+        for (lines, counterpart) in [
+            (&block.removed, &others_added),
+            (&block.added, &others_removed),
+        ] {
+            let text = move_text(lines, keep_indent);
+            if text.is_empty() {
+                continue;
+            }
+            if !counterpart.contains(&format!("\n{text}\n")) {
+                return false;
+            }
+            moved_any = true;
+        }
+    }
+    moved_any
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(filename: &str, patch: &str) -> PrFile {
+        PrFile {
+            filename: filename.to_string(),
+            previous_filename: None,
+            status: "modified".to_string(),
+            additions: 1,
+            deletions: 1,
+            patch: Some(patch.to_string()),
+            patch_truncated: false,
+            full_content: None,
+        }
+    }
+
+    fn classify_one(filename: &str, patch: &str) -> ChangeClass {
+        classify_files(&[file(filename, patch)])[0]
+    }
+
+    #[test]
+    fn test_formatting_only_change() {
+        let patch = "@@ -1,3 +1,5 @@\n-fn add(a: i32,b: i32) -> i32 { a+b }\n+fn add(a: i32, b: i32) -> i32 {\n+    a + b\n+}\n";
+        assert_eq!(classify_one("src/lib.rs", patch), ChangeClass::Formatting);
+
+        // Trailing commas added by a formatter are ignored outside Python.
+        let patch = "@@ -1 +1,4 @@\n-call(a, b);\n+call(\n+    a,\n+    b,\n+);\n";
+        assert_eq!(classify_one("src/lib.rs", patch), ChangeClass::Formatting);
+    }
+
+    #[test]
+    fn test_comment_only_change() {
+        let patch = "@@ -1,2 +1,2 @@\n-// Adds two numbers.\n+/// Returns the sum of two numbers.\n fn add() {}\n";
+        assert_eq!(classify_one("src/lib.rs", patch), ChangeClass::Comment);
+
+        let patch = "@@ -1 +1 @@\n-x = 1  # one\n+x = 1  # the number one\n";
+        assert_eq!(classify_one("app.py", patch), ChangeClass::Comment);
+    }
+
+    #[test]
+    fn test_behavioural_changes_are_not_masked() {
+        // Literal change.
+        let patch = "@@ -1 +1 @@\n-let x = 1;\n+let x = 2;\n";
+        assert_eq!(classify_one("src/lib.rs", patch), ChangeClass::Behavioural);
+
+        // Whitespace inside a string literal is significant.
+        let patch = "@@ -1 +1 @@\n-let s = \"a b\";\n+let s = \"a  b\";\n";
+        assert_eq!(classify_one("src/lib.rs", patch), ChangeClass::Behavioural);
+
+        // Python indentation is significant.
+        let patch = "@@ -1,2 +1,2 @@\n if ok:\n-    run()\n+run()\n";
+        assert_eq!(classify_one("app.py", patch), ChangeClass::Behavioural);
+
+        // A statement moved past a context line is reordered, not reformatted.
+        let patch = "@@ -1,3 +1,3 @@\n-a();\n b();\n+a();\n";
+        assert_eq!(classify_one("src/lib.rs", patch), ChangeClass::Behavioural);
+
+        // Unsupported languages are never classified.
+        let patch = "@@ -1 +1 @@\n-# Title\n+#  Title\n";
+        assert_eq!(classify_one("README.md", patch), ChangeClass::Behavioural);
+    }
+
+    #[test]
+    fn test_rename_requires_declaration_and_consistency() {
+        let decl = file(
+            "src/lib.rs",
+            "@@ -1 +1 @@\n-pub fn old_name() {}\n+pub fn new_name() {}\n",
+        );
+        let call = file(
+            "src/main.rs",
+            "@@ -1 +1 @@\n-    lib::old_name();\n+    lib::new_name();\n",
+        );
+        assert_eq!(
+            classify_files(&[decl.clone(), call.clone()]),
+            vec![ChangeClass::Rename, ChangeClass::Rename]
+        );
+
+        // Without the declaration, the call site may now bind to a different function.
+        assert_eq!(classify_files(&[call]), vec![ChangeClass::Behavioural]);
+
+        // The same old name mapped to two new names is not a rename.
+        let conflicting = file(
+            "src/other.rs",
+            "@@ -1 +1 @@\n-    old_name();\n+    other_name();\n",
+        );
+        assert_eq!(
+            classify_files(&[decl, conflicting]),
+            vec![ChangeClass::Behavioural, ChangeClass::Behavioural]
+        );
+    }
+
+    #[test]
+    fn test_rename_requires_fresh_name_and_renamed_declaration() {
+        // `b` is already bound, so the return now reads a different variable.
+        let patch =
+            "@@ -1,3 +1,3 @@\n let b = 2;\n-let a = 1;\n-return a;\n+let b = 1;\n+return b;\n";
+        assert_eq!(classify_one("src/lib.rs", patch), ChangeClass::Behavioural);
+
+        // A use renamed without its declaration.
+        let patch = "@@ -1,2 +1,2 @@\n let a = 1;\n-return a;\n+return b;\n";
+        assert_eq!(classify_one("src/lib.rs", patch), ChangeClass::Behavioural);
+
+        // A local variable cannot be renamed from another file.
+        let decl = file(
+            "src/a.rs",
+            "@@ -1 +1 @@\n-    let total = 0;\n+    let sum = 0;\n",
+        );
+        let other = file(
+            "src/b.rs",
+            "@@ -1 +1 @@\n-    print(total);\n+    print(sum);\n",
+        );
+        assert_eq!(
+            classify_files(&[decl.clone(), other]),
+            vec![ChangeClass::Behavioural, ChangeClass::Behavioural]
+        );
+        assert_eq!(classify_files(&[decl]), vec![ChangeClass::Rename]);
+    }
+
+    #[test]
+    fn test_line_breaks_and_directives_are_significant() {
+        // Automatic semicolon insertion: `return` followed by a newline returns undefined.
+        let patch = "@@ -1 +1,2 @@\n-return value;\n+return\n+value;\n";
+        assert_eq!(classify_one("src/app.js", patch), ChangeClass::Behavioural);
+        let patch = "@@ -1 +1 @@\n-return  value;\n+return value;\n";
+        assert_eq!(classify_one("src/app.js", patch), ChangeClass::Formatting);
+
+        // Build constraints and type comments are read by tools.
+        let patch = "@@ -1 +1 @@\n-//go:build linux\n+//go:build windows\n";
+        assert_eq!(classify_one("main.go", patch), ChangeClass::Behavioural);
+        let patch = "@@ -1 +1 @@\n-x = []  # type: List[int]\n+x = []  # type: List[str]\n";
+        assert_eq!(classify_one("app.py", patch), ChangeClass::Behavioural);
+    }
+
+    #[test]
+    fn test_python_move_must_keep_indentation() {
+        let mut source = file(
+            "a.py",
+            "@@ -1,2 +0,0 @@\n-def helper(x):\n-    return x * 2\n",
+        );
+        source.additions = 0;
+        let mut target = file(
+            "b.py",
+            "@@ -1 +1,3 @@\n class Tools:\n+    def helper(x):\n+        return x * 2\n",
+        );
+        target.deletions = 0;
+        assert_eq!(
+            classify_files(&[source.clone(), target]),
+            vec![ChangeClass::Behavioural, ChangeClass::Behavioural]
+        );
+
+        let mut same_indent = file(
+            "b.py",
+            "@@ -1 +1,3 @@\n import a\n+def helper(x):\n+    return x * 2\n",
+        );
+        same_indent.deletions = 0;
+        assert_eq!(
+            classify_files(&[source, same_indent]),
+            vec![ChangeClass::Move, ChangeClass::Move]
+        );
+    }
+
+    #[test]
+    fn test_cross_file_move() {
+        let mut source = file(
+            "src/a.rs",
+            "@@ -1,4 +1 @@\n use std::fmt;\n-fn helper(x: u32) -> u32 {\n-    x * 2\n-}\n",
+        );
+        source.additions = 0;
+        let mut target = file(
+            "src/b.rs",
+            "@@ -1 +1,5 @@\n+use crate::a;\n+\n+fn helper(x: u32) -> u32 {\n+    x * 2\n+}\n",
+        );
+        target.deletions = 0;
+        assert_eq!(
+            classify_files(&[source.clone(), target]),
+            vec![ChangeClass::Move, ChangeClass::Move]
+        );
+
+        // The same removal with no matching addition is a deletion.
+        assert_eq!(classify_files(&[source]), vec![ChangeClass::Behavioural]);
+
+        let renamed = PrFile {
+            status: "renamed".to_string(),
+            additions: 0,
+            deletions: 0,
+            patch: None,
+            ..file("docs/guide.md", "")
+        };
+        assert_eq!(classify_files(&[renamed]), vec![ChangeClass::Move]);
+    }
+}
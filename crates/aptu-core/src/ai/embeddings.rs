@@ -0,0 +1,376 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Text embeddings for related-issue detection.
+//!
+//! Two embedders are available:
+//! - [`Embedder::LexicalHash`]: a dependency-free keyword fallback that hashes
+//!   word unigrams, word bigrams, and character trigrams into a fixed-size
+//!   vector. Works offline and needs no API key, but its similarity only
+//!   measures shared wording: paraphrases and synonyms ("dark mode" vs "dark
+//!   theme") score near zero, and unrelated issues written from the same
+//!   template can score high.
+//! - [`Embedder::Remote`]: the provider's OpenAI-compatible `/embeddings`
+//!   endpoint, used when `ai.embedding_model` is configured.
+//!
+//! All vectors are L2-normalized, so [`cosine`] is a plain dot product.
+
+use anyhow::{Context, Result};
+use reqwest::Client;
+use secrecy::{ExposeSecret, SecretString};
+use serde::Deserialize;
+use serde_json::json;
+use tracing::debug;
+
+use super::registry::{PROVIDER_ANTHROPIC, get_provider};
+use crate::auth::TokenProvider;
+use crate::config::AiConfig;
+
+/// Name of the lexical hashing embedder, recorded in on-disk indexes.
+pub const LEXICAL_EMBEDDER: &str = "local-hash-v1";
+
+/// Dimensionality of lexical hashing embeddings.
+pub const LEXICAL_DIMENSIONS: usize = 256;
+
+/// Maximum number of characters of issue text that are embedded.
+pub const MAX_EMBED_CHARS: usize = 2000;
+
+/// Maximum number of inputs sent in a single `/embeddings` request.
+const REMOTE_BATCH_SIZE: usize = 64;
+
+/// Weight of a character trigram relative to a word or word bigram.
+const TRIGRAM_WEIGHT: f32 = 0.5;
+
+/// Produces embedding vectors for issue text.
+#[derive(Debug, Clone)]
+pub enum Embedder {
+    /// Keyword fallback: lexical feature hashing, no semantic matching.
+    LexicalHash,
+    /// Provider `/embeddings` endpoint.
+    Remote {
+        /// HTTP client.
+        http: Client,
+        /// Provider name (for index bookkeeping and logs).
+        provider: String,
+        /// Embeddings endpoint URL.
+        url: String,
+        /// Provider API key.
+        api_key: SecretString,
+        /// Embedding model identifier.
+        model: String,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingsResponse {
+    data: Vec<EmbeddingData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingData {
+    index: usize,
+    embedding: Vec<f32>,
+}
+
+impl Embedder {
+    /// Selects an embedder from the AI configuration.
+    ///
+    /// Uses the provider's `/embeddings` endpoint when `embedding_model` is
+    /// set and an API key is available; otherwise (including for Anthropic,
+    /// which has no embeddings endpoint) falls back to [`Embedder::LexicalHash`].
+    #[must_use]
+    pub fn from_config(config: &AiConfig, provider: &dyn TokenProvider) -> Self {
+        let Some(model) = config.embedding_model.clone() else {
+            return Self::LexicalHash;
+        };
+        if config.provider == PROVIDER_ANTHROPIC {
+            debug!("Anthropic has no embeddings endpoint, using lexical hashing embedder");
+            return Self::LexicalHash;
+        }
+        let Some(provider_config) = get_provider(&config.provider) else {
+            return Self::LexicalHash;
+        };
+        let Some(api_key) = provider.ai_api_key(&config.provider) else {
+            debug!(
+                provider = %config.provider,
+                "No API key for embeddings, using lexical hashing embedder"
+            );
+            return Self::LexicalHash;
+        };
+        let Ok(http) = super::client::build_http_client(config.timeout_seconds) else {
+            return Self::LexicalHash;
+        };
+
+        Self::Remote {
+            http,
+            provider: config.provider.clone(),
+            url: embeddings_url(provider_config.api_url),
+            api_key,
+            model,
+        }
+    }
+
+    /// Identifies the embedder; indexes built by a different embedder are
+    /// incompatible and must be rebuilt.
+    #[must_use]
+    pub fn name(&self) -> String {
+        match self {
+            Self::LexicalHash => LEXICAL_EMBEDDER.to_string(),
+            Self::Remote {
+                provider, model, ..
+            } => format!("{provider}:{model}"),
+        }
+    }
+
+    /// Embeds each text, returning one L2-normalized vector per input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the embeddings request fails or the response is
+    /// malformed. The lexical embedder never fails.
+    pub async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        match self {
+            Self::LexicalHash => Ok(texts.iter().map(|t| lexical_embedding(t)).collect()),
+            Self::Remote {
+                http,
+                provider,
+                url,
+                api_key,
+                model,
+            } => {
+                let mut vectors = Vec::with_capacity(texts.len());
+                for batch in texts.chunks(REMOTE_BATCH_SIZE) {
+                    let response = http
+                        .post(url.as_str())
+                        .header(
+                            "Authorization",
+                            format!("Bearer {}", api_key.expose_secret()),
+                        )
+                        .json(&json!({ "model": model, "input": batch }))
+                        .send()
+                        .await
+                        .with_context(|| format!("Failed to send {provider} embeddings request"))?;
+
+                    let status = response.status();
+                    if !status.is_success() {
+                        anyhow::bail!("{provider} embeddings API error (HTTP {status})");
+                    }
+
+                    let parsed: EmbeddingsResponse = response.json().await.with_context(|| {
+                        format!("Failed to parse {provider} embeddings response")
+                    })?;
+                    vectors.extend(order_embeddings(parsed.data, batch.len())?);
+                }
+                Ok(vectors)
+            }
+        }
+    }
+}
+
+/// Derives the `/embeddings` endpoint from a provider's chat completions URL.
+#[must_use]
+pub fn embeddings_url(api_url: &str) -> String {
+    let base = api_url
+        .strip_suffix("/chat/completions")
+        .unwrap_or(api_url)
+        .trim_end_matches('/');
+    format!("{base}/embeddings")
+}
+
+/// Reorders embeddings by their `index` field and L2-normalizes them.
+fn order_embeddings(mut data: Vec<EmbeddingData>, expected: usize) -> Result<Vec<Vec<f32>>> {
+    if data.len() != expected {
+        anyhow::bail!(
+            "Embeddings response has {} vectors, expected {expected}",
+            data.len()
+        );
+    }
+    data.sort_by_key(|d| d.index);
+    Ok(data
+        .into_iter()
+        .map(|d| {
+            let mut vector = d.embedding;
+            normalize(&mut vector);
+            vector
+        })
+        .collect())
+}
+
+/// Builds the text embedded for an issue: its title followed by the start of
+/// its body, truncated to [`MAX_EMBED_CHARS`] characters.
+#[must_use]
+pub fn issue_text(title: &str, body: &str) -> String {
+    let mut text = format!("{title}\n{body}");
+    if let Some((cut, _)) = text.char_indices().nth(MAX_EMBED_CHARS) {
+        text.truncate(cut);
+    }
+    text
+}
+
+/// Cosine similarity of two L2-normalized vectors.
+///
+/// Returns `0.0` for vectors of different lengths.
+#[must_use]
+pub fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Embeds text with the lexical feature-hashing embedder.
+///
+/// Lowercased alphanumeric words, adjacent word pairs, and character
+/// trigrams of each word are hashed into [`LEXICAL_DIMENSIONS`] buckets with a
+/// hash-derived sign, so unrelated features tend to cancel rather than
+/// accumulate.
+#[must_use]
+pub fn lexical_embedding(text: &str) -> Vec<f32> {
+    let mut vector = vec![0.0_f32; LEXICAL_DIMENSIONS];
+    let lowered = text.to_lowercase();
+    let words: Vec<&str> = lowered
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.len() > 1)
+        .collect();
+
+    for word in &words {
+        add_feature(&mut vector, word.as_bytes(), 1.0);
+
+        let padded: Vec<char> = format!("#{word}#").chars().collect();
+        for trigram in padded.windows(3) {
+            let trigram: String = trigram.iter().collect();
+            add_feature(&mut vector, trigram.as_bytes(), TRIGRAM_WEIGHT);
+        }
+    }
+    for pair in words.windows(2) {
+        let bigram = format!("{} {}", pair[0], pair[1]);
+        add_feature(&mut vector, bigram.as_bytes(), 1.0);
+    }
+
+    normalize(&mut vector);
+    vector
+}
+
+/// Adds a hashed feature to `vector`.
+fn add_feature(vector: &mut [f32], feature: &[u8], weight: f32) {
+    let hash = fnv1a(feature);
+    #[allow(clippy::cast_possible_truncation)]
+    let bucket = (hash % vector.len() as u64) as usize;
+    let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+    vector[bucket] += sign * weight;
+}
+
+/// 64-bit FNV-1a hash (stable across platforms and releases, unlike
+/// `DefaultHasher`).
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Scales `vector` to unit length (leaves the zero vector unchanged).
+fn normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for x in vector.iter_mut() {
+            *x /= norm;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lexical_embedding_is_normalized_and_deterministic() {
+        let a = lexical_embedding("Crash when parsing config file");
+        let b = lexical_embedding("Crash when parsing config file");
+
+        assert_eq!(a.len(), LEXICAL_DIMENSIONS);
+        assert_eq!(a, b);
+        assert!((cosine(&a, &a) - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn lexical_embedding_ranks_similar_text_higher() {
+        let query = lexical_embedding("App crashes when the config file is missing");
+        let similar = lexical_embedding("Crash on startup if config file missing");
+        let unrelated = lexical_embedding("Add dark mode to the settings page");
+
+        assert!(cosine(&query, &similar) > cosine(&query, &unrelated));
+    }
+
+    #[test]
+    fn lexical_embedding_of_empty_text_is_zero() {
+        let empty = lexical_embedding("");
+
+        assert!(empty.iter().all(|x| x.abs() < f32::EPSILON));
+        assert!(cosine(&empty, &lexical_embedding("anything")).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn embeddings_url_replaces_chat_completions() {
+        assert_eq!(
+            embeddings_url("https://openrouter.ai/api/v1/chat/completions"),
+            "https://openrouter.ai/api/v1/embeddings"
+        );
+        assert_eq!(
+            embeddings_url("https://example.com/v1/"),
+            "https://example.com/v1/embeddings"
+        );
+    }
+
+    #[test]
+    fn order_embeddings_sorts_by_index_and_checks_count() {
+        let data = vec![
+            EmbeddingData {
+                index: 1,
+                embedding: vec![0.0, 2.0],
+            },
+            EmbeddingData {
+                index: 0,
+                embedding: vec![3.0, 0.0],
+            },
+        ];
+
+        let ordered = order_embeddings(data, 2).unwrap();
+
+        assert_eq!(ordered, vec![vec![1.0, 0.0], vec![0.0, 1.0]]);
+        assert!(order_embeddings(Vec::new(), 1).is_err());
+    }
+
+    #[test]
+    fn issue_text_truncates_on_char_boundary() {
+        let body = "é".repeat(MAX_EMBED_CHARS * 2);
+
+        let text = issue_text("Title", &body);
+
+        assert_eq!(text.chars().count(), MAX_EMBED_CHARS);
+        assert!(text.starts_with("Title\n"));
+    }
+
+    #[test]
+    fn from_config_without_embedding_model_is_lexical() {
+        struct NoKeys;
+        impl TokenProvider for NoKeys {
+            fn github_token(&self) -> Option<SecretString> {
+                None
+            }
+            fn ai_api_key(&self, _provider: &str) -> Option<SecretString> {
+                None
+            }
+        }
+
+        let mut config = AiConfig::default();
+        assert_eq!(
+            Embedder::from_config(&config, &NoKeys).name(),
+            LEXICAL_EMBEDDER
+        );
+
+        config.embedding_model = Some("text-embedding-3-small".to_string());
+        assert_eq!(
+            Embedder::from_config(&config, &NoKeys).name(),
+            LEXICAL_EMBEDDER
+        );
+    }
+}
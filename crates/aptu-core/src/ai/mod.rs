@@ -8,7 +8,10 @@ pub mod circuit_breaker;
 pub mod client;
 pub mod context;
 pub mod dep_enrichment;
+pub mod diff_class;
+pub mod embeddings;
 pub mod models;
+pub mod plan_context;
 pub mod prompts;
 pub mod provider;
 pub mod registry;
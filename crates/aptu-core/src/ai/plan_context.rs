@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Local code context for implementation plans.
+//!
+//! `aptu issue plan` grounds its plan in the checkout at `--repo-path`: the
+//! repository graph is loaded (from the same cache PR review uses), the
+//! symbols the issue most likely concerns are located with
+//! [`relevant_symbols`](crate::graph::query::relevant_symbols), and their
+//! neighbourhood and source are rendered for the prompt.
+
+use std::path::Path;
+
+use crate::ai::types::{CodeSymbol, IssueDetails};
+
+/// Maximum number of symbols located for an issue.
+pub const MAX_PLAN_SYMBOLS: usize = 15;
+
+/// Code context gathered from a local checkout for an implementation plan.
+#[derive(Debug, Clone, Default)]
+pub struct PlanContext {
+    /// Symbols the issue most likely concerns, best first.
+    pub symbols: Vec<CodeSymbol>,
+    /// Callers and callees of those symbols, rendered as text.
+    pub graph_context: String,
+    /// Source of the most relevant functions.
+    pub sources: String,
+}
+
+impl PlanContext {
+    /// Returns `true` when no code context was found.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+}
+
+/// Builds the code context for planning `issue` from the checkout at
+/// `repo_path`.
+///
+/// Returns an empty context when no symbol matches the issue, or when the
+/// `ast-context` and `graph` features are off or on WASM.
+#[cfg(all(
+    feature = "ast-context",
+    feature = "graph",
+    not(target_arch = "wasm32")
+))]
+pub async fn build_plan_context(issue: &IssueDetails, repo_path: &Path) -> PlanContext {
+    let mut text = format!("{}\n{}", issue.title, issue.body);
+    for comment in &issue.comments {
+        text.push('\n');
+        text.push_str(&comment.body);
+    }
+    let root = repo_path.to_path_buf();
+
+    match tokio::task::spawn_blocking(move || plan_context_sync(&root, &text)).await {
+        Ok(context) => context,
+        Err(e) => {
+            tracing::warn!("build_plan_context: blocking task panicked: {e}");
+            PlanContext::default()
+        }
+    }
+}
+
+/// Builds the plan code context (stub when `ast-context` or `graph` is off or
+/// on WASM).
+#[allow(clippy::unused_async)]
+#[cfg(not(all(
+    feature = "ast-context",
+    feature = "graph",
+    not(target_arch = "wasm32")
+)))]
+pub async fn build_plan_context(issue: &IssueDetails, repo_path: &Path) -> PlanContext {
+    let _ = (issue, repo_path);
+    PlanContext::default()
+}
+
+#[cfg(all(
+    feature = "ast-context",
+    feature = "graph",
+    not(target_arch = "wasm32")
+))]
+fn plan_context_sync(root: &Path, text: &str) -> PlanContext {
+    use std::fmt::Write;
+
+    use crate::ai::review_context::truncate_at_line_boundary;
+    use crate::graph::Node;
+    use crate::graph::export::node_kind;
+    use crate::graph::query::{blast_radius, relevant_symbols, render_subgraph_text};
+    use crate::graph::repo::load_local_graph;
+
+    /// Maximum number of nodes in the rendered call graph.
+    const PLAN_GRAPH_NODES: usize = 40;
+    /// Soft cap on the call graph section, in characters.
+    const PLAN_GRAPH_CAP: usize = 3_000;
+    /// Maximum number of function bodies included as source excerpts.
+    const PLAN_SOURCE_FUNCTIONS: usize = 5;
+    /// Soft cap on each function body, in characters.
+    const PLAN_SOURCE_CAP: usize = 1_500;
+
+    let graph = load_local_graph(root);
+    let found = relevant_symbols(&graph, text, MAX_PLAN_SYMBOLS);
+    if found.is_empty() {
+        return PlanContext::default();
+    }
+
+    let symbols = found
+        .iter()
+        .map(|&idx| CodeSymbol {
+            kind: node_kind(&graph[idx]).to_string(),
+            name: graph[idx].name().to_string(),
+            path: graph[idx].path().to_string(),
+        })
+        .collect();
+
+    let subgraph = blast_radius(&graph, &found, PLAN_GRAPH_NODES, 1);
+    let seeds: Vec<&Node> = found.iter().map(|&idx| &graph[idx]).collect();
+    let graph_context =
+        truncate_at_line_boundary(&render_subgraph_text(&subgraph, &seeds), PLAN_GRAPH_CAP);
+
+    let mut sources = String::new();
+    for &idx in found
+        .iter()
+        .filter(|&&idx| matches!(graph[idx], Node::Function { .. }))
+        .take(PLAN_SOURCE_FUNCTIONS)
+    {
+        let node = &graph[idx];
+        let Ok(source) = std::fs::read_to_string(root.join(node.path())) else {
+            continue;
+        };
+        // Method nodes are keyed by their type, as the bodies are.
+        for body in crate::ast_context::function_bodies(node.path(), &source)
+            .into_iter()
+            .filter(|b| b.key == node.name())
+        {
+            let _ = write!(
+                sources,
+                "{} ({}):\n{}\n\n",
+                body.key,
+                node.path(),
+                truncate_at_line_boundary(&body.body, PLAN_SOURCE_CAP).trim_end()
+            );
+        }
+    }
+
+    PlanContext {
+        symbols,
+        graph_context,
+        sources,
+    }
+}
+
+#[cfg(all(
+    test,
+    feature = "ast-context",
+    feature = "graph",
+    not(target_arch = "wasm32")
+))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_context_includes_every_same_named_method() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        std::fs::write(
+            dir.path().join("src/lib.rs"),
+            "pub struct Parser;\npub struct Lexer;\n\nimpl Parser {\n    pub fn reset_state(&mut self) {\n        let _ = 1;\n    }\n}\n\nimpl Lexer {\n    pub fn reset_state(&mut self) {\n        let _ = 2;\n    }\n}\n",
+        )
+        .unwrap();
+
+        let context = plan_context_sync(dir.path(), "Calling reset_state twice panics");
+
+        assert!(
+            context.sources.contains("Parser::reset_state (src/lib.rs)"),
+            "{}",
+            context.sources
+        );
+        assert!(context.sources.contains("Lexer::reset_state (src/lib.rs)"));
+        assert!(context.sources.contains("let _ = 2;"));
+    }
+}
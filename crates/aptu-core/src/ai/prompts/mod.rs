@@ -40,6 +40,30 @@ pub const PR_LABEL_SCHEMA: &str = include_str!("pr_label_schema.json");
 pub const PR_LABEL_GUIDELINES: &str = include_str!("pr_label_guidelines.md");
 /// Example output for PR label suggestion user prompts.
 pub const PR_LABEL_EXAMPLE: &str = include_str!("pr_label_example.md");
+/// JSON schema for duplicate cluster responses.
+pub const DEDUPE_SCHEMA: &str = include_str!("dedupe_schema.json");
+/// Guidelines for duplicate cluster system prompts.
+pub const DEDUPE_GUIDELINES: &str = include_str!("dedupe_guidelines.md");
+/// Example output for duplicate cluster user prompts.
+pub const DEDUPE_EXAMPLE: &str = include_str!("dedupe_example.md");
+/// JSON schema for stale nudge responses.
+pub const STALE_SCHEMA: &str = include_str!("stale_schema.json");
+/// Guidelines for stale nudge system prompts.
+pub const STALE_GUIDELINES: &str = include_str!("stale_guidelines.md");
+/// Example output for stale nudge user prompts.
+pub const STALE_EXAMPLE: &str = include_str!("stale_example.md");
+/// JSON schema for issue split responses.
+pub const SPLIT_SCHEMA: &str = include_str!("split_schema.json");
+/// Guidelines for issue split system prompts.
+pub const SPLIT_GUIDELINES: &str = include_str!("split_guidelines.md");
+/// Example output for issue split user prompts.
+pub const SPLIT_EXAMPLE: &str = include_str!("split_example.md");
+/// JSON schema for implementation plan responses.
+pub const PLAN_SCHEMA: &str = include_str!("plan_schema.json");
+/// Guidelines for implementation plan system prompts.
+pub const PLAN_GUIDELINES: &str = include_str!("plan_guidelines.md");
+/// Example output for implementation plan user prompts.
+pub const PLAN_EXAMPLE: &str = include_str!("plan_example.md");
 /// Best-practices context injected into all system prompts (tooling recommendations).
 pub const TOOLING_CONTEXT: &str = include_str!("tooling_context.md");
 
@@ -91,13 +115,58 @@ pub fn build_pr_label_system_prompt(context: &str) -> String {
     )
 }
 
+/// Builds the system prompt for reviewing a cluster of possible duplicate issues.
+#[must_use]
+pub fn build_dedupe_system_prompt(context: &str) -> String {
+    format!(
+        "You are a senior OSS maintainer. Your mission is to decide which issues in a cluster \
+         of similar reports are true duplicates and which one to keep open.\n\n\
+         {context}\n\n\
+         {DEDUPE_GUIDELINES}"
+    )
+}
+
+/// Builds the system prompt for writing a follow-up on an inactive issue or PR.
+#[must_use]
+pub fn build_stale_system_prompt(context: &str) -> String {
+    format!(
+        "You are a senior OSS maintainer. Your mission is to write a short, context-aware \
+         follow-up on an inactive issue or pull request that helps it move forward or close.\n\n\
+         {context}\n\n\
+         {STALE_GUIDELINES}"
+    )
+}
+
+/// Builds the system prompt for splitting a large issue into sub-issues.
+#[must_use]
+pub fn build_split_system_prompt(context: &str) -> String {
+    format!(
+        "You are a senior OSS maintainer. Your mission is to break a large issue into a short \
+         sequence of focused child issues that can each be completed in one pull request.\n\n\
+         {context}\n\n\
+         {SPLIT_GUIDELINES}"
+    )
+}
+
+/// Builds the system prompt for turning an issue into an implementation plan.
+#[must_use]
+pub fn build_plan_system_prompt(context: &str) -> String {
+    format!(
+        "You are a senior software engineer. Your mission is to turn an issue into a concrete, \
+         ordered implementation plan that names the files and functions to change.\n\n\
+         {context}\n\n\
+         {PLAN_GUIDELINES}"
+    )
+}
+
 // ---------------------------------------------------------------------------
 // User-prompt builder functions (moved from provider.rs)
 // ---------------------------------------------------------------------------
 
+use super::plan_context::PlanContext;
 use super::provider::{SCHEMA_PREAMBLE, sanitize_prompt_field};
 use super::review_context::{ReviewContext, truncate_at_line_boundary};
-use super::types::IssueDetails;
+use super::types::{DuplicateCandidate, IssueDetails, StaleNudgeInput};
 use std::fmt::Write;
 use tracing;
 
@@ -107,6 +176,10 @@ const MAX_COMMENTS: usize = 5;
 const MAX_LABELS: usize = 20;
 const MAX_MILESTONES: usize = 10;
 const MAX_FILES: usize = 20;
+const MAX_DEDUPE_BODY_LENGTH: usize = 1000;
+const MAX_STALE_COMMENT_LENGTH: usize = 500;
+const MAX_SPLIT_COMMENT_LENGTH: usize = 500;
+const MAX_PLAN_COMMENT_LENGTH: usize = 500;
 
 /// Appends JSON schema to the prompt with the shared preamble.
 fn append_schema(prompt: &mut String, schema: &str) {
@@ -114,6 +187,71 @@ fn append_schema(prompt: &mut String, schema: &str) {
     prompt.push_str(schema);
 }
 
+/// Appends the required issue form fields the author left empty, if any.
+fn append_missing_template_fields(prompt: &mut String, issue: &IssueDetails) {
+    let Some(check) = issue
+        .template_check
+        .as_ref()
+        .filter(|check| !check.missing_fields.is_empty())
+    else {
+        return;
+    };
+    prompt.push_str("<missing_template_fields>\n");
+    let _ = writeln!(
+        prompt,
+        "Template: {}",
+        sanitize_prompt_field(&check.template)
+    );
+    for field in &check.missing_fields {
+        let _ = writeln!(prompt, "- {}", sanitize_prompt_field(field));
+    }
+    prompt.push_str("</missing_template_fields>\n");
+    prompt.push_str(
+        "These required fields of the issue template were left empty. Ask for each of them \
+         in clarifying_questions, unless the body answers it elsewhere.\n\n",
+    );
+}
+
+/// Appends the earlier aptu triage of the issue, if any.
+///
+/// Returns `true` when a `<previous_triage>` block was written.
+fn append_previous_triage(prompt: &mut String, issue: &IssueDetails) -> bool {
+    let Some(prior) = &issue.prior_triage else {
+        return false;
+    };
+    prompt.push_str("<previous_triage>\n");
+    let _ = writeln!(
+        prompt,
+        "{}",
+        truncate_at_line_boundary(&sanitize_prompt_field(&prior.body), MAX_BODY_LENGTH)
+    );
+    prompt.push_str("</previous_triage>\n");
+    true
+}
+
+/// Appends related issues from the repository search, with their similarity
+/// when they came from the embedding index.
+fn append_related_issues(prompt: &mut String, issue: &IssueDetails) {
+    if issue.repo_context.is_empty() {
+        return;
+    }
+    prompt.push_str("Related Issues in Repository (for context):\n");
+    for related in issue.repo_context.iter().take(10) {
+        let _ = write!(
+            prompt,
+            "- #{} [{}] {}",
+            related.number,
+            sanitize_prompt_field(&related.state),
+            sanitize_prompt_field(&related.title)
+        );
+        if let Some(similarity) = related.similarity {
+            let _ = write!(prompt, " (similarity {similarity:.2})");
+        }
+        prompt.push('\n');
+    }
+    prompt.push('\n');
+}
+
 /// Builds the user prompt for issue triage.
 #[must_use]
 pub fn build_user_prompt(issue: &IssueDetails) -> String {
@@ -144,7 +282,7 @@ pub fn build_user_prompt(issue: &IssueDetails) -> String {
     // Include recent comments (limited)
     if !issue.comments.is_empty() {
         prompt.push_str("Recent Comments:\n");
-        for comment in issue.comments.iter().take(MAX_COMMENTS) {
+        for comment in issue.comments.iter().rev().take(MAX_COMMENTS).rev() {
             let sanitized_comment_body = sanitize_prompt_field(&comment.body);
             let comment_body = if sanitized_comment_body.len() > 500 {
                 format!("{}...", &sanitized_comment_body[..500])
@@ -161,21 +299,21 @@ pub fn build_user_prompt(issue: &IssueDetails) -> String {
         prompt.push('\n');
     }
 
-    // Include related issues from search (for context)
-    if !issue.repo_context.is_empty() {
-        prompt.push_str("Related Issues in Repository (for context):\n");
-        for related in issue.repo_context.iter().take(10) {
-            let _ = writeln!(
-                prompt,
-                "- #{} [{}] {}",
-                related.number,
-                sanitize_prompt_field(&related.state),
-                sanitize_prompt_field(&related.title)
-            );
-        }
-        prompt.push('\n');
+    // Include the earlier triage when re-triaging after the issue changed
+    if append_previous_triage(&mut prompt, issue) {
+        prompt.push_str(
+            "The issue was edited or the author replied since this triage. Revise it with the \
+             new information: drop clarifying questions that have been answered and keep \
+             conclusions that still hold.\n\n",
+        );
     }
 
+    // Include required issue form fields the author left empty
+    append_missing_template_fields(&mut prompt, issue);
+
+    // Include related issues from search (for context)
+    append_related_issues(&mut prompt, issue);
+
     // Include repository structure (source files)
     if !issue.repo_tree.is_empty() {
         prompt.push_str("Repository Structure (source files):\n");
@@ -249,6 +387,288 @@ pub fn build_create_user_prompt(title: &str, body: &str, _repo: &str) -> String
     prompt
 }
 
+/// Builds the user prompt for reviewing a cluster of possible duplicate issues.
+#[must_use]
+pub fn build_dedupe_user_prompt(candidates: &[DuplicateCandidate]) -> String {
+    let mut prompt = String::new();
+
+    prompt.push_str("<issues>\n");
+    for candidate in candidates {
+        let _ = writeln!(
+            prompt,
+            "## #{} {}",
+            candidate.number,
+            sanitize_prompt_field(&candidate.title)
+        );
+        let _ = writeln!(prompt, "Created: {}", candidate.created_at);
+        if !candidate.labels.is_empty() {
+            let _ = writeln!(prompt, "Labels: {}", candidate.labels.join(", "));
+        }
+
+        // Sanitize body before truncation (injection tag could straddle the boundary)
+        let sanitized_body = sanitize_prompt_field(&candidate.body);
+        let body = if sanitized_body.is_empty() {
+            "[No description provided]".to_string()
+        } else if sanitized_body.len() > MAX_DEDUPE_BODY_LENGTH {
+            let cut = sanitized_body.floor_char_boundary(MAX_DEDUPE_BODY_LENGTH);
+            format!(
+                "{}...\n[APTU: body truncated by size budget -- do not speculate on missing content]",
+                &sanitized_body[..cut],
+            )
+        } else {
+            sanitized_body
+        };
+        let _ = writeln!(prompt, "{body}\n");
+    }
+    prompt.push_str("</issues>");
+
+    append_schema(&mut prompt, DEDUPE_SCHEMA);
+    prompt.push_str("\n\nExample output:\n");
+    prompt.push_str(DEDUPE_EXAMPLE);
+
+    prompt
+}
+
+/// Builds the user prompt for a stale nudge.
+#[must_use]
+pub fn build_stale_user_prompt(input: &StaleNudgeInput) -> String {
+    let mut prompt = String::new();
+
+    let _ = writeln!(prompt, "<{}>", input.kind.replace(' ', "_"));
+    let _ = writeln!(
+        prompt,
+        "Title: #{} {}",
+        input.number,
+        sanitize_prompt_field(&input.title)
+    );
+    if !input.author.is_empty() {
+        let _ = writeln!(prompt, "Author: @{}", input.author);
+    }
+    if !input.labels.is_empty() {
+        let _ = writeln!(prompt, "Labels: {}", input.labels.join(", "));
+    }
+    let _ = writeln!(prompt, "Inactive for: {} days", input.inactive_days);
+    match input.close_in_days {
+        Some(days) => {
+            let _ = writeln!(prompt, "Closes in: {days} days without activity");
+        }
+        None => prompt.push_str("Closes in: never (do not mention closing)\n"),
+    }
+
+    // Sanitize body before truncation (injection tag could straddle the boundary)
+    let sanitized_body = sanitize_prompt_field(&input.body);
+    let body = if sanitized_body.is_empty() {
+        "[No description provided]".to_string()
+    } else if sanitized_body.len() > MAX_BODY_LENGTH {
+        let cut = sanitized_body.floor_char_boundary(MAX_BODY_LENGTH);
+        format!(
+            "{}...\n[APTU: body truncated by size budget -- do not speculate on missing content]",
+            &sanitized_body[..cut],
+        )
+    } else {
+        sanitized_body
+    };
+    let _ = writeln!(prompt, "\n{body}");
+    let _ = writeln!(prompt, "</{}>", input.kind.replace(' ', "_"));
+
+    if !input.open_questions.is_empty() {
+        prompt.push_str("\n<open_questions>\n");
+        for question in &input.open_questions {
+            let _ = writeln!(prompt, "- {}", sanitize_prompt_field(question));
+        }
+        prompt.push_str("</open_questions>\n");
+    }
+
+    if !input.recent_comments.is_empty() {
+        prompt.push_str("\n<recent_comments>\n");
+        for comment in input.recent_comments.iter().rev().take(MAX_COMMENTS).rev() {
+            let body = sanitize_prompt_field(&comment.body);
+            let cut = body.floor_char_boundary(MAX_STALE_COMMENT_LENGTH);
+            let _ = writeln!(prompt, "@{}: {}", comment.author, &body[..cut]);
+        }
+        prompt.push_str("</recent_comments>\n");
+    }
+
+    append_schema(&mut prompt, STALE_SCHEMA);
+    prompt.push_str("\n\nExample output:\n");
+    prompt.push_str(STALE_EXAMPLE);
+
+    prompt
+}
+
+/// Builds the user prompt for splitting a large issue into sub-issues.
+#[must_use]
+pub fn build_split_user_prompt(issue: &IssueDetails) -> String {
+    let mut prompt = String::new();
+
+    prompt.push_str("<issue_content>\n");
+    let _ = writeln!(
+        prompt,
+        "Title: #{} {}\n",
+        issue.number,
+        sanitize_prompt_field(&issue.title)
+    );
+
+    // Sanitize body before truncation (injection tag could straddle the boundary)
+    let sanitized_body = sanitize_prompt_field(&issue.body);
+    let body = if sanitized_body.is_empty() {
+        "[No description provided]".to_string()
+    } else if sanitized_body.len() > MAX_BODY_LENGTH {
+        let cut = sanitized_body.floor_char_boundary(MAX_BODY_LENGTH);
+        format!(
+            "{}...\n[APTU: body truncated by size budget -- do not speculate on missing content]",
+            &sanitized_body[..cut],
+        )
+    } else {
+        sanitized_body
+    };
+    let _ = writeln!(prompt, "Body:\n{body}\n");
+
+    if !issue.comments.is_empty() {
+        prompt.push_str("Recent Comments:\n");
+        for comment in issue.comments.iter().rev().take(MAX_COMMENTS).rev() {
+            let body = sanitize_prompt_field(&comment.body);
+            let cut = body.floor_char_boundary(MAX_SPLIT_COMMENT_LENGTH);
+            let _ = writeln!(
+                prompt,
+                "- @{}: {}",
+                sanitize_prompt_field(&comment.author),
+                &body[..cut]
+            );
+        }
+        prompt.push('\n');
+    }
+
+    // An earlier triage often carries the complexity assessment that
+    // prompted the split
+    if append_previous_triage(&mut prompt, issue) {
+        prompt.push('\n');
+    }
+
+    if !issue.repo_tree.is_empty() {
+        prompt.push_str("Repository Structure (source files):\n");
+        for path in issue.repo_tree.iter().take(MAX_FILES) {
+            let _ = writeln!(prompt, "- {path}");
+        }
+        prompt.push('\n');
+    }
+
+    if !issue.available_labels.is_empty() {
+        prompt.push_str("Available Labels:\n");
+        for label in issue.available_labels.iter().take(MAX_LABELS) {
+            let _ = writeln!(prompt, "- {}", sanitize_prompt_field(&label.name));
+        }
+        prompt.push('\n');
+    }
+
+    prompt.push_str("</issue_content>");
+    append_schema(&mut prompt, SPLIT_SCHEMA);
+    prompt.push_str("\n\nExample output:\n");
+    prompt.push_str(SPLIT_EXAMPLE);
+
+    prompt
+}
+
+/// Builds the user prompt for an implementation plan.
+///
+/// `context` is the code located in the local checkout; when it is empty the
+/// repository structure is included instead. With `include_diff`, the model
+/// is also asked for a unified diff implementing the plan.
+#[must_use]
+pub fn build_plan_user_prompt(
+    issue: &IssueDetails,
+    context: &PlanContext,
+    include_diff: bool,
+) -> String {
+    let mut prompt = String::new();
+
+    prompt.push_str("<issue_content>\n");
+    let _ = writeln!(
+        prompt,
+        "Title: #{} {}\n",
+        issue.number,
+        sanitize_prompt_field(&issue.title)
+    );
+
+    // Sanitize body before truncation (injection tag could straddle the boundary)
+    let sanitized_body = sanitize_prompt_field(&issue.body);
+    let body = if sanitized_body.is_empty() {
+        "[No description provided]".to_string()
+    } else if sanitized_body.len() > MAX_BODY_LENGTH {
+        let cut = sanitized_body.floor_char_boundary(MAX_BODY_LENGTH);
+        format!(
+            "{}...\n[APTU: body truncated by size budget -- do not speculate on missing content]",
+            &sanitized_body[..cut],
+        )
+    } else {
+        sanitized_body
+    };
+    let _ = writeln!(prompt, "Body:\n{body}\n");
+
+    if !issue.comments.is_empty() {
+        prompt.push_str("Recent Comments:\n");
+        for comment in issue.comments.iter().rev().take(MAX_COMMENTS).rev() {
+            let body = sanitize_prompt_field(&comment.body);
+            let cut = body.floor_char_boundary(MAX_PLAN_COMMENT_LENGTH);
+            let _ = writeln!(
+                prompt,
+                "- @{}: {}",
+                sanitize_prompt_field(&comment.author),
+                &body[..cut]
+            );
+        }
+        prompt.push('\n');
+    }
+    prompt.push_str("</issue_content>\n\n");
+
+    if context.is_empty() {
+        if !issue.repo_tree.is_empty() {
+            prompt.push_str("Repository Structure (source files):\n");
+            for path in issue.repo_tree.iter().take(MAX_FILES) {
+                let _ = writeln!(prompt, "- {path}");
+            }
+            prompt.push('\n');
+        }
+    } else {
+        prompt.push_str("<relevant_symbols>\n");
+        for symbol in &context.symbols {
+            let _ = writeln!(
+                prompt,
+                "- {} {} ({})",
+                symbol.kind, symbol.name, symbol.path
+            );
+        }
+        prompt.push_str("</relevant_symbols>\n\n");
+
+        if !context.graph_context.is_empty() {
+            prompt.push_str("<call_graph>\n");
+            prompt.push_str(context.graph_context.trim_end());
+            prompt.push_str("\n</call_graph>\n\n");
+        }
+
+        if !context.sources.is_empty() {
+            prompt.push_str("<source>\n");
+            prompt.push_str(context.sources.trim_end());
+            prompt.push_str("\n</source>\n\n");
+        }
+    }
+
+    if include_diff {
+        prompt.push_str(
+            "Also write `diff`: a unified diff in git format (`--- a/<path>`, `+++ b/<path>`) \
+             that implements the steps, with context lines copied exactly from the source above.",
+        );
+    } else {
+        prompt.push_str("Leave `diff` as an empty string.");
+    }
+
+    append_schema(&mut prompt, PLAN_SCHEMA);
+    prompt.push_str("\n\nExample output:\n");
+    prompt.push_str(PLAN_EXAMPLE);
+
+    prompt
+}
+
 /// Builds the user prompt for PR review.
 #[must_use]
 #[allow(clippy::too_many_lines)]
@@ -299,7 +719,21 @@ pub fn build_pr_review_user_prompt(ctx: &mut ReviewContext) -> String {
             )
         };
 
-        let _ = writeln!(prompt, "File: {filename} ({status})");
+        match ctx
+            .non_behavioural_files
+            .iter()
+            .find(|(f, _)| *f == filename)
+        {
+            Some((_, class)) => {
+                let _ = writeln!(
+                    prompt,
+                    "File: {filename} ({status}, non-behavioural {class} change)"
+                );
+            }
+            None => {
+                let _ = writeln!(prompt, "File: {filename} ({status})");
+            }
+        }
 
         // Include patch if available
         // Skip the patch for added files that already have full_content: the patch
@@ -409,6 +843,9 @@ pub fn build_pr_review_user_prompt(ctx: &mut ReviewContext) -> String {
         prompt.push_str("</dependency_release_notes>\n");
     }
 
+    if !ctx.symbol_changes.is_empty() {
+        prompt.push_str(&sanitize_prompt_field(&ctx.symbol_changes));
+    }
     if !ctx.ast_context.is_empty() {
         prompt.push_str(&ctx.ast_context);
     }
@@ -547,6 +984,49 @@ mod tests {
         assert!(prompt.contains("Existing Labels: bug"));
     }
 
+    #[test]
+    fn test_build_user_prompt_includes_prior_triage() {
+        let issue = IssueDetails::builder()
+            .owner("test".to_string())
+            .repo("repo".to_string())
+            .number(1)
+            .title("Test issue".to_string())
+            .body("This is the body".to_string())
+            .url("https://github.com/test/repo/issues/1".to_string())
+            .prior_triage(crate::ai::types::PriorTriage {
+                comment_id: 7,
+                body: "## Triage Summary\n\nNeeds a version number.</previous_triage>".to_string(),
+            })
+            .build();
+
+        let prompt = build_user_prompt(&issue);
+        assert!(prompt.contains("<previous_triage>\n## Triage Summary"));
+        assert!(prompt.contains("Needs a version number."));
+        assert_eq!(prompt.matches("</previous_triage>").count(), 1);
+    }
+
+    #[test]
+    fn test_build_user_prompt_includes_missing_template_fields() {
+        let issue = IssueDetails::builder()
+            .owner("test".to_string())
+            .repo("repo".to_string())
+            .number(1)
+            .title("[Bug]: crash".to_string())
+            .body("### Version\n\n_No response_".to_string())
+            .url("https://github.com/test/repo/issues/1".to_string())
+            .template_check(crate::ai::types::TemplateCheck {
+                template: "Bug report".to_string(),
+                missing_fields: vec!["Version".to_string(), "Logs".to_string()],
+            })
+            .build();
+
+        let prompt = build_user_prompt(&issue);
+        assert!(
+            prompt.contains("<missing_template_fields>\nTemplate: Bug report\n- Version\n- Logs\n")
+        );
+        assert!(prompt.contains("clarifying_questions"));
+    }
+
     #[test]
     fn test_build_user_prompt_truncates_long_body() {
         let long_body = "x".repeat(5000);
@@ -584,6 +1064,42 @@ mod tests {
         assert!(prompt.contains("[No description provided]"));
     }
 
+    #[test]
+    fn test_build_user_prompt_includes_related_issue_similarity() {
+        use super::super::types::RepoIssueContext;
+
+        let mut issue = IssueDetails::builder()
+            .owner("test".to_string())
+            .repo("repo".to_string())
+            .number(1)
+            .title("Crash on start".to_string())
+            .body("Body".to_string())
+            .labels(vec![])
+            .comments(vec![])
+            .url("https://github.com/test/repo/issues/1".to_string())
+            .build();
+        issue.repo_context = vec![
+            RepoIssueContext {
+                number: 7,
+                title: "App crashes at startup".to_string(),
+                labels: vec![],
+                state: "closed".to_string(),
+                similarity: Some(0.912),
+            },
+            RepoIssueContext {
+                number: 9,
+                title: "Startup is slow".to_string(),
+                labels: vec![],
+                state: "open".to_string(),
+                similarity: None,
+            },
+        ];
+
+        let prompt = build_user_prompt(&issue);
+        assert!(prompt.contains("- #7 [closed] App crashes at startup (similarity 0.91)\n"));
+        assert!(prompt.contains("- #9 [open] Startup is slow\n"));
+    }
+
     #[test]
     fn test_build_create_system_prompt_contains_json_schema() {
         let system_prompt = build_create_system_prompt("");
@@ -729,6 +1245,7 @@ mod tests {
 
         let files = vec![PrFile {
             filename: "big_file.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 0,
             deletions: 0,
@@ -749,6 +1266,7 @@ mod tests {
             files,
             labels: vec![],
             head_sha: String::new(),
+            base_sha: String::new(),
             review_comments: vec![],
             instructions: None,
             dep_enrichments: vec![],
@@ -801,6 +1319,7 @@ mod tests {
 
         let files = vec![PrFile {
             filename: "file_with_content.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 1,
             deletions: 0,
@@ -821,6 +1340,7 @@ mod tests {
             files,
             labels: vec![],
             head_sha: String::new(),
+            base_sha: String::new(),
             review_comments: vec![],
             instructions: None,
             dep_enrichments: vec![],
@@ -859,6 +1379,7 @@ mod tests {
 
         let files = vec![PrFile {
             filename: "utf8_file.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 0,
             deletions: 0,
@@ -879,6 +1400,7 @@ mod tests {
             files,
             labels: vec![],
             head_sha: String::new(),
+            base_sha: String::new(),
             review_comments: vec![],
             instructions: None,
             dep_enrichments: vec![],
@@ -913,6 +1435,7 @@ mod tests {
 
         let files = vec![PrFile {
             filename: "oversized.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 0,
             deletions: 0,
@@ -933,6 +1456,7 @@ mod tests {
             files,
             labels: vec![],
             head_sha: String::new(),
+            base_sha: String::new(),
             review_comments: vec![],
             instructions: None,
             dep_enrichments: vec![],
@@ -990,6 +1514,7 @@ mod tests {
 
         let files = vec![PrFile {
             filename: "exact_size.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 0,
             deletions: 0,
@@ -1010,6 +1535,7 @@ mod tests {
             files,
             labels: vec![],
             head_sha: String::new(),
+            base_sha: String::new(),
             review_comments: vec![],
             instructions: None,
             dep_enrichments: vec![],
@@ -1052,6 +1578,7 @@ mod tests {
 
         let files = vec![PrFile {
             filename: "src/lib.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 1,
             deletions: 0,
@@ -1072,6 +1599,7 @@ mod tests {
             files,
             labels: vec![],
             head_sha: String::new(),
+            base_sha: String::new(),
             review_comments,
             instructions: None,
             dep_enrichments: vec![],
@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Duplicate review: pick the canonical issue in a cluster of similar issues.
+//!
+//! Provides `review_duplicate_cluster` and prompt builder helpers.
+
+use anyhow::Result;
+use tracing::{debug, instrument};
+
+use super::http::send_and_parse;
+use super::parse::provider_response_format;
+use crate::ai::provider::AiProvider;
+use crate::ai::types::{
+    ChatCompletionRequest, ChatMessage, DuplicateCandidate, DuplicateClusterResponse,
+};
+use crate::history::AiStats;
+
+/// Builds the system prompt for duplicate cluster review.
+#[must_use]
+pub(super) fn build_dedupe_system_prompt_fn(custom_guidance: Option<&str>) -> String {
+    let context = crate::ai::context::load_custom_guidance(custom_guidance);
+    crate::ai::prompts::build_dedupe_system_prompt(&context)
+}
+
+/// Reviews a cluster of similar issues using the provider's API.
+///
+/// The model picks the canonical issue and which of the others are true
+/// duplicates of it. Numbers outside the cluster are dropped from the
+/// response, as is the canonical issue if listed among its own duplicates.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - API request fails (network, timeout, rate limit)
+/// - Response cannot be parsed as valid JSON
+/// - The chosen canonical issue is not in the cluster
+#[instrument(skip(provider, candidates), fields(cluster_size = candidates.len()))]
+pub(super) async fn review_duplicate_cluster(
+    provider: &(impl AiProvider + ?Sized),
+    candidates: &[DuplicateCandidate],
+) -> Result<(DuplicateClusterResponse, AiStats)> {
+    debug!(model = %provider.model(), "Calling {} API for duplicate review", provider.name());
+
+    // Build request
+    #[cfg(not(target_arch = "wasm32"))]
+    let system_content = if let Some(override_prompt) =
+        crate::ai::context::load_system_prompt_override("dedupe_system").await
+    {
+        override_prompt
+    } else {
+        build_dedupe_system_prompt_fn(provider.custom_guidance())
+    };
+    #[cfg(target_arch = "wasm32")]
+    let system_content = build_dedupe_system_prompt_fn(provider.custom_guidance());
+
+    let mut messages = vec![
+        ChatMessage {
+            role: "system".to_string(),
+            content: Some(system_content),
+            reasoning: None,
+            cache_control: None,
+        },
+        ChatMessage {
+            role: "user".to_string(),
+            content: Some(crate::ai::prompts::build_dedupe_user_prompt(candidates)),
+            reasoning: None,
+            cache_control: None,
+        },
+    ];
+
+    // Inject cache control on system message for Anthropic
+    if provider.is_anthropic()
+        && let Some(msg) = messages.first_mut()
+    {
+        msg.cache_control = Some(crate::ai::types::CacheControl::ephemeral());
+    }
+
+    let request = ChatCompletionRequest {
+        model: provider.model().to_string(),
+        messages,
+        response_format: provider_response_format(provider),
+        max_tokens: Some(provider.max_tokens()),
+        temperature: Some(provider.temperature()),
+    };
+
+    // Send request and parse JSON with retry logic
+    let (response, ai_stats, _finish_reasons) =
+        send_and_parse::<DuplicateClusterResponse>(provider, &request).await?;
+    let response = restrict_to_cluster(response, candidates)?;
+
+    debug!(
+        canonical = response.canonical,
+        duplicate_count = response.duplicates.len(),
+        input_tokens = ai_stats.input_tokens,
+        output_tokens = ai_stats.output_tokens,
+        duration_ms = ai_stats.duration_ms,
+        "Duplicate review complete with stats"
+    );
+
+    Ok((response, ai_stats))
+}
+
+/// Drops issue numbers the model invented and the canonical issue from its
+/// own duplicates.
+fn restrict_to_cluster(
+    mut response: DuplicateClusterResponse,
+    candidates: &[DuplicateCandidate],
+) -> Result<DuplicateClusterResponse> {
+    let in_cluster = |number: u64| candidates.iter().any(|c| c.number == number);
+    if !in_cluster(response.canonical) {
+        anyhow::bail!(
+            "Model chose #{} as canonical, which is not in the cluster",
+            response.canonical
+        );
+    }
+    let canonical = response.canonical;
+    response
+        .duplicates
+        .retain(|&number| number != canonical && in_cluster(number));
+    response.duplicates.sort_unstable();
+    response.duplicates.dedup();
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(number: u64) -> DuplicateCandidate {
+        DuplicateCandidate {
+            number,
+            title: format!("Issue {number}"),
+            body: String::new(),
+            labels: vec![],
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_restrict_to_cluster_drops_unknown_and_canonical() {
+        let response = DuplicateClusterResponse {
+            canonical: 1,
+            duplicates: vec![3, 1, 99, 2, 3],
+            rationale: "same crash".to_string(),
+        };
+
+        let restricted =
+            restrict_to_cluster(response, &[candidate(1), candidate(2), candidate(3)]).unwrap();
+
+        assert_eq!(restricted.canonical, 1);
+        assert_eq!(restricted.duplicates, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_restrict_to_cluster_rejects_unknown_canonical() {
+        let response = DuplicateClusterResponse {
+            canonical: 42,
+            duplicates: vec![1],
+            rationale: String::new(),
+        };
+
+        assert!(restrict_to_cluster(response, &[candidate(1), candidate(2)]).is_err());
+    }
+
+    #[test]
+    fn test_build_dedupe_user_prompt_lists_each_issue() {
+        let mut first = candidate(12);
+        first.body = "Steps to reproduce".to_string();
+        first.labels = vec!["bug".to_string()];
+
+        let prompt = crate::ai::prompts::build_dedupe_user_prompt(&[first, candidate(40)]);
+
+        assert!(prompt.contains("## #12 Issue 12"));
+        assert!(prompt.contains("Labels: bug"));
+        assert!(prompt.contains("Steps to reproduce"));
+        assert!(prompt.contains("## #40 Issue 40"));
+        assert!(prompt.contains("[No description provided]"));
+        assert!(prompt.contains("\"canonical\""));
+    }
+}
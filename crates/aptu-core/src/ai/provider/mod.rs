@@ -7,10 +7,14 @@
 //! request sending, and response parsing.
 
 pub mod create;
+pub mod dedupe;
 pub mod http;
 pub mod label;
 pub mod parse;
+pub mod plan;
 pub mod review;
+pub mod split;
+pub mod stale;
 pub mod triage;
 
 use anyhow::Result;
@@ -18,10 +22,12 @@ use async_trait::async_trait;
 use reqwest::Client;
 use secrecy::SecretString;
 
+use crate::ai::plan_context::PlanContext;
 use crate::ai::registry::ProviderConfig;
 use crate::ai::types::{
-    ChatCompletionRequest, ChatCompletionResponse, CreateIssueResponse, IssueDetails,
-    PrReviewResponse,
+    ChatCompletionRequest, ChatCompletionResponse, CreateIssueResponse, DuplicateCandidate,
+    DuplicateClusterResponse, ImplementationPlan, IssueDetails, IssueSplitResponse,
+    PrReviewResponse, StaleNudgeInput, StaleNudgeResponse,
 };
 use crate::history::AiStats;
 
@@ -188,6 +194,40 @@ pub trait AiProvider: Send + Sync {
         self::label::suggest_pr_labels(self, title, body, file_paths).await
     }
 
+    /// Reviews a cluster of similar issues, choosing the canonical issue and
+    /// its true duplicates, using the provider's API.
+    async fn review_duplicate_cluster(
+        &self,
+        candidates: &[DuplicateCandidate],
+    ) -> Result<(DuplicateClusterResponse, AiStats)> {
+        self::dedupe::review_duplicate_cluster(self, candidates).await
+    }
+
+    /// Writes a follow-up comment for an inactive issue or PR using the
+    /// provider's API.
+    async fn write_stale_nudge(
+        &self,
+        input: &StaleNudgeInput,
+    ) -> Result<(StaleNudgeResponse, AiStats)> {
+        self::stale::write_stale_nudge(self, input).await
+    }
+
+    /// Breaks a large issue into child issues using the provider's API.
+    async fn split_issue(&self, issue: &IssueDetails) -> Result<(IssueSplitResponse, AiStats)> {
+        self::split::split_issue(self, issue).await
+    }
+
+    /// Turns an issue into an implementation plan grounded in local code
+    /// context using the provider's API.
+    async fn plan_issue(
+        &self,
+        issue: &IssueDetails,
+        context: &PlanContext,
+        include_diff: bool,
+    ) -> Result<(ImplementationPlan, AiStats)> {
+        self::plan::plan_issue(self, issue, context, include_diff).await
+    }
+
     /// Builds the system prompt for PR review.
     #[must_use]
     fn build_pr_review_system_prompt(custom_guidance: Option<&str>) -> String {
@@ -211,6 +251,30 @@ pub trait AiProvider: Send + Sync {
     fn build_pr_label_user_prompt(title: &str, body: &str, file_paths: &[String]) -> String {
         self::label::build_pr_label_user_prompt(title, body, file_paths)
     }
+
+    /// Builds the system prompt for duplicate cluster review.
+    #[must_use]
+    fn build_dedupe_system_prompt(custom_guidance: Option<&str>) -> String {
+        self::dedupe::build_dedupe_system_prompt_fn(custom_guidance)
+    }
+
+    /// Builds the system prompt for stale nudges.
+    #[must_use]
+    fn build_stale_system_prompt(custom_guidance: Option<&str>) -> String {
+        self::stale::build_stale_system_prompt_fn(custom_guidance)
+    }
+
+    /// Builds the system prompt for issue splitting.
+    #[must_use]
+    fn build_split_system_prompt(custom_guidance: Option<&str>) -> String {
+        self::split::build_split_system_prompt_fn(custom_guidance)
+    }
+
+    /// Builds the system prompt for implementation plans.
+    #[must_use]
+    fn build_plan_system_prompt(custom_guidance: Option<&str>) -> String {
+        self::plan::build_plan_system_prompt_fn(custom_guidance)
+    }
 }
 
 #[cfg(test)]
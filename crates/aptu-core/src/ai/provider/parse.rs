@@ -76,7 +76,7 @@ pub(crate) const SCHEMA_PREAMBLE: &str = "\n\nRespond with valid JSON matching t
 /// regex engine complexity is O(n) in the input length regardless of content.
 pub(crate) static XML_DELIMITERS: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(
-        r"(?i)</?(?:pull_request|issue_content|issue_body|pr_diff|commit_message|pr_comment|file_content|dependency_release_notes)>",
+        r"(?i)</?(?:pull_request|issue_content|issue_body|pr_diff|commit_message|pr_comment|file_content|dependency_release_notes|previous_triage|symbol_changes)>",
     )
     .expect("valid regex")
 });
@@ -0,0 +1,236 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation plans: a step plan for an issue grounded in local code.
+//!
+//! Provides `plan_issue` and prompt builder helpers.
+
+use anyhow::Result;
+use tracing::{debug, instrument};
+
+use super::http::send_and_parse;
+use super::parse::provider_response_format;
+use crate::ai::plan_context::PlanContext;
+use crate::ai::provider::AiProvider;
+use crate::ai::types::{ChatCompletionRequest, ChatMessage, ImplementationPlan, IssueDetails};
+use crate::history::AiStats;
+
+/// Builds the system prompt for implementation plans.
+#[must_use]
+pub(super) fn build_plan_system_prompt_fn(custom_guidance: Option<&str>) -> String {
+    let context = crate::ai::context::load_custom_guidance(custom_guidance);
+    crate::ai::prompts::build_plan_system_prompt(&context)
+}
+
+/// Turns an issue into an implementation plan using the provider's API.
+///
+/// With `include_diff`, the plan also carries a unified diff implementing it.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - API request fails (network, timeout, rate limit)
+/// - Response cannot be parsed as valid JSON
+/// - The model returns no steps, or no usable diff when one was requested
+/// - The diff writes outside the repository
+#[instrument(skip(provider, issue, context), fields(issue_number = issue.number, repo = %format!("{}/{}", issue.owner, issue.repo), symbols = context.symbols.len()))]
+pub(super) async fn plan_issue(
+    provider: &(impl AiProvider + ?Sized),
+    issue: &IssueDetails,
+    context: &PlanContext,
+    include_diff: bool,
+) -> Result<(ImplementationPlan, AiStats)> {
+    debug!(model = %provider.model(), "Calling {} API for implementation plan", provider.name());
+
+    // Build request
+    #[cfg(not(target_arch = "wasm32"))]
+    let system_content = if let Some(override_prompt) =
+        crate::ai::context::load_system_prompt_override("plan_system").await
+    {
+        override_prompt
+    } else {
+        build_plan_system_prompt_fn(provider.custom_guidance())
+    };
+    #[cfg(target_arch = "wasm32")]
+    let system_content = build_plan_system_prompt_fn(provider.custom_guidance());
+
+    let mut messages = vec![
+        ChatMessage {
+            role: "system".to_string(),
+            content: Some(system_content),
+            reasoning: None,
+            cache_control: None,
+        },
+        ChatMessage {
+            role: "user".to_string(),
+            content: Some(crate::ai::prompts::build_plan_user_prompt(
+                issue,
+                context,
+                include_diff,
+            )),
+            reasoning: None,
+            cache_control: None,
+        },
+    ];
+
+    // Inject cache control on system message for Anthropic
+    if provider.is_anthropic()
+        && let Some(msg) = messages.first_mut()
+    {
+        msg.cache_control = Some(crate::ai::types::CacheControl::ephemeral());
+    }
+
+    let request = ChatCompletionRequest {
+        model: provider.model().to_string(),
+        messages,
+        response_format: provider_response_format(provider),
+        max_tokens: Some(provider.max_tokens()),
+        temperature: Some(provider.temperature()),
+    };
+
+    // Send request and parse JSON with retry logic
+    let (response, ai_stats, _finish_reasons) =
+        send_and_parse::<ImplementationPlan>(provider, &request).await?;
+    let response = normalize_plan(response, issue, include_diff)?;
+
+    debug!(
+        step_count = response.steps.len(),
+        diff_bytes = response.diff.len(),
+        input_tokens = ai_stats.input_tokens,
+        output_tokens = ai_stats.output_tokens,
+        duration_ms = ai_stats.duration_ms,
+        "Implementation plan complete with stats"
+    );
+
+    Ok((response, ai_stats))
+}
+
+/// Drops empty steps and, when a diff was requested, strips code fences from
+/// it and checks that it is a unified diff confined to the repository.
+fn normalize_plan(
+    mut response: ImplementationPlan,
+    issue: &IssueDetails,
+    include_diff: bool,
+) -> Result<ImplementationPlan> {
+    response.summary = response.summary.trim().to_string();
+    response
+        .steps
+        .retain(|step| !step.description.trim().is_empty());
+    if response.steps.is_empty() {
+        anyhow::bail!("Model returned no plan steps for #{}", issue.number);
+    }
+    for step in &mut response.steps {
+        step.description = step.description.trim().to_string();
+        step.file = step.file.trim().trim_start_matches("./").to_string();
+        step.symbols.retain(|s| !s.trim().is_empty());
+    }
+
+    if !include_diff {
+        response.diff.clear();
+        return Ok(response);
+    }
+
+    let diff = strip_code_fence(&response.diff);
+    if diff.trim().is_empty() || !diff.lines().any(|line| line.starts_with("+++ ")) {
+        anyhow::bail!("Model did not return a unified diff for #{}", issue.number);
+    }
+    crate::git::patch::validate_patch_paths(diff)?;
+    response.diff = format!("{}\n", diff.trim_end());
+    Ok(response)
+}
+
+/// Removes a surrounding Markdown code fence (```` ```diff ````) if present.
+fn strip_code_fence(diff: &str) -> &str {
+    let trimmed = diff.trim();
+    let Some(rest) = trimmed.strip_prefix("```") else {
+        return diff;
+    };
+    let body = rest.split_once('\n').map_or("", |(_, body)| body);
+    body.strip_suffix("```").unwrap_or(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai::types::{CodeSymbol, PlanStep};
+
+    const DIFF: &str = "--- a/src/config.rs\n+++ b/src/config.rs\n@@ -1 +1 @@\n-old\n+new\n";
+
+    fn issue() -> IssueDetails {
+        let mut issue = IssueDetails::builder()
+            .owner("block".to_string())
+            .repo("goose".to_string())
+            .number(42)
+            .title("Config loader ignores XDG_CONFIG_HOME".to_string())
+            .body("`load_config` always reads ~/.config.".to_string())
+            .url("https://github.com/block/goose/issues/42".to_string())
+            .build();
+        issue.repo_tree = vec!["src/config.rs".to_string()];
+        issue
+    }
+
+    fn plan(steps: &[&str], diff: &str) -> ImplementationPlan {
+        ImplementationPlan {
+            summary: " Read the variable. ".to_string(),
+            steps: steps
+                .iter()
+                .map(|description| PlanStep {
+                    description: (*description).to_string(),
+                    file: "./src/config.rs".to_string(),
+                    symbols: vec!["config_dir".to_string(), " ".to_string()],
+                })
+                .collect(),
+            diff: diff.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_normalize_plan_drops_empty_steps_and_unrequested_diff() {
+        let normalized = normalize_plan(plan(&["Fix it", "  "], DIFF), &issue(), false).unwrap();
+
+        assert_eq!(normalized.summary, "Read the variable.");
+        assert_eq!(normalized.steps.len(), 1);
+        assert_eq!(normalized.steps[0].file, "src/config.rs");
+        assert_eq!(normalized.steps[0].symbols, vec!["config_dir"]);
+        assert!(normalized.diff.is_empty());
+
+        assert!(normalize_plan(plan(&[" "], ""), &issue(), false).is_err());
+    }
+
+    #[test]
+    fn test_normalize_plan_checks_requested_diff() {
+        let fenced = format!("```diff\n{DIFF}```");
+        let normalized = normalize_plan(plan(&["Fix it"], &fenced), &issue(), true).unwrap();
+        assert_eq!(normalized.diff, DIFF);
+
+        assert!(normalize_plan(plan(&["Fix it"], ""), &issue(), true).is_err());
+        assert!(normalize_plan(plan(&["Fix it"], "not a diff"), &issue(), true).is_err());
+        let escaping = "--- a/../x\n+++ b/../x\n@@ -1 +1 @@\n-a\n+b\n";
+        assert!(normalize_plan(plan(&["Fix it"], escaping), &issue(), true).is_err());
+    }
+
+    #[test]
+    fn test_build_plan_user_prompt_uses_code_context() {
+        let context = PlanContext {
+            symbols: vec![CodeSymbol {
+                kind: "fn".to_string(),
+                name: "config_dir".to_string(),
+                path: "src/config.rs".to_string(),
+            }],
+            graph_context: "config_dir (src/config.rs)\n  called by: load_config\n".to_string(),
+            sources: "config_dir (src/config.rs):\nfn config_dir() {}\n".to_string(),
+        };
+        let prompt = crate::ai::prompts::build_plan_user_prompt(&issue(), &context, true);
+
+        assert!(prompt.contains("Title: #42 Config loader ignores XDG_CONFIG_HOME"));
+        assert!(prompt.contains("<relevant_symbols>\n- fn config_dir (src/config.rs)\n"));
+        assert!(prompt.contains("called by: load_config"));
+        assert!(prompt.contains("fn config_dir() {}"));
+        assert!(prompt.contains("Also write `diff`"));
+        assert!(!prompt.contains("Repository Structure"));
+
+        let prompt =
+            crate::ai::prompts::build_plan_user_prompt(&issue(), &PlanContext::default(), false);
+        assert!(prompt.contains("Repository Structure (source files):\n- src/config.rs"));
+        assert!(prompt.contains("Leave `diff` as an empty string."));
+    }
+}
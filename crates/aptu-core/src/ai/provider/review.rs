@@ -169,6 +169,7 @@ mod tests {
             files,
             labels: vec![],
             head_sha: String::new(),
+            base_sha: String::new(),
             review_comments: vec![],
             instructions: None,
             dep_enrichments: vec![],
@@ -188,6 +189,7 @@ mod tests {
         let mut files: Vec<PrFile> = (0..25)
             .map(|i| PrFile {
                 filename: format!("file{i}.rs"),
+                previous_filename: None,
                 status: "modified".to_string(),
                 additions: 1,
                 deletions: 0,
@@ -198,6 +200,7 @@ mod tests {
             .collect();
         files.push(PrFile {
             filename: "extra.rs".to_string(),
+            previous_filename: None,
             status: "added".to_string(),
             additions: 1,
             deletions: 0,
@@ -218,6 +221,7 @@ mod tests {
         let files = vec![
             PrFile {
                 filename: "file1.rs".to_string(),
+                previous_filename: None,
                 status: "modified".to_string(),
                 additions: 100,
                 deletions: 50,
@@ -227,6 +231,7 @@ mod tests {
             },
             PrFile {
                 filename: "file2.rs".to_string(),
+                previous_filename: None,
                 status: "modified".to_string(),
                 additions: 100,
                 deletions: 50,
@@ -263,6 +268,7 @@ mod tests {
     fn test_build_pr_review_user_prompt_with_no_patches() {
         let files = vec![PrFile {
             filename: "file1.rs".to_string(),
+            previous_filename: None,
             status: "added".to_string(),
             additions: 10,
             deletions: 0,
@@ -279,6 +285,7 @@ mod tests {
     fn test_build_pr_review_user_prompt_added_file_skips_patch_when_full_content_present() {
         let files = vec![PrFile {
             filename: "file1.rs".to_string(),
+            previous_filename: None,
             status: "added".to_string(),
             additions: 10,
             deletions: 0,
@@ -310,6 +317,7 @@ mod tests {
     fn test_build_pr_review_user_prompt_added_file_includes_patch_when_no_full_content() {
         let files = vec![PrFile {
             filename: "file2.rs".to_string(),
+            previous_filename: None,
             status: "added".to_string(),
             additions: 10,
             deletions: 0,
@@ -332,6 +340,7 @@ mod tests {
 
         let mut pr = make_pr(vec![PrFile {
             filename: "file.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 1,
             deletions: 0,
@@ -357,6 +366,7 @@ mod tests {
     fn test_full_content_truncation_annotation_added() {
         let files = vec![PrFile {
             filename: "large_file.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 10,
             deletions: 5,
@@ -390,6 +400,7 @@ mod tests {
         let files = vec![
             PrFile {
                 filename: "big.rs".to_string(),
+                previous_filename: None,
                 status: "modified".to_string(),
                 additions: 100,
                 deletions: 50,
@@ -399,6 +410,7 @@ mod tests {
             },
             PrFile {
                 filename: "huge.rs".to_string(),
+                previous_filename: None,
                 status: "added".to_string(),
                 additions: 200,
                 deletions: 0,
@@ -430,6 +442,7 @@ mod tests {
     fn test_no_dep_enrichment_when_no_manifest_files() {
         let files = vec![PrFile {
             filename: "readme.md".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 10,
             deletions: 5,
@@ -449,6 +462,7 @@ mod tests {
     fn test_dep_enrichment_injected_after_pull_request_tag() {
         let files = vec![PrFile {
             filename: "Cargo.toml".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 5,
             deletions: 3,
@@ -493,6 +507,7 @@ mod tests {
     fn test_dep_enrichment_sanitized() {
         let files = vec![PrFile {
             filename: "Cargo.toml".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 1,
             deletions: 1,
@@ -0,0 +1,296 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Issue splitting: a sub-issue breakdown of a large issue.
+//!
+//! Provides `split_issue` and prompt builder helpers.
+
+use anyhow::Result;
+use tracing::{debug, instrument};
+
+use super::http::send_and_parse;
+use super::parse::provider_response_format;
+use crate::ai::provider::AiProvider;
+use crate::ai::types::{
+    ChatCompletionRequest, ChatMessage, IssueDetails, IssueSplitResponse, SubIssueTask,
+};
+use crate::history::AiStats;
+
+/// Maximum number of child issues created from one split.
+pub const MAX_SUB_ISSUES: usize = 10;
+
+/// Builds the system prompt for issue splitting.
+#[must_use]
+pub(super) fn build_split_system_prompt_fn(custom_guidance: Option<&str>) -> String {
+    let context = crate::ai::context::load_custom_guidance(custom_guidance);
+    crate::ai::prompts::build_split_system_prompt(&context)
+}
+
+/// Breaks a large issue into child issues using the provider's API.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - API request fails (network, timeout, rate limit)
+/// - Response cannot be parsed as valid JSON
+/// - The model returns no tasks
+#[instrument(skip(provider, issue), fields(issue_number = issue.number, repo = %format!("{}/{}", issue.owner, issue.repo)))]
+pub(super) async fn split_issue(
+    provider: &(impl AiProvider + ?Sized),
+    issue: &IssueDetails,
+) -> Result<(IssueSplitResponse, AiStats)> {
+    debug!(model = %provider.model(), "Calling {} API for issue split", provider.name());
+
+    // Build request
+    #[cfg(not(target_arch = "wasm32"))]
+    let system_content = if let Some(override_prompt) =
+        crate::ai::context::load_system_prompt_override("split_system").await
+    {
+        override_prompt
+    } else {
+        build_split_system_prompt_fn(provider.custom_guidance())
+    };
+    #[cfg(target_arch = "wasm32")]
+    let system_content = build_split_system_prompt_fn(provider.custom_guidance());
+
+    let mut messages = vec![
+        ChatMessage {
+            role: "system".to_string(),
+            content: Some(system_content),
+            reasoning: None,
+            cache_control: None,
+        },
+        ChatMessage {
+            role: "user".to_string(),
+            content: Some(crate::ai::prompts::build_split_user_prompt(issue)),
+            reasoning: None,
+            cache_control: None,
+        },
+    ];
+
+    // Inject cache control on system message for Anthropic
+    if provider.is_anthropic()
+        && let Some(msg) = messages.first_mut()
+    {
+        msg.cache_control = Some(crate::ai::types::CacheControl::ephemeral());
+    }
+
+    let request = ChatCompletionRequest {
+        model: provider.model().to_string(),
+        messages,
+        response_format: provider_response_format(provider),
+        max_tokens: Some(provider.max_tokens()),
+        temperature: Some(provider.temperature()),
+    };
+
+    // Send request and parse JSON with retry logic
+    let (response, ai_stats, _finish_reasons) =
+        send_and_parse::<IssueSplitResponse>(provider, &request).await?;
+    let response = normalize_split(response, issue)?;
+
+    debug!(
+        task_count = response.tasks.len(),
+        input_tokens = ai_stats.input_tokens,
+        output_tokens = ai_stats.output_tokens,
+        duration_ms = ai_stats.duration_ms,
+        "Issue split complete with stats"
+    );
+
+    Ok((response, ai_stats))
+}
+
+/// Drops untitled tasks, orders tasks so each comes after the tasks it
+/// depends on, caps the task count, and drops labels the repository does not
+/// have.
+///
+/// Dependencies on untitled tasks, unknown positions, or the task itself are
+/// dropped. Circular dependencies are rejected, since no creation order can
+/// satisfy them.
+fn normalize_split(
+    mut response: IssueSplitResponse,
+    issue: &IssueDetails,
+) -> Result<IssueSplitResponse> {
+    // 1-based positions of the kept tasks, indexed by original position
+    let mut positions = Vec::with_capacity(response.tasks.len());
+    let mut kept = 0;
+    for task in &response.tasks {
+        if task.title.trim().is_empty() {
+            positions.push(None);
+        } else {
+            kept += 1;
+            positions.push(Some(kept));
+        }
+    }
+
+    response.tasks.retain(|task| !task.title.trim().is_empty());
+    if response.tasks.is_empty() {
+        anyhow::bail!("Model returned no sub-issues for #{}", issue.number);
+    }
+
+    for (index, task) in response.tasks.iter_mut().enumerate() {
+        task.title = task.title.trim().to_string();
+        task.depends_on = task
+            .depends_on
+            .iter()
+            .filter_map(|&dep| positions.get(dep.checked_sub(1)?).copied().flatten())
+            .filter(|&dep| dep != index + 1)
+            .collect();
+        task.depends_on.sort_unstable();
+        task.depends_on.dedup();
+        if !issue.available_labels.is_empty() {
+            task.labels
+                .retain(|label| issue.available_labels.iter().any(|l| &l.name == label));
+        }
+    }
+
+    let order = dependency_order(&response.tasks).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Model returned circular sub-issue dependencies for #{}",
+            issue.number
+        )
+    })?;
+    // New 1-based position of each task, indexed by current position
+    let mut new_positions = vec![0; order.len()];
+    for (new_index, &old_index) in order.iter().enumerate() {
+        new_positions[old_index] = new_index + 1;
+    }
+    let mut tasks: Vec<Option<SubIssueTask>> = response.tasks.into_iter().map(Some).collect();
+    response.tasks = order
+        .iter()
+        .filter_map(|&old_index| tasks[old_index].take())
+        .map(|mut task| {
+            task.depends_on = task
+                .depends_on
+                .iter()
+                .map(|&dep| new_positions[dep - 1])
+                .collect();
+            task.depends_on.sort_unstable();
+            task
+        })
+        .collect();
+    response.tasks.truncate(MAX_SUB_ISSUES);
+    Ok(response)
+}
+
+/// Returns task indices ordered so every task follows its dependencies,
+/// keeping the model's order where dependencies allow, or `None` if the
+/// dependencies are circular.
+fn dependency_order(tasks: &[SubIssueTask]) -> Option<Vec<usize>> {
+    let mut order = Vec::with_capacity(tasks.len());
+    let mut placed = vec![false; tasks.len()];
+    while order.len() < tasks.len() {
+        let next = (0..tasks.len()).find(|&index| {
+            !placed[index] && tasks[index].depends_on.iter().all(|&dep| placed[dep - 1])
+        })?;
+        placed[next] = true;
+        order.push(next);
+    }
+    Some(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai::types::RepoLabel;
+
+    fn issue() -> IssueDetails {
+        let mut issue = IssueDetails::builder()
+            .owner("block".to_string())
+            .repo("goose".to_string())
+            .number(42)
+            .title("Support OAuth login".to_string())
+            .body("We need OAuth.".to_string())
+            .url("https://github.com/block/goose/issues/42".to_string())
+            .build();
+        issue.available_labels = vec![RepoLabel {
+            name: "enhancement".to_string(),
+            description: String::new(),
+            color: "a2eeef".to_string(),
+        }];
+        issue.repo_tree = vec!["src/auth/mod.rs".to_string()];
+        issue
+    }
+
+    fn task(title: &str, labels: &[&str], depends_on: Vec<usize>) -> SubIssueTask {
+        SubIssueTask {
+            title: title.to_string(),
+            body: String::new(),
+            labels: labels.iter().map(ToString::to_string).collect(),
+            depends_on,
+        }
+    }
+
+    #[test]
+    fn test_normalize_split_filters_dependencies_and_labels() {
+        let response = IssueSplitResponse {
+            tasks: vec![
+                task("Add config", &["enhancement", "made-up"], vec![1, 2]),
+                task("  ", &[], vec![]),
+                task("Add flow", &[], vec![1, 1, 0]),
+                task("Document", &[], vec![2, 3, 5]),
+            ],
+            rationale: String::new(),
+        };
+
+        let normalized = normalize_split(response, &issue()).unwrap();
+
+        assert_eq!(normalized.tasks.len(), 3);
+        assert_eq!(normalized.tasks[0].labels, vec!["enhancement"]);
+        assert!(normalized.tasks[0].depends_on.is_empty());
+        assert_eq!(normalized.tasks[1].depends_on, vec![1]);
+        assert_eq!(normalized.tasks[2].depends_on, vec![2]);
+    }
+
+    #[test]
+    fn test_normalize_split_orders_tasks_after_dependencies() {
+        let response = IssueSplitResponse {
+            tasks: vec![
+                task("Document", &[], vec![3]),
+                task("Add config", &[], vec![]),
+                task("Add flow", &[], vec![2]),
+            ],
+            rationale: String::new(),
+        };
+
+        let normalized = normalize_split(response, &issue()).unwrap();
+
+        let titles: Vec<&str> = normalized.tasks.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["Add config", "Add flow", "Document"]);
+        assert!(normalized.tasks[0].depends_on.is_empty());
+        assert_eq!(normalized.tasks[1].depends_on, vec![1]);
+        assert_eq!(normalized.tasks[2].depends_on, vec![2]);
+
+        let circular = IssueSplitResponse {
+            tasks: vec![task("A", &[], vec![2]), task("B", &[], vec![1])],
+            rationale: String::new(),
+        };
+        assert!(normalize_split(circular, &issue()).is_err());
+    }
+
+    #[test]
+    fn test_normalize_split_rejects_empty_and_caps_count() {
+        let empty = IssueSplitResponse::default();
+        assert!(normalize_split(empty, &issue()).is_err());
+
+        let many = IssueSplitResponse {
+            tasks: (0..15)
+                .map(|i| task(&format!("Task {i}"), &[], vec![]))
+                .collect(),
+            rationale: String::new(),
+        };
+        assert_eq!(
+            normalize_split(many, &issue()).unwrap().tasks.len(),
+            MAX_SUB_ISSUES
+        );
+    }
+
+    #[test]
+    fn test_build_split_user_prompt_includes_context() {
+        let prompt = crate::ai::prompts::build_split_user_prompt(&issue());
+
+        assert!(prompt.contains("Title: #42 Support OAuth login"));
+        assert!(prompt.contains("We need OAuth."));
+        assert!(prompt.contains("- src/auth/mod.rs"));
+        assert!(prompt.contains("Available Labels:\n- enhancement"));
+        assert!(prompt.contains("\"depends_on\""));
+    }
+}
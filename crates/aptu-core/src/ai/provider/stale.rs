@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Stale nudges: a follow-up comment for an inactive issue or PR.
+//!
+//! Provides `write_stale_nudge` and prompt builder helpers.
+
+use anyhow::Result;
+use tracing::{debug, instrument};
+
+use super::http::send_and_parse;
+use super::parse::provider_response_format;
+use crate::ai::provider::AiProvider;
+use crate::ai::types::{ChatCompletionRequest, ChatMessage, StaleNudgeInput, StaleNudgeResponse};
+use crate::history::AiStats;
+
+/// Builds the system prompt for stale nudges.
+#[must_use]
+pub(super) fn build_stale_system_prompt_fn(custom_guidance: Option<&str>) -> String {
+    let context = crate::ai::context::load_custom_guidance(custom_guidance);
+    crate::ai::prompts::build_stale_system_prompt(&context)
+}
+
+/// Writes a follow-up comment for an inactive issue or PR using the
+/// provider's API.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - API request fails (network, timeout, rate limit)
+/// - Response cannot be parsed as valid JSON
+/// - The model returns an empty comment
+#[instrument(skip(provider, input), fields(number = input.number))]
+pub(super) async fn write_stale_nudge(
+    provider: &(impl AiProvider + ?Sized),
+    input: &StaleNudgeInput,
+) -> Result<(StaleNudgeResponse, AiStats)> {
+    debug!(model = %provider.model(), "Calling {} API for stale nudge", provider.name());
+
+    // Build request
+    #[cfg(not(target_arch = "wasm32"))]
+    let system_content = if let Some(override_prompt) =
+        crate::ai::context::load_system_prompt_override("stale_system").await
+    {
+        override_prompt
+    } else {
+        build_stale_system_prompt_fn(provider.custom_guidance())
+    };
+    #[cfg(target_arch = "wasm32")]
+    let system_content = build_stale_system_prompt_fn(provider.custom_guidance());
+
+    let mut messages = vec![
+        ChatMessage {
+            role: "system".to_string(),
+            content: Some(system_content),
+            reasoning: None,
+            cache_control: None,
+        },
+        ChatMessage {
+            role: "user".to_string(),
+            content: Some(crate::ai::prompts::build_stale_user_prompt(input)),
+            reasoning: None,
+            cache_control: None,
+        },
+    ];
+
+    // Inject cache control on system message for Anthropic
+    if provider.is_anthropic()
+        && let Some(msg) = messages.first_mut()
+    {
+        msg.cache_control = Some(crate::ai::types::CacheControl::ephemeral());
+    }
+
+    let request = ChatCompletionRequest {
+        model: provider.model().to_string(),
+        messages,
+        response_format: provider_response_format(provider),
+        max_tokens: Some(provider.max_tokens()),
+        temperature: Some(provider.temperature()),
+    };
+
+    // Send request and parse JSON with retry logic
+    let (response, ai_stats, _finish_reasons) =
+        send_and_parse::<StaleNudgeResponse>(provider, &request).await?;
+    if response.comment.trim().is_empty() {
+        anyhow::bail!("Model returned an empty stale nudge");
+    }
+
+    debug!(
+        comment_len = response.comment.len(),
+        input_tokens = ai_stats.input_tokens,
+        output_tokens = ai_stats.output_tokens,
+        duration_ms = ai_stats.duration_ms,
+        "Stale nudge complete with stats"
+    );
+
+    Ok((response, ai_stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai::types::IssueComment;
+
+    fn input() -> StaleNudgeInput {
+        StaleNudgeInput {
+            kind: "issue".to_string(),
+            number: 7,
+            title: "Crash on start".to_string(),
+            body: "Steps to reproduce".to_string(),
+            author: "sam".to_string(),
+            labels: vec!["bug".to_string()],
+            inactive_days: 63,
+            close_in_days: Some(14),
+            open_questions: vec!["Which version?".to_string()],
+            recent_comments: vec![IssueComment {
+                id: 1,
+                author: "maintainer".to_string(),
+                body: "Can you share logs?".to_string(),
+                created_at: None,
+                updated_at: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_build_stale_user_prompt_includes_context() {
+        let prompt = crate::ai::prompts::build_stale_user_prompt(&input());
+
+        assert!(prompt.contains("<issue>"));
+        assert!(prompt.contains("Title: #7 Crash on start"));
+        assert!(prompt.contains("Author: @sam"));
+        assert!(prompt.contains("Inactive for: 63 days"));
+        assert!(prompt.contains("Closes in: 14 days"));
+        assert!(prompt.contains("- Which version?"));
+        assert!(prompt.contains("@maintainer: Can you share logs?"));
+        assert!(prompt.contains("\"comment\""));
+    }
+
+    #[test]
+    fn test_build_stale_user_prompt_without_close() {
+        let mut input = input();
+        input.kind = "pull request".to_string();
+        input.close_in_days = None;
+        input.open_questions.clear();
+
+        let prompt = crate::ai::prompts::build_stale_user_prompt(&input);
+
+        assert!(prompt.contains("<pull_request>"));
+        assert!(prompt.contains("Closes in: never"));
+        assert!(!prompt.contains("<open_questions>"));
+    }
+}
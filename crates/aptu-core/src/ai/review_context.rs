@@ -7,6 +7,7 @@
 
 use std::path::PathBuf;
 
+use crate::ai::diff_class::ChangeClass;
 use crate::ai::types::PrDetails;
 use crate::config::ReviewConfig;
 
@@ -76,6 +77,12 @@ pub struct ReviewContext {
     pub graph_context: String,
     /// Whether the structural graph was loaded from the on-disk cache (false when feature is off).
     pub graph_cache_hit: bool,
+    /// Base and head source of each function the PR modifies (empty if not available or
+    /// feature disabled).
+    pub symbol_changes: String,
+    /// Files whose diff cannot change behaviour (renames, moves, formatting, comments),
+    /// with their classification. Their patches are the first content dropped for budget.
+    pub non_behavioural_files: Vec<(String, ChangeClass)>,
 }
 
 impl ReviewContext {
@@ -118,10 +125,22 @@ impl ReviewContext {
         if !self.call_graph.is_empty() {
             context_sizes.push(format!("call graph: {} chars", self.call_graph.len()));
         }
+        if !self.symbol_changes.is_empty() {
+            context_sizes.push(format!("before/after: {} chars", self.symbol_changes.len()));
+        }
         if !context_sizes.is_empty() {
             let _ = writeln!(summary, "Context: {}", context_sizes.join(", "));
         }
 
+        if !self.non_behavioural_files.is_empty() {
+            let files: Vec<String> = self
+                .non_behavioural_files
+                .iter()
+                .map(|(file, class)| format!("{file} ({class})"))
+                .collect();
+            let _ = writeln!(summary, "Non-behavioural: {}", files.join(", "));
+        }
+
         // Truncation summary
         if self.files_truncated > 0 {
             let _ = writeln!(
@@ -134,6 +153,13 @@ impl ReviewContext {
         summary
     }
 
+    /// Returns true when the PR has files and none of them can change behaviour, in
+    /// which case the AI call is skipped.
+    #[must_use]
+    pub fn all_non_behavioural(&self) -> bool {
+        self.files_total > 0 && self.non_behavioural_files.len() == self.files_total
+    }
+
     /// Records a file truncation event.
     ///
     /// Updates truncation counters and emits a debug log.
@@ -164,6 +190,7 @@ impl Default for ReviewContext {
                 url: String::new(),
                 labels: Vec::new(),
                 head_sha: String::new(),
+                base_sha: String::new(),
                 review_comments: Vec::new(),
                 instructions: None,
                 dep_enrichments: Vec::new(),
@@ -187,6 +214,8 @@ impl Default for ReviewContext {
             estimated_size: 0,
             graph_context: String::new(),
             graph_cache_hit: false,
+            symbol_changes: String::new(),
+            non_behavioural_files: Vec::new(),
         }
     }
 }
@@ -226,6 +255,15 @@ pub async fn build_review_context(
         .as_ref()
         .map(|p| p.to_string_lossy().into_owned());
 
+    // Step 1b: Classify each file's diff so non-behavioural changes are dropped first
+    let non_behavioural_files: Vec<(String, ChangeClass)> = pr
+        .files
+        .iter()
+        .zip(crate::ai::diff_class::classify_files(&pr.files))
+        .filter(|(_, class)| !class.is_behavioural())
+        .map(|(file, class)| (file.filename.clone(), class))
+        .collect();
+
     // Step 2: Build AST context if repo_path resolved
     // When `ast-context` feature is enabled, build_ctx_ast returns AstContextOutput
     // (which carries both the text string and the structural GraphDb).
@@ -244,7 +282,7 @@ pub async fn build_review_context(
 
     // Step 4: Estimate total chars and decide call_graph budget
     // (call_graph and graph_context not yet built, pass empty strings)
-    let estimated_size = estimate_pr_size(&pr, &ast_context, "", "");
+    let estimated_size = estimate_pr_size(&pr, &ast_context, "", "", "");
     let max_prompt_chars = review_config.max_prompt_chars;
     let budget_remaining = max_prompt_chars.saturating_sub(estimated_size);
 
@@ -257,12 +295,20 @@ pub async fn build_review_context(
     };
 
     // Re-estimate with actual call_graph for accurate routing (graph_context still empty here)
-    let final_estimated_size = estimate_pr_size(&pr, &ast_context, &call_graph, "");
+    let final_estimated_size = estimate_pr_size(&pr, &ast_context, &call_graph, "", "");
 
     // Step 5b: Build structural graph context if enabled
     let (mut graph_context, graph_cache_hit) =
         build_ctx_graph(graph_config, repo_path_ref.as_deref(), &pr, &ast_output).await;
 
+    // Step 5c: Build before/after bodies of modified functions
+    let mut symbol_changes = build_ctx_symbol_changes(
+        repo_path_ref.as_deref(),
+        &pr,
+        review_config.max_chars_per_file,
+    )
+    .await;
+
     // Step 6: Apply budget drop order
     let mut ast_context = ast_context;
     let mut budget_drops = Vec::new();
@@ -271,6 +317,8 @@ pub async fn build_review_context(
         &mut ast_context,
         &mut call_graph,
         &mut graph_context,
+        &mut symbol_changes,
+        &non_behavioural_files,
         deep,
         max_prompt_chars,
         &mut budget_drops,
@@ -310,6 +358,8 @@ pub async fn build_review_context(
         estimated_size: final_estimated_size,
         graph_context,
         graph_cache_hit,
+        symbol_changes,
+        non_behavioural_files,
     })
 }
 
@@ -348,31 +398,45 @@ async fn enrich_deps(
     .await
 }
 
-/// Applies budget drop order: `call_graph` -> `graph_context` -> `ast_context` -> `dep_enrichments` -> patches -> `full_content`.
+/// Applies budget drop order: non-behavioural files -> `call_graph` -> `graph_context` -> `symbol_changes` -> `ast_context` -> `dep_enrichments` -> patches -> `full_content`.
 /// Enforces the prompt budget by dropping enrichment sections in priority order.
 ///
-/// When the assembled prompt exceeds `max_prompt_chars`, sections are cleared in
-/// the following order (lowest-priority dropped first):
+/// When the assembled prompt exceeds `max_prompt_chars`, the patch and full content of
+/// non-behavioural files go first, since they cannot change what the code does. Then
+/// sections are cleared in the following order (lowest-priority dropped first):
 ///
 /// 1. `call_graph` -- dropped first unless `deep` is explicitly set
 /// 2. `graph_context` -- dropped second (petgraph blast-radius subgraph; added in #1408)
-/// 3. `ast_context` -- dropped third
-/// 4. `dep_enrichments` -- dropped fourth
-/// 5. file patches -- dropped largest-first
-/// 6. file `full_content` -- dropped largest-first as last resort
+/// 3. `symbol_changes` -- dropped third (before/after bodies of modified functions)
+/// 4. `ast_context` -- dropped fourth
+/// 5. `dep_enrichments` -- dropped fifth
+/// 6. file patches -- dropped largest-first
+/// 7. file `full_content` -- dropped largest-first as last resort
 ///
 /// Each drop is logged at `WARN` level with the section name and character count.
 /// The function never returns an error; sections that cannot fit are silently cleared.
+#[allow(clippy::too_many_arguments)]
 fn apply_budget_drops(
     pr: &mut PrDetails,
     ast_context: &mut String,
     call_graph: &mut String,
     graph_context: &mut String,
+    symbol_changes: &mut String,
+    non_behavioural_files: &[(String, ChangeClass)],
     deep: bool,
     max_prompt_chars: usize,
     budget_drops: &mut Vec<String>,
 ) {
-    let mut estimated_size = estimate_pr_size(pr, ast_context, call_graph, graph_context);
+    let mut estimated_size =
+        estimate_pr_size(pr, ast_context, call_graph, graph_context, symbol_changes);
+
+    drop_non_behavioural_files(
+        &mut pr.files,
+        non_behavioural_files,
+        &mut estimated_size,
+        max_prompt_chars,
+        budget_drops,
+    );
 
     // Drop call_graph if over budget (unless explicitly enabled)
     if estimated_size > max_prompt_chars && !deep {
@@ -387,7 +451,7 @@ fn apply_budget_drops(
         budget_drops.push("call_graph".to_string());
     }
 
-    // Drop graph_context second (priority tier 2: between call_graph and ast_context; added in #1408).
+    // Drop graph_context second (priority tier 2: between call_graph and symbol_changes; added in #1408).
     if estimated_size > max_prompt_chars {
         tracing::warn!(
             section = "graph_context",
@@ -401,6 +465,19 @@ fn apply_budget_drops(
         budget_drops.push("graph_context".to_string());
     }
 
+    // Drop symbol_changes if still over budget; the patches still show what changed.
+    if estimated_size > max_prompt_chars && !symbol_changes.is_empty() {
+        tracing::warn!(
+            section = "symbol_changes",
+            chars = symbol_changes.len(),
+            "Dropping section: prompt budget exceeded"
+        );
+        let dropped_chars = symbol_changes.len();
+        symbol_changes.clear();
+        estimated_size -= dropped_chars;
+        budget_drops.push("symbol_changes".to_string());
+    }
+
     // Drop ast_context if still over budget
     if estimated_size > max_prompt_chars {
         tracing::warn!(
@@ -430,6 +507,36 @@ fn apply_budget_drops(
     );
 }
 
+/// Drops the patch and full content of non-behavioural files until under budget.
+fn drop_non_behavioural_files(
+    files: &mut [crate::ai::types::PrFile],
+    non_behavioural_files: &[(String, ChangeClass)],
+    estimated_size: &mut usize,
+    max_prompt_chars: usize,
+    budget_drops: &mut Vec<String>,
+) {
+    for (filename, class) in non_behavioural_files {
+        if *estimated_size <= max_prompt_chars {
+            break;
+        }
+        let Some(file) = files.iter_mut().find(|f| &f.filename == filename) else {
+            continue;
+        };
+        let dropped_chars = file.patch.take().map_or(0, |p| p.len())
+            + file.full_content.take().map_or(0, |c| c.len());
+        if dropped_chars > 0 {
+            tracing::warn!(
+                file = %filename,
+                class = %class,
+                chars = dropped_chars,
+                "Dropping non-behavioural file: prompt budget exceeded"
+            );
+            *estimated_size -= dropped_chars;
+            budget_drops.push(format!("non_behavioural:{filename}"));
+        }
+    }
+}
+
 /// Drops `dep_enrichments` if the prompt is still over budget.
 fn drop_dep_enrichments_by_size(
     pr: &mut PrDetails,
@@ -532,13 +639,14 @@ fn drop_full_content_by_size(
 /// Estimates the total character size of a PR review prompt.
 ///
 /// Sums title, body, file metadata, patches, `full_content`, `dep_enrichments`,
-/// `ast_context`, `call_graph`, and overhead.
+/// `ast_context`, `call_graph`, `graph_context`, `symbol_changes`, and overhead.
 #[must_use]
 pub(crate) fn estimate_pr_size(
     pr: &PrDetails,
     ast_context: &str,
     call_graph: &str,
     graph_context: &str,
+    symbol_changes: &str,
 ) -> usize {
     let mut size = 0;
 
@@ -570,6 +678,9 @@ pub(crate) fn estimate_pr_size(
     // Structural graph context
     size += graph_context.len();
 
+    // Before/after bodies of modified functions
+    size += symbol_changes.len();
+
     // Overhead
     size += PROMPT_OVERHEAD_CHARS;
 
@@ -624,11 +735,16 @@ async fn build_ctx_call_graph(
     }
 }
 
-/// Builds structural graph context from PR-changed files when both `ast-context` and
-/// `graph` features are enabled.
+/// Builds structural graph context for the PR when both `ast-context` and `graph`
+/// features are enabled.
 ///
-/// Accepts a pre-built [`AstContextOutput`] containing the structural graph from
-/// `ast_context.rs`, and passes it to `cache::load_or_build` for caching.
+/// Loads the repository-wide graph for the PR's base SHA from cache (on a miss,
+/// assembling it from the persisted per-file index after re-parsing only files
+/// whose content changed in the checkout at `repo_path`; the index and result are cached
+/// only when the checkout is cleanly at the base SHA), patches it with the PR's changed
+/// files, and runs the blast-radius BFS over the full graph so callers in
+/// untouched files are included. Falls back to the changed-file graph in the
+/// pre-built [`AstContextOutput`] when the repository walk yields no symbols.
 ///
 /// Returns `(rendered_text, cache_hit)`. Returns empty string and `false` when the
 /// feature is off, when `graph_config.enabled` is false, or when `repo_path` is absent.
@@ -645,24 +761,60 @@ async fn build_ctx_graph(
         if !graph_config.enabled {
             return (String::new(), false);
         }
-        let Some(_repo_path_str) = repo_path else {
+        let Some(repo_path_str) = repo_path else {
             return (String::new(), false);
         };
-        let sha = pr.head_sha.clone();
+        let sha = if pr.base_sha.is_empty() {
+            pr.head_sha.clone()
+        } else {
+            pr.base_sha.clone()
+        };
         let owner_str = pr.owner.clone();
         let repo_str = pr.repo.clone();
         let graph_config_owned = graph_config.clone();
         let graph_owned = ast_output.graph.clone();
-        let function_names: Vec<String> = derive_modified_symbols(&pr.files);
+        let repo_root = PathBuf::from(repo_path_str);
+        let files = pr.files.clone();
+        let head_sha = pr.head_sha.clone();
 
         let spawn_result = tokio::task::spawn_blocking(move || {
-            let (mut graph, cache_hit) = crate::graph::cache::load_or_build(
-                &owner_str,
-                &repo_str,
-                &sha,
-                graph_owned,
-                &graph_config_owned,
-            );
+            let mut function_names = derive_modified_symbols(&files);
+            for sym in derive_enclosing_symbols(&repo_root, &files, &head_sha) {
+                if !function_names.contains(&sym) {
+                    function_names.push(sym);
+                }
+            }
+            // The graph and index are built from the working tree, so they are
+            // only cached when the checkout is exactly the base commit.
+            let at_base = crate::graph::repo::checkout_at(&repo_root, &sha);
+            let build = || {
+                // Reuse per-file symbols from earlier commits; only files whose
+                // content changed are re-parsed.
+                let mut index = crate::graph::cache::load_file_index(&owner_str, &repo_str);
+                crate::graph::repo::update_index(&repo_root, &mut index);
+                if at_base {
+                    crate::graph::cache::persist_file_index(&owner_str, &repo_str, &index);
+                }
+                crate::graph::repo::graph_from_index(&index)
+            };
+            let (mut graph, cache_hit) = if at_base {
+                crate::graph::cache::load_or_build_with(
+                    &owner_str,
+                    &repo_str,
+                    &sha,
+                    &graph_config_owned,
+                    build,
+                )
+            } else {
+                tracing::debug!(sha = %sha, "Checkout is not at the base commit; graph not cached");
+                crate::graph::cache::load_cached(&owner_str, &repo_str, &sha, &graph_config_owned)
+                    .map_or_else(|| (build(), false), |graph| (graph, true))
+            };
+            if graph.node_count() == 0 {
+                graph = graph_owned;
+            } else {
+                crate::graph::repo::apply_file_changes(&mut graph, &repo_root, &files);
+            }
             let fn_refs: Vec<&str> = function_names.iter().map(String::as_str).collect();
             let modified_nodes = crate::graph::query::find_modified_nodes(&mut graph, &fn_refs);
             let subgraph = crate::graph::query::blast_radius(
@@ -671,8 +823,10 @@ async fn build_ctx_graph(
                 graph_config_owned.max_nodes,
                 graph_config_owned.max_depth,
             );
+            let seeds: Vec<&crate::graph::Node> =
+                modified_nodes.iter().map(|&idx| &graph[idx]).collect();
             (
-                crate::graph::query::render_subgraph_text(&subgraph),
+                crate::graph::query::render_subgraph_text(&subgraph, &seeds),
                 cache_hit,
             )
         })
@@ -764,6 +918,265 @@ fn derive_modified_symbols(files: &[crate::ai::types::PrFile]) -> Vec<String> {
     symbols
 }
 
+/// Derives modified symbol names by mapping each diff hunk onto the AST of the
+/// changed file at the PR head.
+///
+/// Unlike [`derive_modified_symbols`], which only sees declaration lines, this
+/// resolves every added or removed line to its innermost enclosing function,
+/// method, or class, so edits inside a body are attributed to that symbol. Works
+/// for every language supported by `aptu_coder_core::language_for_extension`.
+///
+/// Hunks describe the head side, so each file is read at `head_sha` from the
+/// repository at `repo_root`, falling back to the fetched head content
+/// (`PrFile::full_content`). The working tree is never read, since the
+/// checkout may be at another commit; callers diffing the working tree pass an
+/// empty `head_sha` and supply its content in `full_content`. Removed files and
+/// files with no head content are skipped.
+#[cfg(all(feature = "ast-context", feature = "graph"))]
+pub(crate) fn derive_enclosing_symbols(
+    repo_root: &std::path::Path,
+    files: &[crate::ai::types::PrFile],
+    head_sha: &str,
+) -> Vec<String> {
+    let mut symbols: Vec<String> = Vec::new();
+
+    for file in files {
+        if file.status == "removed" {
+            continue;
+        }
+        let Some(patch) = &file.patch else {
+            continue;
+        };
+        let lines = hunk_changed_lines(patch);
+        if lines.is_empty() {
+            continue;
+        }
+        let Some(source) =
+            git_show(repo_root, head_sha, &file.filename).or_else(|| file.full_content.clone())
+        else {
+            tracing::debug!(
+                "derive_enclosing_symbols: {} not available at head",
+                file.filename
+            );
+            continue;
+        };
+        for sym in crate::ast_context::enclosing_symbols(&file.filename, &source, &lines) {
+            if !symbols.contains(&sym) {
+                symbols.push(sym);
+            }
+        }
+    }
+
+    symbols
+}
+
+/// Returns the new-side (1-based) line numbers touched by a unified-diff patch.
+///
+/// Added lines map to their own line number. Removed lines map to the new-side
+/// line at which the removal happened, so deletion-only hunks still resolve to
+/// the symbol they were removed from.
+#[cfg(feature = "ast-context")]
+fn hunk_changed_lines(patch: &str) -> Vec<usize> {
+    let mut lines: Vec<usize> = Vec::new();
+    let mut new_line: Option<usize> = None;
+
+    for line in patch.lines() {
+        if let Some(header) = line.strip_prefix("@@") {
+            // "@@ -a,b +c,d @@ context": parse the start of the "+c,d" range.
+            new_line = header
+                .split_whitespace()
+                .find_map(|part| part.strip_prefix('+'))
+                .and_then(|range| range.split(',').next())
+                .and_then(|start| start.parse().ok());
+            continue;
+        }
+        let Some(current) = new_line.as_mut() else {
+            continue;
+        };
+        let touched = match line.as_bytes().first() {
+            Some(b'+') => {
+                let at = *current;
+                *current += 1;
+                at
+            }
+            Some(b'-') => (*current).max(1),
+            // "\ No newline at end of file" markers do not occupy a line.
+            Some(b'\\') => continue,
+            _ => {
+                *current += 1;
+                continue;
+            }
+        };
+        if lines.last() != Some(&touched) {
+            lines.push(touched);
+        }
+    }
+
+    lines
+}
+
+/// Soft cap on the before/after section, in characters.
+#[cfg(all(feature = "ast-context", not(target_arch = "wasm32")))]
+const SYMBOL_CHANGES_CAP: usize = 8_000;
+
+/// Builds a before/after section with the base and head source of each
+/// function the PR modifies.
+///
+/// Diff hunks are mapped to their enclosing functions in the head version of
+/// each file, and both versions of every such function are read from the local
+/// repository at `repo_path` (`git show <sha>:<path>`); the head side falls back
+/// to `PrFile::full_content`, then to the working tree. Functions that are new,
+/// deleted, or unchanged in body are skipped, since the diff already shows them
+/// whole. Each body is trimmed to `max_chars_per_body` with
+/// [`truncate_at_line_boundary`], and blocks that would push the section past
+/// [`SYMBOL_CHANGES_CAP`] are left out.
+///
+/// Returns an empty string when `repo_path` is absent, the base SHA is unknown
+/// or not available locally, or no modified function changed.
+#[cfg(all(feature = "ast-context", not(target_arch = "wasm32")))]
+async fn build_ctx_symbol_changes(
+    repo_path: Option<&str>,
+    pr: &PrDetails,
+    max_chars_per_body: usize,
+) -> String {
+    let Some(repo_path) = repo_path else {
+        return String::new();
+    };
+    if pr.base_sha.is_empty() {
+        return String::new();
+    }
+    let repo_root = PathBuf::from(repo_path);
+    let files = pr.files.clone();
+    let (base_sha, head_sha) = (pr.base_sha.clone(), pr.head_sha.clone());
+
+    let result = tokio::task::spawn_blocking(move || {
+        symbol_changes_sync(&repo_root, &files, &base_sha, &head_sha, max_chars_per_body)
+    })
+    .await;
+    match result {
+        Ok(text) => text,
+        Err(e) => {
+            tracing::warn!("build_ctx_symbol_changes: blocking task panicked: {e}");
+            String::new()
+        }
+    }
+}
+
+/// Builds the before/after section (stub when `ast-context` is off or on WASM).
+#[allow(clippy::unused_async)]
+#[cfg(not(all(feature = "ast-context", not(target_arch = "wasm32"))))]
+async fn build_ctx_symbol_changes(
+    repo_path: Option<&str>,
+    pr: &PrDetails,
+    max_chars_per_body: usize,
+) -> String {
+    let _ = (repo_path, pr, max_chars_per_body);
+    String::new()
+}
+
+#[cfg(all(feature = "ast-context", not(target_arch = "wasm32")))]
+fn symbol_changes_sync(
+    repo_root: &std::path::Path,
+    files: &[crate::ai::types::PrFile],
+    base_sha: &str,
+    head_sha: &str,
+    max_chars_per_body: usize,
+) -> String {
+    use std::fmt::Write as _;
+
+    let mut output = String::new();
+    for file in files {
+        if file.status.eq_ignore_ascii_case("removed") {
+            continue;
+        }
+        let Some(patch) = &file.patch else {
+            continue;
+        };
+        let lines = hunk_changed_lines(patch);
+        if lines.is_empty() {
+            continue;
+        }
+        let Some(after) = git_show(repo_root, head_sha, &file.filename)
+            .or_else(|| file.full_content.clone())
+            .or_else(|| std::fs::read_to_string(repo_root.join(&file.filename)).ok())
+        else {
+            continue;
+        };
+        let base_path = file.previous_filename.as_deref().unwrap_or(&file.filename);
+        let Some(before) = git_show(repo_root, base_sha, base_path) else {
+            tracing::debug!(
+                "symbol_changes: {} not available at base {}",
+                base_path,
+                base_sha
+            );
+            continue;
+        };
+
+        let after_bodies = crate::ast_context::function_bodies(&file.filename, &after);
+        let before_bodies = crate::ast_context::function_bodies(base_path, &before);
+        let mut changed: Vec<&crate::ast_context::FunctionBody> = Vec::new();
+        for &line in &lines {
+            let innermost = after_bodies
+                .iter()
+                .filter(|b| (b.start..=b.end).contains(&line))
+                .min_by_key(|b| b.end - b.start);
+            if let Some(body) = innermost
+                && !changed.iter().any(|c| c.key == body.key)
+            {
+                changed.push(body);
+            }
+        }
+        for new in changed {
+            let Some(old) = before_bodies.iter().find(|b| b.key == new.key) else {
+                continue;
+            };
+            if old.body == new.body {
+                continue;
+            }
+            let mut block = String::new();
+            let _ = writeln!(block, "### `{}` ({})", new.key, file.filename);
+            let _ = writeln!(
+                block,
+                "Before:\n```\n{}\n```\nAfter:\n```\n{}\n```",
+                truncate_at_line_boundary(&old.body, max_chars_per_body).trim_end(),
+                truncate_at_line_boundary(&new.body, max_chars_per_body).trim_end()
+            );
+            if output.len() + block.len() > SYMBOL_CHANGES_CAP {
+                continue;
+            }
+            output.push_str(&block);
+        }
+    }
+
+    if output.is_empty() {
+        return output;
+    }
+    format!("\n<symbol_changes>\n{output}</symbol_changes>\n")
+}
+
+/// Reads `path` at commit `sha` from the repository at `repo_root`.
+#[cfg(all(feature = "ast-context", not(target_arch = "wasm32")))]
+fn git_show(repo_root: &std::path::Path, sha: &str, path: &str) -> Option<String> {
+    use std::process::Command;
+
+    if sha.is_empty() {
+        return None;
+    }
+    Command::new("git")
+        .arg("show")
+        .arg(format!("{sha}:{path}"))
+        .current_dir(repo_root)
+        .output()
+        .ok()
+        .and_then(|output| {
+            if output.status.success() {
+                String::from_utf8(output.stdout).ok()
+            } else {
+                None
+            }
+        })
+}
+
 /// Infers the repository path from the current working directory.
 #[cfg(not(target_arch = "wasm32"))]
 fn infer_repo_path_from_cwd(pr_owner: &str, pr_repo: &str) -> Option<String> {
@@ -904,9 +1317,11 @@ mod tests {
             head_branch: "feat".to_string(),
             base_branch: "main".to_string(),
             head_sha: String::new(),
+            base_sha: String::new(),
             review_comments: vec![],
             files: vec![PrFile {
                 filename: "src/lib.rs".to_string(),
+                previous_filename: None,
                 status: "modified".to_string(),
                 patch: Some("x".repeat(patch_chars)),
                 patch_truncated: false,
@@ -952,6 +1367,7 @@ mod tests {
 
         let mut drops = Vec::new();
         let mut graph_context = String::new();
+        let mut symbol_changes = String::new();
         // Updated in #1408: apply_budget_drops now takes graph_context as a new drop tier
         // between call_graph and ast_context. Priority order:
         // call_graph -> graph_context -> ast_context -> dep_enrichments -> patches -> full_content
@@ -960,6 +1376,8 @@ mod tests {
             &mut ast_context,
             &mut call_graph,
             &mut graph_context,
+            &mut symbol_changes,
+            &[],
             false,
             max_prompt_chars,
             &mut drops,
@@ -990,11 +1408,14 @@ mod tests {
 
         let mut drops = Vec::new();
         let mut graph_context = String::new();
+        let mut symbol_changes = String::new();
         apply_budget_drops(
             &mut pr,
             &mut ast_context,
             &mut call_graph,
             &mut graph_context,
+            &mut symbol_changes,
+            &[],
             false,
             max_prompt_chars,
             &mut drops,
@@ -1012,6 +1433,38 @@ mod tests {
         );
     }
 
+    /// Verifies that non-behavioural files are dropped before any enrichment section.
+    #[test]
+    fn test_apply_budget_drops_non_behavioural_first() {
+        // Arrange: one formatting-only file large enough that dropping it fits the budget
+        let mut pr = make_pr_with_content(800, 400);
+        let mut ast_context = String::new();
+        let mut call_graph = "b".repeat(300);
+        let mut graph_context = String::new();
+        let mut symbol_changes = String::new();
+        let non_behavioural = vec![("src/lib.rs".to_string(), ChangeClass::Formatting)];
+        let mut drops = Vec::new();
+
+        // Act: budget fits everything except the non-behavioural file's content
+        apply_budget_drops(
+            &mut pr,
+            &mut ast_context,
+            &mut call_graph,
+            &mut graph_context,
+            &mut symbol_changes,
+            &non_behavioural,
+            false,
+            1_500,
+            &mut drops,
+        );
+
+        // Assert
+        assert!(pr.files[0].patch.is_none());
+        assert!(pr.files[0].full_content.is_none());
+        assert_eq!(drops, vec!["non_behavioural:src/lib.rs".to_string()]);
+        assert_eq!(call_graph.len(), 300, "call_graph should survive");
+    }
+
     #[test]
     fn test_verbose_summary_all_fields() {
         // Arrange: ReviewContext with repo path (inferred), dep enrichments, ast, call graph
@@ -1204,8 +1657,8 @@ mod tests {
         let pr = make_pr_with_content(0, 0);
         let ast_context = "";
         let call_graph = "fn foo() -> bar\nfn baz() -> qux";
-        let size = estimate_pr_size(&pr, ast_context, call_graph, "");
-        let without_call_graph = estimate_pr_size(&pr, ast_context, "", "");
+        let size = estimate_pr_size(&pr, ast_context, call_graph, "", "");
+        let without_call_graph = estimate_pr_size(&pr, ast_context, "", "", "");
         // Delta between with and without call_graph should be exactly call_graph.len()
         assert_eq!(size - without_call_graph, call_graph.len());
         // Total should include PROMPT_OVERHEAD_CHARS
@@ -1219,7 +1672,7 @@ mod tests {
         let pr = make_pr_with_content(50, 100);
         let ast_context = "fn foo() {}";
         let call_graph = "caller -> callee\nother -> thing";
-        let size = estimate_pr_size(&pr, ast_context, call_graph, "");
+        let size = estimate_pr_size(&pr, ast_context, call_graph, "", "");
         assert!(
             size >= call_graph.len() + PROMPT_OVERHEAD_CHARS,
             "estimated size {} should be >= call_graph.len() {} + overhead {}",
@@ -1239,6 +1692,7 @@ mod tests {
         // Arrange: file with no patch
         let files = vec![PrFile {
             filename: "src/lib.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             patch: None,
             patch_truncated: false,
@@ -1276,6 +1730,7 @@ mod tests {
 ";
         let files = vec![PrFile {
             filename: "src/lib.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             patch: Some(patch.to_string()),
             patch_truncated: false,
@@ -1322,6 +1777,7 @@ mod tests {
 ";
         let files = vec![PrFile {
             filename: "src/lib.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             patch: Some(patch.to_string()),
             patch_truncated: true,
@@ -1355,6 +1811,7 @@ mod tests {
 ";
         let files = vec![PrFile {
             filename: "src/renamed.rs".to_string(),
+            previous_filename: None,
             status: "renamed".to_string(),
             patch: Some(patch.to_string()),
             patch_truncated: false,
@@ -1389,6 +1846,7 @@ mod tests {
 ";
         let files = vec![PrFile {
             filename: "src/lib.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             patch: Some(patch.to_string()),
             patch_truncated: false,
@@ -1424,6 +1882,7 @@ mod tests {
 ";
         let files = vec![PrFile {
             filename: "src/lib.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             patch: Some(patch.to_string()),
             patch_truncated: false,
@@ -1459,6 +1918,7 @@ mod tests {
 ";
         let files = vec![PrFile {
             filename: "src/lib.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             patch: Some(patch.to_string()),
             patch_truncated: false,
@@ -1497,6 +1957,7 @@ mod tests {
 ";
         let files = vec![PrFile {
             filename: "src/lib.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             patch: Some(patch.to_string()),
             patch_truncated: false,
@@ -1523,4 +1984,208 @@ mod tests {
             "should extract named struct name"
         );
     }
+
+    // -----------------------------------------------------------------------
+    // derive_enclosing_symbols tests
+    // -----------------------------------------------------------------------
+
+    #[cfg(feature = "ast-context")]
+    fn patched_file(filename: &str, patch: &str) -> PrFile {
+        PrFile {
+            filename: filename.to_string(),
+            previous_filename: None,
+            status: "modified".to_string(),
+            patch: Some(patch.to_string()),
+            patch_truncated: false,
+            full_content: None,
+            additions: 1,
+            deletions: 1,
+        }
+    }
+
+    #[cfg(all(feature = "ast-context", feature = "graph"))]
+    #[test]
+    fn test_hunk_changed_lines_tracks_new_side_positions() {
+        let patch = "\
+@@ -3,4 +3,4 @@ def handler():
+     a = 1
+-    b = 2
++    b = 3
+     c = 4
+@@ -20,3 +20,2 @@
+ x
+-y
+ z
+\\ No newline at end of file
+";
+        assert_eq!(hunk_changed_lines(patch), vec![4, 21]);
+    }
+
+    #[cfg(all(feature = "ast-context", feature = "graph"))]
+    #[test]
+    fn test_enclosing_symbols_resolve_body_edits_across_languages() {
+        // Arrange: body-only edits that SYMBOL_RE cannot see
+        let dir = tempfile::tempdir().unwrap();
+        let with_head = |filename: &str, patch: &str, head: &str| PrFile {
+            full_content: Some(head.to_string()),
+            ..patched_file(filename, patch)
+        };
+        let files = vec![
+            with_head(
+                "app.py",
+                "@@ -3,3 +3,3 @@\n class Service:\n     def handle(self):\n-        return 1\n+        return 2\n",
+                "import os\n\nclass Service:\n    def handle(self):\n        return 2\n\ndef helper():\n    return 1\n",
+            ),
+            with_head(
+                "main.go",
+                "@@ -3,3 +3,3 @@\n func Process() int {\n-\treturn 1\n+\treturn 2\n }\n",
+                "package main\n\nfunc Process() int {\n\treturn 2\n}\n",
+            ),
+            with_head(
+                "util.ts",
+                "@@ -1,3 +1,3 @@\n export function format(x: number): string {\n-  return String(x);\n+  return String(x + 1);\n }\n",
+                "export function format(x: number): string {\n  return String(x + 1);\n}\n",
+            ),
+        ];
+
+        // Act
+        let symbols = derive_enclosing_symbols(dir.path(), &files, "");
+
+        // Assert
+        assert!(derive_modified_symbols(&files).is_empty());
+        assert_eq!(symbols, vec!["handle", "Process", "format"]);
+    }
+
+    #[cfg(all(feature = "ast-context", feature = "graph"))]
+    #[test]
+    fn test_enclosing_symbols_read_head_content_not_checkout() {
+        // Arrange: the checkout still has the base version, where line 5 is
+        // inside `old_helper`; at head it is inside `handler`.
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("app.py"),
+            "def old_helper():\n    a = 1\n    b = 2\n    c = 3\n    return a\n",
+        )
+        .unwrap();
+        let without_head = patched_file("app.py", "@@ -5 +5 @@\n-    return a\n+    return 2\n");
+        let mut file = without_head.clone();
+        file.full_content = Some("import os\n\n\ndef handler():\n    return 2\n".to_string());
+
+        // Act
+        let symbols = derive_enclosing_symbols(dir.path(), &[file], "");
+        let unresolved = derive_enclosing_symbols(dir.path(), &[without_head], "");
+
+        // Assert: without head content the file is skipped, not read from
+        // the checkout.
+        assert_eq!(symbols, vec!["handler"]);
+        assert!(unresolved.is_empty());
+    }
+
+    #[cfg(all(feature = "ast-context", feature = "graph"))]
+    #[test]
+    fn test_enclosing_symbols_skip_removed_and_missing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut removed = patched_file("gone.py", "@@ -1,2 +0,0 @@\n-def f():\n-    pass\n");
+        removed.status = "removed".to_string();
+        let missing = patched_file("missing.go", "@@ -1 +1 @@\n-a\n+b\n");
+
+        let symbols = derive_enclosing_symbols(dir.path(), &[removed, missing], "");
+
+        assert!(symbols.is_empty());
+    }
+
+    #[cfg(all(feature = "ast-context", not(target_arch = "wasm32")))]
+    #[test]
+    fn test_symbol_changes_show_base_and_head_bodies() {
+        // Arrange: a two-commit repository where only `scale` changes
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?} failed");
+            String::from_utf8(output.stdout).unwrap().trim().to_string()
+        };
+        git(&["init", "-q"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "user.name", "Test"]);
+        git(&["config", "commit.gpgsign", "false"]);
+        let write = |body: &str| {
+            std::fs::write(
+                dir.path().join("lib.rs"),
+                format!("pub fn scale(x: u32) -> u32 {{\n    {body}\n}}\n\npub fn id(x: u32) -> u32 {{\n    x\n}}\n"),
+            )
+            .unwrap();
+            git(&["add", "."]);
+            git(&["commit", "-q", "-m", "change"]);
+            git(&["rev-parse", "HEAD"])
+        };
+        let base = write("x * 2");
+        let head = write("x * 3");
+        let files = vec![patched_file(
+            "lib.rs",
+            "@@ -1,3 +1,3 @@\n pub fn scale(x: u32) -> u32 {\n-    x * 2\n+    x * 3\n }\n",
+        )];
+
+        // Act
+        let text = symbol_changes_sync(dir.path(), &files, &base, &head, 1_000);
+        let missing_base = symbol_changes_sync(dir.path(), &files, "0000000", &head, 1_000);
+
+        // Assert
+        assert!(text.starts_with("\n<symbol_changes>\n### `scale` (lib.rs)\n"));
+        assert!(text.contains("Before:\n```\npub fn scale(x: u32) -> u32 {\n    x * 2\n}\n```"));
+        assert!(text.contains("After:\n```\npub fn scale(x: u32) -> u32 {\n    x * 3\n}\n```"));
+        assert!(!text.contains("`id`"));
+        assert!(missing_base.is_empty());
+    }
+
+    #[cfg(all(feature = "ast-context", not(target_arch = "wasm32")))]
+    #[test]
+    fn test_symbol_changes_key_methods_by_type_across_renames() {
+        // Arrange: `B::new` changes while the file moves from old.rs to new.rs
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?} failed");
+            String::from_utf8(output.stdout).unwrap().trim().to_string()
+        };
+        git(&["init", "-q"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "user.name", "Test"]);
+        git(&["config", "commit.gpgsign", "false"]);
+        let source = |b: &str| {
+            format!(
+                "pub struct A;\npub struct B;\n\nimpl A {{\n    pub fn new() -> Self {{\n        A\n    }}\n}}\n\nimpl B {{\n    pub fn new() -> Self {{\n        {b}\n    }}\n}}\n"
+            )
+        };
+        std::fs::write(dir.path().join("old.rs"), source("B")).unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "base"]);
+        let base = git(&["rev-parse", "HEAD"]);
+        git(&["mv", "old.rs", "new.rs"]);
+        std::fs::write(dir.path().join("new.rs"), source("Self")).unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "head"]);
+        let head = git(&["rev-parse", "HEAD"]);
+        let mut file = patched_file(
+            "new.rs",
+            "@@ -11,3 +11,3 @@\n     pub fn new() -> Self {\n-        B\n+        Self\n     }\n",
+        );
+        file.status = "renamed".to_string();
+        file.previous_filename = Some("old.rs".to_string());
+
+        // Act
+        let text = symbol_changes_sync(dir.path(), &[file], &base, &head, 1_000);
+
+        // Assert
+        assert!(text.contains("### `B::new` (new.rs)"), "{text}");
+        assert!(text.contains("Before:\n```\n    pub fn new() -> Self {\n        B\n    }\n```"));
+        assert!(!text.contains("A::new"));
+    }
 }
@@ -237,6 +237,26 @@ pub struct TriageResponse {
     /// Automatic complexity assessment.
     #[serde(default)]
     pub complexity: Option<ComplexityAssessment>,
+    /// People who could own the work, from CODEOWNERS and commit history for
+    /// the affected areas. Filled in after analysis, not by the model.
+    #[serde(default)]
+    pub suggested_assignees: Vec<SuggestedAssignee>,
+    /// Labels forced by triage rules. Applied even when the label is normally
+    /// reserved for maintainers. Filled in after analysis, not by the model.
+    #[serde(default)]
+    pub forced_labels: Vec<String>,
+    /// Names of the triage rules that matched this issue.
+    #[serde(default)]
+    pub applied_rules: Vec<String>,
+}
+
+/// A suggested assignee for an issue.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct SuggestedAssignee {
+    /// GitHub login.
+    pub login: String,
+    /// Why they were suggested (e.g. code owner of, or recent commits to, an affected path).
+    pub reason: String,
 }
 
 /// Context about a related issue from repository search.
@@ -250,6 +270,10 @@ pub struct RepoIssueContext {
     pub labels: Vec<String>,
     /// Issue state (open or closed).
     pub state: String,
+    /// Embedding similarity to the issue being triaged (0.0-1.0), when the
+    /// issue was found through the embedding index rather than keyword search.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub similarity: Option<f32>,
 }
 
 /// A label available in the repository.
@@ -296,6 +320,12 @@ pub struct IssueDetails {
     /// Recent comments on the issue.
     #[builder(default)]
     pub comments: Vec<IssueComment>,
+    /// Every comment on the issue, when there are more than `comments` holds.
+    ///
+    /// Used to find earlier aptu triage comments outside the prompt's comment
+    /// window.
+    #[serde(default)]
+    pub comment_history: Option<Vec<IssueComment>>,
     /// Issue URL.
     pub url: String,
     /// Related issues from repository search (for AI context).
@@ -326,6 +356,18 @@ pub struct IssueDetails {
     /// Issue last update timestamp.
     #[serde(default)]
     pub updated_at: Option<String>,
+    /// When the issue body was last edited (ISO 8601), if ever.
+    #[serde(default)]
+    pub last_edited_at: Option<String>,
+    /// Author's relationship to the repository (e.g. `OWNER`, `CONTRIBUTOR`, `NONE`).
+    #[serde(default)]
+    pub author_association: Option<String>,
+    /// Earlier aptu triage to revise, when re-triaging after the issue changed.
+    #[serde(default)]
+    pub prior_triage: Option<PriorTriage>,
+    /// Issue form the issue was filed with and the required fields left empty.
+    #[serde(default)]
+    pub template_check: Option<TemplateCheck>,
 }
 
 /// A comment on an issue.
@@ -337,6 +379,33 @@ pub struct IssueComment {
     pub author: String,
     /// Comment body.
     pub body: String,
+    /// Comment creation timestamp (ISO 8601).
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// Comment last update timestamp (ISO 8601).
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+/// An earlier aptu triage comment on an issue.
+///
+/// When set on [`IssueDetails`], the triage prompt includes it as context and
+/// the new triage replaces the comment in place.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriorTriage {
+    /// ID of the triage comment to edit.
+    pub comment_id: u64,
+    /// Rendered markdown of the earlier triage.
+    pub body: String,
+}
+
+/// Result of checking an issue against the repository's issue forms.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemplateCheck {
+    /// Name of the issue form the issue was filed with.
+    pub template: String,
+    /// Labels of required fields the author left empty.
+    pub missing_fields: Vec<String>,
 }
 
 /// Response from AI for creating an issue.
@@ -398,6 +467,9 @@ pub struct PrDetails {
     /// Head commit SHA (used as `commit_id` when posting inline review comments).
     #[serde(default)]
     pub head_sha: String,
+    /// Base commit SHA (the commit the PR branch is compared against).
+    #[serde(default)]
+    pub base_sha: String,
     /// Review comments on the PR.
     #[serde(default)]
     pub review_comments: Vec<PrReviewCommentDetails>,
@@ -414,6 +486,9 @@ pub struct PrDetails {
 pub struct PrFile {
     /// File path.
     pub filename: String,
+    /// Path before the change, set for renamed files.
+    #[serde(default)]
+    pub previous_filename: Option<String>,
     /// Change status (added, modified, removed, renamed).
     pub status: String,
     /// Number of additions.
@@ -557,6 +632,128 @@ pub struct PrLabelResponse {
     pub suggested_labels: Vec<String>,
 }
 
+/// An open issue in a cluster of possible duplicates.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateCandidate {
+    /// Issue number.
+    pub number: u64,
+    /// Issue title.
+    pub title: String,
+    /// Issue body.
+    pub body: String,
+    /// Issue labels.
+    pub labels: Vec<String>,
+    /// Creation timestamp (ISO 8601).
+    pub created_at: String,
+}
+
+/// Structured verdict on a cluster of possible duplicate issues from AI.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct DuplicateClusterResponse {
+    /// Issue to keep open as the canonical report.
+    pub canonical: u64,
+    /// Issues that duplicate the canonical one (empty if none do).
+    #[serde(default)]
+    pub duplicates: Vec<u64>,
+    /// Why the canonical issue was chosen and the duplicates match it.
+    #[serde(default)]
+    pub rationale: String,
+}
+
+/// An inactive issue or pull request to write a stale nudge for.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaleNudgeInput {
+    /// Kind of item ("issue" or "pull request").
+    pub kind: String,
+    /// Issue or PR number.
+    pub number: u64,
+    /// Title.
+    pub title: String,
+    /// Body.
+    pub body: String,
+    /// Author login (empty if unknown).
+    pub author: String,
+    /// Label names.
+    pub labels: Vec<String>,
+    /// Days since the item was last updated.
+    pub inactive_days: i64,
+    /// Days until the item is closed (`None` if it will not be closed).
+    pub close_in_days: Option<u32>,
+    /// Clarifying questions from an earlier triage the author has not answered.
+    pub open_questions: Vec<String>,
+    /// Most recent comments, oldest first.
+    pub recent_comments: Vec<IssueComment>,
+}
+
+/// Structured stale nudge from AI.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct StaleNudgeResponse {
+    /// Follow-up comment to post.
+    pub comment: String,
+}
+
+/// A child issue proposed when splitting a large issue.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SubIssueTask {
+    /// Child issue title.
+    pub title: String,
+    /// Child issue body (markdown).
+    #[serde(default)]
+    pub body: String,
+    /// Labels to apply to the child issue.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// 1-based positions of earlier tasks this one depends on.
+    #[serde(default)]
+    pub depends_on: Vec<usize>,
+}
+
+/// Structured sub-issue breakdown of a large issue from AI.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct IssueSplitResponse {
+    /// Child issues, in the order they should be worked on.
+    pub tasks: Vec<SubIssueTask>,
+    /// Why the issue was split this way.
+    #[serde(default)]
+    pub rationale: String,
+}
+
+/// A source symbol in the local checkout that an issue appears to concern.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeSymbol {
+    /// Symbol kind (`function`, `struct`, `enum`, or `trait`).
+    pub kind: String,
+    /// Symbol name.
+    pub name: String,
+    /// File path relative to the repository root.
+    pub path: String,
+}
+
+/// One step of an implementation plan.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct PlanStep {
+    /// What to change and why.
+    pub description: String,
+    /// File to change, relative to the repository root (empty if none).
+    #[serde(default)]
+    pub file: String,
+    /// Functions or types to change or add in that file.
+    #[serde(default)]
+    pub symbols: Vec<String>,
+}
+
+/// Structured implementation plan for an issue from AI.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ImplementationPlan {
+    /// One-paragraph summary of the approach.
+    pub summary: String,
+    /// Ordered steps naming concrete files and symbols.
+    pub steps: Vec<PlanStep>,
+    /// Unified diff implementing the plan (empty unless requested).
+    #[serde(default)]
+    pub diff: String,
+}
+
 #[cfg(test)]
 mod tests {
     use std::assert_matches;
@@ -93,6 +93,456 @@ fn floor_char_boundary(s: &str, max: usize) -> usize {
     idx
 }
 
+/// Returns the names of the innermost symbols enclosing the given 1-based lines.
+///
+/// Each line maps to the smallest function or method whose span contains it,
+/// falling back to the smallest enclosing class/struct when the line is outside
+/// any function body (e.g. a field declaration). Lines outside every symbol are
+/// ignored. Returns an empty `Vec` for unsupported languages or parse failures.
+#[cfg(feature = "ast-context")]
+pub(crate) fn enclosing_symbols(filename: &str, source: &str, lines: &[usize]) -> Vec<String> {
+    let ext = Path::new(filename)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("");
+    let Some(language) = language_for_extension(ext) else {
+        return Vec::new();
+    };
+    let semantic =
+        match aptu_coder_core::parser::SemanticExtractor::extract(source, language, None, None) {
+            Ok(semantic) => semantic,
+            Err(e) => {
+                debug!("enclosing_symbols: skipping {}: {}", filename, e);
+                return Vec::new();
+            }
+        };
+
+    let functions: Vec<_> = semantic
+        .functions
+        .iter()
+        .chain(semantic.classes.iter().flat_map(|c| c.methods.iter()))
+        .map(|f| (f.name.as_str(), f.line, f.end_line))
+        .collect();
+    let classes: Vec<_> = semantic
+        .classes
+        .iter()
+        .map(|c| (c.name.as_str(), c.line, c.end_line))
+        .collect();
+
+    let innermost = |spans: &[(&str, usize, usize)], line: usize| {
+        spans
+            .iter()
+            .filter(|(_, start, end)| (*start..=*end).contains(&line))
+            .min_by_key(|(_, start, end)| end - start)
+            .map(|(name, _, _)| (*name).to_string())
+    };
+
+    let mut symbols: Vec<String> = Vec::new();
+    for &line in lines {
+        if let Some(name) = innermost(&functions, line).or_else(|| innermost(&classes, line))
+            && !symbols.contains(&name)
+        {
+            symbols.push(name);
+        }
+    }
+    symbols
+}
+
+/// Source text of one function or method, as returned by [`function_bodies`].
+#[cfg(feature = "ast-context")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FunctionBody {
+    /// `Type::name` for methods and `name` for free functions. A key that
+    /// repeats within the file gets a `#n` suffix (`Foo::new#2`).
+    pub key: String,
+    /// Bare function name.
+    pub name: String,
+    /// First line of the span (1-based).
+    pub start: usize,
+    /// Last line of the span (1-based, inclusive).
+    pub end: usize,
+    /// Source text of the span.
+    pub body: String,
+}
+
+/// A function or method together with the type it is defined on.
+#[cfg(feature = "ast-context")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct OwnedFunction<'a> {
+    /// Enclosing class, struct, or trait; `None` for free functions.
+    pub owner: Option<&'a str>,
+    /// Bare function name.
+    pub name: &'a str,
+    /// First line of the span (1-based).
+    pub line: usize,
+    /// Last line of the span (1-based, inclusive).
+    pub end_line: usize,
+}
+
+#[cfg(feature = "ast-context")]
+impl OwnedFunction<'_> {
+    /// `Type::name` for methods and `name` for free functions.
+    pub fn qualified(&self) -> String {
+        self.owner.map_or_else(
+            || self.name.to_string(),
+            |owner| format!("{owner}::{}", self.name),
+        )
+    }
+}
+
+/// Returns every function and method in `semantic` with its owner, in line order.
+///
+/// Methods listed under a class carry their owner; the flat function list
+/// repeats some of them, so it only adds spans not seen yet. A function that
+/// sits inside a class span (e.g. a Python method) takes the innermost such
+/// class as its owner.
+#[cfg(feature = "ast-context")]
+pub(crate) fn owned_functions(
+    semantic: &aptu_coder_core::SemanticAnalysis,
+) -> Vec<OwnedFunction<'_>> {
+    let mut functions: Vec<OwnedFunction<'_>> = Vec::new();
+    for class in &semantic.classes {
+        for method in &class.methods {
+            functions.push(OwnedFunction {
+                owner: Some(class.name.as_str()),
+                name: method.name.as_str(),
+                line: method.line,
+                end_line: method.end_line,
+            });
+        }
+    }
+    for func in &semantic.functions {
+        if functions.iter().any(|f| f.line == func.line) {
+            continue;
+        }
+        let owner = semantic
+            .classes
+            .iter()
+            .filter(|c| c.line < func.line && func.end_line <= c.end_line)
+            .min_by_key(|c| c.end_line - c.line)
+            .map(|c| c.name.as_str());
+        functions.push(OwnedFunction {
+            owner,
+            name: func.name.as_str(),
+            line: func.line,
+            end_line: func.end_line,
+        });
+    }
+    functions.sort_by_key(|f| f.line);
+    functions
+}
+
+/// Returns the source text of each function or method in `source`, in line order.
+///
+/// Spans come from the parsed `line`/`end_line` of each function, so leading
+/// attributes and doc comments the parser attaches are included. Methods are
+/// keyed by their enclosing type (see [`owned_functions`]) so that `A::new`
+/// and `B::new` stay distinct. Returns an empty `Vec` for unsupported
+/// languages or parse failures.
+#[cfg(feature = "ast-context")]
+pub(crate) fn function_bodies(filename: &str, source: &str) -> Vec<FunctionBody> {
+    let ext = Path::new(filename)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("");
+    let Some(language) = language_for_extension(ext) else {
+        return Vec::new();
+    };
+    let semantic =
+        match aptu_coder_core::parser::SemanticExtractor::extract(source, language, None, None) {
+            Ok(semantic) => semantic,
+            Err(e) => {
+                debug!("function_bodies: skipping {}: {}", filename, e);
+                return Vec::new();
+            }
+        };
+
+    let lines: Vec<&str> = source.lines().collect();
+    let mut seen: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+    let mut bodies = Vec::new();
+    for func in owned_functions(&semantic) {
+        let start = func.line.saturating_sub(1);
+        let end = func.end_line.min(lines.len());
+        if start >= end {
+            continue;
+        }
+        let base = func.qualified();
+        let count = seen.entry(base.clone()).or_insert(0);
+        *count += 1;
+        let key = if *count == 1 {
+            base
+        } else {
+            format!("{base}#{count}")
+        };
+        bodies.push(FunctionBody {
+            key,
+            name: func.name.to_string(),
+            start: func.line,
+            end: func.end_line,
+            body: lines[start..end].join("\n"),
+        });
+    }
+    bodies
+}
+
+/// Returns `true` if a function is test code judging by its file and name.
+///
+/// Test files are recognised by path (`tests/`, `*_test.go`, `*.spec.ts`,
+/// `test_*.py`, ...); outside them, functions named `test_*` (pytest, and the
+/// usual Rust convention) count as tests. Test attributes and test modules
+/// need the syntax tree; see [`SourceFacts::is_test`].
+#[cfg(feature = "ast-context")]
+#[must_use]
+pub fn is_test_symbol(path: &str, name: &str) -> bool {
+    aptu_coder_core::test_detection::is_test_file(Path::new(path)) || name.starts_with("test_")
+}
+
+/// Tree-sitter queries for test items, type kinds, and visibility, per language.
+///
+/// Captures: `@attribute` (Rust attribute items), `@function`/`@function_item`
+/// (function name and item), `@module`/`@module_item` (inline module name and
+/// item), `@trait`/`@enum` (type names), and `@exported`/`@exported_item`
+/// (name and item of a declaration with a visibility modifier or `export`,
+/// the modifier captured as `@visibility`).
+#[cfg(feature = "ast-context")]
+fn source_facts_query(language: &str) -> Option<&'static str> {
+    match language {
+        "rust" => Some(
+            "(attribute_item) @attribute
+             (function_item name: (identifier) @function) @function_item
+             (mod_item name: (identifier) @module body: (declaration_list)) @module_item
+             (trait_item name: (type_identifier) @trait)
+             (enum_item name: (type_identifier) @enum)
+             (function_item (visibility_modifier) @visibility name: (identifier) @exported) @exported_item
+             (struct_item (visibility_modifier) @visibility name: (type_identifier) @exported) @exported_item
+             (enum_item (visibility_modifier) @visibility name: (type_identifier) @exported) @exported_item
+             (trait_item (visibility_modifier) @visibility name: (type_identifier) @exported) @exported_item",
+        ),
+        "java" => Some(
+            "(interface_declaration name: (identifier) @trait)
+             (enum_declaration name: (identifier) @enum)
+             (method_declaration (modifiers) @visibility name: (identifier) @exported) @exported_item
+             (class_declaration (modifiers) @visibility name: (identifier) @exported) @exported_item
+             (interface_declaration (modifiers) @visibility name: (identifier) @exported) @exported_item
+             (enum_declaration (modifiers) @visibility name: (identifier) @exported) @exported_item",
+        ),
+        "csharp" => Some(
+            "(interface_declaration name: (identifier) @trait)
+             (enum_declaration name: (identifier) @enum)
+             (method_declaration (modifier) @visibility name: (identifier) @exported) @exported_item
+             (class_declaration (modifier) @visibility name: (identifier) @exported) @exported_item
+             (interface_declaration (modifier) @visibility name: (identifier) @exported) @exported_item
+             (enum_declaration (modifier) @visibility name: (identifier) @exported) @exported_item",
+        ),
+        "typescript" | "tsx" => Some(
+            "(interface_declaration name: (type_identifier) @trait)
+             (enum_declaration name: (identifier) @enum)
+             (export_statement declaration: (function_declaration name: (identifier) @exported)) @exported_item
+             (export_statement declaration: (class_declaration name: (type_identifier) @exported)) @exported_item
+             (export_statement declaration: (interface_declaration name: (type_identifier) @exported)) @exported_item
+             (export_statement declaration: (enum_declaration name: (identifier) @exported)) @exported_item",
+        ),
+        "javascript" => Some(
+            "(export_statement declaration: (function_declaration name: (identifier) @exported)) @exported_item
+             (export_statement declaration: (class_declaration name: (identifier) @exported)) @exported_item",
+        ),
+        "go" => Some("(type_spec name: (type_identifier) @trait type: (interface_type))"),
+        _ => None,
+    }
+}
+
+/// What a file's syntax tree says about its tests and types, beyond the
+/// [`aptu_coder_core::SemanticAnalysis`] of the same file.
+///
+/// Shared by the per-PR graph builder and the repository graph so both agree
+/// on which functions are tests and which types are traits or enums.
+#[cfg(feature = "ast-context")]
+#[derive(Debug, Default)]
+pub(crate) struct SourceFacts {
+    /// Functions carrying a test attribute (`#[test]`, `#[tokio::test]`, ...)
+    /// as `(name, line)`, the 1-based line of the `fn` item.
+    test_functions: Vec<(String, usize)>,
+    /// 1-based inclusive line ranges of test modules: inline modules named
+    /// `tests` or `test`, or marked `#[cfg(test)]`.
+    test_modules: Vec<(usize, usize)>,
+    /// Kind (`trait` or `enum`) of the types declared as one.
+    kinds: std::collections::HashMap<String, &'static str>,
+    /// Declarations visible outside their module as `(name, line,
+    /// visibility)`, the 1-based line of the item: Rust's modifier as written
+    /// (`pub`, `pub(crate)`), or `pub` for `export` and `public`.
+    visible: Vec<(String, usize, String)>,
+    /// Language of the file, for languages whose visibility follows the name.
+    language: Option<&'static str>,
+}
+
+#[cfg(feature = "ast-context")]
+impl SourceFacts {
+    /// Runs one tree-sitter query over `source`. Unsupported languages and
+    /// query failures yield no facts.
+    pub fn extract(path: &str, source: &str) -> Self {
+        let mut facts = Self::default();
+        let Some(language) = Path::new(path)
+            .extension()
+            .and_then(|e| e.to_str())
+            .and_then(language_for_extension)
+        else {
+            return facts;
+        };
+        facts.language = Some(language);
+        let Some(query) = source_facts_query(language) else {
+            return facts;
+        };
+        let captures = match aptu_coder_core::execute_query(language, source, query) {
+            Ok(captures) => captures,
+            Err(e) => {
+                debug!("source_facts: skipping {}: {}", path, e);
+                return facts;
+            }
+        };
+
+        let of = |name: &'static str| captures.iter().filter(move |c| c.capture_name == name);
+        let attributes: Vec<&aptu_coder_core::QueryCapture> = of("attribute").collect();
+        // The item capture that contains a name capture.
+        let item_of = |item: &'static str, name: &aptu_coder_core::QueryCapture| {
+            of(item)
+                .filter(|i| i.start_byte <= name.start_byte && name.end_byte <= i.end_byte)
+                .min_by_key(|i| i.end_byte - i.start_byte)
+                .cloned()
+        };
+
+        for name in of("function") {
+            let Some(item) = item_of("function_item", name) else {
+                continue;
+            };
+            if attributes_before(&attributes, &item)
+                .iter()
+                .any(|a| is_test_attribute(&a.text))
+            {
+                facts
+                    .test_functions
+                    .push((name.text.clone(), item.start_line + 1));
+            }
+        }
+        for name in of("module") {
+            let Some(item) = item_of("module_item", name) else {
+                continue;
+            };
+            let cfg_test = attributes_before(&attributes, &item)
+                .iter()
+                .any(|a| a.text.split_whitespace().collect::<String>() == "#[cfg(test)]");
+            if cfg_test || name.text == "tests" || name.text == "test" {
+                facts
+                    .test_modules
+                    .push((item.start_line + 1, item.end_line + 1));
+            }
+        }
+        for (capture, kind) in [("trait", "trait"), ("enum", "enum")] {
+            for name in of(capture) {
+                facts.kinds.insert(name.text.clone(), kind);
+            }
+        }
+        for name in of("exported") {
+            let Some(item) = item_of("exported_item", name) else {
+                continue;
+            };
+            let modifiers: Vec<&str> = of("visibility")
+                .filter(|v| item.start_byte <= v.start_byte && v.end_byte <= name.start_byte)
+                .map(|v| v.text.as_str())
+                .collect();
+            let visibility = match language {
+                "rust" => modifiers.first().map(|m| (*m).to_string()),
+                "java" | "csharp" => modifiers
+                    .iter()
+                    .any(|m| m.split_whitespace().any(|word| word == "public"))
+                    .then(|| "pub".to_string()),
+                _ => Some("pub".to_string()),
+            };
+            if let Some(visibility) = visibility {
+                facts
+                    .visible
+                    .push((name.text.clone(), item.start_line + 1, visibility));
+            }
+        }
+        facts
+    }
+
+    /// Returns the visibility of the function or type `name` spanning
+    /// `line..=end_line`: as declared (see [`SourceFacts::visible`]), by the
+    /// naming rule of Go (capitalised) and Python (no leading underscore), or
+    /// `private`.
+    pub fn visibility(&self, name: &str, line: usize, end_line: usize) -> String {
+        if let Some((_, _, visibility)) = self
+            .visible
+            .iter()
+            .find(|(n, item, _)| n == name && (line..=end_line).contains(item))
+        {
+            return visibility.clone();
+        }
+        let exported = match self.language {
+            Some("go") => name.starts_with(|c: char| c.is_uppercase()),
+            Some("python") => !name.starts_with('_'),
+            _ => false,
+        };
+        if exported { "pub" } else { "private" }.to_string()
+    }
+
+    /// Returns `true` if the function `name` spanning `line..=end_line` is
+    /// test code: by path or name (see [`is_test_symbol`]), by a test
+    /// attribute, or by sitting inside a test module.
+    pub fn is_test(&self, path: &str, name: &str, line: usize, end_line: usize) -> bool {
+        is_test_symbol(path, name)
+            || self
+                .test_modules
+                .iter()
+                .any(|&(start, end)| start <= line && end_line <= end)
+            || self
+                .test_functions
+                .iter()
+                .any(|(test, item)| test == name && (line..=end_line).contains(item))
+    }
+
+    /// Returns the kind of the type `name`: `trait`, `enum`, or `struct`
+    /// (the default, also for classes).
+    pub fn kind(&self, name: &str) -> &'static str {
+        self.kinds.get(name).copied().unwrap_or("struct")
+    }
+}
+
+/// Returns the attributes directly above `item`, nearest first.
+///
+/// Each attribute must end on the line where the next one (or the item)
+/// starts, or on the line before it.
+#[cfg(feature = "ast-context")]
+fn attributes_before<'a>(
+    attributes: &[&'a aptu_coder_core::QueryCapture],
+    item: &aptu_coder_core::QueryCapture,
+) -> Vec<&'a aptu_coder_core::QueryCapture> {
+    let mut found = Vec::new();
+    let (mut next, mut line) = (item.start_byte, item.start_line);
+    while let Some(&attribute) = attributes
+        .iter()
+        .filter(|a| a.end_byte <= next && (a.end_line == line || a.end_line + 1 == line))
+        .max_by_key(|a| a.end_byte)
+    {
+        found.push(attribute);
+        (next, line) = (attribute.start_byte, attribute.start_line);
+    }
+    found
+}
+
+/// Returns `true` for Rust attributes that mark a test: `#[test]`, and
+/// framework variants such as `#[tokio::test]`, `#[rstest]`, or
+/// `#[test_case(..)]`.
+#[cfg(feature = "ast-context")]
+fn is_test_attribute(attribute: &str) -> bool {
+    let inner = attribute
+        .trim()
+        .trim_start_matches("#[")
+        .trim_end_matches(']');
+    let path = inner.split('(').next().unwrap_or("").trim();
+    path == "test" || path.ends_with("::test") || path == "rstest" || path == "test_case"
+}
+
 /// Build a compact AST context string for the changed files in a PR.
 ///
 /// Returns empty string if `repo_path` is invalid or no files have analysis results.
@@ -130,6 +580,9 @@ fn build_ast_context_sync(repo_path: &str, files: &[PrFile]) -> AstContextOutput
         Vec::new();
     #[cfg(feature = "graph")]
     let mut impl_traits: Vec<aptu_coder_core::ImplTraitInfo> = Vec::new();
+    // Source text per analysed file, for the test and type-kind query.
+    #[cfg(feature = "graph")]
+    let mut sources: Vec<String> = Vec::new();
 
     for file in files {
         let ext = Path::new(&file.filename)
@@ -166,6 +619,7 @@ fn build_ast_context_sync(repo_path: &str, files: &[PrFile]) -> AstContextOutput
                 {
                     analysis_pairs.push((full_path.clone(), analysis.semantic.clone()));
                     impl_traits.extend(analysis.semantic.impl_traits.clone());
+                    sources.push(std::fs::read_to_string(&full_path).unwrap_or_default());
                 }
             }
             Err(e) => {
@@ -202,13 +656,14 @@ fn build_ast_context_sync(repo_path: &str, files: &[PrFile]) -> AstContextOutput
             Ok(call_graph) => {
                 // Reuse the already-accumulated (path, semantic) pairs -- no second analyze_file.
                 let mut merged = crate::graph::GraphDb::new();
-                for (full_path, semantic) in &analysis_pairs {
+                for ((full_path, semantic), source) in analysis_pairs.iter().zip(&sources) {
                     let rel_name = full_path.file_name().map_or_else(
                         || full_path.to_string_lossy().into_owned(),
                         |n| n.to_string_lossy().into_owned(),
                     );
                     let file_graph = crate::graph::builder::build_from_analysis(
                         &rel_name,
+                        source,
                         semantic,
                         &call_graph,
                     );
@@ -355,6 +810,7 @@ mod tests {
     fn make_pr_file(filename: &str) -> PrFile {
         PrFile {
             filename: filename.to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 0,
             deletions: 0,
@@ -364,6 +820,111 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "ast-context")]
+    #[test]
+    fn test_source_facts_find_rust_test_attributes_and_modules() {
+        let source = "#[derive(Debug)]\npub enum Mode {\n    A,\n}\n\npub trait Shape {}\n\n#[tokio::test]\n#[ignore]\nasync fn checks_async() {}\n\n/// Not a test.\n#[inline]\nfn helper() {}\n\n#[cfg(test)]\nmod unit {\n    fn setup() {}\n}\n\nmod tests {\n    fn inner() { let s = \"}\"; }\n}\n";
+
+        let facts = SourceFacts::extract("src/lib.rs", source);
+
+        assert!(facts.is_test("src/lib.rs", "checks_async", 8, 10));
+        assert!(!facts.is_test("src/lib.rs", "helper", 12, 14));
+        assert!(facts.is_test("src/lib.rs", "setup", 18, 18));
+        assert!(facts.is_test("src/lib.rs", "inner", 22, 22));
+        assert_eq!(facts.kind("Mode"), "enum");
+        assert_eq!(facts.kind("Shape"), "trait");
+        assert_eq!(facts.kind("Other"), "struct");
+    }
+
+    #[cfg(feature = "ast-context")]
+    #[test]
+    fn test_source_facts_kinds_across_languages() {
+        let cases = [
+            ("A.java", "interface Shape {}\nenum Mode { A }\n"),
+            ("a.cs", "interface Shape {}\nenum Mode { A }\n"),
+            ("a.ts", "interface Shape {}\nenum Mode { A }\n"),
+            ("a.tsx", "interface Shape {}\nenum Mode { A }\n"),
+        ];
+        for (path, source) in cases {
+            let facts = SourceFacts::extract(path, source);
+            assert_eq!(facts.kind("Shape"), "trait", "{path}");
+            assert_eq!(facts.kind("Mode"), "enum", "{path}");
+        }
+        let go = SourceFacts::extract("a.go", "package a\n\ntype Shape interface {}\n");
+        assert_eq!(go.kind("Shape"), "trait");
+    }
+
+    #[cfg(feature = "ast-context")]
+    #[test]
+    fn test_source_facts_visibility_across_languages() {
+        let rust = SourceFacts::extract(
+            "lib.rs",
+            "pub fn api() {}\npub(crate) struct Inner;\nfn helper() {}\n",
+        );
+        assert_eq!(rust.visibility("api", 1, 1), "pub");
+        assert_eq!(rust.visibility("Inner", 2, 2), "pub(crate)");
+        assert_eq!(rust.visibility("helper", 3, 3), "private");
+
+        let ts = SourceFacts::extract(
+            "a.ts",
+            "export function api() {}\nfunction helper() {}\nexport class Service {}\n",
+        );
+        assert_eq!(ts.visibility("api", 1, 1), "pub");
+        assert_eq!(ts.visibility("helper", 2, 2), "private");
+        assert_eq!(ts.visibility("Service", 3, 3), "pub");
+
+        let java = SourceFacts::extract(
+            "A.java",
+            "public class A {\n    public void run() {}\n    private void step() {}\n}\n",
+        );
+        assert_eq!(java.visibility("A", 1, 4), "pub");
+        assert_eq!(java.visibility("run", 2, 2), "pub");
+        assert_eq!(java.visibility("step", 3, 3), "private");
+
+        let cs = SourceFacts::extract(
+            "a.cs",
+            "public class A {\n    public void Run() {}\n    void Step() {}\n}\n",
+        );
+        assert_eq!(cs.visibility("Run", 2, 2), "pub");
+        assert_eq!(cs.visibility("Step", 3, 3), "private");
+
+        let js = SourceFacts::extract("a.js", "export function api() {}\nfunction helper() {}\n");
+        assert_eq!(js.visibility("api", 1, 1), "pub");
+        assert_eq!(js.visibility("helper", 2, 2), "private");
+
+        let go = SourceFacts::extract("a.go", "package a\n\nfunc Run() {}\nfunc step() {}\n");
+        assert_eq!(go.visibility("Run", 3, 3), "pub");
+        assert_eq!(go.visibility("step", 4, 4), "private");
+
+        let py = SourceFacts::extract("a.py", "def run():\n    pass\n\ndef _step():\n    pass\n");
+        assert_eq!(py.visibility("run", 1, 2), "pub");
+        assert_eq!(py.visibility("_step", 4, 5), "private");
+    }
+
+    #[cfg(feature = "ast-context")]
+    #[test]
+    fn test_function_bodies_key_methods_by_owner() {
+        let source = "struct A;\nstruct B;\n\nimpl A {\n    fn new() -> Self {\n        A\n    }\n}\n\nimpl B {\n    fn new() -> Self {\n        B\n    }\n}\n\nfn new() {}\n";
+
+        let bodies = function_bodies("lib.rs", source);
+        let keys: Vec<&str> = bodies.iter().map(|b| b.key.as_str()).collect();
+
+        assert_eq!(keys, vec!["A::new", "B::new", "new"]);
+        assert!(bodies[1].body.contains("        B"));
+        assert!(bodies.iter().all(|b| b.name == "new"));
+    }
+
+    #[cfg(feature = "ast-context")]
+    #[test]
+    fn test_function_bodies_python_methods_take_class_owner() {
+        let source = "class A:\n    def run(self):\n        return 1\n\n\nclass B:\n    def run(self):\n        return 2\n";
+
+        let bodies = function_bodies("m.py", source);
+        let keys: Vec<&str> = bodies.iter().map(|b| b.key.as_str()).collect();
+
+        assert_eq!(keys, vec!["A::run", "B::run"]);
+    }
+
     #[tokio::test]
     async fn test_build_ast_context_missing_path_returns_empty() {
         let files = vec![make_pr_file("src/main.rs")];
@@ -137,6 +137,12 @@ pub struct AiConfig {
     /// cached model registry before creating an AI client. Provides helpful
     /// suggestions if an invalid model ID is detected.
     pub validation_enabled: bool,
+    /// Embedding model for the related-issue index (default: unset).
+    ///
+    /// When set, issue text is embedded through the provider's
+    /// OpenAI-compatible `/embeddings` endpoint. When unset, or when the
+    /// provider has no embeddings endpoint, a local hashing embedder is used.
+    pub embedding_model: Option<String>,
 }
 
 impl Default for AiConfig {
@@ -155,6 +161,7 @@ impl Default for AiConfig {
             fallback: None,
             custom_guidance: None,
             validation_enabled: true,
+            embedding_model: None,
         }
     }
 }
@@ -208,6 +208,9 @@ pub struct AppConfig {
     /// Prompt injection defence settings.
     #[serde(default)]
     pub prompt: PromptConfig,
+    /// Stale issue and PR sweeper settings.
+    #[serde(default)]
+    pub stale: crate::config::StaleConfig,
 }
 
 /// Returns the Aptu configuration directory.
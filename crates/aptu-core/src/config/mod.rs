@@ -23,6 +23,7 @@ pub mod cache;
 pub mod graph;
 pub mod loader;
 pub mod review;
+pub mod stale;
 
 pub use ai::{AiConfig, FallbackConfig, FallbackEntry, TaskOverride, TaskType, TasksConfig};
 pub use cache::{CacheConfig, ReposConfig};
@@ -36,3 +37,4 @@ pub use loader::{
     UserConfig, config_dir, config_file_path, data_dir, prompts_dir,
 };
 pub use review::ReviewConfig;
+pub use stale::StaleConfig;
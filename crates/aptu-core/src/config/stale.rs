@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Stale issue and PR sweeper configuration.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Stale sweeper configuration.
+///
+/// Controls when `aptu issue stale` and `aptu pr stale` nudge and close
+/// inactive items:
+///
+/// - `days_until_stale`: 60 days without activity before an item is nudged
+///   and labeled.
+/// - `days_until_close`: 14 days of grace after the nudge before the item is
+///   closed; set to `0` to nudge without ever closing.
+/// - `label_days`: per-label inactivity thresholds, e.g. a shorter window for
+///   `needs-info`. When several labels match, the shortest window wins.
+/// - `exempt_labels`: items carrying any of these labels are never swept.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct StaleConfig {
+    /// Days without activity before an item is marked stale (default: `60`).
+    pub days_until_stale: u32,
+    /// Days after the stale nudge before the item is closed (default: `14`, `0` disables closing).
+    pub days_until_close: u32,
+    /// Label applied to stale items (default: `"stale"`).
+    pub stale_label: String,
+    /// Labels that exempt an item from the sweep (default: `["pinned", "security"]`).
+    pub exempt_labels: Vec<String>,
+    /// Per-label overrides for `days_until_stale`.
+    pub label_days: BTreeMap<String, u32>,
+}
+
+impl Default for StaleConfig {
+    fn default() -> Self {
+        Self {
+            days_until_stale: 60,
+            days_until_close: 14,
+            stale_label: "stale".to_string(),
+            exempt_labels: vec!["pinned".to_string(), "security".to_string()],
+            label_days: BTreeMap::new(),
+        }
+    }
+}
+
+impl StaleConfig {
+    /// Returns how many days an item with `labels` may be inactive before it
+    /// is stale, or `None` if one of its labels exempts it.
+    #[must_use]
+    pub fn days_for(&self, labels: &[String]) -> Option<u32> {
+        if labels.iter().any(|l| self.exempt_labels.contains(l)) {
+            return None;
+        }
+        Some(
+            labels
+                .iter()
+                .filter_map(|l| self.label_days.get(l).copied())
+                .min()
+                .unwrap_or(self.days_until_stale),
+        )
+    }
+
+    /// Returns the shortest inactivity window of any label, which bounds how
+    /// far back the sweep has to search.
+    #[must_use]
+    pub fn min_days(&self) -> u32 {
+        self.label_days
+            .values()
+            .copied()
+            .chain(std::iter::once(self.days_until_stale))
+            .min()
+            .unwrap_or(self.days_until_stale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_for_uses_shortest_matching_label() {
+        let config = StaleConfig {
+            label_days: BTreeMap::from([
+                ("needs-info".to_string(), 14),
+                ("question".to_string(), 30),
+            ]),
+            ..StaleConfig::default()
+        };
+
+        assert_eq!(config.days_for(&["bug".to_string()]), Some(60));
+        assert_eq!(
+            config.days_for(&["question".to_string(), "needs-info".to_string()]),
+            Some(14)
+        );
+        assert_eq!(config.min_days(), 14);
+    }
+
+    #[test]
+    fn test_days_for_exempt_label() {
+        let config = StaleConfig::default();
+
+        assert_eq!(
+            config.days_for(&["bug".to_string(), "pinned".to_string()]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_deserializes_from_toml_with_missing_fields() {
+        let config: StaleConfig = toml::from_str("days_until_stale = 30").unwrap();
+        assert_eq!(config.days_until_stale, 30);
+        assert_eq!(config.days_until_close, 14);
+        assert_eq!(config.stale_label, "stale");
+    }
+}
@@ -0,0 +1,294 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Duplicate issue detection and closing facade functions.
+
+use serde::Serialize;
+use tracing::{debug, instrument, warn};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::ai::AiProvider;
+use crate::auth::TokenProvider;
+use crate::config::AiConfig;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::config::TaskType;
+use crate::error::AptuError;
+use crate::github::dedupe::DedupeRecord;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::github::{auth::create_client_from_provider, graphql::fetch_issue_with_repo_context};
+
+/// An issue in a proposed duplicate cluster.
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateIssue {
+    /// Issue number.
+    pub number: u64,
+    /// Issue title.
+    pub title: String,
+    /// Issue URL.
+    pub url: String,
+    /// Embedding similarity to the canonical issue (1.0 for the canonical issue itself).
+    pub similarity: f32,
+}
+
+/// A canonical issue and the open issues that duplicate it.
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateCluster {
+    /// Issue to keep open.
+    pub canonical: DuplicateIssue,
+    /// Issues to close as duplicates of the canonical issue.
+    pub duplicates: Vec<DuplicateIssue>,
+    /// Model-written rationale, posted on each closed duplicate.
+    pub rationale: String,
+}
+
+/// Proposed duplicate clusters for a repository.
+#[derive(Debug, Clone, Default)]
+pub struct DuplicateProposals {
+    /// Clusters the model confirmed contain duplicates.
+    pub clusters: Vec<DuplicateCluster>,
+    /// Number of similarity clusters found before review.
+    pub candidate_clusters: usize,
+    /// AI usage for each reviewed cluster.
+    pub stats: Vec<crate::history::AiStats>,
+}
+
+/// Finds clusters of duplicate open issues in a repository.
+///
+/// Refreshes the repository's embedding index, groups open issues whose
+/// similarity is at least `threshold`, and asks the model to pick a canonical
+/// issue per cluster and confirm which others duplicate it. Clusters the
+/// model rejects are dropped. At most `limit` clusters are reviewed. Without
+/// a `threshold`, the default for the configured embedder is used (see
+/// [`crate::github::issue_index::default_duplicate_threshold`]).
+///
+/// Issues whose body trips the prompt-injection scanner are left out of
+/// review, since the model's answer decides which issues get closed.
+///
+/// # Errors
+///
+/// Returns an error if the GitHub token is unavailable, the index cannot be
+/// refreshed, or every AI provider fails.
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(provider, ai_config), fields(owner = %owner, repo = %repo))]
+pub async fn find_duplicate_clusters(
+    provider: &dyn TokenProvider,
+    owner: &str,
+    repo: &str,
+    threshold: Option<f32>,
+    limit: Option<usize>,
+    ai_config: &AiConfig,
+) -> crate::Result<DuplicateProposals> {
+    use crate::ai::types::DuplicateCandidate;
+    use crate::security::SecurityScanner;
+
+    let client = create_client_from_provider(provider)?;
+    let embedder = crate::ai::embeddings::Embedder::from_config(ai_config, provider);
+    let index = crate::github::issue_index::refresh_issue_index(&client, owner, repo, &embedder)
+        .await
+        .map_err(|e| AptuError::GitHub {
+            message: e.to_string(),
+        })?;
+
+    let threshold = threshold
+        .unwrap_or_else(|| crate::github::issue_index::default_duplicate_threshold(&embedder));
+    let clusters = index.duplicate_clusters(threshold);
+    let mut proposals = DuplicateProposals {
+        candidate_clusters: clusters.len(),
+        ..DuplicateProposals::default()
+    };
+    debug!(clusters = clusters.len(), "Found similarity clusters");
+
+    let (provider_name, model_name) = ai_config.resolve_for_task(TaskType::Triage, None);
+    let scanner = SecurityScanner::new();
+
+    for cluster in clusters.into_iter().take(limit.unwrap_or(usize::MAX)) {
+        let numbers = std::iter::once(cluster.seed).chain(cluster.members.iter().map(|m| m.0));
+
+        let mut candidates = Vec::new();
+        let mut urls = std::collections::HashMap::new();
+        for number in numbers {
+            let issue = match fetch_issue_with_repo_context(&client, owner, repo, number).await {
+                Ok((issue, _)) => issue,
+                Err(e) => {
+                    warn!(number, error = %e, "Failed to fetch issue, leaving it out of the cluster");
+                    continue;
+                }
+            };
+            let body = issue.body.unwrap_or_default();
+            if scanner
+                .scan_file(&body, "issue.md")
+                .iter()
+                .any(|f| f.pattern_id.starts_with("prompt-injection"))
+            {
+                warn!(
+                    number,
+                    "Prompt injection patterns detected, leaving issue out of the cluster"
+                );
+                continue;
+            }
+            urls.insert(number, issue.url);
+            candidates.push(DuplicateCandidate {
+                number,
+                title: issue.title,
+                body,
+                labels: issue.labels.nodes.into_iter().map(|l| l.name).collect(),
+                created_at: issue.created_at,
+            });
+        }
+        if candidates.len() < 2 {
+            continue;
+        }
+
+        let (verdict, stats) = super::ai_client::try_with_fallback(
+            provider,
+            &provider_name,
+            &model_name,
+            ai_config,
+            |client| {
+                let candidates = candidates.clone();
+                async move { client.review_duplicate_cluster(&candidates).await }
+            },
+        )
+        .await?;
+        proposals.stats.push(stats);
+
+        if verdict.duplicates.is_empty() {
+            debug!(seed = cluster.seed, "Model found no duplicates in cluster");
+            continue;
+        }
+
+        let describe = |number: u64| {
+            let title = candidates
+                .iter()
+                .find(|c| c.number == number)
+                .map(|c| c.title.clone())
+                .unwrap_or_default();
+            DuplicateIssue {
+                number,
+                title,
+                url: urls.get(&number).cloned().unwrap_or_default(),
+                similarity: index
+                    .similarity(verdict.canonical, number)
+                    .unwrap_or_default(),
+            }
+        };
+        proposals.clusters.push(DuplicateCluster {
+            canonical: describe(verdict.canonical),
+            duplicates: verdict.duplicates.iter().map(|&n| describe(n)).collect(),
+            rationale: verdict.rationale.clone(),
+        });
+    }
+
+    Ok(proposals)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn find_duplicate_clusters(
+    _provider: &dyn TokenProvider,
+    _owner: &str,
+    _repo: &str,
+    _threshold: Option<f32>,
+    _limit: Option<usize>,
+    _ai_config: &AiConfig,
+) -> crate::Result<DuplicateProposals> {
+    crate::facade::wasm_unsupported!("find_duplicate_clusters");
+}
+
+/// Closes an issue as a duplicate of `canonical`.
+///
+/// Posts a "Duplicate of #N" comment with the rationale, adds the `duplicate`
+/// label if the repository has one, closes the issue as not planned, and
+/// records what was done so `revert_issue` can undo it.
+///
+/// # Errors
+///
+/// Returns an error if the GitHub token is unavailable, any GitHub API call
+/// fails, or the dedupe log cannot be written.
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(provider, rationale), fields(owner = %owner, repo = %repo, number, canonical))]
+pub async fn close_duplicate(
+    provider: &dyn TokenProvider,
+    owner: &str,
+    repo: &str,
+    number: u64,
+    canonical: u64,
+    rationale: &str,
+) -> crate::Result<DedupeRecord> {
+    use crate::github::dedupe::{DUPLICATE_LABEL, record_dedupe, render_duplicate_comment};
+    use crate::github::issues::{post_comment, set_issue_state, update_issue_labels_and_milestone};
+
+    let github_error = |e: anyhow::Error| AptuError::GitHub {
+        message: e.to_string(),
+    };
+    let client = create_client_from_provider(provider)?;
+
+    let (issue, repo_data) = fetch_issue_with_repo_context(&client, owner, repo, number)
+        .await
+        .map_err(github_error)?;
+
+    let comment_url = post_comment(
+        &client,
+        owner,
+        repo,
+        number,
+        &render_duplicate_comment(canonical, rationale),
+    )
+    .await
+    .map_err(github_error)?;
+
+    let existing_labels: Vec<String> = issue.labels.nodes.into_iter().map(|l| l.name).collect();
+    let available_labels: Vec<crate::ai::types::RepoLabel> =
+        repo_data.labels.nodes.into_iter().map(Into::into).collect();
+    let labeled = if existing_labels.iter().any(|l| l == DUPLICATE_LABEL) {
+        false
+    } else if available_labels.iter().any(|l| l.name == DUPLICATE_LABEL) {
+        update_issue_labels_and_milestone(
+            &client,
+            owner,
+            repo,
+            number,
+            &existing_labels,
+            &[DUPLICATE_LABEL.to_string()],
+            &[],
+            None,
+            None,
+            &available_labels,
+            &[],
+        )
+        .await
+        .map_err(github_error)?;
+        true
+    } else {
+        debug!("Repository has no duplicate label, skipping");
+        false
+    };
+
+    set_issue_state(&client, owner, repo, number, "closed", Some("not_planned"))
+        .await
+        .map_err(github_error)?;
+
+    let record = DedupeRecord {
+        canonical,
+        comment_url,
+        labeled,
+        closed: true,
+        timestamp: chrono::Utc::now(),
+    };
+    record_dedupe(owner, repo, number, record.clone()).map_err(|e| AptuError::Config {
+        message: e.to_string(),
+    })?;
+
+    debug!("Issue closed as duplicate");
+    Ok(record)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn close_duplicate(
+    _provider: &dyn TokenProvider,
+    _owner: &str,
+    _repo: &str,
+    _number: u64,
+    _canonical: u64,
+    _rationale: &str,
+) -> crate::Result<DedupeRecord> {
+    crate::facade::wasm_unsupported!("close_duplicate");
+}
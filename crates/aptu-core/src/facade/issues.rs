@@ -21,11 +21,15 @@ use crate::github::graphql::fetch_issue_with_repo_context;
 use crate::github::issues::{create_issue as gh_create_issue, filter_labels_by_relevance};
 use crate::sanitize::sanitise_user_field;
 use crate::security::SecurityScanner;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::triage_rules::TriageRules;
 
 /// Analyzes a GitHub issue and generates triage suggestions.
 ///
 /// This function abstracts the credential resolution and API client creation,
 /// allowing platforms to provide credentials via `TokenProvider` implementations.
+/// Triage rules from `triage-rules.toml` are applied to the model's suggestions,
+/// and a matching `skip_ai` rule answers without calling the model at all.
 ///
 /// # Arguments
 ///
@@ -40,6 +44,7 @@ use crate::security::SecurityScanner;
 ///
 /// Returns an error if:
 /// - GitHub or AI provider token is not available from the provider
+/// - The triage rules file is invalid
 /// - AI API call fails
 /// - Response parsing fails
 #[cfg(not(target_arch = "wasm32"))]
@@ -60,6 +65,26 @@ pub async fn analyze_issue(
         app_config.prompt.max_issue_body_bytes,
     )?;
 
+    // Deterministic rules may triage the issue without the model
+    let rules = TriageRules::load()?;
+    if let Some(mut triage) = rules.skip_ai_triage(issue) {
+        flag_missing_fields(issue, &mut triage);
+        rules.apply(issue, &mut triage);
+        debug!(rules = ?triage.applied_rules, "Triaged by rules, skipping AI");
+        let stats = crate::history::AiStats {
+            provider: "rules".to_string(),
+            model: "triage-rules".to_string(),
+            ..Default::default()
+        };
+        return Ok((
+            AiResponse {
+                triage,
+                stats: stats.clone(),
+            },
+            stats,
+        ));
+    }
+
     // Clone issue into mutable local variable for potential label enrichment
     let mut issue_mut = issue.clone();
 
@@ -119,7 +144,7 @@ pub async fn analyze_issue(
         ai_config.resolve_for_task(TaskType::Triage, Some(issue.body.len()));
 
     // Use fallback chain if configured
-    let ai_response = super::ai_client::try_with_fallback(
+    let mut ai_response = super::ai_client::try_with_fallback(
         provider,
         &provider_name,
         &model_name,
@@ -131,10 +156,50 @@ pub async fn analyze_issue(
     )
     .await?;
 
+    flag_missing_fields(issue, &mut ai_response.triage);
+    rules.apply(issue, &mut ai_response.triage);
+
+    // Suggest assignees for the affected areas (best effort)
+    if let Some(complexity) = &ai_response.triage.complexity
+        && !complexity.affected_areas.is_empty()
+        && !issue.owner.is_empty()
+        && !issue.repo.is_empty()
+        && let Ok(client) = create_client_from_provider(provider)
+    {
+        ai_response.triage.suggested_assignees = crate::github::owners::suggest_assignees(
+            &client,
+            &issue.owner,
+            &issue.repo,
+            &complexity.affected_areas,
+        )
+        .await;
+    }
+
     let stats = ai_response.stats.clone();
     Ok((ai_response, stats))
 }
 
+/// Forces the `needs-info` label when required issue form fields are empty
+/// and the repository has that label. Triage rules may still forbid it.
+#[cfg(not(target_arch = "wasm32"))]
+fn flag_missing_fields(issue: &IssueDetails, triage: &mut TriageResponse) {
+    use crate::github::templates::NEEDS_INFO_LABEL;
+
+    let has_missing = issue
+        .template_check
+        .as_ref()
+        .is_some_and(|c| !c.missing_fields.is_empty());
+    if let Some(label) = issue
+        .available_labels
+        .iter()
+        .find(|l| l.name.eq_ignore_ascii_case(NEEDS_INFO_LABEL))
+        && has_missing
+        && !triage.forced_labels.contains(&label.name)
+    {
+        triage.forced_labels.push(label.name.clone());
+    }
+}
+
 #[cfg(target_arch = "wasm32")]
 pub async fn analyze_issue(
     _provider: &dyn crate::auth::TokenProvider,
@@ -144,48 +209,20 @@ pub async fn analyze_issue(
     crate::facade::wasm_unsupported!("analyze_issue");
 }
 
-/// Fetches an issue for triage analysis.
-///
-/// Parses the issue reference, checks authentication, and fetches issue details
-/// including labels, milestones, and repository context.
-///
-/// # Arguments
-///
-/// * `provider` - Token provider for GitHub credentials
-/// * `reference` - Issue reference (URL, owner/repo#number, or bare number)
-/// * `repo_context` - Optional repository context for bare numbers
-///
-/// # Returns
-///
-/// Issue details including title, body, labels, comments, and available labels/milestones.
-///
-/// # Errors
+/// Fetches an issue together with its repository's labels and milestones in
+/// a single GraphQL call.
 ///
-/// Returns an error if:
-/// - GitHub token is not available from the provider
-/// - Issue reference cannot be parsed
-/// - GitHub API call fails
+/// Returns the issue details, the repository's primary language (`unknown`
+/// when GitHub has none), and the issue's total comment count.
 #[cfg(not(target_arch = "wasm32"))]
-#[allow(clippy::too_many_lines)]
-#[instrument(skip(provider), fields(reference = %reference))]
-pub async fn fetch_issue_for_triage(
-    provider: &dyn TokenProvider,
-    reference: &str,
-    repo_context: Option<&str>,
-) -> crate::Result<IssueDetails> {
-    // Parse the issue reference
-    let (owner, repo, number) =
-        crate::github::issues::parse_issue_reference(reference, repo_context).map_err(|e| {
-            AptuError::GitHub {
-                message: e.to_string(),
-            }
-        })?;
-
-    // Create GitHub client from provider
-    let client = create_client_from_provider(provider)?;
-
+async fn fetch_issue_details(
+    client: &octocrab::Octocrab,
+    owner: &str,
+    repo: &str,
+    number: u64,
+) -> crate::Result<(IssueDetails, String, usize)> {
     // Fetch issue with repository context (labels, milestones) in a single GraphQL call
-    let (issue_node, repo_data) = fetch_issue_with_repo_context(&client, &owner, &repo, number)
+    let (issue_node, repo_data) = fetch_issue_with_repo_context(client, owner, repo, number)
         .await
         .map_err(|e| AptuError::GitHub {
             message: e.to_string(),
@@ -203,11 +240,8 @@ pub async fn fetch_issue_for_triage(
         .comments
         .nodes
         .iter()
-        .map(|comment| crate::ai::types::IssueComment {
-            id: comment.id,
-            author: comment.author.login.clone(),
-            body: comment.body.clone(),
-        })
+        .cloned()
+        .map(Into::into)
         .collect();
 
     let available_labels: Vec<crate::ai::types::RepoLabel> = repo_data
@@ -233,8 +267,8 @@ pub async fn fetch_issue_for_triage(
         .collect();
 
     let mut issue_details = IssueDetails::builder()
-        .owner(owner.clone())
-        .repo(repo.clone())
+        .owner(owner.to_string())
+        .repo(repo.to_string())
         .number(number)
         .title(issue_node.title.clone())
         .body(issue_node.body.clone().unwrap_or_default())
@@ -249,17 +283,142 @@ pub async fn fetch_issue_for_triage(
     issue_details.author = issue_node.author.as_ref().map(|a| a.login.clone());
     issue_details.created_at = Some(issue_node.created_at.clone());
     issue_details.updated_at = Some(issue_node.updated_at.clone());
+    issue_details
+        .last_edited_at
+        .clone_from(&issue_node.last_edited_at);
+    issue_details
+        .author_association
+        .clone_from(&issue_node.author_association);
 
-    // Extract keywords and language for parallel calls
-    let keywords = crate::github::issues::extract_keywords(&issue_details.title);
     let language = repo_data
         .primary_language
         .as_ref()
         .map_or("unknown", |l| l.name.as_str())
         .to_string();
+    let comment_count = issue_node.comments.total_count as usize;
+    Ok((issue_details, language, comment_count))
+}
+
+/// Fetches an issue for implementation planning.
+///
+/// Like [`fetch_issue_for_triage`], but only the issue and the repository
+/// tree are fetched: no comment history, related-issue search, embedding
+/// lookup, or issue form check.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - GitHub token is not available from the provider
+/// - Issue reference cannot be parsed
+/// - GitHub API call fails
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(provider), fields(reference = %reference))]
+pub async fn fetch_issue_for_plan(
+    provider: &dyn TokenProvider,
+    reference: &str,
+    repo_context: Option<&str>,
+) -> crate::Result<IssueDetails> {
+    let (owner, repo, number) =
+        crate::github::issues::parse_issue_reference(reference, repo_context).map_err(|e| {
+            AptuError::GitHub {
+                message: e.to_string(),
+            }
+        })?;
+    let client = create_client_from_provider(provider)?;
+    let (mut issue_details, language, _) =
+        fetch_issue_details(&client, &owner, &repo, number).await?;
+
+    let keywords = crate::github::issues::extract_keywords(&issue_details.title);
+    match crate::github::issues::fetch_repo_tree(&client, &owner, &repo, &language, &keywords).await
+    {
+        Ok(tree) => issue_details.repo_tree = tree,
+        Err(e) => {
+            debug!(error = %e, "Failed to fetch repository tree, continuing without context");
+        }
+    }
+    Ok(issue_details)
+}
 
-    // Run search and tree fetch in parallel
-    let (search_result, tree_result) = tokio::join!(
+#[cfg(target_arch = "wasm32")]
+pub async fn fetch_issue_for_plan(
+    _provider: &dyn crate::auth::TokenProvider,
+    _reference: &str,
+    _repo_context: Option<&str>,
+) -> crate::Result<crate::ai::types::IssueDetails> {
+    crate::facade::wasm_unsupported!("fetch_issue_for_plan");
+}
+
+/// Fetches an issue for triage analysis.
+///
+/// Parses the issue reference, checks authentication, and fetches issue details
+/// including labels, milestones, and repository context.
+///
+/// # Arguments
+///
+/// * `provider` - Token provider for GitHub credentials
+/// * `reference` - Issue reference (URL, owner/repo#number, or bare number)
+/// * `repo_context` - Optional repository context for bare numbers
+///
+/// # Returns
+///
+/// Issue details including title, body, labels, comments, and available labels/milestones.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - GitHub token is not available from the provider
+/// - Issue reference cannot be parsed
+/// - GitHub API call fails
+#[cfg(not(target_arch = "wasm32"))]
+#[allow(clippy::too_many_lines)]
+#[instrument(skip(provider), fields(reference = %reference))]
+pub async fn fetch_issue_for_triage(
+    provider: &dyn TokenProvider,
+    reference: &str,
+    repo_context: Option<&str>,
+) -> crate::Result<IssueDetails> {
+    // Parse the issue reference
+    let (owner, repo, number) =
+        crate::github::issues::parse_issue_reference(reference, repo_context).map_err(|e| {
+            AptuError::GitHub {
+                message: e.to_string(),
+            }
+        })?;
+
+    // Create GitHub client from provider
+    let client = create_client_from_provider(provider)?;
+    let (mut issue_details, language, comment_count) =
+        fetch_issue_details(&client, &owner, &repo, number).await?;
+
+    // The prompt only sees the first comments; earlier triage may come later
+    if comment_count > issue_details.comments.len() {
+        match crate::github::issues::fetch_all_comments(&client, &owner, &repo, number).await {
+            Ok(history) => issue_details.comment_history = Some(history),
+            Err(e) => tracing::warn!(error = %e, "Failed to fetch comment history"),
+        }
+    }
+
+    // Extract keywords for the parallel calls
+    let keywords = crate::github::issues::extract_keywords(&issue_details.title);
+
+    // Embed with the configured embedding model, or the lexical fallback
+    let embedder = crate::ai::embeddings::Embedder::from_config(
+        &load_config().unwrap_or_default().ai,
+        provider,
+    );
+
+    // Run embedding lookup, keyword search, tree and issue form fetches in parallel
+    let (similar_result, search_result, tree_result, forms) = tokio::join!(
+        crate::github::issue_index::find_similar_issues(
+            &client,
+            &owner,
+            &repo,
+            &embedder,
+            &issue_details.title,
+            &issue_details.body,
+            number,
+            crate::github::issue_index::DEFAULT_TOP_K,
+        ),
         crate::github::issues::search_related_issues(
             &client,
             &owner,
@@ -267,13 +426,40 @@ pub async fn fetch_issue_for_triage(
             &issue_details.title,
             number
         ),
-        crate::github::issues::fetch_repo_tree(&client, &owner, &repo, &language, &keywords)
+        crate::github::issues::fetch_repo_tree(&client, &owner, &repo, &language, &keywords),
+        crate::github::templates::fetch_issue_forms(&client, &owner, &repo)
     );
 
-    // Handle search results
+    // Check required issue form fields deterministically
+    issue_details.template_check =
+        crate::github::templates::check_issue(&forms, &issue_details.title, &issue_details.body);
+    if let Some(check) = &issue_details.template_check {
+        debug!(template = %check.template, missing = ?check.missing_fields, "Matched issue form");
+    }
+
+    // Handle embedding results (nearest first, with similarity scores)
+    match similar_result {
+        Ok(similar) => {
+            debug!(similar_count = similar.len(), "Found similar issues");
+            issue_details.repo_context = similar;
+        }
+        Err(e) => {
+            debug!(error = %e, "Failed to query issue index, continuing with keyword search");
+        }
+    }
+
+    // Handle search results, skipping issues already found by embedding
     match search_result {
         Ok(related) => {
-            issue_details.repo_context = related;
+            for issue in related {
+                if !issue_details
+                    .repo_context
+                    .iter()
+                    .any(|r| r.number == issue.number)
+                {
+                    issue_details.repo_context.push(issue);
+                }
+            }
             debug!(
                 related_count = issue_details.repo_context.len(),
                 "Found related issues"
@@ -314,6 +500,8 @@ pub async fn fetch_issue_for_triage(
 /// Posts a triage comment to GitHub.
 ///
 /// Renders the triage response as markdown and posts it as a comment on the issue.
+/// When the issue carries a [`crate::ai::types::PriorTriage`], that comment is
+/// edited in place instead.
 ///
 /// # Arguments
 ///
@@ -323,7 +511,7 @@ pub async fn fetch_issue_for_triage(
 ///
 /// # Returns
 ///
-/// The URL of the posted comment.
+/// The URL of the posted or updated comment.
 ///
 /// # Errors
 ///
@@ -340,21 +528,32 @@ pub async fn post_triage_comment(
     // Create GitHub client from provider
     let client = create_client_from_provider(provider)?;
 
-    // Render markdown and post comment
+    // Render markdown, then edit the earlier triage or post a new comment
     let comment_body = crate::triage::render_triage_markdown(triage);
-    let comment_url = crate::github::issues::post_comment(
-        &client,
-        &issue_details.owner,
-        &issue_details.repo,
-        issue_details.number,
-        &comment_body,
-    )
-    .await
+    let comment_url = if let Some(prior) = &issue_details.prior_triage {
+        crate::github::issues::update_comment(
+            &client,
+            &issue_details.owner,
+            &issue_details.repo,
+            prior.comment_id,
+            &comment_body,
+        )
+        .await
+    } else {
+        crate::github::issues::post_comment(
+            &client,
+            &issue_details.owner,
+            &issue_details.repo,
+            issue_details.number,
+            &comment_body,
+        )
+        .await
+    }
     .map_err(|e| AptuError::GitHub {
         message: e.to_string(),
     })?;
 
-    debug!(comment_url = %comment_url, "Triage comment posted");
+    debug!(comment_url = %comment_url, updated = issue_details.prior_triage.is_some(), "Triage comment posted");
     Ok(comment_url)
 }
 
@@ -408,6 +607,7 @@ pub async fn apply_triage_labels(
         issue_details.number,
         &issue_details.labels,
         &triage.suggested_labels,
+        &triage.forced_labels,
         issue_details.milestone.as_deref(),
         triage.suggested_milestone.as_deref(),
         &issue_details.available_labels,
@@ -437,6 +637,58 @@ pub async fn apply_triage_labels(
     crate::facade::wasm_unsupported!("apply_triage_labels");
 }
 
+/// Assigns users to an issue.
+///
+/// # Arguments
+///
+/// * `provider` - Token provider for GitHub credentials
+/// * `issue_details` - Issue details (owner, repo, number)
+/// * `assignees` - Logins to assign
+///
+/// # Returns
+///
+/// The logins GitHub actually assigned; users who cannot be assigned in the
+/// repository are silently dropped by GitHub.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - GitHub token is not available from the provider
+/// - GitHub API call fails
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(provider), fields(owner = %issue_details.owner, repo = %issue_details.repo, number = issue_details.number))]
+pub async fn assign_issue(
+    provider: &dyn TokenProvider,
+    issue_details: &IssueDetails,
+    assignees: &[String],
+) -> crate::Result<Vec<String>> {
+    let client = create_client_from_provider(provider)?;
+
+    let assigned = crate::github::issues::add_assignees(
+        &client,
+        &issue_details.owner,
+        &issue_details.repo,
+        issue_details.number,
+        assignees,
+    )
+    .await
+    .map_err(|e| AptuError::GitHub {
+        message: e.to_string(),
+    })?;
+
+    tracing::info!(assignees = ?assigned, "Assignees applied");
+    Ok(assigned)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn assign_issue(
+    _provider: &dyn crate::auth::TokenProvider,
+    _issue_details: &crate::ai::types::IssueDetails,
+    _assignees: &[String],
+) -> crate::Result<Vec<String>> {
+    crate::facade::wasm_unsupported!("assign_issue");
+}
+
 /// Formats a GitHub issue with AI assistance.
 ///
 /// This function takes raw issue title and body, and uses AI to format them
@@ -588,6 +840,11 @@ mod tests {
             author: Some("test-author".to_string()),
             created_at: Some("2024-01-01T00:00:00Z".to_string()),
             updated_at: Some("2024-01-01T00:00:00Z".to_string()),
+            last_edited_at: None,
+            author_association: None,
+            prior_triage: None,
+            template_check: None,
+            comment_history: None,
         };
 
         let ai_config = AiConfig {
@@ -604,6 +861,7 @@ mod tests {
             fallback: None,
             custom_guidance: None,
             validation_enabled: false,
+            embedding_model: None,
         };
 
         let provider = MockProvider;
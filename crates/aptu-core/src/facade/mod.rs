@@ -24,21 +24,33 @@ macro_rules! wasm_unsupported {
 pub(crate) use wasm_unsupported;
 
 pub mod ai_client;
+pub mod dedupe;
 pub mod issues;
 pub mod models;
+pub mod plan;
 pub mod pr_create;
 pub mod pr_review;
 pub mod repos;
 pub mod revert;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod security;
+pub mod split;
+pub mod stale;
 
+pub use dedupe::{DuplicateCluster, DuplicateIssue, DuplicateProposals};
+#[cfg(not(target_arch = "wasm32"))]
+pub use dedupe::{close_duplicate, find_duplicate_clusters};
 pub use issues::format_issue;
 #[cfg(not(target_arch = "wasm32"))]
 pub use issues::{
-    analyze_issue, apply_triage_labels, fetch_issue_for_triage, post_issue, post_triage_comment,
+    analyze_issue, apply_triage_labels, assign_issue, fetch_issue_for_plan, fetch_issue_for_triage,
+    post_issue, post_triage_comment,
 };
 #[cfg(not(target_arch = "wasm32"))]
 pub use models::{list_models, validate_model};
 #[cfg(not(target_arch = "wasm32"))]
+pub use plan::plan_issue;
+#[cfg(not(target_arch = "wasm32"))]
 pub use pr_create::create_pr;
 #[cfg(not(target_arch = "wasm32"))]
 pub use pr_review::{analyze_pr, fetch_pr_for_review, label_pr, post_pr_review};
@@ -50,3 +62,11 @@ pub use repos::{
 pub use revert::RevertOutcome;
 #[cfg(not(target_arch = "wasm32"))]
 pub use revert::{revert_issue, revert_pr};
+#[cfg(not(target_arch = "wasm32"))]
+pub use security::validate_security_findings;
+pub use split::{CreatedSubIssue, SplitOutcome};
+#[cfg(not(target_arch = "wasm32"))]
+pub use split::{create_sub_issues, plan_issue_split};
+pub use stale::{StaleApplied, StaleCandidate};
+#[cfg(not(target_arch = "wasm32"))]
+pub use stale::{apply_stale_action, find_stale_items};
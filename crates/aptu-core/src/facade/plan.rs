@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation plan facade functions.
+
+use std::path::Path;
+
+#[cfg(not(target_arch = "wasm32"))]
+use tracing::{debug, instrument};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::ai::AiProvider;
+use crate::ai::types::{CodeSymbol, ImplementationPlan, IssueDetails};
+use crate::auth::TokenProvider;
+use crate::config::AiConfig;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::config::TaskType;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::error::AptuError;
+
+/// Turns an issue into an implementation plan grounded in a local checkout.
+///
+/// The symbols the issue most likely concerns are located in the repository
+/// graph at `repo_path` (the same graph PR review uses), and their callers,
+/// callees and source are given to the model, so the plan can name concrete
+/// files and functions. When nothing matches, the plan falls back to the
+/// issue's repository tree. Returns the plan together with the symbols that
+/// were located. `issue` should come from [`crate::fetch_issue_for_plan`].
+///
+/// With `include_diff`, the plan carries a unified diff suitable for
+/// `aptu pr create --diff`; it is checked for paths outside the repository.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The issue body contains prompt injection patterns
+/// - AI provider token is not available from the provider
+/// - AI API call fails, the model returns no steps, or a requested diff is
+///   missing or unsafe
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(provider, issue, ai_config), fields(issue_number = issue.number, repo = %format!("{}/{}", issue.owner, issue.repo), repo_path = %repo_path.display()))]
+pub async fn plan_issue(
+    provider: &dyn TokenProvider,
+    issue: &IssueDetails,
+    repo_path: &Path,
+    ai_config: &AiConfig,
+    include_diff: bool,
+) -> crate::Result<(ImplementationPlan, Vec<CodeSymbol>, crate::history::AiStats)> {
+    use crate::security::SecurityScanner;
+
+    if SecurityScanner::new()
+        .scan_file(&issue.body, "issue.md")
+        .iter()
+        .any(|f| f.pattern_id.starts_with("prompt-injection"))
+    {
+        return Err(AptuError::SecurityScan {
+            message: "Prompt injection patterns detected in issue body".to_string(),
+        });
+    }
+
+    let context = crate::ai::plan_context::build_plan_context(issue, repo_path).await;
+    debug!(symbols = context.symbols.len(), "Plan context built");
+
+    let size = issue.body.len() + context.graph_context.len() + context.sources.len();
+    let (provider_name, model_name) = ai_config.resolve_for_task(TaskType::Review, Some(size));
+
+    let (plan, ai_stats) = super::ai_client::try_with_fallback(
+        provider,
+        &provider_name,
+        &model_name,
+        ai_config,
+        |client| {
+            let issue = issue.clone();
+            let context = context.clone();
+            async move { client.plan_issue(&issue, &context, include_diff).await }
+        },
+    )
+    .await?;
+
+    Ok((plan, context.symbols, ai_stats))
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn plan_issue(
+    _provider: &dyn TokenProvider,
+    _issue: &IssueDetails,
+    _repo_path: &Path,
+    _ai_config: &AiConfig,
+    _include_diff: bool,
+) -> crate::Result<(ImplementationPlan, Vec<CodeSymbol>, crate::history::AiStats)> {
+    crate::facade::wasm_unsupported!("plan_issue");
+}
@@ -2,7 +2,7 @@
 
 //! PR review and labeling facade functions.
 
-use tracing::{debug, error, instrument};
+use tracing::{debug, error, info, instrument};
 
 use crate::ai::provider::AiProvider;
 use crate::ai::types::{PrDetails, PrReviewComment, ReviewEvent};
@@ -138,7 +138,9 @@ fn reconstruct_diff_from_pr(files: &[crate::ai::types::PrFile]) -> String {
 ///
 /// # Returns
 ///
-/// Tuple of (review response, AI stats).
+/// Tuple of (review response, AI stats, context record). When every file is
+/// non-behavioural (renames, moves, formatting, comments), the AI call is skipped and
+/// a placeholder review is returned with `ai_skipped` set on the record.
 ///
 /// # Errors
 ///
@@ -203,6 +205,18 @@ pub async fn analyze_pr(
         }
     }
 
+    // Generate trace ID for this review operation
+    let trace_id = uuid::Uuid::new_v4().simple().to_string();
+
+    // Build ReviewContextRecord from context; AI response metadata is filled in below
+    let mut context_record =
+        review_context_record(pr_details, &ctx, review_config.max_prompt_chars, &trace_id);
+
+    // The cheapest AI call is the one you skip: nothing here can change behaviour
+    if let Some((review, ai_stats)) = skip_non_behavioural(&ctx, &trace_id, &mut context_record) {
+        return Ok((review, ai_stats, context_record));
+    }
+
     // Resolve task-specific provider and model
     let (provider_name, model_name) =
         ai_config.resolve_for_task(TaskType::Review, Some(ctx.estimated_size));
@@ -227,9 +241,6 @@ pub async fn analyze_pr(
         return Err(AptuError::SecurityScan { message });
     }
 
-    // Generate trace ID for this review operation
-    let trace_id = uuid::Uuid::new_v4().simple().to_string();
-
     // Use fallback chain if configured
     let (response, mut ai_stats, finish_reasons) = super::ai_client::try_with_fallback(
         provider,
@@ -245,17 +256,33 @@ pub async fn analyze_pr(
     .await?;
 
     // Set trace_id on ai_stats
-    ai_stats.trace_id = Some(trace_id.clone());
+    ai_stats.trace_id = Some(trace_id);
 
-    // Build ReviewContextRecord from context and response metadata
-    let context_record = crate::metrics::ReviewContextRecord {
-        trace_id,
+    // Fill in response metadata
+    context_record.model.clone_from(&ai_stats.model);
+    context_record.prompt_chars_final = ai_stats.prompt_chars;
+    context_record.finish_reasons = finish_reasons;
+
+    Ok((response, ai_stats, context_record))
+}
+
+/// Builds the context metrics record for a review; the model, prompt size and
+/// finish reasons are filled in after the AI call.
+#[cfg(not(target_arch = "wasm32"))]
+fn review_context_record(
+    pr_details: &PrDetails,
+    ctx: &crate::ai::review_context::ReviewContext,
+    max_prompt_chars: usize,
+    trace_id: &str,
+) -> crate::metrics::ReviewContextRecord {
+    crate::metrics::ReviewContextRecord {
+        trace_id: trace_id.to_string(),
         operation: "pr_review".to_string(),
         pr: format!(
             "{}/{}#{}",
             pr_details.owner, pr_details.repo, pr_details.number
         ),
-        model: ai_stats.model.clone(),
+        model: String::new(),
         github_actor: std::env::var("GITHUB_ACTOR").ok(),
         files_total: ctx.files_total,
         files_with_patch: ctx.files_with_patch,
@@ -267,14 +294,66 @@ pub async fn analyze_pr(
         graph_cache_hit: ctx.graph_cache_hit,
         dep_enrichments_count: ctx.dep_enrichments_count,
         dep_enrichments_chars: ctx.dep_enrichments_chars,
-        budget_drops: ctx.budget_drops,
+        budget_drops: ctx.budget_drops.clone(),
         cwd_inferred: ctx.cwd_inferred,
-        prompt_chars_final: ai_stats.prompt_chars,
-        finish_reasons,
-        max_prompt_chars: review_config.max_prompt_chars,
+        prompt_chars_final: 0,
+        finish_reasons: Vec::new(),
+        max_prompt_chars,
+        non_behavioural_files: ctx
+            .non_behavioural_files
+            .iter()
+            .map(|(file, _)| file.clone())
+            .collect(),
+        ai_skipped: false,
+    }
+}
+
+/// Returns the placeholder review and empty stats when every file is
+/// non-behavioural, marking the record as skipped; `None` when the AI review
+/// must run.
+#[cfg(not(target_arch = "wasm32"))]
+fn skip_non_behavioural(
+    ctx: &crate::ai::review_context::ReviewContext,
+    trace_id: &str,
+    context_record: &mut crate::metrics::ReviewContextRecord,
+) -> Option<(crate::ai::types::PrReviewResponse, crate::history::AiStats)> {
+    if !ctx.all_non_behavioural() {
+        return None;
+    }
+    info!(
+        files = ctx.files_total,
+        "All changes are non-behavioural; skipping AI review"
+    );
+    context_record.ai_skipped = true;
+    let ai_stats = crate::history::AiStats {
+        trace_id: Some(trace_id.to_string()),
+        ..Default::default()
     };
+    Some((non_behavioural_review(ctx), ai_stats))
+}
 
-    Ok((response, ai_stats, context_record))
+/// Builds the review returned in place of an AI call when every file is non-behavioural.
+#[cfg(not(target_arch = "wasm32"))]
+fn non_behavioural_review(
+    ctx: &crate::ai::review_context::ReviewContext,
+) -> crate::ai::types::PrReviewResponse {
+    let files: Vec<String> = ctx
+        .non_behavioural_files
+        .iter()
+        .map(|(file, class)| format!("`{file}` ({class})"))
+        .collect();
+    crate::ai::types::PrReviewResponse {
+        summary: format!(
+            "No behavioural changes detected, so AI review was skipped. Files: {}.",
+            files.join(", ")
+        ),
+        verdict: "comment".to_string(),
+        strengths: Vec::new(),
+        concerns: Vec::new(),
+        comments: Vec::new(),
+        suggestions: Vec::new(),
+        disclaimer: None,
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -638,6 +717,7 @@ mod tests {
             head_branch: "feature".to_string(),
             files: vec![PrFile {
                 filename: "test.rs".to_string(),
+                previous_filename: None,
                 status: "modified".to_string(),
                 additions: 5,
                 deletions: 0,
@@ -651,6 +731,7 @@ mod tests {
             url: "https://github.com/test-owner/test-repo/pull/1".to_string(),
             labels: vec![],
             head_sha: "abc123".to_string(),
+            base_sha: "def456".to_string(),
             review_comments: vec![],
             instructions: None,
             dep_enrichments: vec![],
@@ -670,6 +751,7 @@ mod tests {
             fallback: None,
             custom_guidance: None,
             validation_enabled: false,
+            embedding_model: None,
         };
 
         let provider = MockProvider;
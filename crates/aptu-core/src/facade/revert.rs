@@ -4,7 +4,7 @@
 
 #[cfg(not(target_arch = "wasm32"))]
 use octocrab::Octocrab;
-use tracing::{debug, instrument};
+use tracing::{debug, instrument, warn};
 
 use crate::error::AptuError;
 #[cfg(not(target_arch = "wasm32"))]
@@ -19,12 +19,18 @@ pub struct RevertOutcome {
     pub labels_removed: Vec<String>,
     /// IDs of comments that were removed.
     pub comment_ids: Vec<u64>,
+    /// Whether the issue was reopened because aptu had closed it as a duplicate.
+    pub reopened: bool,
+    /// Child issues closed because aptu had created them by splitting this issue.
+    pub closed_sub_issues: Vec<u64>,
 }
 
 /// Reverts all comments and labels posted by the authenticated aptu user on an issue.
 ///
 /// Fetches the issue with comments, identifies comments authored by the authenticated user,
-/// and deletes them along with any labels (if not in dry-run mode).
+/// and deletes them along with any labels (if not in dry-run mode). Issues that
+/// `aptu issue dedupe` closed as duplicates are reopened, and child issues that
+/// `aptu issue split` created are closed as not planned.
 ///
 /// # Arguments
 ///
@@ -50,9 +56,11 @@ pub async fn revert_issue(
     number: u64,
     dry_run: bool,
 ) -> crate::Result<RevertOutcome> {
+    use crate::github::dedupe::{load_dedupe_log, take_dedupe_record};
     use crate::github::issues::{
-        delete_issue_comment, fetch_issue_with_comments, remove_issue_label,
+        delete_issue_comment, fetch_issue_with_comments, remove_issue_label, set_issue_state,
     };
+    use crate::github::split::load_split_log;
 
     debug!("Reverting issue comments and labels");
 
@@ -92,10 +100,19 @@ pub async fn revert_issue(
             label_count = labels_to_remove.len(),
             "Dry-run mode: no deletions will be performed"
         );
+        let reopened = load_dedupe_log(owner, repo)
+            .get(&number)
+            .is_some_and(|record| record.closed);
+        let closed_sub_issues = load_split_log(owner, repo)
+            .remove(&number)
+            .map(|record| record.children)
+            .unwrap_or_default();
         return Ok(RevertOutcome {
             dry_run: true,
             labels_removed: labels_to_remove,
             comment_ids: comment_ids_to_delete,
+            reopened,
+            closed_sub_issues,
         });
     }
 
@@ -119,13 +136,74 @@ pub async fn revert_issue(
     }
     debug!(count = labels_to_remove.len(), "Labels removed");
 
+    // Reopen the issue if aptu closed it as a duplicate. Records are dropped
+    // only after GitHub accepts the change, so a failed revert can be retried.
+    let reopened = load_dedupe_log(owner, repo)
+        .get(&number)
+        .is_some_and(|record| record.closed);
+    if reopened {
+        set_issue_state(client, owner, repo, number, "open", Some("reopened"))
+            .await
+            .map_err(|e| AptuError::GitHub {
+                message: format!("Failed to reopen issue: {e}"),
+            })?;
+        debug!("Issue reopened");
+    }
+    take_dedupe_record(owner, repo, number).map_err(|e| AptuError::Config {
+        message: e.to_string(),
+    })?;
+
+    // Close the child issues if aptu split this issue
+    let closed_sub_issues = close_split_children(client, owner, repo, number).await?;
+    debug!(count = closed_sub_issues.len(), "Sub-issues closed");
+
     Ok(RevertOutcome {
         dry_run: false,
         labels_removed: labels_to_remove,
         comment_ids: comment_ids_to_delete,
+        reopened,
+        closed_sub_issues,
     })
 }
 
+/// Closes the child issues aptu created by splitting `number` as not planned.
+///
+/// The split record is dropped only once every child is closed; if one fails,
+/// the children still open stay recorded so the next revert closes them.
+#[cfg(not(target_arch = "wasm32"))]
+async fn close_split_children(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    number: u64,
+) -> crate::Result<Vec<u64>> {
+    use crate::github::issues::set_issue_state;
+    use crate::github::split::{load_split_log, retain_split_children, take_split_record};
+
+    let children = load_split_log(owner, repo)
+        .remove(&number)
+        .map(|record| record.children)
+        .unwrap_or_default();
+    let mut closed = Vec::with_capacity(children.len());
+    for (index, child) in children.iter().enumerate() {
+        if let Err(e) =
+            set_issue_state(client, owner, repo, *child, "closed", Some("not_planned")).await
+        {
+            if let Err(log_error) = retain_split_children(owner, repo, number, &children[index..]) {
+                warn!(error = %log_error, "Failed to update split log");
+            }
+            return Err(AptuError::GitHub {
+                message: format!("Failed to close sub-issue #{child}: {e}"),
+            });
+        }
+        closed.push(*child);
+    }
+    take_split_record(owner, repo, number).map_err(|e| AptuError::Config {
+        message: e.to_string(),
+    })?;
+    Ok(closed)
+}
+
 #[cfg(target_arch = "wasm32")]
 pub async fn revert_issue(
     _owner: &str,
@@ -221,6 +299,8 @@ pub async fn revert_pr(
             dry_run: true,
             labels_removed: labels_to_remove,
             comment_ids: comment_ids_to_delete,
+            reopened: false,
+            closed_sub_issues: Vec::new(),
         });
     }
 
@@ -251,6 +331,8 @@ pub async fn revert_pr(
         dry_run: false,
         labels_removed: labels_to_remove,
         comment_ids: comment_ids_to_delete,
+        reopened: false,
+        closed_sub_issues: Vec::new(),
     })
 }
 
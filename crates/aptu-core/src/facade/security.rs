@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! LLM validation of security scan findings.
+
+use std::collections::HashMap;
+
+use tracing::{debug, instrument, warn};
+
+use crate::auth::TokenProvider;
+use crate::config::{AiConfig, TaskType};
+use crate::security::{Finding, FindingCache, SecurityValidator, ValidatedFinding};
+
+/// Number of findings sent to the model per validation request.
+const VALIDATION_BATCH_SIZE: usize = 5;
+
+/// Validates security findings with the configured AI model.
+///
+/// Verdicts are looked up in `cache` first (keyed by pattern, normalized
+/// snippet, and model); remaining findings are sent to the model in batches
+/// of five through the configured fallback chain, and the resulting verdicts
+/// are cached. Findings the model cannot judge fall back to pattern
+/// confidence and are not cached.
+///
+/// # Arguments
+///
+/// * `provider` - Token provider for AI provider credentials
+/// * `findings` - Findings to validate
+/// * `file_contents` - File contents keyed by finding path, used for context snippets
+/// * `ai_config` - AI configuration (the `review` task model is used)
+/// * `cache` - Optional verdict cache
+///
+/// # Returns
+///
+/// One validated finding per input finding, in the same order.
+///
+/// # Errors
+///
+/// Returns an error if no AI provider credentials are available or every
+/// provider in the fallback chain fails.
+#[allow(clippy::implicit_hasher)]
+#[instrument(skip_all, fields(count = findings.len()))]
+pub async fn validate_security_findings(
+    provider: &dyn TokenProvider,
+    findings: &[Finding],
+    file_contents: &HashMap<String, String>,
+    ai_config: &AiConfig,
+    cache: Option<&FindingCache>,
+) -> crate::Result<Vec<ValidatedFinding>> {
+    let (provider_name, model_name) = ai_config.resolve_for_task(TaskType::Review, None);
+
+    let mut verdicts: Vec<Option<ValidatedFinding>> = Vec::with_capacity(findings.len());
+    for finding in findings {
+        let cached = match cache {
+            Some(cache) => cache
+                .get_verdict(finding, &model_name)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!(error = %e, "Failed to read validation cache");
+                    None
+                }),
+            None => None,
+        };
+        verdicts.push(cached);
+    }
+
+    let misses: Vec<usize> = (0..findings.len())
+        .filter(|&i| verdicts[i].is_none())
+        .collect();
+    debug!(
+        cached = findings.len() - misses.len(),
+        uncached = misses.len(),
+        "Validation cache lookup complete"
+    );
+
+    for chunk in misses.chunks(VALIDATION_BATCH_SIZE) {
+        let batch: Vec<Finding> = chunk.iter().map(|&i| findings[i].clone()).collect();
+        let validated = super::ai_client::try_with_fallback(
+            provider,
+            &provider_name,
+            &model_name,
+            ai_config,
+            |client| {
+                let batch = batch.clone();
+                async move {
+                    SecurityValidator::new(client)
+                        .validate_findings_batch(&batch, file_contents)
+                        .await
+                }
+            },
+        )
+        .await?;
+
+        for (&i, verdict) in chunk.iter().zip(validated) {
+            if let Some(cache) = cache
+                && let Err(e) = cache.set_verdict(&verdict).await
+            {
+                warn!(error = %e, "Failed to write validation cache");
+            }
+            verdicts[i] = Some(verdict);
+        }
+    }
+
+    Ok(verdicts.into_iter().flatten().collect())
+}
@@ -0,0 +1,251 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Sub-issue decomposition facade functions.
+
+use serde::Serialize;
+use tracing::{debug, instrument, warn};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::ai::AiProvider;
+use crate::ai::types::{IssueDetails, IssueSplitResponse};
+use crate::auth::TokenProvider;
+use crate::config::AiConfig;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::config::TaskType;
+use crate::error::AptuError;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::github::auth::create_client_from_provider;
+
+/// A child issue created by splitting an issue.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatedSubIssue {
+    /// Issue number.
+    pub number: u64,
+    /// Issue title.
+    pub title: String,
+    /// Issue URL.
+    pub url: String,
+    /// Labels applied to the issue.
+    pub labels: Vec<String>,
+}
+
+/// Result of creating the child issues of a split.
+#[derive(Debug, Clone, Serialize)]
+pub struct SplitOutcome {
+    /// Child issues created, in task order.
+    pub created: Vec<CreatedSubIssue>,
+    /// Whether the children were linked with GitHub's sub-issues API.
+    pub linked: bool,
+    /// URL of the task list comment posted on the parent when linking failed.
+    pub tasklist_url: Option<String>,
+    /// Non-fatal problems (labels or links that could not be applied).
+    pub warnings: Vec<String>,
+}
+
+/// Asks the model for a sub-issue breakdown of an issue.
+///
+/// `issue` should come from `fetch_issue_for_triage`, so the breakdown can
+/// use the repository's labels and file tree.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The issue body contains prompt injection patterns
+/// - AI provider token is not available from the provider
+/// - AI API call fails or the model returns no tasks
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(provider, issue, ai_config), fields(issue_number = issue.number, repo = %format!("{}/{}", issue.owner, issue.repo)))]
+pub async fn plan_issue_split(
+    provider: &dyn TokenProvider,
+    issue: &IssueDetails,
+    ai_config: &AiConfig,
+) -> crate::Result<(IssueSplitResponse, crate::history::AiStats)> {
+    use crate::security::SecurityScanner;
+
+    if SecurityScanner::new()
+        .scan_file(&issue.body, "issue.md")
+        .iter()
+        .any(|f| f.pattern_id.starts_with("prompt-injection"))
+    {
+        return Err(AptuError::SecurityScan {
+            message: "Prompt injection patterns detected in issue body".to_string(),
+        });
+    }
+
+    let (provider_name, model_name) =
+        ai_config.resolve_for_task(TaskType::Triage, Some(issue.body.len()));
+
+    super::ai_client::try_with_fallback(
+        provider,
+        &provider_name,
+        &model_name,
+        ai_config,
+        |client| {
+            let issue = issue.clone();
+            async move { client.split_issue(&issue).await }
+        },
+    )
+    .await
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn plan_issue_split(
+    _provider: &dyn TokenProvider,
+    _issue: &IssueDetails,
+    _ai_config: &AiConfig,
+) -> crate::Result<(IssueSplitResponse, crate::history::AiStats)> {
+    crate::facade::wasm_unsupported!("plan_issue_split");
+}
+
+/// Creates the child issues of a split and links them to the parent.
+///
+/// Each child is created in task order with a "Part of #N" line and its
+/// dependencies, then labeled and added as a sub-issue of the parent. If the
+/// sub-issues API rejects a link, a task list comment on the parent links
+/// the children instead. Created issues are recorded so `revert_issue` on the
+/// parent can close them.
+///
+/// # Errors
+///
+/// Returns an error if a task depends on a task that does not come before
+/// it, the GitHub token is unavailable, an issue cannot be created, or the
+/// split log cannot be written. Issues created before a failure are still
+/// recorded.
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(provider, plan), fields(owner = %owner, repo = %repo, parent, tasks = plan.tasks.len()))]
+pub async fn create_sub_issues(
+    provider: &dyn TokenProvider,
+    owner: &str,
+    repo: &str,
+    parent: u64,
+    plan: &IssueSplitResponse,
+) -> crate::Result<SplitOutcome> {
+    use crate::github::issues::{apply_labels_to_number, create_issue, post_comment};
+    use crate::github::split::{
+        SplitRecord, add_sub_issue, record_split, render_sub_issue_body, render_tasklist_comment,
+    };
+
+    check_dependency_order(plan)?;
+    let github_error = |e: anyhow::Error| AptuError::GitHub {
+        message: e.to_string(),
+    };
+    let client = create_client_from_provider(provider)?;
+
+    let mut created: Vec<CreatedSubIssue> = Vec::new();
+    let mut warnings = Vec::new();
+    let mut failure = None;
+    for task in &plan.tasks {
+        // Dependencies are 1-based positions of earlier, already created tasks
+        let dependencies: Vec<u64> = task
+            .depends_on
+            .iter()
+            .map(|&dep| created[dep - 1].number)
+            .collect();
+        let body = render_sub_issue_body(task, parent, &dependencies);
+        let (url, number) = match create_issue(&client, owner, repo, &task.title, &body).await {
+            Ok(issue) => issue,
+            Err(e) => {
+                failure = Some(github_error(e));
+                break;
+            }
+        };
+
+        let labels = match apply_labels_to_number(&client, owner, repo, number, &task.labels).await
+        {
+            Ok(labels) => labels,
+            Err(e) => {
+                warn!(number, error = %e, "Failed to label sub-issue");
+                warnings.push(format!("Could not label #{number}: {e}"));
+                Vec::new()
+            }
+        };
+        created.push(CreatedSubIssue {
+            number,
+            title: task.title.clone(),
+            url,
+            labels,
+        });
+    }
+
+    let mut linked = !created.is_empty();
+    for child in &created {
+        if let Err(e) = add_sub_issue(&client, owner, repo, parent, child.number).await {
+            warn!(child = child.number, error = %e, "Sub-issues API unavailable");
+            linked = false;
+            break;
+        }
+    }
+
+    let mut tasklist_url = None;
+    if !linked && !created.is_empty() {
+        let children: Vec<(u64, String)> = created
+            .iter()
+            .map(|c| (c.number, c.title.clone()))
+            .collect();
+        match post_comment(
+            &client,
+            owner,
+            repo,
+            parent,
+            &render_tasklist_comment(&children),
+        )
+        .await
+        {
+            Ok(url) => tasklist_url = Some(url),
+            Err(e) => warnings.push(format!("Could not link sub-issues to #{parent}: {e}")),
+        }
+    }
+
+    if !created.is_empty() {
+        let record = SplitRecord {
+            children: created.iter().map(|c| c.number).collect(),
+            linked,
+            comment_url: tasklist_url.clone(),
+            timestamp: chrono::Utc::now(),
+        };
+        record_split(owner, repo, parent, record).map_err(|e| AptuError::Config {
+            message: e.to_string(),
+        })?;
+    }
+
+    if let Some(e) = failure {
+        return Err(e);
+    }
+
+    debug!(count = created.len(), linked, "Sub-issues created");
+    Ok(SplitOutcome {
+        created,
+        linked,
+        tasklist_url,
+        warnings,
+    })
+}
+
+/// Checks that every task depends only on tasks before it, since children are
+/// created in order.
+#[cfg(not(target_arch = "wasm32"))]
+fn check_dependency_order(plan: &IssueSplitResponse) -> crate::Result<()> {
+    let misplaced = plan.tasks.iter().enumerate().find_map(|(index, task)| {
+        task.depends_on
+            .iter()
+            .any(|&dep| dep == 0 || dep > index)
+            .then_some(index + 1)
+    });
+    match misplaced {
+        Some(position) => Err(AptuError::Config {
+            message: format!("Sub-issue {position} depends on a task that does not come before it"),
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn create_sub_issues(
+    _provider: &dyn TokenProvider,
+    _owner: &str,
+    _repo: &str,
+    _parent: u64,
+    _plan: &IssueSplitResponse,
+) -> crate::Result<SplitOutcome> {
+    crate::facade::wasm_unsupported!("create_sub_issues");
+}
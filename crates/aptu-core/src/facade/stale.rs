@@ -0,0 +1,276 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Stale issue and PR sweeper facade functions.
+
+use serde::Serialize;
+use tracing::{debug, instrument, warn};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::ai::AiProvider;
+use crate::auth::TokenProvider;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::config::TaskType;
+use crate::config::{AiConfig, StaleConfig};
+use crate::error::AptuError;
+use crate::github::stale::{StaleAction, StaleItem, StaleKind};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::github::{auth::create_client_from_provider, stale::STALE_MARKER};
+
+/// An inactive item and what the sweep proposes to do with it.
+#[derive(Debug, Clone, Serialize)]
+pub struct StaleCandidate {
+    /// The issue or pull request.
+    pub item: StaleItem,
+    /// Proposed action.
+    pub action: StaleAction,
+}
+
+/// What applying a stale action did.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StaleApplied {
+    /// URL of the posted comment, if any.
+    pub comment_url: Option<String>,
+    /// AI usage, if the nudge was written by the model.
+    pub ai_stats: Option<crate::history::AiStats>,
+}
+
+/// Finds open issues or pull requests that are due a stale action.
+///
+/// Items are returned oldest update first, each with the action
+/// [`crate::github::stale::plan_stale_action`] proposes for it.
+///
+/// # Errors
+///
+/// Returns an error if the GitHub token is unavailable or a search fails.
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(provider, config), fields(owner = %owner, repo = %repo, kind = ?kind))]
+pub async fn find_stale_items(
+    provider: &dyn TokenProvider,
+    owner: &str,
+    repo: &str,
+    kind: StaleKind,
+    config: &StaleConfig,
+) -> crate::Result<Vec<StaleCandidate>> {
+    use crate::github::stale::{fetch_stale_candidates, plan_stale_action};
+
+    let client = create_client_from_provider(provider)?;
+    let now = chrono::Utc::now();
+    let items = fetch_stale_candidates(&client, owner, repo, kind, config, now)
+        .await
+        .map_err(|e| AptuError::GitHub {
+            message: e.to_string(),
+        })?;
+
+    let mut candidates: Vec<StaleCandidate> = items
+        .into_iter()
+        .filter_map(|item| {
+            plan_stale_action(&item, config, now).map(|action| StaleCandidate { item, action })
+        })
+        .collect();
+    candidates.sort_by_key(|c| c.item.updated_at);
+
+    debug!(count = candidates.len(), "Found stale candidates");
+    Ok(candidates)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn find_stale_items(
+    _provider: &dyn TokenProvider,
+    _owner: &str,
+    _repo: &str,
+    _kind: StaleKind,
+    _config: &StaleConfig,
+) -> crate::Result<Vec<StaleCandidate>> {
+    crate::facade::wasm_unsupported!("find_stale_items");
+}
+
+/// Applies a stale action to an item.
+///
+/// - Nudge: asks the model for a follow-up that references the item's
+///   context (falling back to a generic message if the body trips the
+///   prompt-injection scanner), posts it, and applies the stale label.
+/// - Close: posts a closing note and closes the item (issues as not planned).
+/// - Unmark: removes the stale label.
+///
+/// # Errors
+///
+/// Returns an error if the GitHub token is unavailable, a GitHub API call
+/// fails, or every AI provider fails.
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(provider, candidate, stale_config, ai_config), fields(owner = %owner, repo = %repo, number = candidate.item.number))]
+pub async fn apply_stale_action(
+    provider: &dyn TokenProvider,
+    owner: &str,
+    repo: &str,
+    kind: StaleKind,
+    candidate: &StaleCandidate,
+    stale_config: &StaleConfig,
+    ai_config: &AiConfig,
+) -> crate::Result<StaleApplied> {
+    use crate::github::issues::{
+        apply_labels_to_number, post_comment, remove_issue_label, set_issue_state,
+    };
+
+    let github_error = |e: anyhow::Error| AptuError::GitHub {
+        message: e.to_string(),
+    };
+    let client = create_client_from_provider(provider)?;
+    let number = candidate.item.number;
+
+    match candidate.action {
+        StaleAction::Nudge { inactive_days } => {
+            let (text, ai_stats) = write_nudge(
+                provider,
+                kind,
+                &candidate.item,
+                inactive_days,
+                stale_config,
+                ai_config,
+            )
+            .await?;
+            let body = format!(
+                "{}\n\n{STALE_MARKER}\n\n---\n*{}*\n",
+                text.trim(),
+                crate::triage::APTU_SIGNATURE
+            );
+            let comment_url = post_comment(&client, owner, repo, number, &body)
+                .await
+                .map_err(github_error)?;
+            apply_labels_to_number(
+                &client,
+                owner,
+                repo,
+                number,
+                std::slice::from_ref(&stale_config.stale_label),
+            )
+            .await
+            .map_err(github_error)?;
+            debug!("Stale nudge posted");
+            Ok(StaleApplied {
+                comment_url: Some(comment_url),
+                ai_stats,
+            })
+        }
+        StaleAction::Close { stale_days } => {
+            let body = format!(
+                "Closing this {} after {stale_days} days without activity since it was marked stale. \
+                 If it is still relevant, comment or reopen it.\n\n---\n*{}*\n",
+                kind.noun(),
+                crate::triage::APTU_SIGNATURE
+            );
+            let comment_url = post_comment(&client, owner, repo, number, &body)
+                .await
+                .map_err(github_error)?;
+            let state_reason = match kind {
+                StaleKind::Issue => Some("not_planned"),
+                StaleKind::PullRequest => None,
+            };
+            set_issue_state(&client, owner, repo, number, "closed", state_reason)
+                .await
+                .map_err(github_error)?;
+            debug!("Stale item closed");
+            Ok(StaleApplied {
+                comment_url: Some(comment_url),
+                ai_stats: None,
+            })
+        }
+        StaleAction::Unmark => {
+            remove_issue_label(&client, owner, repo, number, &stale_config.stale_label)
+                .await
+                .map_err(github_error)?;
+            debug!("Stale label removed");
+            Ok(StaleApplied::default())
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn apply_stale_action(
+    _provider: &dyn TokenProvider,
+    _owner: &str,
+    _repo: &str,
+    _kind: StaleKind,
+    _candidate: &StaleCandidate,
+    _stale_config: &StaleConfig,
+    _ai_config: &AiConfig,
+) -> crate::Result<StaleApplied> {
+    crate::facade::wasm_unsupported!("apply_stale_action");
+}
+
+/// Writes the nudge text for an item, with the model unless its body trips
+/// the prompt-injection scanner.
+#[cfg(not(target_arch = "wasm32"))]
+async fn write_nudge(
+    provider: &dyn TokenProvider,
+    kind: StaleKind,
+    item: &StaleItem,
+    inactive_days: i64,
+    stale_config: &StaleConfig,
+    ai_config: &AiConfig,
+) -> crate::Result<(String, Option<crate::history::AiStats>)> {
+    use crate::ai::types::{IssueComment, StaleNudgeInput};
+    use crate::github::stale::open_clarifying_questions;
+    use crate::security::SecurityScanner;
+
+    let close_in_days =
+        (stale_config.days_until_close > 0).then_some(stale_config.days_until_close);
+
+    if SecurityScanner::new()
+        .scan_file(&item.body, "issue.md")
+        .iter()
+        .any(|f| f.pattern_id.starts_with("prompt-injection"))
+    {
+        warn!(
+            number = item.number,
+            "Prompt injection patterns detected, using generic stale nudge"
+        );
+        let closing = close_in_days.map_or_else(String::new, |days| {
+            format!(
+                " It will be closed in {days} days without further activity; any comment keeps it open."
+            )
+        });
+        let text = format!(
+            "This {} has had no activity for {inactive_days} days. Is it still relevant?{closing}",
+            kind.noun()
+        );
+        return Ok((text, None));
+    }
+
+    let input = StaleNudgeInput {
+        kind: kind.noun().to_string(),
+        number: item.number,
+        title: item.title.clone(),
+        body: item.body.clone(),
+        author: item.author.clone(),
+        labels: item.labels.clone(),
+        inactive_days,
+        close_in_days,
+        open_questions: open_clarifying_questions(item),
+        recent_comments: item
+            .comments
+            .iter()
+            .map(|c| IssueComment {
+                id: c.id,
+                author: c.author.clone(),
+                body: c.body.clone(),
+                created_at: Some(c.created_at.to_rfc3339()),
+                updated_at: None,
+            })
+            .collect(),
+    };
+
+    let (provider_name, model_name) = ai_config.resolve_for_task(TaskType::Triage, None);
+    let (response, stats) = super::ai_client::try_with_fallback(
+        provider,
+        &provider_name,
+        &model_name,
+        ai_config,
+        |client| {
+            let input = input.clone();
+            async move { client.write_stale_nudge(&input).await }
+        },
+    )
+    .await?;
+
+    Ok((response.comment, Some(stats)))
+}
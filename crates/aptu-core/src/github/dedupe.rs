@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Closing issues as duplicates, and the record that lets it be undone.
+//!
+//! Every issue closed by `aptu issue dedupe` gets a [`DedupeRecord`] in
+//! `~/.local/share/aptu/dedupe/<owner>/<repo>.json`. `revert_issue` consults
+//! the log to reopen issues that aptu closed, then drops their record.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Label applied to issues closed as duplicates.
+pub const DUPLICATE_LABEL: &str = "duplicate";
+
+/// What aptu did to an issue when closing it as a duplicate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DedupeRecord {
+    /// The canonical issue this one duplicates.
+    pub canonical: u64,
+    /// URL of the "duplicate of" comment.
+    pub comment_url: String,
+    /// Whether the `duplicate` label was added.
+    pub labeled: bool,
+    /// Whether the issue was closed.
+    pub closed: bool,
+    /// When the issue was closed.
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Returns the path of a repository's dedupe log
+/// (`~/.local/share/aptu/dedupe/<owner>/<repo>.json`).
+#[must_use]
+pub fn dedupe_log_path(owner: &str, repo: &str) -> PathBuf {
+    crate::config::data_dir()
+        .join("dedupe")
+        .join(owner)
+        .join(format!("{repo}.json"))
+}
+
+/// Loads a repository's dedupe log keyed by issue number, or an empty log if
+/// there is none or it cannot be read.
+#[must_use]
+pub fn load_dedupe_log(owner: &str, repo: &str) -> BTreeMap<u64, DedupeRecord> {
+    std::fs::read_to_string(dedupe_log_path(owner, repo))
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Saves a repository's dedupe log, creating parent directories as needed.
+///
+/// # Errors
+///
+/// Returns an error if the log cannot be serialized or written.
+pub fn save_dedupe_log(owner: &str, repo: &str, log: &BTreeMap<u64, DedupeRecord>) -> Result<()> {
+    let path = dedupe_log_path(owner, repo);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+    let contents = serde_json::to_string_pretty(log).context("Failed to serialize dedupe log")?;
+    std::fs::write(&path, contents)
+        .with_context(|| format!("Failed to write dedupe log: {}", path.display()))
+}
+
+/// Records that aptu closed `number` as a duplicate.
+///
+/// # Errors
+///
+/// Returns an error if the log cannot be written.
+pub fn record_dedupe(owner: &str, repo: &str, number: u64, record: DedupeRecord) -> Result<()> {
+    let mut log = load_dedupe_log(owner, repo);
+    log.insert(number, record);
+    save_dedupe_log(owner, repo, &log)
+}
+
+/// Removes and returns the dedupe record for `number`, if any.
+///
+/// # Errors
+///
+/// Returns an error if the log cannot be written.
+pub fn take_dedupe_record(owner: &str, repo: &str, number: u64) -> Result<Option<DedupeRecord>> {
+    let mut log = load_dedupe_log(owner, repo);
+    let record = log.remove(&number);
+    if record.is_some() {
+        save_dedupe_log(owner, repo, &log)?;
+    }
+    Ok(record)
+}
+
+/// Renders the comment posted on an issue closed as a duplicate.
+#[must_use]
+pub fn render_duplicate_comment(canonical: u64, rationale: &str) -> String {
+    let mut comment = format!("Duplicate of #{canonical}\n\n");
+    if !rationale.trim().is_empty() {
+        comment.push_str(rationale.trim());
+        comment.push_str("\n\n");
+    }
+    comment.push_str(
+        "Closing in favor of the canonical issue; please follow and comment there. \
+         If this is not a duplicate, say so and it will be reopened.\n\n",
+    );
+    comment.push_str("---\n*");
+    comment.push_str(crate::triage::APTU_SIGNATURE);
+    comment.push_str("*\n");
+    comment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_duplicate_comment_names_canonical_and_signs() {
+        let comment = render_duplicate_comment(12, "Both report the same panic.");
+
+        assert!(comment.starts_with("Duplicate of #12\n"));
+        assert!(comment.contains("Both report the same panic."));
+        assert!(comment.contains(crate::triage::APTU_SIGNATURE));
+    }
+
+    #[test]
+    fn render_duplicate_comment_without_rationale() {
+        let comment = render_duplicate_comment(3, "  ");
+
+        assert!(comment.starts_with("Duplicate of #3\n\nClosing"));
+    }
+
+    #[test]
+    fn dedupe_log_round_trips_through_json() {
+        let mut log = BTreeMap::new();
+        log.insert(
+            40,
+            DedupeRecord {
+                canonical: 12,
+                comment_url: "https://github.com/o/r/issues/40#issuecomment-1".to_string(),
+                labeled: true,
+                closed: true,
+                timestamp: Utc::now(),
+            },
+        );
+
+        let json = serde_json::to_string(&log).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<BTreeMap<u64, DedupeRecord>>(&json).unwrap(),
+            log
+        );
+    }
+}
@@ -14,6 +14,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
 use tracing::{debug, instrument};
 
+use super::issues::UntriagedIssue;
 use crate::ai::types::{IssueComment, RepoLabel, RepoMilestone};
 use crate::error::{AptuError, ResourceType};
 #[cfg(not(target_arch = "wasm32"))]
@@ -256,11 +257,18 @@ pub struct RepoMilestonesConnection {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct IssueCommentNode {
     /// Comment ID.
+    #[serde(rename = "databaseId")]
     pub id: u64,
     /// Comment author login.
     pub author: Author,
     /// Comment body.
     pub body: String,
+    /// Comment creation timestamp (ISO 8601).
+    #[serde(rename = "createdAt", default)]
+    pub created_at: Option<String>,
+    /// Comment last update timestamp (ISO 8601).
+    #[serde(rename = "updatedAt", default)]
+    pub updated_at: Option<String>,
 }
 
 impl From<IssueCommentNode> for IssueComment {
@@ -269,6 +277,8 @@ impl From<IssueCommentNode> for IssueComment {
             id: node.id,
             author: node.author.login,
             body: node.body,
+            created_at: node.created_at,
+            updated_at: node.updated_at,
         }
     }
 }
@@ -313,6 +323,12 @@ pub struct IssueNodeDetailed {
     /// Issue last update timestamp (ISO 8601).
     #[serde(rename = "updatedAt")]
     pub updated_at: String,
+    /// When the issue body was last edited (ISO 8601), if ever.
+    #[serde(rename = "lastEditedAt", default)]
+    pub last_edited_at: Option<String>,
+    /// Author's relationship to the repository (e.g. `OWNER`, `CONTRIBUTOR`, `NONE`).
+    #[serde(rename = "authorAssociation", default)]
+    pub author_association: Option<String>,
 }
 
 /// Repository data from GraphQL response for triage.
@@ -364,6 +380,8 @@ fn build_issue_with_repo_context_query(owner: &str, repo: &str, number: u64) ->
                     }}
                     createdAt
                     updatedAt
+                    lastEditedAt
+                    authorAssociation
                     labels(first: 10) {{
                         nodes {{
                             name
@@ -372,10 +390,13 @@ fn build_issue_with_repo_context_query(owner: &str, repo: &str, number: u64) ->
                     comments(first: 5) {{
                         totalCount
                         nodes {{
+                            databaseId
                             author {{
                                 login
                             }}
                             body
+                            createdAt
+                            updatedAt
                         }}
                     }}
                 }}
@@ -672,3 +693,319 @@ mod tag_resolution_tests {
         assert!(query_str.contains("oid"));
     }
 }
+
+/// Issues requested per page when sweeping a repository for triage (the
+/// GraphQL maximum).
+const TRIAGE_PAGE_SIZE: u32 = 100;
+
+/// One page of a repository's issues, oldest first, filtered for triage.
+#[derive(Debug, Clone, Default)]
+pub struct TriagePage {
+    /// Issues needing triage as `(cursor, issue)` pairs. Resuming after a
+    /// cursor skips that issue and every issue before it.
+    pub issues: Vec<(String, UntriagedIssue)>,
+    /// Cursor of the last issue on the page, whether or not it needs triage.
+    pub end_cursor: Option<String>,
+    /// Whether more issues follow this page.
+    pub has_next_page: bool,
+    /// Number of issues on the page before filtering.
+    pub scanned: usize,
+}
+
+/// Issues connection with per-issue cursors, used for triage sweeps.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TriageIssuesConnection {
+    page_info: PageInfo,
+    edges: Vec<TriageIssueEdge>,
+}
+
+/// Pagination state of a GraphQL connection.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TriageIssueEdge {
+    cursor: String,
+    node: TriageIssueNode,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TriageIssueNode {
+    number: u64,
+    title: String,
+    created_at: String,
+    url: String,
+    labels: Option<LabelCount>,
+    milestone: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LabelCount {
+    total_count: u64,
+}
+
+/// Builds a GraphQL query for one page of a repository's issues in creation
+/// order.
+///
+/// `since` is applied server-side (`filterBy.since` matches issues updated at
+/// or after the timestamp, a superset of those created after it). Each issue
+/// carries its label count and milestone so triage candidates can be picked
+/// without further requests. `states` is a GraphQL `IssueState` list such as
+/// `[OPEN]`.
+fn build_triage_page_query(
+    owner: &str,
+    repo: &str,
+    states: &str,
+    since: Option<&str>,
+    after: Option<&str>,
+) -> Value {
+    let since = since.map_or_else(|| "null".to_string(), |s| format!("\"{s}\""));
+    let after = after.map_or_else(|| "null".to_string(), |c| format!("\"{c}\""));
+    let query = format!(
+        r#"query {{
+  repository(owner: "{owner}", name: "{repo}") {{
+    issues(
+      first: {TRIAGE_PAGE_SIZE}
+      after: {after}
+      states: {states}
+      filterBy: {{ since: {since} }}
+      orderBy: {{ field: CREATED_AT, direction: ASC }}
+    ) {{
+      pageInfo {{
+        hasNextPage
+        endCursor
+      }}
+      edges {{
+        cursor
+        node {{
+          number
+          title
+          createdAt
+          url
+          labels {{
+            totalCount
+          }}
+          milestone {{
+            number
+          }}
+        }}
+      }}
+    }}
+  }}
+}}"#
+    );
+
+    json!({ "query": query })
+}
+
+/// Parses a triage page from the `data` of a [`build_triage_page_query`]
+/// response.
+///
+/// In default mode (`force = false`) only issues without labels or without a
+/// milestone are kept; with `force` every issue is kept. Issues created before
+/// `since` (fetched because they were updated after it) are dropped.
+fn parse_triage_page(data: &Value, since: Option<&str>, force: bool) -> Result<TriagePage> {
+    let issues_val = data
+        .get("repository")
+        .filter(|v| !v.is_null())
+        .and_then(|r| r.get("issues"))
+        .context("Repository not found in GraphQL response")?;
+    let connection: TriageIssuesConnection =
+        serde_json::from_value(issues_val.clone()).context("Failed to parse issues page")?;
+
+    let since = since.and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok());
+    let scanned = connection.edges.len();
+    let issues = connection
+        .edges
+        .into_iter()
+        .filter(|edge| {
+            force
+                || edge.node.labels.as_ref().is_none_or(|l| l.total_count == 0)
+                || edge.node.milestone.as_ref().is_none_or(Value::is_null)
+        })
+        .filter(|edge| {
+            since.is_none_or(|since| {
+                chrono::DateTime::parse_from_rfc3339(&edge.node.created_at)
+                    .map_or(true, |created_at| created_at >= since)
+            })
+        })
+        .map(|edge| {
+            let node = edge.node;
+            (
+                edge.cursor,
+                UntriagedIssue {
+                    number: node.number,
+                    title: node.title,
+                    created_at: node.created_at,
+                    url: node.url,
+                },
+            )
+        })
+        .collect();
+
+    Ok(TriagePage {
+        issues,
+        end_cursor: connection.page_info.end_cursor,
+        has_next_page: connection.page_info.has_next_page,
+        scanned,
+    })
+}
+
+/// Fetches one page of a repository's issues needing triage, oldest first.
+///
+/// Pass the `end_cursor` of the previous page (or the cursor of the last issue
+/// processed) as `after` to continue a sweep. See [`parse_triage_page`] for
+/// the `since` and `force` semantics.
+///
+/// # Errors
+///
+/// Returns an error if the GraphQL query fails or the repository is not found.
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(client), fields(owner = %owner, repo = %repo))]
+pub async fn fetch_triage_page(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    since: Option<&str>,
+    force: bool,
+    state: octocrab::params::State,
+    after: Option<&str>,
+) -> Result<TriagePage> {
+    let states = match state {
+        octocrab::params::State::Open => "[OPEN]",
+        octocrab::params::State::Closed => "[CLOSED]",
+        _ => "[OPEN, CLOSED]",
+    };
+    let query = build_triage_page_query(owner, repo, states, since, after);
+
+    let response: Value =
+        (|| async { client.graphql(&query).await.map_err(|e| anyhow::anyhow!(e)) })
+            .retry(retry_backoff())
+            .notify(|err, dur| {
+                tracing::warn!(
+                    error = %err,
+                    retry_after = ?dur,
+                    "Retrying fetch_triage_page (GraphQL query)"
+                );
+            })
+            .await
+            .context("Failed to execute GraphQL query")?;
+
+    if let Some(errors) = response.get("errors") {
+        let error_msg = serde_json::to_string_pretty(errors).unwrap_or_default();
+        anyhow::bail!("GraphQL error: {error_msg}");
+    }
+
+    let data = response
+        .get("data")
+        .context("Missing 'data' field in GraphQL response")?;
+    let page = parse_triage_page(data, since, force)?;
+
+    debug!(
+        scanned = page.scanned,
+        needing_triage = page.issues.len(),
+        has_next_page = page.has_next_page,
+        "Fetched triage page"
+    );
+    Ok(page)
+}
+
+#[cfg(test)]
+mod triage_page_tests {
+    use super::*;
+
+    fn edge(cursor: &str, number: u64, created_at: &str, labels: u64, milestone: bool) -> Value {
+        json!({
+            "cursor": cursor,
+            "node": {
+                "number": number,
+                "title": format!("Issue {number}"),
+                "createdAt": created_at,
+                "url": format!("https://github.com/o/r/issues/{number}"),
+                "labels": { "totalCount": labels },
+                "milestone": if milestone { json!({ "number": 1 }) } else { Value::Null },
+            }
+        })
+    }
+
+    fn page_data(edges: &[Value]) -> Value {
+        json!({
+            "repository": {
+                "issues": {
+                    "pageInfo": { "hasNextPage": true, "endCursor": "c4" },
+                    "edges": edges,
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn build_triage_page_query_paginates_server_side() {
+        let query = build_triage_page_query(
+            "o",
+            "r",
+            "[OPEN]",
+            Some("2026-01-01T00:00:00Z"),
+            Some("abc"),
+        );
+        let query_str = query["query"].as_str().unwrap();
+
+        assert!(query_str.contains("repository(owner: \"o\", name: \"r\")"));
+        assert!(query_str.contains("after: \"abc\""));
+        assert!(query_str.contains("states: [OPEN]"));
+        assert!(query_str.contains("filterBy: { since: \"2026-01-01T00:00:00Z\" }"));
+        assert!(query_str.contains("pageInfo"));
+        assert!(query_str.contains("totalCount"));
+
+        let first = build_triage_page_query("o", "r", "[OPEN, CLOSED]", None, None);
+        let first_str = first["query"].as_str().unwrap();
+        assert!(first_str.contains("after: null"));
+        assert!(first_str.contains("since: null"));
+        assert!(first_str.contains("states: [OPEN, CLOSED]"));
+    }
+
+    #[test]
+    fn parse_triage_page_keeps_unlabeled_or_unmilestoned_since_date() {
+        let data = page_data(&[
+            edge("c1", 1, "2025-12-01T00:00:00Z", 0, false),
+            edge("c2", 2, "2026-01-02T00:00:00Z", 0, true),
+            edge("c3", 3, "2026-01-03T00:00:00Z", 2, true),
+            edge("c4", 4, "2026-01-04T00:00:00Z", 1, false),
+        ]);
+
+        let page = parse_triage_page(&data, Some("2026-01-01T00:00:00Z"), false).unwrap();
+
+        let kept: Vec<(&str, u64)> = page
+            .issues
+            .iter()
+            .map(|(cursor, issue)| (cursor.as_str(), issue.number))
+            .collect();
+        assert_eq!(kept, vec![("c2", 2), ("c4", 4)]);
+        assert_eq!(page.scanned, 4);
+        assert_eq!(page.end_cursor.as_deref(), Some("c4"));
+        assert!(page.has_next_page);
+    }
+
+    #[test]
+    fn parse_triage_page_force_keeps_triaged_issues() {
+        let data = page_data(&[edge("c1", 1, "2026-01-03T00:00:00Z", 2, true)]);
+
+        let page = parse_triage_page(&data, None, true).unwrap();
+
+        assert_eq!(page.issues.len(), 1);
+    }
+
+    #[test]
+    fn parse_triage_page_missing_repository_is_error() {
+        let data = json!({ "repository": null });
+
+        assert!(parse_triage_page(&data, None, false).is_err());
+    }
+}
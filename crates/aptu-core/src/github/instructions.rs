@@ -76,7 +76,7 @@ pub async fn fetch_repo_instructions(
 ///
 /// Returns `None` on any error (404, decode failure, etc.).
 #[cfg(not(target_arch = "wasm32"))]
-async fn fetch_file_content(
+pub(crate) async fn fetch_file_content(
     client: &octocrab::Octocrab,
     owner: &str,
     repo: &str,
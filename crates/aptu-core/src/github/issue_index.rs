@@ -0,0 +1,707 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! On-disk embedding index of a repository's issues.
+//!
+//! Each repository gets an index at
+//! `~/.local/share/aptu/issue-index/<owner>/<repo>.json` holding one
+//! embedding per issue (open and closed). The index is refreshed
+//! incrementally: only issues updated since the last sync are fetched and
+//! re-embedded. Switching embedders discards the index, since vectors from
+//! different embedders are not comparable.
+
+use std::collections::BTreeMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::collections::HashMap;
+use std::path::PathBuf;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::{Arc, LazyLock, Mutex};
+
+use anyhow::{Context, Result};
+#[cfg(not(target_arch = "wasm32"))]
+use backon::Retryable;
+#[cfg(not(target_arch = "wasm32"))]
+use octocrab::Octocrab;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+#[cfg(not(target_arch = "wasm32"))]
+use tracing::{debug, instrument};
+
+use crate::ai::embeddings::cosine;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::ai::embeddings::{Embedder, issue_text};
+use crate::ai::types::RepoIssueContext;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::retry::retry_backoff;
+
+/// Number of issues fetched per GraphQL page when refreshing an index.
+const INDEX_PAGE_SIZE: u32 = 100;
+
+/// Maximum pages fetched per refresh; larger backlogs are indexed over
+/// several runs.
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_REFRESH_PAGES: usize = 10;
+
+/// Number of nearest issues returned for a query.
+pub const DEFAULT_TOP_K: usize = 5;
+
+/// Minimum similarity for an issue to be reported as related.
+pub const MIN_SIMILARITY: f32 = 0.2;
+
+/// Default similarity at or above which open issues are clustered as
+/// possible duplicates when an embedding model is configured.
+pub const DEFAULT_DUPLICATE_THRESHOLD: f32 = 0.6;
+
+/// Default duplicate threshold for [`Embedder::LexicalHash`], whose scores
+/// measure shared wording rather than meaning. Unrelated issues written from
+/// the same template ("Improve error message for ...") score around 0.57, so
+/// the keyword fallback only clusters near-identical wording.
+pub const LEXICAL_DUPLICATE_THRESHOLD: f32 = 0.7;
+
+/// Maximum number of issues clustered around one seed issue.
+pub const MAX_CLUSTER_SIZE: usize = 10;
+
+/// Default duplicate threshold for the vectors `embedder` produces.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn default_duplicate_threshold(embedder: &Embedder) -> f32 {
+    match embedder {
+        Embedder::LexicalHash => LEXICAL_DUPLICATE_THRESHOLD,
+        Embedder::Remote { .. } => DEFAULT_DUPLICATE_THRESHOLD,
+    }
+}
+
+/// An indexed issue and its embedding.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IndexedIssue {
+    /// Issue title.
+    pub title: String,
+    /// Issue state (`open` or `closed`).
+    pub state: String,
+    /// Issue labels.
+    pub labels: Vec<String>,
+    /// Last update timestamp (ISO 8601).
+    pub updated_at: String,
+    /// L2-normalized embedding of the title and body.
+    pub vector: Vec<f32>,
+}
+
+/// Open issues similar to a seed issue.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssueCluster {
+    /// Oldest issue in the cluster, which the others were compared against.
+    pub seed: u64,
+    /// Other members with their similarity to the seed, most similar first.
+    pub members: Vec<(u64, f32)>,
+}
+
+/// Embedding index of one repository's issues.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IssueIndex {
+    /// Name of the embedder that produced the vectors.
+    pub embedder: String,
+    /// `updatedAt` of the most recently updated indexed issue.
+    pub synced_at: Option<String>,
+    /// Indexed issues keyed by number.
+    pub issues: BTreeMap<u64, IndexedIssue>,
+}
+
+impl IssueIndex {
+    /// Creates an empty index for the given embedder.
+    #[must_use]
+    pub fn new(embedder: impl Into<String>) -> Self {
+        Self {
+            embedder: embedder.into(),
+            synced_at: None,
+            issues: BTreeMap::new(),
+        }
+    }
+
+    /// Loads a repository's index, or an empty one if there is none, it
+    /// cannot be read, or it was built by a different embedder.
+    #[must_use]
+    pub fn load(owner: &str, repo: &str, embedder: &str) -> Self {
+        std::fs::read_to_string(issue_index_path(owner, repo))
+            .ok()
+            .and_then(|contents| serde_json::from_str::<Self>(&contents).ok())
+            .filter(|index| index.embedder == embedder)
+            .unwrap_or_else(|| Self::new(embedder))
+    }
+
+    /// Saves the index, creating parent directories as needed.
+    ///
+    /// The file is written to a uniquely named temporary sibling and renamed
+    /// into place so concurrent triage runs never observe a partial index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index cannot be serialized or written.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self, owner: &str, repo: &str) -> Result<()> {
+        use std::io::Write;
+
+        let path = issue_index_path(owner, repo);
+        let parent = path
+            .parent()
+            .context("Issue index path has no parent directory")?;
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        let contents = serde_json::to_string(self).context("Failed to serialize issue index")?;
+        let mut tmp = tempfile::NamedTempFile::new_in(parent)
+            .with_context(|| format!("Failed to create temp file in {}", parent.display()))?;
+        tmp.write_all(contents.as_bytes())
+            .with_context(|| format!("Failed to write issue index: {}", tmp.path().display()))?;
+        tmp.persist(&path)
+            .with_context(|| format!("Failed to write issue index: {}", path.display()))?;
+        Ok(())
+    }
+
+    /// Inserts or replaces an issue, advancing `synced_at` if it is newer.
+    pub fn upsert(&mut self, number: u64, issue: IndexedIssue) {
+        if self
+            .synced_at
+            .as_deref()
+            .is_none_or(|synced| issue.updated_at.as_str() > synced)
+        {
+            self.synced_at = Some(issue.updated_at.clone());
+        }
+        self.issues.insert(number, issue);
+    }
+
+    /// Returns up to `k` issues most similar to `query`, best first, with
+    /// their similarity. Issues below [`MIN_SIMILARITY`] and `exclude` are
+    /// skipped.
+    #[must_use]
+    pub fn nearest(&self, query: &[f32], k: usize, exclude: u64) -> Vec<(u64, f32)> {
+        let mut scored: Vec<(u64, f32)> = self
+            .issues
+            .iter()
+            .filter(|(number, _)| **number != exclude)
+            .map(|(number, issue)| (*number, cosine(query, &issue.vector)))
+            .filter(|(_, similarity)| *similarity >= MIN_SIMILARITY)
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        scored.truncate(k);
+        scored
+    }
+
+    /// Returns the similarity of two indexed issues, if both are indexed.
+    #[must_use]
+    pub fn similarity(&self, a: u64, b: u64) -> Option<f32> {
+        Some(cosine(
+            &self.issues.get(&a)?.vector,
+            &self.issues.get(&b)?.vector,
+        ))
+    }
+
+    /// Groups open issues into clusters of possible duplicates.
+    ///
+    /// Issues are visited oldest (lowest number) first; each issue not yet
+    /// clustered seeds a cluster of the unclustered open issues at least
+    /// `threshold` similar to it, capped at [`MAX_CLUSTER_SIZE`]. Comparing
+    /// against the seed rather than any member keeps chains of loosely
+    /// related issues from merging into one cluster. Only clusters with at
+    /// least two issues are returned.
+    #[must_use]
+    pub fn duplicate_clusters(&self, threshold: f32) -> Vec<IssueCluster> {
+        let open: Vec<(u64, &IndexedIssue)> = self
+            .issues
+            .iter()
+            .filter(|(_, issue)| issue.state == "open")
+            .map(|(number, issue)| (*number, issue))
+            .collect();
+        let mut clustered = std::collections::HashSet::new();
+        let mut clusters = Vec::new();
+
+        for (seed, seed_issue) in &open {
+            if clustered.contains(seed) {
+                continue;
+            }
+            let mut members: Vec<(u64, f32)> = open
+                .iter()
+                .filter(|(number, _)| number > seed && !clustered.contains(number))
+                .map(|(number, issue)| (*number, cosine(&seed_issue.vector, &issue.vector)))
+                .filter(|(_, similarity)| *similarity >= threshold)
+                .collect();
+            if members.is_empty() {
+                continue;
+            }
+            members.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+            members.truncate(MAX_CLUSTER_SIZE - 1);
+
+            clustered.insert(*seed);
+            clustered.extend(members.iter().map(|(number, _)| *number));
+            clusters.push(IssueCluster {
+                seed: *seed,
+                members,
+            });
+        }
+
+        clusters
+    }
+
+    /// Returns the `k` nearest issues to `query` as prompt context, with
+    /// similarity scores.
+    #[must_use]
+    pub fn related(&self, query: &[f32], k: usize, exclude: u64) -> Vec<RepoIssueContext> {
+        self.nearest(query, k, exclude)
+            .into_iter()
+            .filter_map(|(number, similarity)| {
+                let issue = self.issues.get(&number)?;
+                Some(RepoIssueContext {
+                    number,
+                    title: issue.title.clone(),
+                    labels: issue.labels.clone(),
+                    state: issue.state.clone(),
+                    similarity: Some(similarity),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Returns the path of a repository's issue index
+/// (`~/.local/share/aptu/issue-index/<owner>/<repo>.json`).
+#[must_use]
+pub fn issue_index_path(owner: &str, repo: &str) -> PathBuf {
+    crate::config::data_dir()
+        .join("issue-index")
+        .join(owner)
+        .join(format!("{repo}.json"))
+}
+
+/// An issue fetched for indexing.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IndexIssueNode {
+    number: u64,
+    title: String,
+    #[serde(default)]
+    body: Option<String>,
+    state: String,
+    updated_at: String,
+    labels: Option<IndexLabels>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IndexLabels {
+    nodes: Vec<IndexLabel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IndexLabel {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IndexPageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IndexIssuesConnection {
+    page_info: IndexPageInfo,
+    nodes: Vec<IndexIssueNode>,
+}
+
+/// Builds a GraphQL query for one page of issues updated since `since`,
+/// least recently updated first.
+fn build_index_page_query(
+    owner: &str,
+    repo: &str,
+    since: Option<&str>,
+    after: Option<&str>,
+) -> Value {
+    let since = since.map_or_else(|| "null".to_string(), |s| format!("\"{s}\""));
+    let after = after.map_or_else(|| "null".to_string(), |c| format!("\"{c}\""));
+    let query = format!(
+        r#"query {{
+  repository(owner: "{owner}", name: "{repo}") {{
+    issues(
+      first: {INDEX_PAGE_SIZE}
+      after: {after}
+      states: [OPEN, CLOSED]
+      filterBy: {{ since: {since} }}
+      orderBy: {{ field: UPDATED_AT, direction: ASC }}
+    ) {{
+      pageInfo {{
+        hasNextPage
+        endCursor
+      }}
+      nodes {{
+        number
+        title
+        body
+        state
+        updatedAt
+        labels(first: 10) {{
+          nodes {{
+            name
+          }}
+        }}
+      }}
+    }}
+  }}
+}}"#
+    );
+
+    json!({ "query": query })
+}
+
+/// Parses the issues connection from the `data` of a
+/// [`build_index_page_query`] response.
+fn parse_index_page(data: &Value) -> Result<IndexIssuesConnection> {
+    let issues_val = data
+        .get("repository")
+        .filter(|v| !v.is_null())
+        .and_then(|r| r.get("issues"))
+        .context("Repository not found in GraphQL response")?;
+    serde_json::from_value(issues_val.clone()).context("Failed to parse issues page")
+}
+
+/// Brings a repository's issue index up to date and saves it.
+///
+/// Fetches issues updated since the last sync (all issues on first use),
+/// embeds them, and replaces their entries. At most `MAX_REFRESH_PAGES`
+/// pages are fetched per call; the remainder is picked up next time.
+///
+/// # Errors
+///
+/// Returns an error if the GraphQL query, the embedding request, or saving
+/// the index fails.
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(client, embedder), fields(owner = %owner, repo = %repo))]
+pub async fn refresh_issue_index(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    embedder: &Embedder,
+) -> Result<IssueIndex> {
+    let mut index = IssueIndex::load(owner, repo, &embedder.name());
+    let since = index.synced_at.clone();
+    let mut after: Option<String> = None;
+    let mut updated = 0usize;
+
+    for _ in 0..MAX_REFRESH_PAGES {
+        let query = build_index_page_query(owner, repo, since.as_deref(), after.as_deref());
+        let response: Value =
+            (|| async { client.graphql(&query).await.map_err(|e| anyhow::anyhow!(e)) })
+                .retry(retry_backoff())
+                .notify(|err, dur| {
+                    tracing::warn!(
+                        error = %err,
+                        retry_after = ?dur,
+                        "Retrying refresh_issue_index (GraphQL query)"
+                    );
+                })
+                .await
+                .context("Failed to execute GraphQL query")?;
+
+        if let Some(errors) = response.get("errors") {
+            let error_msg = serde_json::to_string_pretty(errors).unwrap_or_default();
+            anyhow::bail!("GraphQL error: {error_msg}");
+        }
+
+        let data = response
+            .get("data")
+            .context("Missing 'data' field in GraphQL response")?;
+        let page = parse_index_page(data)?;
+
+        let texts: Vec<String> = page
+            .nodes
+            .iter()
+            .map(|node| issue_text(&node.title, node.body.as_deref().unwrap_or_default()))
+            .collect();
+        let vectors = embedder.embed(&texts).await?;
+
+        updated += page.nodes.len();
+        for (node, vector) in page.nodes.into_iter().zip(vectors) {
+            index.upsert(
+                node.number,
+                IndexedIssue {
+                    title: node.title,
+                    state: node.state.to_lowercase(),
+                    labels: node
+                        .labels
+                        .map(|l| l.nodes.into_iter().map(|n| n.name).collect())
+                        .unwrap_or_default(),
+                    updated_at: node.updated_at,
+                    vector,
+                },
+            );
+        }
+
+        if !page.page_info.has_next_page {
+            break;
+        }
+        after = page.page_info.end_cursor;
+    }
+
+    index.save(owner, repo)?;
+    debug!(
+        updated,
+        indexed = index.issues.len(),
+        embedder = %index.embedder,
+        "Refreshed issue index"
+    );
+    Ok(index)
+}
+
+/// Indexes refreshed by this process, keyed by repository and embedder.
+#[cfg(not(target_arch = "wasm32"))]
+type RefreshedIndexes = HashMap<String, Arc<tokio::sync::OnceCell<Arc<IssueIndex>>>>;
+
+#[cfg(not(target_arch = "wasm32"))]
+static REFRESHED: LazyLock<Mutex<RefreshedIndexes>> = LazyLock::new(Mutex::default);
+
+/// Returns the repository's index, refreshing it on first use in this
+/// process only.
+///
+/// Bulk triage looks up similar issues for every issue of a repository; the
+/// issues it triages and labels are not worth re-embedding between them.
+/// Concurrent callers for the same repository wait for a single refresh; a
+/// failed refresh is retried by the next caller.
+///
+/// # Errors
+///
+/// Returns an error if refreshing the index fails.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn refreshed_issue_index(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    embedder: &Embedder,
+) -> Result<Arc<IssueIndex>> {
+    let key = format!(
+        "{}/{}@{}",
+        owner.to_lowercase(),
+        repo.to_lowercase(),
+        embedder.name()
+    );
+    let cell = REFRESHED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .entry(key)
+        .or_default()
+        .clone();
+    cell.get_or_try_init(|| async {
+        refresh_issue_index(client, owner, repo, embedder)
+            .await
+            .map(Arc::new)
+    })
+    .await
+    .cloned()
+}
+
+/// Finds the issues most similar to the given title and body using the
+/// repository's embedding index, refreshed once per process (see
+/// [`refreshed_issue_index`]).
+///
+/// # Errors
+///
+/// Returns an error if refreshing the index or embedding the query fails.
+#[cfg(not(target_arch = "wasm32"))]
+#[allow(clippy::too_many_arguments)]
+pub async fn find_similar_issues(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    embedder: &Embedder,
+    title: &str,
+    body: &str,
+    exclude_number: u64,
+    k: usize,
+) -> Result<Vec<RepoIssueContext>> {
+    let index = refreshed_issue_index(client, owner, repo, embedder).await?;
+    let query = embedder
+        .embed(&[issue_text(title, body)])
+        .await?
+        .pop()
+        .context("Embedder returned no vector for the query")?;
+    Ok(index.related(&query, k, exclude_number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indexed(title: &str, updated_at: &str, vector: Vec<f32>) -> IndexedIssue {
+        IndexedIssue {
+            title: title.to_string(),
+            state: "open".to_string(),
+            labels: vec!["bug".to_string()],
+            updated_at: updated_at.to_string(),
+            vector,
+        }
+    }
+
+    #[test]
+    fn upsert_advances_synced_at_monotonically() {
+        let mut index = IssueIndex::new("local-hash-v1");
+
+        index.upsert(1, indexed("a", "2026-01-02T00:00:00Z", vec![1.0, 0.0]));
+        index.upsert(2, indexed("b", "2026-01-01T00:00:00Z", vec![0.0, 1.0]));
+        index.upsert(1, indexed("a2", "2026-01-03T00:00:00Z", vec![1.0, 0.0]));
+
+        assert_eq!(index.synced_at.as_deref(), Some("2026-01-03T00:00:00Z"));
+        assert_eq!(index.issues.len(), 2);
+        assert_eq!(index.issues[&1].title, "a2");
+    }
+
+    #[test]
+    fn nearest_ranks_by_similarity_and_excludes() {
+        let mut index = IssueIndex::new("local-hash-v1");
+        index.upsert(1, indexed("same", "t", vec![1.0, 0.0]));
+        index.upsert(2, indexed("close", "t", vec![0.8, 0.6]));
+        index.upsert(3, indexed("orthogonal", "t", vec![0.0, 1.0]));
+        index.upsert(4, indexed("self", "t", vec![1.0, 0.0]));
+
+        let nearest = index.nearest(&[1.0, 0.0], 5, 4);
+
+        let numbers: Vec<u64> = nearest.iter().map(|(n, _)| *n).collect();
+        assert_eq!(numbers, vec![1, 2]);
+        assert!((nearest[1].1 - 0.8).abs() < 1e-6);
+        assert_eq!(index.nearest(&[1.0, 0.0], 1, 4).len(), 1);
+    }
+
+    #[test]
+    fn duplicate_clusters_group_open_issues_around_oldest() {
+        let mut index = IssueIndex::new("local-hash-v1");
+        index.upsert(1, indexed("crash", "t", vec![1.0, 0.0]));
+        index.upsert(2, indexed("crash again", "t", vec![0.8, 0.6]));
+        index.upsert(3, indexed("crash too", "t", vec![0.6, 0.8]));
+        index.upsert(4, indexed("other", "t", vec![0.0, 1.0]));
+        let mut closed = indexed("closed crash", "t", vec![1.0, 0.0]);
+        closed.state = "closed".to_string();
+        index.upsert(5, closed);
+
+        let clusters = index.duplicate_clusters(0.7);
+
+        // #3 is 0.6 from #1 (below threshold) but 0.96 from #2; it is not
+        // chained into #1's cluster and instead seeds one with #4.
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].seed, 1);
+        assert_eq!(
+            clusters[0]
+                .members
+                .iter()
+                .map(|(n, _)| *n)
+                .collect::<Vec<_>>(),
+            vec![2]
+        );
+        assert_eq!(clusters[1].seed, 3);
+        assert_eq!(
+            clusters[1]
+                .members
+                .iter()
+                .map(|(n, _)| *n)
+                .collect::<Vec<_>>(),
+            vec![4]
+        );
+    }
+
+    #[test]
+    fn duplicate_clusters_skip_singletons() {
+        let mut index = IssueIndex::new("local-hash-v1");
+        index.upsert(1, indexed("a", "t", vec![1.0, 0.0]));
+        index.upsert(2, indexed("b", "t", vec![0.0, 1.0]));
+
+        assert!(index.duplicate_clusters(0.5).is_empty());
+    }
+
+    #[test]
+    fn lexical_threshold_skips_shared_template_wording() {
+        use crate::ai::embeddings::lexical_embedding;
+
+        let similarity = |a: &str, b: &str| cosine(&lexical_embedding(a), &lexical_embedding(b));
+
+        // Same template, different problems: lexically close, not duplicates.
+        assert!(
+            similarity(
+                "Improve error message for missing token",
+                "Improve error message for invalid config"
+            ) < LEXICAL_DUPLICATE_THRESHOLD
+        );
+        // Near-identical wording still clusters.
+        assert!(
+            similarity(
+                "Crash when parsing config file",
+                "Crash when parsing config file on Windows"
+            ) >= LEXICAL_DUPLICATE_THRESHOLD
+        );
+    }
+
+    #[test]
+    fn related_carries_similarity() {
+        let mut index = IssueIndex::new("local-hash-v1");
+        index.upsert(7, indexed("Crash on start", "t", vec![1.0, 0.0]));
+
+        let related = index.related(&[1.0, 0.0], DEFAULT_TOP_K, 1);
+
+        assert_eq!(related.len(), 1);
+        assert_eq!(related[0].number, 7);
+        assert_eq!(related[0].labels, vec!["bug".to_string()]);
+        assert!(
+            related[0]
+                .similarity
+                .is_some_and(|s| (s - 1.0).abs() < 1e-6)
+        );
+    }
+
+    #[test]
+    fn index_round_trips_through_json() {
+        let mut index = IssueIndex::new("openrouter:text-embedding-3-small");
+        index.upsert(3, indexed("x", "2026-01-01T00:00:00Z", vec![0.6, 0.8]));
+
+        let json = serde_json::to_string(&index).unwrap();
+
+        assert_eq!(serde_json::from_str::<IssueIndex>(&json).unwrap(), index);
+    }
+
+    #[test]
+    fn build_index_page_query_fetches_updated_issues_incrementally() {
+        let query = build_index_page_query("o", "r", Some("2026-01-01T00:00:00Z"), Some("abc"));
+        let query_str = query["query"].as_str().unwrap();
+
+        assert!(query_str.contains("repository(owner: \"o\", name: \"r\")"));
+        assert!(query_str.contains("after: \"abc\""));
+        assert!(query_str.contains("states: [OPEN, CLOSED]"));
+        assert!(query_str.contains("filterBy: { since: \"2026-01-01T00:00:00Z\" }"));
+        assert!(query_str.contains("orderBy: { field: UPDATED_AT, direction: ASC }"));
+        assert!(query_str.contains("body"));
+
+        let first = build_index_page_query("o", "r", None, None);
+        let first_str = first["query"].as_str().unwrap();
+        assert!(first_str.contains("since: null"));
+        assert!(first_str.contains("after: null"));
+    }
+
+    #[test]
+    fn parse_index_page_reads_nodes() {
+        let data = json!({
+            "repository": {
+                "issues": {
+                    "pageInfo": { "hasNextPage": false, "endCursor": "c1" },
+                    "nodes": [{
+                        "number": 12,
+                        "title": "Crash",
+                        "body": null,
+                        "state": "CLOSED",
+                        "updatedAt": "2026-01-01T00:00:00Z",
+                        "labels": { "nodes": [{ "name": "bug" }] }
+                    }]
+                }
+            }
+        });
+
+        let page = parse_index_page(&data).unwrap();
+
+        assert!(!page.page_info.has_next_page);
+        assert_eq!(page.page_info.end_cursor.as_deref(), Some("c1"));
+        assert_eq!(page.nodes[0].number, 12);
+        assert!(page.nodes[0].body.is_none());
+        assert!(parse_index_page(&json!({ "repository": null })).is_err());
+    }
+}
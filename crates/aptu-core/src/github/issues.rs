@@ -161,6 +161,8 @@ pub async fn fetch_issue_with_comments(
             id: c.id.0,
             author: c.user.login.clone(),
             body: c.body.clone().unwrap_or_default(),
+            created_at: Some(c.created_at.to_rfc3339()),
+            updated_at: c.updated_at.map(|t| t.to_rfc3339()),
         })
         .collect();
 
@@ -186,6 +188,70 @@ pub async fn fetch_issue_with_comments(
     Ok(details)
 }
 
+/// Maximum number of comments read by [`fetch_all_comments`].
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_HISTORY_COMMENTS: usize = 1000;
+
+/// Fetches every comment on an issue, oldest first, paging through the list.
+///
+/// Stops after [`MAX_HISTORY_COMMENTS`]; issues with longer threads are rare.
+///
+/// # Errors
+///
+/// Returns an error if the first page cannot be fetched.
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(client), fields(owner = %owner, repo = %repo, number = number))]
+pub async fn fetch_all_comments(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    number: u64,
+) -> Result<Vec<IssueComment>> {
+    let mut page = client
+        .issues(owner, repo)
+        .list_comments(number)
+        .per_page(100)
+        .send()
+        .await
+        .with_context(|| format!("Failed to fetch comments for issue #{number}"))?;
+
+    let mut comments = Vec::new();
+    loop {
+        comments.extend(page.items.into_iter().map(|c| IssueComment {
+            id: c.id.0,
+            author: c.user.login,
+            body: c.body.unwrap_or_default(),
+            created_at: Some(c.created_at.to_rfc3339()),
+            updated_at: c.updated_at.map(|t| t.to_rfc3339()),
+        }));
+
+        if comments.len() >= MAX_HISTORY_COMMENTS {
+            tracing::warn!(
+                "Issue #{} has reached {}-comment cap; stopping pagination",
+                number,
+                MAX_HISTORY_COMMENTS
+            );
+            comments.truncate(MAX_HISTORY_COMMENTS);
+            break;
+        }
+
+        match client
+            .get_page::<octocrab::models::issues::Comment>(&page.next)
+            .await
+        {
+            Ok(Some(next_page)) => page = next_page,
+            Ok(None) => break,
+            Err(e) => {
+                tracing::warn!("Error fetching next page of issue comments: {}", e);
+                break;
+            }
+        }
+    }
+
+    debug!(comments = comments.len(), "Fetched issue comment history");
+    Ok(comments)
+}
+
 /// Extracts significant keywords from an issue title for search.
 ///
 /// Filters out common stop words and returns lowercase keywords.
@@ -294,6 +360,7 @@ pub async fn search_related_issues(
                 title: item.title.clone(),
                 labels: item.labels.iter().map(|l| l.name.clone()).collect(),
                 state: format!("{:?}", item.state).to_lowercase(),
+                similarity: None,
             })
         })
         .collect();
@@ -336,6 +403,39 @@ pub async fn post_comment(
     Ok(comment_url)
 }
 
+/// Replaces the body of an existing issue comment.
+///
+/// # Returns
+///
+/// The URL of the updated comment.
+///
+/// # Errors
+///
+/// Returns an error if the API request fails.
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(client, body), fields(owner = %owner, repo = %repo, comment_id = comment_id))]
+pub async fn update_comment(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    comment_id: u64,
+    body: &str,
+) -> Result<String> {
+    debug!("Updating issue comment");
+
+    let comment = client
+        .issues(owner, repo)
+        .update_comment(octocrab::models::CommentId(comment_id), body)
+        .await
+        .with_context(|| format!("Failed to update comment #{comment_id}"))?;
+
+    let comment_url = comment.html_url.to_string();
+
+    debug!(url = %comment_url, "Comment updated successfully");
+
+    Ok(comment_url)
+}
+
 /// Deletes a comment from a GitHub issue.
 ///
 /// # Errors
@@ -421,6 +521,38 @@ pub async fn remove_issue_label(
     }
 }
 
+/// Sets an issue's or PR's state (`"open"` or `"closed"`), with an optional
+/// `state_reason` such as `"not_planned"` or `"reopened"`.
+///
+/// # Errors
+///
+/// Returns an error if the API request fails.
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(client), fields(owner = %owner, repo = %repo, number = number))]
+pub async fn set_issue_state(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    number: u64,
+    state: &str,
+    state_reason: Option<&str>,
+) -> Result<()> {
+    debug!("Updating issue state");
+
+    let route = format!("/repos/{owner}/{repo}/issues/{number}");
+    let mut body = serde_json::json!({ "state": state });
+    if let Some(reason) = state_reason {
+        body["state_reason"] = serde_json::Value::String(reason.to_string());
+    }
+    let _: serde_json::Value = client
+        .patch(&route, Some(&body))
+        .await
+        .with_context(|| format!("Failed to set issue #{number} to {state}"))?;
+
+    debug!("Issue state updated");
+    Ok(())
+}
+
 /// Creates a new GitHub issue.
 ///
 /// Posts a new issue with the given title and body to the repository.
@@ -480,16 +612,22 @@ const MAINTAINER_ONLY_LABELS: &[&str] = &["good first issue", "help wanted"];
 /// - If existing labels contain a priority label (p[0-9]), skip AI-suggested priority labels
 /// - Merge remaining labels with case-insensitive deduplication
 /// - Preserve all existing labels
+/// - Forced labels (from triage rules) bypass the priority and maintainer-only checks
 ///
 /// # Arguments
 ///
 /// * `existing_labels` - Labels currently on the issue
 /// * `suggested_labels` - Labels suggested by AI
+/// * `forced_labels` - Labels forced by triage rules
 ///
 /// # Returns
 ///
 /// Merged label list with duplicates removed (case-insensitive)
-fn merge_labels(existing_labels: &[String], suggested_labels: &[String]) -> Vec<String> {
+fn merge_labels(
+    existing_labels: &[String],
+    suggested_labels: &[String],
+    forced_labels: &[String],
+) -> Vec<String> {
     // Check if existing labels contain a priority label
     let has_priority = existing_labels.iter().any(|label| is_priority_label(label));
 
@@ -520,6 +658,12 @@ fn merge_labels(existing_labels: &[String], suggested_labels: &[String]) -> Vec<
         }
     }
 
+    for forced in forced_labels {
+        if !merged.iter().any(|l| l.eq_ignore_ascii_case(forced)) {
+            merged.push(forced.clone());
+        }
+    }
+
     merged
 }
 
@@ -542,6 +686,7 @@ pub async fn update_issue_labels_and_milestone(
     number: u64,
     existing_labels: &[String],
     suggested_labels: &[String],
+    forced_labels: &[String],
     existing_milestone: Option<&str>,
     suggested_milestone: Option<&str>,
     available_labels: &[crate::ai::types::RepoLabel],
@@ -555,18 +700,25 @@ pub async fn update_issue_labels_and_milestone(
     let available_label_names: std::collections::HashSet<_> =
         available_labels.iter().map(|l| l.name.as_str()).collect();
 
-    // Validate suggested labels
+    // Validate suggested and forced labels
     let mut valid_suggested = Vec::new();
-    for label in suggested_labels {
-        if available_label_names.contains(label.as_str()) {
-            valid_suggested.push(label.clone());
-        } else {
+    let mut valid_forced = Vec::new();
+    for (label, forced) in suggested_labels
+        .iter()
+        .map(|l| (l, false))
+        .chain(forced_labels.iter().map(|l| (l, true)))
+    {
+        if !available_label_names.contains(label.as_str()) {
             warnings.push(format!("Label '{label}' not found in repository"));
+        } else if forced {
+            valid_forced.push(label.clone());
+        } else {
+            valid_suggested.push(label.clone());
         }
     }
 
     // Merge existing and suggested labels additively
-    let applied_labels = merge_labels(existing_labels, &valid_suggested);
+    let applied_labels = merge_labels(existing_labels, &valid_suggested, &valid_forced);
 
     // Validate and find milestone (only set if issue has no existing milestone)
     let applied_milestone = if existing_milestone.is_none() {
@@ -664,6 +816,56 @@ pub async fn apply_labels_to_number(
     Ok(labels.to_vec())
 }
 
+/// Adds assignees to an issue or PR.
+///
+/// # Returns
+///
+/// The requested logins that are now assigned. GitHub silently skips users
+/// who cannot be assigned in the repository.
+///
+/// # Errors
+///
+/// Returns an error if the API request fails.
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(client), fields(owner = %owner, repo = %repo, number = number))]
+pub async fn add_assignees(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    number: u64,
+    assignees: &[String],
+) -> Result<Vec<String>> {
+    debug!("Adding assignees to issue/PR");
+
+    if assignees.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let route = format!("/repos/{owner}/{repo}/issues/{number}/assignees");
+    let payload = serde_json::json!({ "assignees": assignees });
+
+    let response = client
+        .post::<_, serde_json::Value>(route, Some(&payload))
+        .await
+        .with_context(|| {
+            format!("Failed to add assignees to issue/PR #{number} in {owner}/{repo}")
+        })?;
+
+    let assigned: Vec<String> = response
+        .get("assignees")
+        .and_then(serde_json::Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|a| a.get("login").and_then(serde_json::Value::as_str))
+        .filter(|login| assignees.iter().any(|a| a.eq_ignore_ascii_case(login)))
+        .map(str::to_string)
+        .collect();
+
+    debug!(assignees = ?assigned, "Assignees added");
+
+    Ok(assigned)
+}
+
 /// Priority labels that should be included first in tiered filtering.
 /// These labels are most actionable for issue triage.
 const PRIORITY_LABELS: &[&str] = &[
@@ -981,20 +1183,23 @@ pub async fn fetch_repo_tree(
 /// Fetches issues needing triage from a specific repository.
 ///
 /// In default mode (force=false), returns issues that are either unlabeled OR missing a milestone.
-/// In force mode (force=true), returns ALL open issues with no filtering.
+/// In force mode (force=true), returns ALL issues in the specified state with no filtering.
+///
+/// Walks the repository's issues oldest first, one GraphQL page at a time (see
+/// [`super::graphql::fetch_triage_page`]), until every page has been read.
 ///
 /// # Arguments
 ///
 /// * `client` - The Octocrab GitHub client
 /// * `owner` - Repository owner
 /// * `repo` - Repository name
-/// * `since` - Optional RFC3339 timestamp to filter issues created after this date (client-side filtering)
+/// * `since` - Optional RFC3339 timestamp to filter issues created after this date
 /// * `force` - If true, return all issues in the specified state; if false, filter to unlabeled or milestone-missing issues
 /// * `state` - Issue state filter (Open, Closed, or All)
 ///
 /// # Errors
 ///
-/// Returns an error if the REST API request fails.
+/// Returns an error if a GraphQL request fails.
 #[cfg(not(target_arch = "wasm32"))]
 #[instrument(skip(client), fields(owner = %owner, repo = %repo))]
 pub async fn fetch_issues_needing_triage(
@@ -1007,45 +1212,26 @@ pub async fn fetch_issues_needing_triage(
 ) -> Result<Vec<UntriagedIssue>> {
     debug!("Fetching issues needing triage");
 
-    let issues_page: octocrab::Page<octocrab::models::issues::Issue> = client
-        .issues(owner, repo)
-        .list()
-        .state(state)
-        .per_page(100)
-        .send()
-        .await
-        .context("Failed to fetch issues from repository")?;
-
-    let total_issues = issues_page.items.len();
-
-    let mut issues_needing_triage: Vec<UntriagedIssue> = issues_page
-        .items
-        .into_iter()
-        .filter(|issue| {
-            if force {
-                true
-            } else {
-                issue.labels.is_empty() || issue.milestone.is_none()
-            }
-        })
-        .map(|issue| UntriagedIssue {
-            number: issue.number,
-            title: issue.title,
-            created_at: issue.created_at.to_rfc3339(),
-            url: issue.html_url.to_string(),
-        })
-        .collect();
-
-    if let Some(since_date) = since
-        && let Ok(since_timestamp) = chrono::DateTime::parse_from_rfc3339(since_date)
-    {
-        issues_needing_triage.retain(|issue| {
-            if let Ok(created_at) = chrono::DateTime::parse_from_rfc3339(&issue.created_at) {
-                created_at >= since_timestamp
-            } else {
-                true
-            }
-        });
+    let mut issues_needing_triage: Vec<UntriagedIssue> = Vec::new();
+    let mut total_issues = 0;
+    let mut after: Option<String> = None;
+    loop {
+        let page = super::graphql::fetch_triage_page(
+            client,
+            owner,
+            repo,
+            since,
+            force,
+            state,
+            after.as_deref(),
+        )
+        .await?;
+        total_issues += page.scanned;
+        issues_needing_triage.extend(page.issues.into_iter().map(|(_, issue)| issue));
+        if !page.has_next_page || page.end_cursor.is_none() {
+            break;
+        }
+        after = page.end_cursor;
     }
 
     debug!(
@@ -1057,6 +1243,74 @@ pub async fn fetch_issues_needing_triage(
     Ok(issues_needing_triage)
 }
 
+/// Progress of a `--since` triage sweep, checkpointed per repository so an
+/// interrupted sweep can resume.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TriageCheckpoint {
+    /// RFC3339 timestamp the sweep selects issues from.
+    pub since: String,
+    /// Issue state filter (`open`, `closed`, or `all`).
+    pub state: String,
+    /// Whether the sweep includes already-triaged issues.
+    pub force: bool,
+    /// Cursor of the last issue processed; `None` before the first page.
+    pub cursor: Option<String>,
+    /// Number of issues processed so far.
+    pub processed: usize,
+    /// Issues whose triage failed, retried before the sweep moves on.
+    #[serde(default)]
+    pub failed: Vec<u64>,
+}
+
+impl TriageCheckpoint {
+    /// Returns `true` if `self` was recorded for the same sweep as `other`
+    /// (same `since`, `state`, and `force`).
+    #[must_use]
+    pub fn same_sweep(&self, other: &Self) -> bool {
+        self.since == other.since && self.state == other.state && self.force == other.force
+    }
+}
+
+/// Returns the path of a repository's triage checkpoint
+/// (`~/.local/share/aptu/triage/<owner>/<repo>.json`).
+#[must_use]
+pub fn triage_checkpoint_path(owner: &str, repo: &str) -> std::path::PathBuf {
+    crate::config::data_dir()
+        .join("triage")
+        .join(owner)
+        .join(format!("{repo}.json"))
+}
+
+/// Loads a repository's triage checkpoint, or `None` if there is none or it
+/// cannot be read.
+#[must_use]
+pub fn load_triage_checkpoint(owner: &str, repo: &str) -> Option<TriageCheckpoint> {
+    let contents = std::fs::read_to_string(triage_checkpoint_path(owner, repo)).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Saves a repository's triage checkpoint, creating parent directories as needed.
+pub fn save_triage_checkpoint(
+    owner: &str,
+    repo: &str,
+    checkpoint: &TriageCheckpoint,
+) -> Result<()> {
+    let path = triage_checkpoint_path(owner, repo);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+    let contents = serde_json::to_string_pretty(checkpoint)
+        .context("Failed to serialize triage checkpoint")?;
+    std::fs::write(&path, contents)
+        .with_context(|| format!("Failed to write triage checkpoint: {}", path.display()))
+}
+
+/// Removes a repository's triage checkpoint, if any.
+pub fn clear_triage_checkpoint(owner: &str, repo: &str) {
+    let _ = std::fs::remove_file(triage_checkpoint_path(owner, repo));
+}
+
 #[cfg(test)]
 mod fetch_issues_needing_triage_tests {
     #[test]
@@ -1133,6 +1387,49 @@ mod fetch_issues_needing_triage_tests {
 
         assert!(!passes);
     }
+
+    #[test]
+    fn triage_checkpoint_resumes_only_the_same_sweep() {
+        let saved = super::TriageCheckpoint {
+            since: "2026-01-01T00:00:00Z".to_string(),
+            state: "open".to_string(),
+            force: false,
+            cursor: Some("Y3Vyc29yOjEwMA==".to_string()),
+            processed: 100,
+            failed: vec![42],
+        };
+        let fresh = super::TriageCheckpoint {
+            cursor: None,
+            processed: 0,
+            failed: Vec::new(),
+            ..saved.clone()
+        };
+
+        assert!(saved.same_sweep(&fresh));
+        assert!(!saved.same_sweep(&super::TriageCheckpoint {
+            state: "all".to_string(),
+            ..fresh.clone()
+        }));
+        assert!(!saved.same_sweep(&super::TriageCheckpoint {
+            force: true,
+            ..fresh
+        }));
+
+        let json = serde_json::to_string(&saved).unwrap();
+        assert_eq!(
+            serde_json::from_str::<super::TriageCheckpoint>(&json).unwrap(),
+            saved
+        );
+
+        // Checkpoints written before failures were tracked still load
+        let legacy = r#"{"since":"2026-01-01T00:00:00Z","state":"open","force":false,"cursor":null,"processed":3}"#;
+        assert!(
+            serde_json::from_str::<super::TriageCheckpoint>(legacy)
+                .unwrap()
+                .failed
+                .is_empty()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -1242,7 +1539,7 @@ mod merge_labels_tests {
     fn preserves_existing_and_adds_new() {
         let existing = vec!["bug".to_string(), "enhancement".to_string()];
         let suggested = vec!["documentation".to_string()];
-        let merged = merge_labels(&existing, &suggested);
+        let merged = merge_labels(&existing, &suggested, &[]);
         assert_eq!(merged.len(), 3);
         assert!(merged.contains(&"bug".to_string()));
         assert!(merged.contains(&"enhancement".to_string()));
@@ -1253,7 +1550,7 @@ mod merge_labels_tests {
     fn deduplicates_case_insensitive() {
         let existing = vec!["Bug".to_string()];
         let suggested = vec!["bug".to_string(), "enhancement".to_string()];
-        let merged = merge_labels(&existing, &suggested);
+        let merged = merge_labels(&existing, &suggested, &[]);
         assert_eq!(merged.len(), 2);
         assert!(merged.contains(&"Bug".to_string()));
         assert!(merged.contains(&"enhancement".to_string()));
@@ -1264,7 +1561,7 @@ mod merge_labels_tests {
         // P1 (uppercase) exists, p2 suggested - should keep P1, skip p2, add bug
         let existing = vec!["P1".to_string()];
         let suggested = vec!["p2".to_string(), "bug".to_string()];
-        let merged = merge_labels(&existing, &suggested);
+        let merged = merge_labels(&existing, &suggested, &[]);
         assert_eq!(merged.len(), 2);
         assert!(merged.contains(&"P1".to_string()));
         assert!(merged.contains(&"bug".to_string()));
@@ -1274,11 +1571,11 @@ mod merge_labels_tests {
     #[test]
     fn handles_empty_inputs() {
         // Empty existing: suggested labels pass through
-        let merged = merge_labels(&[], &["bug".to_string(), "p1".to_string()]);
+        let merged = merge_labels(&[], &["bug".to_string(), "p1".to_string()], &[]);
         assert_eq!(merged.len(), 2);
 
         // Empty suggested: existing labels preserved
-        let merged = merge_labels(&["bug".to_string()], &[]);
+        let merged = merge_labels(&["bug".to_string()], &[], &[]);
         assert_eq!(merged.len(), 1);
         assert!(merged.contains(&"bug".to_string()));
     }
@@ -1291,7 +1588,7 @@ mod merge_labels_tests {
             "help wanted".to_string(),
             "bug".to_string(),
         ];
-        let merged = merge_labels(&existing, &suggested);
+        let merged = merge_labels(&existing, &suggested, &[]);
         assert_eq!(merged.len(), 1);
         assert!(merged.contains(&"bug".to_string()));
         assert!(!merged.contains(&"good first issue".to_string()));
@@ -1306,7 +1603,7 @@ mod merge_labels_tests {
             "HELP WANTED".to_string(),
             "enhancement".to_string(),
         ];
-        let merged = merge_labels(&existing, &suggested);
+        let merged = merge_labels(&existing, &suggested, &[]);
         assert_eq!(merged.len(), 1);
         assert!(merged.contains(&"enhancement".to_string()));
         assert!(!merged.contains(&"Good First Issue".to_string()));
@@ -1318,7 +1615,7 @@ mod merge_labels_tests {
         // priority: high exists, priority: medium suggested - should keep priority: high, skip priority: medium, add bug
         let existing = vec!["priority: high".to_string()];
         let suggested = vec!["priority: medium".to_string(), "bug".to_string()];
-        let merged = merge_labels(&existing, &suggested);
+        let merged = merge_labels(&existing, &suggested, &[]);
         assert_eq!(merged.len(), 2);
         assert!(merged.contains(&"priority: high".to_string()));
         assert!(merged.contains(&"bug".to_string()));
@@ -1330,12 +1627,21 @@ mod merge_labels_tests {
         // p1 exists, priority: high suggested - should keep p1, skip priority: high, add bug
         let existing = vec!["p1".to_string()];
         let suggested = vec!["priority: high".to_string(), "bug".to_string()];
-        let merged = merge_labels(&existing, &suggested);
+        let merged = merge_labels(&existing, &suggested, &[]);
         assert_eq!(merged.len(), 2);
         assert!(merged.contains(&"p1".to_string()));
         assert!(merged.contains(&"bug".to_string()));
         assert!(!merged.contains(&"priority: high".to_string()));
     }
+
+    #[test]
+    fn forced_labels_bypass_maintainer_only_filter() {
+        let existing = vec!["bug".to_string()];
+        let suggested = vec!["help wanted".to_string()];
+        let forced = vec!["good first issue".to_string(), "BUG".to_string()];
+        let merged = merge_labels(&existing, &suggested, &forced);
+        assert_eq!(merged, vec!["bug", "good first issue"]);
+    }
 }
 
 #[cfg(test)]
@@ -8,11 +8,17 @@ use anyhow::{Context, Result};
 use tracing::debug;
 
 pub mod auth;
+pub mod dedupe;
 pub mod graphql;
 pub mod instructions;
+pub mod issue_index;
 pub mod issues;
+pub mod owners;
 pub mod pulls;
 pub mod ratelimit;
+pub mod split;
+pub mod stale;
+pub mod templates;
 
 // Re-export client creation function (wasm32: no GitHub client)
 #[cfg(not(target_arch = "wasm32"))]
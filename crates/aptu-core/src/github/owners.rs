@@ -0,0 +1,485 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Assignee suggestions from CODEOWNERS and commit history.
+//!
+//! Given the paths a triage names in `complexity.affected_areas`, suggests
+//! people who could own the work: code owners of those paths (the last
+//! matching CODEOWNERS rule wins, as on GitHub) and authors of recent commits
+//! touching them on the default branch. Code ownership outweighs a handful of
+//! commits; ties are broken by login.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+#[cfg(not(target_arch = "wasm32"))]
+use anyhow::{Context, Result};
+#[cfg(not(target_arch = "wasm32"))]
+use backon::Retryable;
+#[cfg(not(target_arch = "wasm32"))]
+use octocrab::Octocrab;
+use regex::Regex;
+use serde_json::{Value, json};
+#[cfg(not(target_arch = "wasm32"))]
+use tracing::{debug, instrument, warn};
+
+use crate::ai::types::SuggestedAssignee;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::retry::retry_backoff;
+
+/// Locations GitHub reads CODEOWNERS from, in precedence order.
+#[cfg(not(target_arch = "wasm32"))]
+const CODEOWNERS_PATHS: &[&str] = &[".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Maximum affected paths looked up per issue.
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_OWNER_PATHS: usize = 5;
+
+/// Commits read per path.
+const RECENT_COMMITS_PER_PATH: u32 = 20;
+
+/// Maximum assignees suggested per issue.
+pub const MAX_SUGGESTED_ASSIGNEES: usize = 3;
+
+/// Score for owning a path, relative to one recent commit to it.
+const CODEOWNER_WEIGHT: usize = 5;
+
+/// A single CODEOWNERS rule.
+#[derive(Debug, Clone)]
+pub struct CodeOwnersRule {
+    /// The path pattern as written.
+    pub pattern: String,
+    /// User owners, without the `@`. Teams and email owners cannot be
+    /// assigned to issues and are dropped.
+    pub owners: Vec<String>,
+    regex: Regex,
+}
+
+impl CodeOwnersRule {
+    /// Whether the rule's pattern matches a repository-relative path.
+    #[must_use]
+    pub fn matches(&self, path: &str) -> bool {
+        self.regex.is_match(path)
+    }
+}
+
+/// Parses a CODEOWNERS file. Rules are kept in file order, including rules
+/// without assignable owners, since a later rule can clear ownership.
+#[must_use]
+pub fn parse_codeowners(content: &str) -> Vec<CodeOwnersRule> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let line = line.split('#').next().unwrap_or_default();
+            let mut fields = line.split_whitespace();
+            let pattern = fields.next()?;
+            let owners = fields
+                .filter_map(|owner| owner.strip_prefix('@'))
+                .filter(|owner| !owner.contains('/'))
+                .map(str::to_string)
+                .collect();
+            Some(CodeOwnersRule {
+                pattern: pattern.to_string(),
+                owners,
+                regex: pattern_regex(pattern)?,
+            })
+        })
+        .collect()
+}
+
+/// Translates a CODEOWNERS (gitignore-style) pattern into a regex over
+/// repository-relative paths.
+pub(crate) fn pattern_regex(pattern: &str) -> Option<Regex> {
+    // A slash at the start or in the middle anchors the pattern to the root
+    let anchored = pattern.starts_with('/') || pattern.trim_end_matches('/').contains('/');
+    let body = pattern.trim_start_matches('/').trim_end_matches('/');
+    if body.is_empty() {
+        return None;
+    }
+
+    let mut re = String::from(if anchored { "^" } else { "^(?:.*/)?" });
+    let mut chars = body.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    re.push_str("(?:.*/)?");
+                } else {
+                    re.push_str(".*");
+                }
+            }
+            '*' => re.push_str("[^/]*"),
+            '?' => re.push_str("[^/]"),
+            c => re.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    // `docs/*` owns only files directly in `docs/`; other patterns also own
+    // everything beneath a matching directory
+    if !body.ends_with("/*") {
+        re.push_str("(?:/.*)?");
+    }
+    re.push('$');
+
+    Regex::new(&re).ok()
+}
+
+/// Turns an affected area from the model into a repository-relative path,
+/// or `None` if it does not look like one (e.g. "authentication module").
+#[cfg(not(target_arch = "wasm32"))]
+fn normalize_path(area: &str) -> Option<String> {
+    let path = area
+        .trim()
+        .trim_matches('`')
+        .trim_start_matches("./")
+        .trim_matches('/');
+    (!path.is_empty() && !path.contains(char::is_whitespace)).then(|| path.to_string())
+}
+
+/// Evidence collected for one candidate assignee.
+#[derive(Default)]
+struct Evidence<'a> {
+    score: usize,
+    owned: Vec<&'a str>,
+    commits: Vec<(&'a str, usize)>,
+}
+
+impl Evidence<'_> {
+    fn reason(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.owned.is_empty() {
+            let paths: Vec<String> = self.owned.iter().map(|p| format!("`{p}`")).collect();
+            parts.push(format!("code owner of {}", paths.join(", ")));
+        }
+        for (path, count) in &self.commits {
+            let noun = if *count == 1 { "commit" } else { "commits" };
+            parts.push(format!("{count} recent {noun} to `{path}`"));
+        }
+        parts.join("; ")
+    }
+}
+
+/// Ranks candidate assignees for `paths`.
+///
+/// `committers[i]` lists the commit author logins (one entry per commit) for
+/// `paths[i]`. Bot accounts are ignored.
+#[must_use]
+pub fn rank_assignees(
+    paths: &[String],
+    rules: &[CodeOwnersRule],
+    committers: &[Vec<String>],
+) -> Vec<SuggestedAssignee> {
+    let mut candidates: BTreeMap<&str, Evidence<'_>> = BTreeMap::new();
+
+    for (i, path) in paths.iter().enumerate() {
+        if let Some(rule) = rules.iter().rev().find(|r| r.matches(path)) {
+            for owner in &rule.owners {
+                let evidence = candidates.entry(owner).or_default();
+                evidence.score += CODEOWNER_WEIGHT;
+                evidence.owned.push(path);
+            }
+        }
+
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for login in committers.get(i).into_iter().flatten() {
+            if !login.ends_with("[bot]") {
+                *counts.entry(login).or_default() += 1;
+            }
+        }
+        for (login, count) in counts {
+            let evidence = candidates.entry(login).or_default();
+            evidence.score += count;
+            evidence.commits.push((path, count));
+        }
+    }
+
+    // Stable sort keeps logins alphabetical within a score
+    let mut ranked: Vec<(&str, Evidence<'_>)> = candidates.into_iter().collect();
+    ranked.sort_by_key(|(_, evidence)| std::cmp::Reverse(evidence.score));
+
+    ranked
+        .into_iter()
+        .take(MAX_SUGGESTED_ASSIGNEES)
+        .map(|(login, evidence)| SuggestedAssignee {
+            login: login.to_string(),
+            reason: evidence.reason(),
+        })
+        .collect()
+}
+
+/// Builds a GraphQL query for recent commit authors of each path on the
+/// default branch, one aliased `history` connection per path.
+fn build_history_query(owner: &str, repo: &str, paths: &[String]) -> Value {
+    let histories = paths
+        .iter()
+        .enumerate()
+        .fold(String::new(), |mut out, (i, path)| {
+            let path = serde_json::to_string(path).unwrap_or_default();
+            let _ = writeln!(
+                out,
+                "p{i}: history(first: {RECENT_COMMITS_PER_PATH}, path: {path}) {{ nodes {{ author {{ user {{ login }} }} }} }}"
+            );
+            out
+        });
+
+    let query = format!(
+        r#"query {{
+            repository(owner: "{owner}", name: "{repo}") {{
+                defaultBranchRef {{
+                    target {{
+                        ... on Commit {{
+                            {histories}
+                        }}
+                    }}
+                }}
+            }}
+        }}"#
+    );
+
+    json!({ "query": query })
+}
+
+/// Reads the per-path commit author logins from a history query response.
+/// Commits whose author is not linked to a GitHub user are skipped.
+fn parse_history(data: &Value, count: usize) -> Vec<Vec<String>> {
+    let target = data.pointer("/repository/defaultBranchRef/target");
+    (0..count)
+        .map(|i| {
+            target
+                .and_then(|t| t.get(format!("p{i}")))
+                .and_then(|history| history.get("nodes"))
+                .and_then(Value::as_array)
+                .map(|nodes| {
+                    nodes
+                        .iter()
+                        .filter_map(|n| n.pointer("/author/user/login").and_then(Value::as_str))
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Fetches and parses the repository's CODEOWNERS file from the default
+/// branch, trying each location GitHub supports. Returns no rules if none
+/// exists.
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(client), fields(owner = %owner, repo = %repo))]
+pub async fn fetch_codeowners(client: &Octocrab, owner: &str, repo: &str) -> Vec<CodeOwnersRule> {
+    for path in CODEOWNERS_PATHS {
+        if let Some(content) =
+            super::instructions::fetch_file_content(client, owner, repo, path, "HEAD").await
+        {
+            debug!(path, "Fetched CODEOWNERS");
+            return parse_codeowners(&content);
+        }
+    }
+    debug!("No CODEOWNERS file found");
+    Vec::new()
+}
+
+/// Fetches recent commit author logins for each path.
+#[cfg(not(target_arch = "wasm32"))]
+async fn fetch_recent_committers(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    paths: &[String],
+) -> Result<Vec<Vec<String>>> {
+    let query = build_history_query(owner, repo, paths);
+    let response: Value =
+        (|| async { client.graphql(&query).await.map_err(|e| anyhow::anyhow!(e)) })
+            .retry(retry_backoff())
+            .notify(|err, dur| {
+                tracing::warn!(
+                    error = %err,
+                    retry_after = ?dur,
+                    "Retrying fetch_recent_committers (GraphQL query)"
+                );
+            })
+            .await
+            .context("Failed to execute GraphQL query")?;
+
+    if let Some(errors) = response.get("errors") {
+        let error_msg = serde_json::to_string_pretty(errors).unwrap_or_default();
+        anyhow::bail!("GraphQL error: {error_msg}");
+    }
+
+    let data = response
+        .get("data")
+        .context("Missing 'data' field in GraphQL response")?;
+    Ok(parse_history(data, paths.len()))
+}
+
+/// Suggests assignees for an issue from the areas its triage says are
+/// affected.
+///
+/// Best effort: a missing CODEOWNERS file or failed history lookup narrows
+/// the evidence rather than failing, and areas that are not paths are
+/// ignored.
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(client, affected_areas), fields(owner = %owner, repo = %repo))]
+pub async fn suggest_assignees(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    affected_areas: &[String],
+) -> Vec<SuggestedAssignee> {
+    let mut paths: Vec<String> = Vec::new();
+    for path in affected_areas.iter().filter_map(|a| normalize_path(a)) {
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+    paths.truncate(MAX_OWNER_PATHS);
+    if paths.is_empty() {
+        return Vec::new();
+    }
+
+    let (rules, committers) = tokio::join!(
+        fetch_codeowners(client, owner, repo),
+        fetch_recent_committers(client, owner, repo, &paths)
+    );
+    let committers = committers.unwrap_or_else(|e| {
+        warn!(error = %e, "Failed to fetch commit history for assignee suggestions");
+        Vec::new()
+    });
+
+    let suggestions = rank_assignees(&paths, &rules, &committers);
+    debug!(count = suggestions.len(), "Suggested assignees");
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CODEOWNERS: &str = "\
+# Default owners
+*                       @lead
+*.md                    @docs-writer @org/docs-team
+/crates/aptu-core/      @core-dev
+docs/*                  @docs-writer
+/crates/aptu-core/src/ai/ @ai-dev alice@example.com
+/vendor/
+";
+
+    #[test]
+    fn parse_codeowners_keeps_user_owners_only() {
+        let rules = parse_codeowners(CODEOWNERS);
+
+        assert_eq!(rules.len(), 6);
+        assert_eq!(rules[1].owners, vec!["docs-writer".to_string()]);
+        assert_eq!(rules[4].owners, vec!["ai-dev".to_string()]);
+        assert!(rules[5].owners.is_empty());
+    }
+
+    #[test]
+    fn last_matching_rule_wins() {
+        let rules = parse_codeowners(CODEOWNERS);
+        let owners = |path: &str| {
+            rules
+                .iter()
+                .rev()
+                .find(|r| r.matches(path))
+                .map(|r| r.owners.clone())
+                .unwrap_or_default()
+        };
+
+        assert_eq!(
+            owners("crates/aptu-core/src/ai/provider/mod.rs"),
+            ["ai-dev"]
+        );
+        assert_eq!(owners("crates/aptu-core/src/retry.rs"), ["core-dev"]);
+        assert_eq!(owners("crates/aptu-cli/README.md"), ["docs-writer"]);
+        assert_eq!(owners("docs/CONFIGURATION.md"), ["docs-writer"]);
+        assert_eq!(owners("crates/aptu-cli/src/main.rs"), ["lead"]);
+        assert!(owners("vendor/lib.rs").is_empty());
+    }
+
+    #[test]
+    fn pattern_regex_follows_codeowners_semantics() {
+        let matches = |pattern: &str, path: &str| pattern_regex(pattern).unwrap().is_match(path);
+
+        // `docs/*` covers direct children only
+        assert!(matches("docs/*", "docs/guide.md"));
+        assert!(!matches("docs/*", "docs/api/guide.md"));
+        // Unanchored directory patterns match at any depth
+        assert!(matches("apps/", "web/apps/main.js"));
+        assert!(!matches("/apps/", "web/apps/main.js"));
+        // `**` spans directories
+        assert!(matches("**/logs", "build/deep/logs/today.txt"));
+        assert!(matches("src/**/test.rs", "src/a/b/test.rs"));
+        assert!(!matches("*.rs", "src/main.rsx"));
+    }
+
+    #[test]
+    fn normalize_path_rejects_prose() {
+        assert_eq!(
+            normalize_path("`./crates/aptu-core/src/ai/`"),
+            Some("crates/aptu-core/src/ai".to_string())
+        );
+        assert_eq!(normalize_path("authentication module"), None);
+        assert_eq!(normalize_path("  "), None);
+    }
+
+    #[test]
+    fn rank_assignees_weighs_ownership_over_commits() {
+        let rules = parse_codeowners(CODEOWNERS);
+        let paths = vec![
+            "crates/aptu-core/src/ai".to_string(),
+            "crates/aptu-core/src/retry.rs".to_string(),
+        ];
+        let committers = vec![
+            vec![
+                "bob".to_string(),
+                "bob".to_string(),
+                "dependabot[bot]".to_string(),
+            ],
+            vec!["carol".to_string(), "bob".to_string()],
+        ];
+
+        let ranked = rank_assignees(&paths, &rules, &committers);
+
+        assert_eq!(ranked.len(), MAX_SUGGESTED_ASSIGNEES);
+        assert_eq!(ranked[0].login, "ai-dev");
+        assert_eq!(ranked[0].reason, "code owner of `crates/aptu-core/src/ai`");
+        assert_eq!(ranked[1].login, "core-dev");
+        assert_eq!(ranked[2].login, "bob");
+        assert_eq!(
+            ranked[2].reason,
+            "2 recent commits to `crates/aptu-core/src/ai`; 1 recent commit to `crates/aptu-core/src/retry.rs`"
+        );
+    }
+
+    #[test]
+    fn parse_history_reads_aliased_connections() {
+        let data = json!({
+            "repository": {
+                "defaultBranchRef": {
+                    "target": {
+                        "p0": { "nodes": [
+                            { "author": { "user": { "login": "alice" } } },
+                            { "author": { "user": null } }
+                        ] },
+                        "p1": { "nodes": [] }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(
+            parse_history(&data, 3),
+            vec![vec!["alice".to_string()], vec![], vec![]]
+        );
+    }
+
+    #[test]
+    fn build_history_query_escapes_paths() {
+        let query = build_history_query("o", "r", &["src/\"odd\".rs".to_string()]);
+        let query_str = query["query"].as_str().unwrap();
+
+        assert!(query_str.contains(r#"p0: history(first: 20, path: "src/\"odd\".rs")"#));
+    }
+}
@@ -130,6 +130,7 @@ pub async fn fetch_pr_details(
     loop {
         pr_files.extend(page.items.into_iter().map(|f| PrFile {
             filename: f.filename,
+            previous_filename: f.previous_filename,
             status: format!("{:?}", f.status),
             additions: f.additions,
             deletions: f.deletions,
@@ -339,6 +340,7 @@ pub async fn fetch_pr_details(
         base_branch: pr.base.ref_field.clone(),
         head_branch: pr.head.ref_field.clone(),
         head_sha: pr.head.sha.as_str().to_string(),
+        base_sha: pr.base.sha.as_str().to_string(),
         files: pr_files,
         url: pr
             .html_url
@@ -1223,6 +1225,7 @@ mod tests {
         // Files with removed status or no patch should be skipped.
         let removed_file = PrFile {
             filename: "removed.rs".to_string(),
+            previous_filename: None,
             status: "removed".to_string(),
             additions: 0,
             deletions: 5,
@@ -1232,6 +1235,7 @@ mod tests {
         };
         let modified_file = PrFile {
             filename: "file_0.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 1,
             deletions: 0,
@@ -1241,6 +1245,7 @@ mod tests {
         };
         let no_patch_file = PrFile {
             filename: "file_1.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 1,
             deletions: 0,
@@ -1345,6 +1350,7 @@ mod tests {
         for i in 0..100 {
             page1_items.push(PrFile {
                 filename: format!("file{}.rs", i),
+                previous_filename: None,
                 status: "modified".to_string(),
                 additions: 1,
                 deletions: 0,
@@ -1359,6 +1365,7 @@ mod tests {
         for i in 100..150 {
             page2_items.push(PrFile {
                 filename: format!("file{}.rs", i),
+                previous_filename: None,
                 status: "modified".to_string(),
                 additions: 1,
                 deletions: 0,
@@ -1388,6 +1395,7 @@ mod tests {
         for i in 0..301 {
             files.push(PrFile {
                 filename: format!("file{}.rs", i),
+                previous_filename: None,
                 status: "modified".to_string(),
                 additions: 1,
                 deletions: 0,
@@ -1492,6 +1500,7 @@ mod tests {
         // Test: Added file status is matched case-insensitively
         let file = PrFile {
             filename: "new.rs".to_string(),
+            previous_filename: None,
             status: "Added".to_string(), // Debug repr from Octocrab
             additions: 50,
             deletions: 0,
@@ -1512,6 +1521,7 @@ mod tests {
         // Test: Modified file status is NOT matched (edge case)
         let file = PrFile {
             filename: "existing.rs".to_string(),
+            previous_filename: None,
             status: "Modified".to_string(),
             additions: 10,
             deletions: 5,
@@ -1549,6 +1559,7 @@ mod tests {
         // Test: File with full_content already populated should skip Contents API call
         let file = PrFile {
             filename: "new.rs".to_string(),
+            previous_filename: None,
             status: "Added".to_string(),
             additions: 50,
             deletions: 0,
@@ -1575,6 +1586,7 @@ mod tests {
 
         let file = PrFile {
             filename: "new.rs".to_string(),
+            previous_filename: None,
             status: "Added".to_string(),
             additions: 50,
             deletions: 0,
@@ -1603,6 +1615,7 @@ mod tests {
         // pair it with content = None (simulating fetch_file_contents returning None beyond the cap)
         let mut file = PrFile {
             filename: "test.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 5,
             deletions: 2,
@@ -1626,6 +1639,7 @@ mod tests {
         // Arrange: PrFile with full_content = None, content = Some("fetched content")
         let mut file = PrFile {
             filename: "test.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 5,
             deletions: 2,
@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Splitting an issue into linked sub-issues, and the record that lets it be
+//! undone.
+//!
+//! Every issue split by `aptu issue split` gets a [`SplitRecord`] in
+//! `~/.local/share/aptu/split/<owner>/<repo>.json`, keyed by the parent issue.
+//! `revert_issue` consults the log to close the child issues aptu created,
+//! then drops the record.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+#[cfg(not(target_arch = "wasm32"))]
+use octocrab::Octocrab;
+use serde::{Deserialize, Serialize};
+#[cfg(not(target_arch = "wasm32"))]
+use tracing::{debug, instrument};
+
+use crate::ai::types::SubIssueTask;
+
+/// What aptu did to an issue when splitting it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SplitRecord {
+    /// Child issues created, in task order.
+    pub children: Vec<u64>,
+    /// Whether the children were linked with GitHub's sub-issues API.
+    pub linked: bool,
+    /// URL of the task list comment, when the sub-issues API was unavailable.
+    #[serde(default)]
+    pub comment_url: Option<String>,
+    /// When the issue was split.
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Returns the path of a repository's split log
+/// (`~/.local/share/aptu/split/<owner>/<repo>.json`).
+#[must_use]
+pub fn split_log_path(owner: &str, repo: &str) -> PathBuf {
+    crate::config::data_dir()
+        .join("split")
+        .join(owner)
+        .join(format!("{repo}.json"))
+}
+
+/// Loads a repository's split log keyed by parent issue number, or an empty
+/// log if there is none or it cannot be read.
+#[must_use]
+pub fn load_split_log(owner: &str, repo: &str) -> BTreeMap<u64, SplitRecord> {
+    std::fs::read_to_string(split_log_path(owner, repo))
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Saves a repository's split log, creating parent directories as needed.
+///
+/// # Errors
+///
+/// Returns an error if the log cannot be serialized or written.
+pub fn save_split_log(owner: &str, repo: &str, log: &BTreeMap<u64, SplitRecord>) -> Result<()> {
+    let path = split_log_path(owner, repo);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+    let contents = serde_json::to_string_pretty(log).context("Failed to serialize split log")?;
+    std::fs::write(&path, contents)
+        .with_context(|| format!("Failed to write split log: {}", path.display()))
+}
+
+/// Records that aptu split `parent` into child issues.
+///
+/// Children from an earlier split of the same parent are kept, so a revert
+/// closes all of them.
+///
+/// # Errors
+///
+/// Returns an error if the log cannot be written.
+pub fn record_split(owner: &str, repo: &str, parent: u64, mut record: SplitRecord) -> Result<()> {
+    let mut log = load_split_log(owner, repo);
+    if let Some(earlier) = log.remove(&parent) {
+        let mut children = earlier.children;
+        children.extend(record.children);
+        record.children = children;
+    }
+    log.insert(parent, record);
+    save_split_log(owner, repo, &log)
+}
+
+/// Removes and returns the split record for `parent`, if any.
+///
+/// # Errors
+///
+/// Returns an error if the log cannot be written.
+pub fn take_split_record(owner: &str, repo: &str, parent: u64) -> Result<Option<SplitRecord>> {
+    let mut log = load_split_log(owner, repo);
+    let record = log.remove(&parent);
+    if record.is_some() {
+        save_split_log(owner, repo, &log)?;
+    }
+    Ok(record)
+}
+
+/// Replaces the children recorded for `parent` with `children`, dropping the
+/// record when none are left.
+///
+/// Used when a revert closes only some of the children, so the rest are
+/// still closed by the next revert.
+///
+/// # Errors
+///
+/// Returns an error if the log cannot be written.
+pub fn retain_split_children(owner: &str, repo: &str, parent: u64, children: &[u64]) -> Result<()> {
+    let mut log = load_split_log(owner, repo);
+    if children.is_empty() {
+        log.remove(&parent);
+    } else if let Some(record) = log.get_mut(&parent) {
+        record.children = children.to_vec();
+    } else {
+        return Ok(());
+    }
+    save_split_log(owner, repo, &log)
+}
+
+/// Renders the body of a child issue.
+///
+/// `dependencies` are the issue numbers of the tasks this one depends on.
+#[must_use]
+pub fn render_sub_issue_body(task: &SubIssueTask, parent: u64, dependencies: &[u64]) -> String {
+    let mut body = String::new();
+    if !task.body.trim().is_empty() {
+        body.push_str(task.body.trim());
+        body.push_str("\n\n");
+    }
+    let _ = writeln!(body, "Part of #{parent}");
+    if !dependencies.is_empty() {
+        let refs: Vec<String> = dependencies.iter().map(|n| format!("#{n}")).collect();
+        let _ = writeln!(body, "Depends on {}", refs.join(", "));
+    }
+    body.push_str("\n---\n*");
+    body.push_str(crate::triage::APTU_SIGNATURE);
+    body.push_str("*\n");
+    body
+}
+
+/// Renders the task list comment posted on the parent issue when the
+/// sub-issues API is unavailable.
+#[must_use]
+pub fn render_tasklist_comment(children: &[(u64, String)]) -> String {
+    let mut comment = String::from("Split into sub-issues:\n\n");
+    for (number, title) in children {
+        let _ = writeln!(comment, "- [ ] #{number} {title}");
+    }
+    comment.push_str("\n---\n*");
+    comment.push_str(crate::triage::APTU_SIGNATURE);
+    comment.push_str("*\n");
+    comment
+}
+
+/// Links `child` to `parent` with GitHub's sub-issues API.
+///
+/// # Errors
+///
+/// Returns an error if the child cannot be fetched or the API rejects the
+/// link (for example, when sub-issues are not enabled for the repository).
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(client), fields(owner = %owner, repo = %repo, parent, child))]
+pub async fn add_sub_issue(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    parent: u64,
+    child: u64,
+) -> Result<()> {
+    // The API takes the child's database id, not its number
+    let child_issue = client
+        .issues(owner, repo)
+        .get(child)
+        .await
+        .with_context(|| format!("Failed to fetch issue #{child} in {owner}/{repo}"))?;
+
+    let route = format!("/repos/{owner}/{repo}/issues/{parent}/sub_issues");
+    let payload = serde_json::json!({ "sub_issue_id": child_issue.id.0 });
+    client
+        .post::<_, serde_json::Value>(route, Some(&payload))
+        .await
+        .with_context(|| format!("Failed to add #{child} as a sub-issue of #{parent}"))?;
+
+    debug!("Sub-issue linked");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_sub_issue_body_links_parent_and_dependencies() {
+        let task = SubIssueTask {
+            title: "Implement flow".to_string(),
+            body: "Add the callback handler.".to_string(),
+            labels: vec![],
+            depends_on: vec![1],
+        };
+        let body = render_sub_issue_body(&task, 42, &[43, 44]);
+        assert!(body.starts_with("Add the callback handler.\n\nPart of #42\n"));
+        assert!(body.contains("Depends on #43, #44\n"));
+        assert!(body.contains(crate::triage::APTU_SIGNATURE));
+
+        let body = render_sub_issue_body(&task, 42, &[]);
+        assert!(!body.contains("Depends on"));
+    }
+
+    #[test]
+    fn render_tasklist_comment_lists_children() {
+        let comment = render_tasklist_comment(&[
+            (43, "Add config".to_string()),
+            (44, "Add flow".to_string()),
+        ]);
+        assert!(comment.contains("- [ ] #43 Add config\n- [ ] #44 Add flow\n"));
+    }
+}
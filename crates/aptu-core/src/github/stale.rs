@@ -0,0 +1,738 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Finding inactive issues and pull requests, and deciding what to do with
+//! them.
+//!
+//! Candidates come from two GitHub searches: open items not updated since the
+//! shortest configured inactivity window, and open items already carrying the
+//! stale label. [`plan_stale_action`] then decides per item whether to nudge,
+//! close, unmark, or leave it, based on [`StaleConfig`] and the item's recent
+//! activity. aptu's nudge comments carry [`STALE_MARKER`] so the grace period
+//! can be measured from the nudge rather than from the label.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+#[cfg(not(target_arch = "wasm32"))]
+use backon::Retryable;
+use chrono::{DateTime, Utc};
+#[cfg(not(target_arch = "wasm32"))]
+use octocrab::Octocrab;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+#[cfg(not(target_arch = "wasm32"))]
+use tracing::{debug, instrument};
+
+use crate::config::StaleConfig;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::retry::retry_backoff;
+
+/// Hidden marker included in every stale nudge comment.
+pub const STALE_MARKER: &str = "<!-- aptu:stale -->";
+
+/// Number of items fetched per search page.
+const STALE_PAGE_SIZE: u32 = 50;
+
+/// Maximum search pages fetched per search; GitHub caps search results at
+/// 1000 anyway.
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_STALE_PAGES: usize = 10;
+
+/// Number of most recent comments fetched per item.
+const RECENT_COMMENTS: u32 = 10;
+
+/// aptu applies the stale label right after posting the nudge, which bumps
+/// the item's `updatedAt`; updates this close to the nudge are its own.
+const NUDGE_SETTLE_MINUTES: i64 = 10;
+
+/// Kind of item swept for staleness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StaleKind {
+    /// Issues.
+    Issue,
+    /// Pull requests.
+    PullRequest,
+}
+
+impl StaleKind {
+    /// GitHub search qualifier for this kind.
+    fn qualifier(self) -> &'static str {
+        match self {
+            Self::Issue => "is:issue",
+            Self::PullRequest => "is:pr",
+        }
+    }
+
+    /// Human-readable name, as used in prompts and comments.
+    #[must_use]
+    pub fn noun(self) -> &'static str {
+        match self {
+            Self::Issue => "issue",
+            Self::PullRequest => "pull request",
+        }
+    }
+}
+
+/// A comment on a stale candidate.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StaleComment {
+    /// Comment ID.
+    #[serde(rename = "databaseId", default)]
+    pub id: u64,
+    /// Comment author login (empty for deleted accounts).
+    #[serde(default, deserialize_with = "deserialize_login")]
+    pub author: String,
+    /// Comment body.
+    pub body: String,
+    /// When the comment was created.
+    pub created_at: DateTime<Utc>,
+    /// When the comment was last edited, if ever.
+    #[serde(default)]
+    pub last_edited_at: Option<DateTime<Utc>>,
+}
+
+/// An open issue or pull request that may be stale.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StaleItem {
+    /// Issue or PR number.
+    pub number: u64,
+    /// Title.
+    pub title: String,
+    /// Body.
+    #[serde(default, deserialize_with = "deserialize_body")]
+    pub body: String,
+    /// URL.
+    pub url: String,
+    /// Author login (empty for deleted accounts).
+    #[serde(default, deserialize_with = "deserialize_login")]
+    pub author: String,
+    /// Label names.
+    #[serde(deserialize_with = "deserialize_label_names")]
+    pub labels: Vec<String>,
+    /// Last update time.
+    pub updated_at: DateTime<Utc>,
+    /// When the body was last edited, if ever.
+    #[serde(default)]
+    pub last_edited_at: Option<DateTime<Utc>>,
+    /// Commit date of a pull request's head commit (`None` for issues).
+    #[serde(
+        rename = "commits",
+        default,
+        deserialize_with = "deserialize_head_commit_date"
+    )]
+    pub head_committed_at: Option<DateTime<Utc>>,
+    /// Most recent comments, oldest first.
+    #[serde(deserialize_with = "deserialize_comments")]
+    pub comments: Vec<StaleComment>,
+}
+
+/// What the sweep should do with a stale candidate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "action")]
+pub enum StaleAction {
+    /// Post a nudge and apply the stale label.
+    Nudge {
+        /// Days since the item was last updated.
+        inactive_days: i64,
+    },
+    /// Close the item; the grace period after the nudge has passed.
+    Close {
+        /// Days since the item was marked stale.
+        stale_days: i64,
+    },
+    /// Remove the stale label; the item saw activity after the nudge.
+    Unmark,
+}
+
+fn deserialize_login<'de, D>(deserializer: D) -> std::result::Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Author {
+        login: String,
+    }
+    Ok(Option::<Author>::deserialize(deserializer)?
+        .map(|a| a.login)
+        .unwrap_or_default())
+}
+
+fn deserialize_body<'de, D>(deserializer: D) -> std::result::Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+fn deserialize_label_names<'de, D>(deserializer: D) -> std::result::Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Label {
+        name: String,
+    }
+    #[derive(Deserialize)]
+    struct Labels {
+        nodes: Vec<Label>,
+    }
+    Ok(Labels::deserialize(deserializer)?
+        .nodes
+        .into_iter()
+        .map(|l| l.name)
+        .collect())
+}
+
+fn deserialize_comments<'de, D>(deserializer: D) -> std::result::Result<Vec<StaleComment>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Comments {
+        nodes: Vec<StaleComment>,
+    }
+    Ok(Comments::deserialize(deserializer)?.nodes)
+}
+
+fn deserialize_head_commit_date<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Commit {
+        committed_date: DateTime<Utc>,
+    }
+    #[derive(Deserialize)]
+    struct Node {
+        commit: Commit,
+    }
+    #[derive(Deserialize)]
+    struct Commits {
+        nodes: Vec<Node>,
+    }
+    Ok(Commits::deserialize(deserializer)?
+        .nodes
+        .pop()
+        .map(|n| n.commit.committed_date))
+}
+
+/// One page of search results.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StaleSearchPage {
+    page_info: StalePageInfo,
+    nodes: Vec<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StalePageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+/// Builds the GitHub search string for open items of `kind` in a repository,
+/// narrowed by `filter` (e.g. `updated:<2026-01-01` or `label:"stale"`).
+fn stale_search_string(owner: &str, repo: &str, kind: StaleKind, filter: &str) -> String {
+    format!("repo:{owner}/{repo} {} is:open {filter}", kind.qualifier())
+}
+
+/// Builds a GraphQL search query for one page of stale candidates.
+fn build_stale_search_query(search: &str, after: Option<&str>) -> Value {
+    let search = search.replace('\\', "\\\\").replace('"', "\\\"");
+    let after = after.map_or_else(|| "null".to_string(), |c| format!("\"{c}\""));
+    let fields = format!(
+        r"number
+        title
+        body
+        url
+        updatedAt
+        lastEditedAt
+        author {{
+          login
+        }}
+        labels(first: 20) {{
+          nodes {{
+            name
+          }}
+        }}
+        comments(last: {RECENT_COMMENTS}) {{
+          nodes {{
+            databaseId
+            author {{
+              login
+            }}
+            body
+            createdAt
+            lastEditedAt
+          }}
+        }}"
+    );
+    let query = format!(
+        r#"query {{
+  search(query: "{search}", type: ISSUE, first: {STALE_PAGE_SIZE}, after: {after}) {{
+    pageInfo {{
+      hasNextPage
+      endCursor
+    }}
+    nodes {{
+      ... on Issue {{
+        {fields}
+      }}
+      ... on PullRequest {{
+        {fields}
+        commits(last: 1) {{
+          nodes {{
+            commit {{
+              committedDate
+            }}
+          }}
+        }}
+      }}
+    }}
+  }}
+}}"#
+    );
+
+    json!({ "query": query })
+}
+
+/// Parses a page of stale candidates from the `data` of a
+/// [`build_stale_search_query`] response.
+fn parse_stale_search(data: &Value) -> Result<(Vec<StaleItem>, StalePageInfo)> {
+    let search_val = data
+        .get("search")
+        .filter(|v| !v.is_null())
+        .context("Missing 'search' field in GraphQL response")?;
+    let page: StaleSearchPage =
+        serde_json::from_value(search_val.clone()).context("Failed to parse search page")?;
+    let items = page
+        .nodes
+        .into_iter()
+        .filter(|node| node.get("number").is_some())
+        .map(serde_json::from_value)
+        .collect::<std::result::Result<Vec<StaleItem>, _>>()
+        .context("Failed to parse search result")?;
+    Ok((items, page.page_info))
+}
+
+/// Decides what to do with a stale candidate at `now`, or `None` to leave it.
+///
+/// Items carrying an exempt label are left alone. An unlabeled item is nudged
+/// once it has been inactive for its label's window. A labeled item is
+/// unmarked if it saw activity after the nudge (see [`active_since`]), and
+/// closed once `days_until_close` days have passed since the nudge (or since
+/// its last update, if the label was applied by hand).
+#[must_use]
+pub fn plan_stale_action(
+    item: &StaleItem,
+    config: &StaleConfig,
+    now: DateTime<Utc>,
+) -> Option<StaleAction> {
+    let days = config.days_for(&item.labels)?;
+
+    if !item.labels.contains(&config.stale_label) {
+        let inactive_days = (now - item.updated_at).num_days();
+        return (inactive_days >= i64::from(days)).then_some(StaleAction::Nudge { inactive_days });
+    }
+
+    let nudge = item
+        .comments
+        .iter()
+        .rposition(|c| c.body.contains(STALE_MARKER));
+    let marked_at = match nudge {
+        Some(idx) if item.comments.len() > idx + 1 => return Some(StaleAction::Unmark),
+        Some(idx) => item.comments[idx].created_at,
+        None => item.updated_at,
+    };
+    if active_since(item, nudge, marked_at) {
+        return Some(StaleAction::Unmark);
+    }
+
+    let stale_days = (now - marked_at).num_days();
+    (config.days_until_close > 0 && stale_days >= i64::from(config.days_until_close))
+        .then_some(StaleAction::Close { stale_days })
+}
+
+/// Whether `item` changed after it was marked stale at `marked_at`: a later
+/// update, a body or comment edit, or a newer pull request head commit.
+///
+/// aptu's own nudge is ignored: the label it applies right after the comment
+/// (within [`NUDGE_SETTLE_MINUTES`]) and any edit of the nudge comment itself.
+fn active_since(item: &StaleItem, nudge: Option<usize>, marked_at: DateTime<Utc>) -> bool {
+    let settled = marked_at + chrono::Duration::minutes(NUDGE_SETTLE_MINUTES);
+    let after = |t: Option<DateTime<Utc>>| t.is_some_and(|t| t > marked_at);
+
+    item.updated_at > settled
+        || after(item.last_edited_at)
+        || after(item.head_committed_at)
+        || item
+            .comments
+            .iter()
+            .enumerate()
+            .any(|(idx, c)| Some(idx) != nudge && after(c.last_edited_at))
+}
+
+/// Returns the clarifying questions from aptu's most recent triage comment
+/// that the author has not replied to since.
+#[must_use]
+pub fn open_clarifying_questions(item: &StaleItem) -> Vec<String> {
+    let Some(idx) = item.comments.iter().rposition(|c| {
+        c.body.contains(crate::triage::APTU_SIGNATURE)
+            && c.body.contains("### Clarifying Questions")
+    }) else {
+        return Vec::new();
+    };
+    if item.comments[idx + 1..]
+        .iter()
+        .any(|c| !item.author.is_empty() && c.author == item.author)
+    {
+        return Vec::new();
+    }
+
+    item.comments[idx]
+        .body
+        .split("### Clarifying Questions")
+        .nth(1)
+        .unwrap_or_default()
+        .lines()
+        .skip(1)
+        .take_while(|line| !line.starts_with("###"))
+        .filter_map(|line| {
+            let (number, question) = line.trim().split_once(". ")?;
+            number
+                .chars()
+                .all(|c| c.is_ascii_digit())
+                .then(|| question.trim().to_string())
+        })
+        .collect()
+}
+
+/// Runs one search to completion (up to `MAX_STALE_PAGES` pages).
+#[cfg(not(target_arch = "wasm32"))]
+async fn search_stale_items(client: &Octocrab, search: &str) -> Result<Vec<StaleItem>> {
+    let mut items = Vec::new();
+    let mut after: Option<String> = None;
+
+    for _ in 0..MAX_STALE_PAGES {
+        let query = build_stale_search_query(search, after.as_deref());
+        let response: Value =
+            (|| async { client.graphql(&query).await.map_err(|e| anyhow::anyhow!(e)) })
+                .retry(retry_backoff())
+                .notify(|err, dur| {
+                    tracing::warn!(
+                        error = %err,
+                        retry_after = ?dur,
+                        "Retrying search_stale_items (GraphQL query)"
+                    );
+                })
+                .await
+                .context("Failed to execute GraphQL query")?;
+
+        if let Some(errors) = response.get("errors") {
+            let error_msg = serde_json::to_string_pretty(errors).unwrap_or_default();
+            anyhow::bail!("GraphQL error: {error_msg}");
+        }
+
+        let data = response
+            .get("data")
+            .context("Missing 'data' field in GraphQL response")?;
+        let (page, page_info) = parse_stale_search(data)?;
+        items.extend(page);
+
+        if !page_info.has_next_page {
+            break;
+        }
+        after = page_info.end_cursor;
+    }
+
+    Ok(items)
+}
+
+/// Fetches open items of `kind` that may be stale: those not updated within
+/// the shortest configured window, and those already labeled stale.
+///
+/// # Errors
+///
+/// Returns an error if a GraphQL search fails.
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(client, config), fields(owner = %owner, repo = %repo, kind = ?kind))]
+pub async fn fetch_stale_candidates(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    kind: StaleKind,
+    config: &StaleConfig,
+    now: DateTime<Utc>,
+) -> Result<Vec<StaleItem>> {
+    let cutoff = now - chrono::Duration::days(i64::from(config.min_days()));
+    let inactive = stale_search_string(
+        owner,
+        repo,
+        kind,
+        &format!("updated:<{}", cutoff.format("%Y-%m-%d")),
+    );
+    let marked = stale_search_string(
+        owner,
+        repo,
+        kind,
+        &format!("label:\"{}\"", config.stale_label),
+    );
+
+    let mut by_number = BTreeMap::new();
+    for search in [inactive, marked] {
+        for item in search_stale_items(client, &search).await? {
+            by_number.insert(item.number, item);
+        }
+    }
+
+    debug!(candidates = by_number.len(), "Fetched stale candidates");
+    Ok(by_number.into_values().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(days_ago: i64) -> DateTime<Utc> {
+        now() - chrono::Duration::days(days_ago)
+    }
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-06-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn comment(author: &str, body: &str, days_ago: i64) -> StaleComment {
+        StaleComment {
+            id: 1,
+            author: author.to_string(),
+            body: body.to_string(),
+            created_at: at(days_ago),
+            last_edited_at: None,
+        }
+    }
+
+    fn item(labels: &[&str], updated_days_ago: i64, comments: Vec<StaleComment>) -> StaleItem {
+        StaleItem {
+            number: 7,
+            title: "Crash on start".to_string(),
+            body: String::new(),
+            url: "https://github.com/o/r/issues/7".to_string(),
+            author: "reporter".to_string(),
+            labels: labels.iter().map(ToString::to_string).collect(),
+            updated_at: at(updated_days_ago),
+            last_edited_at: None,
+            head_committed_at: None,
+            comments,
+        }
+    }
+
+    #[test]
+    fn plan_nudges_items_inactive_past_their_window() {
+        let config = StaleConfig {
+            label_days: BTreeMap::from([("needs-info".to_string(), 14)]),
+            ..StaleConfig::default()
+        };
+
+        assert_eq!(
+            plan_stale_action(&item(&["bug"], 61, vec![]), &config, now()),
+            Some(StaleAction::Nudge { inactive_days: 61 })
+        );
+        assert_eq!(
+            plan_stale_action(&item(&["bug"], 30, vec![]), &config, now()),
+            None
+        );
+        assert_eq!(
+            plan_stale_action(&item(&["needs-info"], 30, vec![]), &config, now()),
+            Some(StaleAction::Nudge { inactive_days: 30 })
+        );
+        assert_eq!(
+            plan_stale_action(&item(&["pinned"], 300, vec![]), &config, now()),
+            None
+        );
+    }
+
+    #[test]
+    fn plan_closes_after_grace_period_from_nudge() {
+        let config = StaleConfig::default();
+        let nudge = comment("aptu", &format!("Still relevant? {STALE_MARKER}"), 15);
+
+        assert_eq!(
+            plan_stale_action(&item(&["stale"], 15, vec![nudge.clone()]), &config, now()),
+            Some(StaleAction::Close { stale_days: 15 })
+        );
+
+        let recent = comment("aptu", STALE_MARKER, 3);
+        assert_eq!(
+            plan_stale_action(&item(&["stale"], 3, vec![recent]), &config, now()),
+            None
+        );
+
+        let never_close = StaleConfig {
+            days_until_close: 0,
+            ..StaleConfig::default()
+        };
+        assert_eq!(
+            plan_stale_action(&item(&["stale"], 15, vec![nudge]), &never_close, now()),
+            None
+        );
+    }
+
+    #[test]
+    fn plan_unmarks_when_someone_replied_after_nudge() {
+        let config = StaleConfig::default();
+        let comments = vec![
+            comment("aptu", STALE_MARKER, 10),
+            comment("reporter", "Still happens on 2.0", 2),
+        ];
+
+        assert_eq!(
+            plan_stale_action(&item(&["stale"], 2, comments), &config, now()),
+            Some(StaleAction::Unmark)
+        );
+    }
+
+    #[test]
+    fn plan_unmarks_when_edited_after_nudge() {
+        let config = StaleConfig::default();
+        let nudge = comment("aptu", STALE_MARKER, 15);
+
+        let mut edited = item(&["stale"], 2, vec![nudge.clone()]);
+        edited.last_edited_at = Some(at(2));
+        assert_eq!(
+            plan_stale_action(&edited, &config, now()),
+            Some(StaleAction::Unmark)
+        );
+
+        let mut earlier = comment("reporter", "Crashes on 1.0", 20);
+        earlier.last_edited_at = Some(at(1));
+        assert_eq!(
+            plan_stale_action(&item(&["stale"], 1, vec![earlier, nudge]), &config, now()),
+            Some(StaleAction::Unmark)
+        );
+    }
+
+    #[test]
+    fn plan_unmarks_when_pushed_after_nudge() {
+        let config = StaleConfig::default();
+        let mut pushed = item(&["stale"], 1, vec![comment("aptu", STALE_MARKER, 15)]);
+        pushed.head_committed_at = Some(at(1));
+
+        assert_eq!(
+            plan_stale_action(&pushed, &config, now()),
+            Some(StaleAction::Unmark)
+        );
+    }
+
+    #[test]
+    fn plan_ignores_own_nudge_activity() {
+        let config = StaleConfig::default();
+        let mut nudge = comment("aptu", STALE_MARKER, 15);
+        nudge.last_edited_at = Some(at(14));
+        let mut labeled = item(&["stale"], 15, vec![nudge]);
+        // The stale label lands a minute after the nudge comment.
+        labeled.updated_at = at(15) + chrono::Duration::minutes(1);
+        labeled.head_committed_at = Some(at(40));
+
+        assert_eq!(
+            plan_stale_action(&labeled, &config, now()),
+            Some(StaleAction::Close { stale_days: 15 })
+        );
+    }
+
+    #[test]
+    fn open_clarifying_questions_from_unanswered_triage() {
+        let triage = comment(
+            "aptu",
+            &format!(
+                "### Clarifying Questions\n\n1. Which version?\n2. Which OS?\n\n### Potential Duplicates\n\n- #3\n\n---\n*{}*",
+                crate::triage::APTU_SIGNATURE
+            ),
+            40,
+        );
+
+        let unanswered = item(&[], 40, vec![triage.clone()]);
+        assert_eq!(
+            open_clarifying_questions(&unanswered),
+            vec!["Which version?".to_string(), "Which OS?".to_string()]
+        );
+
+        let answered = item(
+            &[],
+            30,
+            vec![triage, comment("reporter", "2.0 on Linux", 30)],
+        );
+        assert!(open_clarifying_questions(&answered).is_empty());
+    }
+
+    #[test]
+    fn build_stale_search_query_escapes_search_string() {
+        let search = stale_search_string("o", "r", StaleKind::PullRequest, "label:\"stale\"");
+        let query = build_stale_search_query(&search, Some("abc"));
+        let query_str = query["query"].as_str().unwrap();
+
+        assert!(query_str.contains(r#"query: "repo:o/r is:pr is:open label:\"stale\"""#));
+        assert!(query_str.contains("after: \"abc\""));
+        assert!(query_str.contains("... on PullRequest"));
+    }
+
+    #[test]
+    fn parse_stale_search_reads_items() {
+        let data = json!({
+            "search": {
+                "pageInfo": { "hasNextPage": false, "endCursor": null },
+                "nodes": [
+                    {
+                        "number": 7,
+                        "title": "Crash on start",
+                        "body": null,
+                        "url": "https://github.com/o/r/issues/7",
+                        "updatedAt": "2026-01-01T00:00:00Z",
+                        "lastEditedAt": "2025-12-31T00:00:00Z",
+                        "author": null,
+                        "labels": { "nodes": [{ "name": "bug" }] },
+                        "comments": { "nodes": [{
+                            "databaseId": 99,
+                            "author": { "login": "maintainer" },
+                            "body": "Can you share logs?",
+                            "createdAt": "2026-01-01T00:00:00Z"
+                        }] },
+                        "commits": { "nodes": [{
+                            "commit": { "committedDate": "2025-12-30T00:00:00Z" }
+                        }] }
+                    },
+                    {}
+                ]
+            }
+        });
+
+        let (items, page_info) = parse_stale_search(&data).unwrap();
+
+        assert!(!page_info.has_next_page);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].labels, vec!["bug".to_string()]);
+        assert_eq!(items[0].author, "");
+        assert_eq!(items[0].comments[0].id, 99);
+        assert_eq!(items[0].comments[0].author, "maintainer");
+        assert_eq!(items[0].comments[0].last_edited_at, None);
+        assert_eq!(
+            items[0].last_edited_at.map(|t| t.to_rfc3339()).as_deref(),
+            Some("2025-12-31T00:00:00+00:00")
+        );
+        assert_eq!(
+            items[0]
+                .head_committed_at
+                .map(|t| t.to_rfc3339())
+                .as_deref(),
+            Some("2025-12-30T00:00:00+00:00")
+        );
+    }
+}
@@ -0,0 +1,381 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Issue form awareness (`.github/ISSUE_TEMPLATE/*.yml`).
+//!
+//! GitHub renders an issue form submission as one `### <label>` section per
+//! field, with `_No response_` for fields left empty. Matching those headings
+//! against the repository's forms tells which template an issue was filed
+//! with, and which of its required fields the author skipped. The check is
+//! deterministic, so the triage prompt can ask for exactly those fields.
+
+use serde::Deserialize;
+#[cfg(not(target_arch = "wasm32"))]
+use tracing::{debug, instrument};
+
+use crate::ai::types::TemplateCheck;
+
+/// Directory GitHub reads issue forms from.
+#[cfg(not(target_arch = "wasm32"))]
+const TEMPLATE_DIR: &str = ".github/ISSUE_TEMPLATE";
+
+/// Placeholder GitHub renders for an empty form field.
+const NO_RESPONSE: &str = "_No response_";
+
+/// Label applied when required form fields are empty.
+pub const NEEDS_INFO_LABEL: &str = "needs-info";
+
+/// An issue form (GitHub's YAML issue template).
+#[derive(Debug, Clone, Deserialize)]
+pub struct IssueForm {
+    /// Template name shown in the chooser.
+    pub name: String,
+    /// Default title, e.g. `"[Bug]: "`.
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Form elements, in order.
+    #[serde(default)]
+    pub body: Vec<FormElement>,
+}
+
+/// A single element of an issue form.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FormElement {
+    /// Element type: `markdown`, `input`, `textarea`, `dropdown` or `checkboxes`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Element attributes.
+    #[serde(default)]
+    pub attributes: FormAttributes,
+    /// Element validations.
+    #[serde(default)]
+    pub validations: FormValidations,
+}
+
+/// Attributes of a form element.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct FormAttributes {
+    /// Field label, rendered as the section heading.
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Dropdown choices or checkboxes.
+    #[serde(default)]
+    pub options: Vec<FormOption>,
+}
+
+/// A dropdown choice (plain string) or a checkbox.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum FormOption {
+    /// A dropdown choice.
+    Choice(String),
+    /// A checkbox, which may be required.
+    Checkbox {
+        /// Checkbox label.
+        label: String,
+        /// Whether the checkbox must be ticked.
+        #[serde(default)]
+        required: bool,
+    },
+}
+
+/// Validations of a form element.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct FormValidations {
+    /// Whether the field must be filled in.
+    #[serde(default)]
+    pub required: bool,
+}
+
+impl FormElement {
+    /// The field label, unless this element is display-only markdown.
+    fn field_label(&self) -> Option<&str> {
+        if self.kind == "markdown" {
+            return None;
+        }
+        self.attributes.label.as_deref().map(str::trim)
+    }
+}
+
+/// Parses an issue form from YAML, or `None` if it is not a valid form.
+#[must_use]
+pub fn parse_issue_form(content: &str) -> Option<IssueForm> {
+    serde_saphyr::from_str(content).ok()
+}
+
+/// Splits a rendered issue form body into `(heading, value)` sections.
+fn sections(body: &str) -> Vec<(String, String)> {
+    let mut sections: Vec<(String, String)> = Vec::new();
+    for line in body.lines() {
+        if let Some(heading) = line.strip_prefix("### ") {
+            sections.push((heading.trim().to_string(), String::new()));
+        } else if let Some((_, value)) = sections.last_mut() {
+            value.push_str(line);
+            value.push('\n');
+        }
+    }
+    for (_, value) in &mut sections {
+        *value = value.trim().to_string();
+    }
+    sections
+}
+
+/// Finds the form an issue was filed with.
+///
+/// The form whose field labels appear most often as section headings wins,
+/// provided at least half of its fields are present. A title starting with
+/// the form's default title breaks ties.
+#[must_use]
+pub fn match_form<'a>(forms: &'a [IssueForm], title: &str, body: &str) -> Option<&'a IssueForm> {
+    let headings: Vec<String> = sections(body).into_iter().map(|(h, _)| h).collect();
+    forms
+        .iter()
+        .filter_map(|form| {
+            let labels: Vec<&str> = form
+                .body
+                .iter()
+                .filter_map(FormElement::field_label)
+                .collect();
+            let present = labels
+                .iter()
+                .filter(|l| headings.iter().any(|h| h.eq_ignore_ascii_case(l)))
+                .count();
+            let title_match = form
+                .title
+                .as_deref()
+                .map(str::trim)
+                .is_some_and(|prefix| !prefix.is_empty() && title.starts_with(prefix));
+            (present > 0 && present * 2 >= labels.len()).then_some((present, title_match, form))
+        })
+        .max_by_key(|(present, title_match, _)| (*present, *title_match))
+        .map(|(_, _, form)| form)
+}
+
+/// Lists required fields of `form` that the rendered `body` leaves empty.
+///
+/// A field is empty when its section is missing, blank, or `_No response_`.
+/// Required checkboxes are reported by their own label when not ticked.
+#[must_use]
+pub fn missing_required_fields(form: &IssueForm, body: &str) -> Vec<String> {
+    let sections = sections(body);
+    let mut missing = Vec::new();
+    for element in &form.body {
+        let Some(label) = element.field_label() else {
+            continue;
+        };
+        let value = sections
+            .iter()
+            .find(|(h, _)| h.eq_ignore_ascii_case(label))
+            .map(|(_, v)| v.as_str());
+
+        if element.kind == "checkboxes" {
+            for option in &element.attributes.options {
+                if let FormOption::Checkbox {
+                    label: option_label,
+                    required: true,
+                } = option
+                {
+                    let ticked = value.is_some_and(|v| {
+                        v.lines().any(|line| {
+                            let line = line.trim();
+                            (line.starts_with("- [x]") || line.starts_with("- [X]"))
+                                && line.contains(option_label.trim())
+                        })
+                    });
+                    if !ticked {
+                        missing.push(option_label.trim().to_string());
+                    }
+                }
+            }
+        } else if element.validations.required
+            && value.is_none_or(|v| v.is_empty() || v == NO_RESPONSE)
+        {
+            missing.push(label.to_string());
+        }
+    }
+    missing
+}
+
+/// Checks an issue against the repository's forms.
+///
+/// Returns `None` if the issue does not match any form.
+#[must_use]
+pub fn check_issue(forms: &[IssueForm], title: &str, body: &str) -> Option<TemplateCheck> {
+    let form = match_form(forms, title, body)?;
+    Some(TemplateCheck {
+        template: form.name.clone(),
+        missing_fields: missing_required_fields(form, body),
+    })
+}
+
+/// Fetches and parses the repository's issue forms from the default branch.
+///
+/// Best effort: returns an empty list if the directory is missing or
+/// unreadable, and skips files that are not valid forms (including the
+/// chooser's `config.yml` and Markdown templates).
+#[cfg(not(target_arch = "wasm32"))]
+#[instrument(skip(client))]
+pub async fn fetch_issue_forms(
+    client: &octocrab::Octocrab,
+    owner: &str,
+    repo: &str,
+) -> Vec<IssueForm> {
+    let listing = match client
+        .repos(owner, repo)
+        .get_content()
+        .path(TEMPLATE_DIR)
+        .send()
+        .await
+    {
+        Ok(listing) => listing,
+        Err(e) => {
+            debug!(error = %e, "No issue form directory");
+            return Vec::new();
+        }
+    };
+
+    let paths: Vec<String> = listing
+        .items
+        .into_iter()
+        .filter(|item| {
+            item.r#type == "file"
+                && std::path::Path::new(&item.name)
+                    .extension()
+                    .is_some_and(|ext| {
+                        ext.eq_ignore_ascii_case("yml") || ext.eq_ignore_ascii_case("yaml")
+                    })
+                && !item.name.starts_with("config.")
+        })
+        .map(|item| item.path)
+        .collect();
+    let contents =
+        futures::future::join_all(paths.iter().map(|path| {
+            super::instructions::fetch_file_content(client, owner, repo, path, "HEAD")
+        }))
+        .await;
+
+    let mut forms = Vec::new();
+    for (path, content) in paths.iter().zip(contents) {
+        if let Some(form) = content.as_deref().and_then(parse_issue_form) {
+            forms.push(form);
+        } else {
+            debug!(path = %path, "Skipping unparseable issue form");
+        }
+    }
+    debug!(count = forms.len(), "Fetched issue forms");
+    forms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUG_FORM: &str = r#"
+name: Bug report
+description: Report a problem
+title: "[Bug]: "
+labels: ["bug"]
+body:
+  - type: markdown
+    attributes:
+      value: Thanks for taking the time!
+  - type: input
+    id: version
+    attributes:
+      label: Version
+    validations:
+      required: true
+  - type: textarea
+    id: repro
+    attributes:
+      label: Steps to reproduce
+    validations:
+      required: true
+  - type: textarea
+    id: logs
+    attributes:
+      label: Relevant log output
+      render: shell
+  - type: dropdown
+    id: os
+    attributes:
+      label: Operating system
+      options:
+        - Linux
+        - macOS
+    validations:
+      required: true
+  - type: checkboxes
+    id: terms
+    attributes:
+      label: Code of Conduct
+      options:
+        - label: I agree to follow the Code of Conduct
+          required: true
+"#;
+
+    const FEATURE_FORM: &str = r"
+name: Feature request
+body:
+  - type: textarea
+    attributes:
+      label: Problem
+    validations:
+      required: true
+  - type: textarea
+    attributes:
+      label: Proposal
+";
+
+    fn forms() -> Vec<IssueForm> {
+        vec![
+            parse_issue_form(BUG_FORM).unwrap(),
+            parse_issue_form(FEATURE_FORM).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn parse_issue_form_reads_fields_and_options() {
+        let form = parse_issue_form(BUG_FORM).unwrap();
+        assert_eq!(form.name, "Bug report");
+        assert_eq!(form.body.len(), 6);
+        assert!(form.body[1].validations.required);
+        assert!(matches!(
+            &form.body[5].attributes.options[0],
+            FormOption::Checkbox { required: true, .. }
+        ));
+        assert!(parse_issue_form("blank_issues_enabled: false").is_none());
+    }
+
+    #[test]
+    fn check_issue_reports_empty_required_fields() {
+        let body = "### Version\n\n_No response_\n\n### Steps to reproduce\n\n1. Run it\n2. Crash\n\n\
+                    ### Relevant log output\n\n_No response_\n\n### Operating system\n\nLinux\n\n\
+                    ### Code of Conduct\n\n- [ ] I agree to follow the Code of Conduct";
+        let check = check_issue(&forms(), "[Bug]: crash", body).unwrap();
+        assert_eq!(check.template, "Bug report");
+        assert_eq!(
+            check.missing_fields,
+            vec!["Version", "I agree to follow the Code of Conduct"]
+        );
+    }
+
+    #[test]
+    fn check_issue_treats_missing_sections_as_empty() {
+        let body = "### Steps to reproduce\n\nRun it\n\n### Operating system\n\nmacOS\n\n\
+                    ### Code of Conduct\n\n- [X] I agree to follow the Code of Conduct";
+        let check = check_issue(&forms(), "crash", body).unwrap();
+        assert_eq!(check.template, "Bug report");
+        assert_eq!(check.missing_fields, vec!["Version"]);
+    }
+
+    #[test]
+    fn match_form_picks_best_form_or_none() {
+        let forms = forms();
+        let feature = "### Problem\n\nSlow\n\n### Proposal\n\n_No response_";
+        assert_eq!(
+            match_form(&forms, "Faster", feature).map(|f| f.name.as_str()),
+            Some("Feature request")
+        );
+        assert!(match_form(&forms, "Crash", "It crashes when I run it.").is_none());
+    }
+}
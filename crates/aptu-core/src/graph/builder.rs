@@ -6,17 +6,23 @@
 //!
 //! # Design constraint
 //!
-//! This module MUST NOT introduce a second AST parse pass, must not add a
-//! native grammar crate as a dependency, and must not re-read source files.
-//! It consumes only the typed analysis data that `ast_context.rs` already
-//! produced via `aptu_coder_core::analyze_file`.
+//! This module MUST NOT introduce a second semantic analysis pass, must not
+//! add a native grammar crate as a dependency, and must not re-read source
+//! files. It consumes the typed analysis data that `ast_context.rs` already
+//! produced via `aptu_coder_core::analyze_file`, plus the source text it read,
+//! over which [`SourceFacts`] runs the single tree-sitter query shared with
+//! [`super::repo`] for test and type-kind detection.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use petgraph::graph::NodeIndex;
 
 use super::{Edge, GraphDb, Node};
 
+#[cfg(all(feature = "ast-context", feature = "graph"))]
+use crate::ast_context::SourceFacts;
+#[cfg(feature = "ast-context")]
+pub use crate::ast_context::is_test_symbol;
 #[cfg(all(feature = "ast-context", feature = "graph"))]
 use aptu_coder_core::{SemanticAnalysis, graph::CallGraph};
 
@@ -30,18 +36,29 @@ use aptu_coder_core::{SemanticAnalysis, graph::CallGraph};
 /// - `Edge::Calls` from `call_graph.callers`, filtering out pseudo-edges
 ///   (`neighbor_name == "<reference>"`) and impl-trait edges
 ///   (`is_impl_trait == true`)
+/// - a type node (`Node::Struct`, or `Node::Trait`/`Node::Enum` when `source`
+///   declares it as one or it is implemented as a trait) per entry in
+///   `semantic.classes`, with `Edge::HasMethod` to its methods and
+///   `Edge::Implements` to its traits (`semantic.impl_traits`) and base
+///   classes (`ClassInfo::inherits`)
+/// - `Edge::Tests` alongside each `Edge::Calls` edge whose caller is a test
+///   (by path, name, test attribute, or test module; see
+///   [`SourceFacts::is_test`]) and whose callee is not
 ///
-/// All function nodes default to `visibility = "private"` because
-/// `FunctionInfo` has no visibility field.
+/// `FunctionInfo` has no visibility field, so functions and types defined in
+/// `path` take theirs from `source` (see [`SourceFacts::visibility`]); nodes
+/// for callers and callees in other files default to `"private"`.
 ///
 /// `CallEdge.path` is normalized to an empty string for anonymous callers.
 #[cfg(all(feature = "ast-context", feature = "graph"))]
 #[must_use]
 pub fn build_from_analysis(
     path: &str,
+    source: &str,
     semantic: &SemanticAnalysis,
     call_graph: &CallGraph,
 ) -> GraphDb {
+    let facts = SourceFacts::extract(path, source);
     let mut graph = GraphDb::new();
 
     // File node
@@ -56,7 +73,7 @@ pub fn build_from_analysis(
         let fn_idx = graph.add_node(Node::Function {
             name: func.name.clone(),
             path: path.to_string(),
-            visibility: "private".to_string(),
+            visibility: facts.visibility(&func.name, func.line, func.end_line),
         });
         graph.add_edge(file_idx, fn_idx, Edge::Contains);
         fn_name_to_idx.insert(func.name.clone(), fn_idx);
@@ -120,9 +137,135 @@ pub fn build_from_analysis(
         }
     }
 
+    add_types(
+        &mut graph,
+        file_idx,
+        path,
+        semantic,
+        &facts,
+        &mut fn_name_to_idx,
+    );
+    let tests: HashSet<NodeIndex> = semantic
+        .functions
+        .iter()
+        .filter(|f| facts.is_test(path, &f.name, f.line, f.end_line))
+        .filter_map(|f| fn_name_to_idx.get(&f.name).copied())
+        .collect();
+    add_test_edges(&mut graph, &tests);
+
     graph
 }
 
+/// Adds a type node per class with `HasMethod` and `Implements` edges.
+#[cfg(all(feature = "ast-context", feature = "graph"))]
+fn add_types(
+    graph: &mut GraphDb,
+    file_idx: NodeIndex,
+    path: &str,
+    semantic: &SemanticAnalysis,
+    facts: &SourceFacts,
+    fn_name_to_idx: &mut HashMap<String, NodeIndex>,
+) {
+    let trait_names: HashSet<&str> = semantic
+        .impl_traits
+        .iter()
+        .map(|i| i.trait_name.as_str())
+        .collect();
+    let mut type_name_to_idx: HashMap<String, NodeIndex> = HashMap::new();
+    for class in &semantic.classes {
+        let kind = if trait_names.contains(class.name.as_str()) {
+            "trait"
+        } else {
+            facts.kind(&class.name)
+        };
+        let (name, file, visibility) = (
+            class.name.clone(),
+            path.to_string(),
+            facts.visibility(&class.name, class.line, class.end_line),
+        );
+        let node = match kind {
+            "trait" => Node::Trait {
+                name,
+                path: file,
+                visibility,
+            },
+            "enum" => Node::Enum {
+                name,
+                path: file,
+                visibility,
+            },
+            _ => Node::Struct {
+                name,
+                path: file,
+                visibility,
+            },
+        };
+        let type_idx = graph.add_node(node);
+        graph.add_edge(file_idx, type_idx, Edge::Contains);
+        type_name_to_idx.insert(class.name.clone(), type_idx);
+
+        for method in &class.methods {
+            let fn_idx = *fn_name_to_idx
+                .entry(method.name.clone())
+                .or_insert_with(|| {
+                    let idx = graph.add_node(Node::Function {
+                        name: method.name.clone(),
+                        path: path.to_string(),
+                        visibility: facts.visibility(&method.name, method.line, method.end_line),
+                    });
+                    graph.add_edge(file_idx, idx, Edge::Contains);
+                    idx
+                });
+            graph.add_edge(type_idx, fn_idx, Edge::HasMethod);
+        }
+    }
+
+    let implements = semantic
+        .impl_traits
+        .iter()
+        .map(|i| (i.impl_type.as_str(), i.trait_name.as_str()))
+        .chain(semantic.classes.iter().flat_map(|c| {
+            c.inherits
+                .iter()
+                .map(move |base| (c.name.as_str(), base.as_str()))
+        }));
+    for (type_name, base) in implements {
+        let Some(&src) = type_name_to_idx.get(type_name) else {
+            continue;
+        };
+        let dst = type_name_to_idx.get(base).copied().unwrap_or_else(|| {
+            let idx = graph.add_node(Node::Trait {
+                name: base.to_string(),
+                path: String::new(),
+                visibility: "private".to_string(),
+            });
+            type_name_to_idx.insert(base.to_string(), idx);
+            idx
+        });
+        graph.add_edge(src, dst, Edge::Implements);
+    }
+}
+
+/// Adds a `Tests` edge alongside each `Calls` edge from a test to a non-test.
+///
+/// `tests` holds the functions of this file found to be tests from its
+/// source; nodes from other files fall back to [`is_test_symbol`].
+#[cfg(all(feature = "ast-context", feature = "graph"))]
+fn add_test_edges(graph: &mut GraphDb, tests: &HashSet<NodeIndex>) {
+    let is_test = |idx: NodeIndex| {
+        tests.contains(&idx) || is_test_symbol(graph[idx].path(), graph[idx].name())
+    };
+    let tested: Vec<(NodeIndex, NodeIndex)> = graph
+        .edge_indices()
+        .filter(|&e| matches!(graph[e], Edge::Calls))
+        .filter_map(|e| graph.edge_endpoints(e))
+        .filter(|&(src, dst)| is_test(src) && !is_test(dst))
+        .collect();
+    for (src, dst) in tested {
+        graph.add_edge(src, dst, Edge::Tests);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -180,7 +323,7 @@ mod tests {
         let call_graph = make_call_graph(HashMap::new());
 
         // Act
-        let graph = build_from_analysis("src/lib.rs", &semantic, &call_graph);
+        let graph = build_from_analysis("src/lib.rs", "", &semantic, &call_graph);
 
         // Assert: one File node, one Function node, one Contains edge
         let file_count = graph
@@ -233,7 +376,7 @@ mod tests {
         let call_graph = make_call_graph(callers);
 
         // Act
-        let graph = build_from_analysis("src/lib.rs", &semantic, &call_graph);
+        let graph = build_from_analysis("src/lib.rs", "", &semantic, &call_graph);
 
         // Assert: only one Calls edge (real_caller -> target_fn), <reference> filtered out
         let calls_count = graph
@@ -269,7 +412,7 @@ mod tests {
         let call_graph = make_call_graph(HashMap::new());
 
         // Act
-        let graph = build_from_analysis("src/empty.rs", &semantic, &call_graph);
+        let graph = build_from_analysis("src/empty.rs", "", &semantic, &call_graph);
 
         // Assert: only the File node exists
         assert_eq!(graph.node_count(), 1, "expected only the File node");
@@ -288,7 +431,7 @@ mod tests {
         let call_graph = make_call_graph(HashMap::new());
 
         // Act
-        let graph = build_from_analysis("src/lib.rs", &semantic, &call_graph);
+        let graph = build_from_analysis("src/lib.rs", "", &semantic, &call_graph);
 
         // Assert: visibility defaults to "private"
         let visibilities: Vec<&str> = graph
@@ -304,4 +447,100 @@ mod tests {
             "visibility should default to 'private'"
         );
     }
+
+    #[test]
+    fn test_build_from_analysis_emits_type_and_test_edges() {
+        // Arrange: a trait impl parsed from real source, and a test caller
+        let source = "pub trait Shape { fn area(&self) -> f64; }\n\
+                      pub struct Square { side: f64 }\n\
+                      impl Shape for Square {\n    fn area(&self) -> f64 { self.side }\n}\n";
+        let semantic =
+            aptu_coder_core::parser::SemanticExtractor::extract(source, "rust", None, None)
+                .unwrap();
+        let mut callers: HashMap<String, Vec<CallEdge>> = HashMap::new();
+        callers.insert(
+            "area".to_string(),
+            vec![CallEdge {
+                neighbor_name: "test_area".to_string(),
+                path: PathBuf::from("src/lib.rs"),
+                line: 10,
+                is_impl_trait: false,
+            }],
+        );
+
+        // Act
+        let graph = build_from_analysis("src/lib.rs", source, &semantic, &make_call_graph(callers));
+
+        // Assert
+        let edges: Vec<(&str, &str, Edge)> = graph
+            .edge_indices()
+            .filter_map(|e| {
+                let (src, dst) = graph.edge_endpoints(e)?;
+                Some((graph[src].name(), graph[dst].name(), graph[e]))
+            })
+            .collect();
+        assert!(edges.contains(&("Square", "area", Edge::HasMethod)));
+        assert!(edges.contains(&("Square", "Shape", Edge::Implements)));
+        assert!(edges.contains(&("test_area", "area", Edge::Tests)));
+        assert!(
+            graph
+                .node_weights()
+                .any(|n| matches!(n, Node::Trait { name, .. } if name == "Shape"))
+        );
+    }
+
+    #[test]
+    fn test_build_from_analysis_detects_test_attributes_and_enums() {
+        // Arrange: an enum with a derive, and tests without a `test_` prefix
+        let source = "#[derive(Debug)]\n\
+                      pub enum Shape { Square }\n\
+                      pub fn area() -> f64 { 1.0 }\n\
+                      #[test]\n\
+                      fn checks_area() { area(); }\n\
+                      #[cfg(test)]\n\
+                      mod tests {\n    fn helper() { super::area(); }\n}\n";
+        let semantic =
+            aptu_coder_core::parser::SemanticExtractor::extract(source, "rust", None, None)
+                .unwrap();
+        let edge = |caller: &str, line| CallEdge {
+            neighbor_name: caller.to_string(),
+            path: PathBuf::from("src/lib.rs"),
+            line,
+            is_impl_trait: false,
+        };
+        let mut callers: HashMap<String, Vec<CallEdge>> = HashMap::new();
+        callers.insert(
+            "area".to_string(),
+            vec![edge("checks_area", 5), edge("helper", 8)],
+        );
+
+        // Act
+        let graph = build_from_analysis("src/lib.rs", source, &semantic, &make_call_graph(callers));
+
+        // Assert
+        let tests: Vec<&str> = graph
+            .edge_indices()
+            .filter(|&e| graph[e] == Edge::Tests)
+            .filter_map(|e| graph.edge_endpoints(e))
+            .map(|(src, _)| graph[src].name())
+            .collect();
+        assert!(tests.contains(&"checks_area"), "got {tests:?}");
+        assert!(tests.contains(&"helper"), "got {tests:?}");
+        if semantic.classes.iter().any(|c| c.name == "Shape") {
+            assert!(
+                graph
+                    .node_weights()
+                    .any(|n| matches!(n, Node::Enum { name, .. } if name == "Shape"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_test_symbol_uses_path_and_name() {
+        assert!(is_test_symbol("tests/cli.rs", "helper"));
+        assert!(is_test_symbol("pkg/server_test.go", "TestServe"));
+        assert!(is_test_symbol("src/app.spec.ts", "renders"));
+        assert!(is_test_symbol("src/lib.py", "test_parse"));
+        assert!(!is_test_symbol("src/lib.rs", "parse"));
+    }
 }
@@ -10,8 +10,13 @@
 //! ephemeral (derived from the current diff) and are always removed before
 //! serialization; they never appear in a cached graph.
 //!
-//! Only the actual file I/O (`load_or_build`, `persist_graph`) is gated to
-//! non-WASM targets. Path construction and byte encode/decode are pure
+//! Alongside the per-SHA graphs, each repository has a [`FILE_INDEX_NAME`]
+//! file holding the per-file symbol index (same header, postcard payload), so
+//! a graph for a new commit only re-parses files whose content changed. Old
+//! per-SHA graphs are pruned by [`gc`]; the file index is kept.
+//!
+//! Only the actual file I/O (`load_or_build_with`, `persist_graph`, `gc`) is
+//! gated to non-WASM targets. Path construction and byte encode/decode are pure
 //! functions usable on any target.
 
 use std::io::Write;
@@ -21,14 +26,14 @@ use super::{Edge, GraphDb};
 use crate::config::GraphConfig;
 
 /// Cache format version. Bump when the encoding changes in an incompatible way.
-const FORMAT_VERSION: u32 = 3;
+const FORMAT_VERSION: u32 = 10;
 
 /// Compile-time FNV-1a hash over the `Node`/`Edge` variant names.
 ///
 /// Any change to the set (or order) of `Node`/`Edge` variant names must be
 /// reflected in [`SCHEMA_STRING`] so that stale cached graphs are invalidated
 /// by [`decode_graph`] rather than postcard mis-decoding.
-const SCHEMA_STRING: &str = "File|Module|Function|Struct|Enum|Trait|Impl|Contains|Calls|Imports|Implements|HasMethod|Modifies|Tests";
+const SCHEMA_STRING: &str = "File|Module|Function|Struct|Enum|Trait|Impl|Contains|Calls|Imports|Implements|HasMethod|Modifies|Tests|Binds|Requests";
 
 /// Computes the compile-time FNV-1a hash of [`SCHEMA_STRING`].
 #[must_use]
@@ -44,18 +49,72 @@ pub const fn schema_hash() -> u32 {
     hash
 }
 
+/// File name of the per-repository file index, stored next to the SHA caches.
+pub const FILE_INDEX_NAME: &str = "files.idx";
+
+/// Returns the root directory of the graph cache (`~/.local/share/aptu/graph`).
+#[must_use]
+pub fn cache_root() -> PathBuf {
+    crate::config::data_dir().join("graph")
+}
+
 /// Returns the on-disk cache path for a given repository and commit SHA.
 ///
 /// Path shape: `~/.local/share/aptu/graph/<owner>/<repo>/<sha>.bin`.
 #[must_use]
 pub fn cache_path(owner: &str, repo: &str, sha: &str) -> PathBuf {
-    crate::config::data_dir()
-        .join("graph")
+    cache_root()
         .join(owner)
         .join(repo)
         .join(format!("{sha}.bin"))
 }
 
+/// Returns the on-disk path of a repository's file index.
+///
+/// Path shape: `~/.local/share/aptu/graph/<owner>/<repo>/files.idx`.
+#[must_use]
+pub fn file_index_path(owner: &str, repo: &str) -> PathBuf {
+    cache_root().join(owner).join(repo).join(FILE_INDEX_NAME)
+}
+
+/// Returns the cache namespace `(owner, repo)` for a local checkout that is
+/// not tied to a GitHub repository: `("local", <16 hex chars>)`, where the
+/// suffix is derived from the canonical path of `root`.
+#[must_use]
+pub fn local_namespace(root: &std::path::Path) -> (String, String) {
+    use sha2::{Digest, Sha256};
+
+    let canonical = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+    let digest = Sha256::digest(canonical.to_string_lossy().as_bytes());
+    ("local".to_string(), hex::encode(&digest[..8]))
+}
+
+/// Prepends the 8-byte cache header to `payload`.
+fn with_header(payload: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(8 + payload.len());
+    bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&schema_hash().to_le_bytes());
+    bytes.extend_from_slice(payload);
+    bytes
+}
+
+/// Returns the payload after a valid cache header, or `None` if the header is
+/// missing or does not match the current format version and schema hash.
+fn strip_header(bytes: &[u8]) -> Option<&[u8]> {
+    if bytes.len() < 8 {
+        return None;
+    }
+    let version = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    if version != FORMAT_VERSION {
+        return None;
+    }
+    let hash = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+    if hash != schema_hash() {
+        return None;
+    }
+    Some(&bytes[8..])
+}
+
 /// Encodes `graph` into the on-disk cache byte format.
 ///
 /// Removes `Modifies` edges by rebuilding a filtered graph (single O(N+E) pass
@@ -76,12 +135,7 @@ pub fn encode_graph(graph: &GraphDb) -> Option<Vec<u8>> {
     }
 
     let payload = postcard::to_allocvec(&filtered).ok()?;
-
-    let mut bytes = Vec::with_capacity(8 + payload.len());
-    bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
-    bytes.extend_from_slice(&schema_hash().to_le_bytes());
-    bytes.extend_from_slice(&payload);
-    Some(bytes)
+    Some(with_header(&payload))
 }
 
 /// Decodes a graph from on-disk cache bytes.
@@ -91,21 +145,46 @@ pub fn encode_graph(graph: &GraphDb) -> Option<Vec<u8>> {
 /// match [`schema_hash`], or postcard decoding fails.
 #[must_use]
 pub fn decode_graph(bytes: &[u8]) -> Option<GraphDb> {
-    if bytes.len() < 8 {
-        return None;
-    }
-    let version = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
-    if version != FORMAT_VERSION {
-        return None;
-    }
-    let hash = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
-    if hash != schema_hash() {
-        return None;
-    }
-    let graph: GraphDb = postcard::from_bytes(&bytes[8..]).ok()?;
+    let graph: GraphDb = postcard::from_bytes(strip_header(bytes)?).ok()?;
     Some(graph)
 }
 
+/// Encodes a file index into the on-disk cache byte format.
+#[cfg(all(feature = "ast-context", not(target_arch = "wasm32")))]
+#[must_use]
+pub fn encode_file_index(index: &super::repo::FileIndex) -> Option<Vec<u8>> {
+    let payload = postcard::to_allocvec(index).ok()?;
+    Some(with_header(&payload))
+}
+
+/// Decodes a file index from on-disk cache bytes, or `None` on any mismatch.
+#[cfg(all(feature = "ast-context", not(target_arch = "wasm32")))]
+#[must_use]
+pub fn decode_file_index(bytes: &[u8]) -> Option<super::repo::FileIndex> {
+    postcard::from_bytes(strip_header(bytes)?).ok()
+}
+
+/// Loads a repository's file index, or an empty index if none is cached.
+#[cfg(all(feature = "ast-context", not(target_arch = "wasm32")))]
+#[must_use]
+pub fn load_file_index(owner: &str, repo: &str) -> super::repo::FileIndex {
+    std::fs::read(file_index_path(owner, repo))
+        .ok()
+        .and_then(|bytes| decode_file_index(&bytes))
+        .unwrap_or_default()
+}
+
+/// Persists a repository's file index (best-effort, like graph writes).
+#[cfg(all(feature = "ast-context", not(target_arch = "wasm32")))]
+pub fn persist_file_index(owner: &str, repo: &str, index: &super::repo::FileIndex) {
+    let path = file_index_path(owner, repo);
+    let Some(bytes) = encode_file_index(index) else {
+        tracing::warn!(path = %path.display(), "graph cache: index encode failed, skipping write");
+        return;
+    };
+    persist_bytes(&path, &bytes);
+}
+
 /// Loads a cached graph from disk, or persists the provided graph to cache.
 ///
 /// Returns `(graph, cache_hit)`. On cache hit, the provided `graph` is dropped
@@ -114,7 +193,6 @@ pub fn decode_graph(bytes: &[u8]) -> Option<GraphDb> {
 ///
 /// On WASM targets, this always returns the provided graph with `cache_hit = false`
 /// (no disk I/O).
-#[cfg(not(target_arch = "wasm32"))]
 #[must_use]
 pub fn load_or_build(
     owner: &str,
@@ -122,6 +200,25 @@ pub fn load_or_build(
     sha: &str,
     graph: GraphDb,
     cfg: &GraphConfig,
+) -> (GraphDb, bool) {
+    load_or_build_with(owner, repo, sha, cfg, || graph)
+}
+
+/// Loads a cached graph from disk, or builds one with `build` and persists it.
+///
+/// Like [`load_or_build`], but the graph is only built on a cache miss, which
+/// matters when building means parsing a whole repository. Empty graphs are
+/// returned but never persisted, so a failed build does not poison the cache.
+///
+/// On WASM targets, this always calls `build` and returns `cache_hit = false`.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn load_or_build_with(
+    owner: &str,
+    repo: &str,
+    sha: &str,
+    cfg: &GraphConfig,
+    build: impl FnOnce() -> GraphDb,
 ) -> (GraphDb, bool) {
     // Try cache first.
     let path = cache_path(owner, repo, sha);
@@ -129,23 +226,34 @@ pub fn load_or_build(
         return (cached, true);
     }
 
-    // Persist to cache.
-    persist_graph(&path, &graph);
+    let graph = build();
+    if graph.node_count() > 0 {
+        persist_graph(&path, &graph);
+    }
 
     (graph, false)
 }
 
-/// WASM fallback: always return provided graph, no disk I/O.
+/// Loads the cached graph for `sha`, or `None` on a miss. Never writes.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn load_cached(owner: &str, repo: &str, sha: &str, cfg: &GraphConfig) -> Option<GraphDb> {
+    try_load_cached(&cache_path(owner, repo, sha), cfg)
+        .ok()
+        .flatten()
+}
+
+/// WASM fallback: always build, no disk I/O.
 #[cfg(target_arch = "wasm32")]
 #[must_use]
-pub fn load_or_build(
+pub fn load_or_build_with(
     _owner: &str,
     _repo: &str,
     _sha: &str,
-    graph: GraphDb,
     _cfg: &GraphConfig,
+    build: impl FnOnce() -> GraphDb,
 ) -> (GraphDb, bool) {
-    (graph, false)
+    (build(), false)
 }
 
 /// Tries to load a cached graph from `path`.
@@ -182,7 +290,19 @@ fn try_load_cached(path: &PathBuf, cfg: &GraphConfig) -> std::io::Result<Option<
 /// Creates parent directories if needed. Failures are logged at WARN level
 /// and never propagated (caching is best-effort).
 #[cfg(not(target_arch = "wasm32"))]
-fn persist_graph(path: &PathBuf, graph: &GraphDb) {
+fn persist_graph(path: &std::path::Path, graph: &GraphDb) {
+    let Some(bytes) = encode_graph(graph) else {
+        tracing::warn!(path = %path.display(), "graph cache: encode failed, skipping write");
+        return;
+    };
+    persist_bytes(path, &bytes);
+}
+
+/// Atomically writes `bytes` to `path`, creating parent directories.
+///
+/// Failures are logged at WARN level and never propagated.
+#[cfg(not(target_arch = "wasm32"))]
+fn persist_bytes(path: &std::path::Path, bytes: &[u8]) {
     if let Some(parent) = path.parent()
         && let Err(e) = std::fs::create_dir_all(parent)
     {
@@ -194,11 +314,6 @@ fn persist_graph(path: &PathBuf, graph: &GraphDb) {
         return;
     }
 
-    let Some(bytes) = encode_graph(graph) else {
-        tracing::warn!(path = %path.display(), "graph cache: encode failed, skipping write");
-        return;
-    };
-
     // Atomic write: write to a uniquely-named sibling temp file then rename to
     // prevent partial file corruption if the process crashes during the write
     // and avoid races between concurrent writers.
@@ -214,7 +329,7 @@ fn persist_graph(path: &PathBuf, graph: &GraphDb) {
             return;
         }
     };
-    if let Err(e) = tmp.write_all(&bytes) {
+    if let Err(e) = tmp.write_all(bytes) {
         tracing::warn!(
             path = %tmp.path().display(),
             error = %e,
@@ -244,6 +359,85 @@ fn persist_graph(path: &PathBuf, graph: &GraphDb) {
     // will attempt to delete a path that no longer exists, which is harmless.
 }
 
+/// Result of pruning the graph cache with [`gc`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GcReport {
+    /// Cached graphs that were removed (or would be, on a dry run).
+    pub removed: Vec<PathBuf>,
+    /// Total size of the removed files in bytes.
+    pub bytes_freed: u64,
+    /// Cached graphs still within the TTL.
+    pub kept: usize,
+}
+
+/// Removes per-SHA graph caches older than `cfg.cache_ttl_hours`.
+///
+/// Per-repository file indexes are never removed: they stay valid across
+/// commits and make the next build incremental. With `dry_run`, nothing is
+/// deleted and the report lists what would be removed. A missing cache
+/// directory yields an empty report.
+///
+/// # Errors
+///
+/// Returns an I/O error if the cache directory cannot be read or a file
+/// cannot be removed.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn gc(cfg: &GraphConfig, dry_run: bool) -> std::io::Result<GcReport> {
+    gc_dir(
+        &cache_root(),
+        std::time::Duration::from_secs(cfg.cache_ttl_hours * 3600),
+        dry_run,
+    )
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn gc_dir(
+    root: &std::path::Path,
+    ttl: std::time::Duration,
+    dry_run: bool,
+) -> std::io::Result<GcReport> {
+    let mut report = GcReport::default();
+    let mut pending = vec![root.to_path_buf()];
+    let now = std::time::SystemTime::now();
+
+    while let Some(dir) = pending.pop() {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        };
+        for entry in entries {
+            let entry = entry?;
+            let path = entry.path();
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            if path.extension().is_none_or(|ext| ext != "bin") {
+                continue;
+            }
+            let age = metadata
+                .modified()
+                .ok()
+                .and_then(|m| now.duration_since(m).ok())
+                .unwrap_or_default();
+            if age <= ttl {
+                report.kept += 1;
+                continue;
+            }
+            if !dry_run {
+                std::fs::remove_file(&path)?;
+            }
+            report.bytes_freed += metadata.len();
+            report.removed.push(path);
+        }
+    }
+
+    report.removed.sort();
+    Ok(report)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -342,7 +536,7 @@ mod tests {
 
         // Verify that any change to SCHEMA_STRING produces a different hash by
         // computing FNV-1a on a mutated string and asserting divergence.
-        const MUTATED: &str = "File|Module|Function|Struct|Enum|Trait|Impl|Contains|Calls|Imports|Implements|HasMethod|Modifies|Tests|NewVariant";
+        const MUTATED: &str = "File|Module|Function|Struct|Enum|Trait|Impl|Contains|Calls|Imports|Implements|HasMethod|Modifies|Tests|Binds|Requests|NewVariant";
         let mut hash: u32 = 0x811c_9dc5;
         for &b in MUTATED.as_bytes() {
             hash ^= b as u32;
@@ -405,4 +599,84 @@ mod tests {
         assert!(path_str.contains("abc123"), "path should contain sha");
         assert!(path_str.ends_with(".bin"), "path should end with .bin");
     }
+
+    #[test]
+    fn test_gc_dir_removes_only_expired_sha_caches() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo_dir = dir.path().join("owner").join("repo");
+        std::fs::create_dir_all(&repo_dir).unwrap();
+        let old = repo_dir.join("old.bin");
+        let fresh = repo_dir.join("fresh.bin");
+        let index = repo_dir.join(FILE_INDEX_NAME);
+        for path in [&old, &fresh, &index] {
+            std::fs::write(path, b"data").unwrap();
+        }
+        let two_days_ago = std::time::SystemTime::now() - std::time::Duration::from_hours(48);
+        for path in [&old, &index] {
+            std::fs::File::options()
+                .write(true)
+                .open(path)
+                .unwrap()
+                .set_modified(two_days_ago)
+                .unwrap();
+        }
+        let ttl = std::time::Duration::from_hours(24);
+
+        let dry = gc_dir(dir.path(), ttl, true).unwrap();
+        assert_eq!(dry.removed, vec![old.clone()]);
+        assert!(old.exists(), "dry run must not delete");
+
+        let report = gc_dir(dir.path(), ttl, false).unwrap();
+        assert_eq!(report.removed, vec![old.clone()]);
+        assert_eq!(report.bytes_freed, 4);
+        assert_eq!(report.kept, 1);
+        assert!(!old.exists());
+        assert!(fresh.exists());
+        assert!(index.exists(), "file index is never pruned");
+    }
+
+    #[test]
+    fn test_gc_dir_missing_root_is_empty() {
+        let report = gc_dir(
+            std::path::Path::new("/nonexistent/aptu/graph"),
+            std::time::Duration::ZERO,
+            false,
+        )
+        .unwrap();
+        assert_eq!(report, GcReport::default());
+    }
+
+    #[cfg(feature = "ast-context")]
+    #[test]
+    fn test_file_index_round_trip() {
+        let mut index = super::super::repo::FileIndex::default();
+        index.files.insert(
+            "src/lib.rs".to_string(),
+            super::super::repo::FileSymbols {
+                hash: "abc".to_string(),
+                functions: vec!["foo".to_string()],
+                imports: vec!["std::fmt".to_string()],
+                calls: vec![("foo".to_string(), "bar".to_string())],
+                tests: vec!["test_foo".to_string()],
+                types: vec![("struct".to_string(), "Foo".to_string())],
+                methods: vec![("Foo".to_string(), "foo".to_string())],
+                visibility: vec![("Foo".to_string(), "pub".to_string())],
+                implements: vec![("Foo".to_string(), "Display".to_string())],
+                connectors: super::super::connect::Connectors {
+                    exports: vec![super::super::connect::Export {
+                        function: "foo".to_string(),
+                        name: "foo".to_string(),
+                        binding: super::super::connect::Binding::C,
+                    }],
+                    routes: vec![("foo".to_string(), "/foo".to_string())],
+                    ..Default::default()
+                },
+            },
+        );
+
+        let bytes = encode_file_index(&index).expect("encode must succeed");
+
+        assert_eq!(decode_file_index(&bytes), Some(index));
+        assert_eq!(decode_file_index(&bytes[..4]), None);
+    }
 }
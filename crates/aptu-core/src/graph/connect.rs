@@ -0,0 +1,829 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cross-language connectors for the repository graph.
+//!
+//! Name-based call resolution in [`super::repo`] stops at language
+//! boundaries: a Python module calling a `PyO3` export, a TypeScript page
+//! fetching `/api/users`, or C code calling a `#[no_mangle]` function never
+//! name the Rust definition the way a Rust caller does. This module finds the
+//! connection points in each file with line-level patterns and links them with
+//! [`Edge::Binds`] and [`Edge::Requests`] edges, so blast radius and affected
+//! tests follow a change from a Rust core into its Python, C, or TypeScript
+//! callers:
+//!
+//! - FFI and generated bindings: `#[no_mangle]`/`extern "C"` functions,
+//!   `#[pyfunction]`, `#[wasm_bindgen]`, and `#[napi]` exports (honouring
+//!   `name`, `js_name`, and `export_name` renames) are linked from call sites
+//!   in other languages that reach them through the binding: an unqualified
+//!   call from C/C++, a name or module imported from the `PyO3` module in
+//!   Python, or a name or namespace imported from a module in JavaScript and
+//!   TypeScript. Functions declared in a Rust `extern "C" { ... }` block are
+//!   linked to their C/C++ definitions.
+//! - HTTP: route handlers (axum `.route`, actix/rocket attributes,
+//!   Flask/FastAPI decorators, Spring mappings, Express registrations) are
+//!   linked from client call sites (`fetch`, `axios`, `requests`, `reqwest`,
+//!   ...) whose literal path matches the route template. Path parameters
+//!   (`:id`, `{id}`, `<id>`, `${id}`) match any segment.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::LazyLock;
+
+use petgraph::graph::NodeIndex;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::repo::{FileSymbols, function_in};
+use super::{Edge, GraphDb};
+
+/// Rename argument of an export attribute (`js_name = foo`, `name = "foo"`).
+static EXPORT_NAME_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"\b(?:js_name|export_name|name)\s*=\s*"?([A-Za-z_$][\w$]*)"#)
+        .expect("valid EXPORT_NAME_RE")
+});
+
+/// Python `import a.b [as c], d` statement.
+static PY_IMPORT_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^\s*import\s+([\w.]+(?:\s+as\s+\w+)?(?:\s*,\s*[\w.]+(?:\s+as\s+\w+)?)*)\s*(?:#.*)?$",
+    )
+    .expect("valid PY_IMPORT_RE")
+});
+
+/// Python `from a.b import c [as d], e` statement, possibly parenthesized.
+static PY_FROM_IMPORT_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\s*from\s+([\w.]+)\s+import\s+\(?([^)#]*)").expect("valid PY_FROM_IMPORT_RE")
+});
+
+/// JavaScript/TypeScript `import clause from "module"` statement.
+static JS_IMPORT_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^\s*import\s+(?:type\s+)?(.+?)\s+from\s+['"]([^'"]+)['"]"#)
+        .expect("valid JS_IMPORT_RE")
+});
+
+/// `CommonJS` `const x = require("module")` or `const { a, b } = require(...)`.
+static JS_REQUIRE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"\b(?:const|let|var)\s+(\{[^}]*\}|[A-Za-z_$][\w$]*)\s*=\s*require\(\s*['"]([^'"]+)['"]\s*\)"#)
+        .expect("valid JS_REQUIRE_RE")
+});
+
+/// Call expression with its dotted qualifier (`mylib.checksum(`).
+static QUALIFIED_CALL_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"([A-Za-z_$][\w$]*(?:\s*\.\s*[A-Za-z_$][\w$]*)*)\s*\(")
+        .expect("valid QUALIFIED_CALL_RE")
+});
+
+/// Opening line of a Rust `extern` block declaring foreign functions.
+static EXTERN_BLOCK_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^\s*(?:unsafe\s+)?extern\s*(?:"[^"]*")?\s*\{"#).expect("valid EXTERN_BLOCK_RE")
+});
+
+/// Function declaration inside an `extern` block.
+static FOREIGN_FN_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\bfn\s+([A-Za-z_]\w*)").expect("valid FOREIGN_FN_RE"));
+
+/// Route attributes and decorators that annotate the next function: actix and
+/// rocket (`#[get("/x")]`), Flask and `FastAPI` (`@app.get("/x")`), and Spring
+/// (`@GetMapping("/x")`).
+static ROUTE_ANNOTATION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"^\s*(?:#\[(?:[\w:]+::)?(?:get|post|put|delete|patch|head|route)\(\s*"([^"]+)"|@[\w.]*\b(?:get|post|put|delete|patch|route|api_route)\(\s*["']([^"']+)["']|@(?:Get|Post|Put|Delete|Patch|Request)Mapping\(\s*(?:(?:value|path)\s*=\s*)?"([^"]+)")"#,
+    )
+    .expect("valid ROUTE_ANNOTATION_RE")
+});
+
+/// Route registrations naming their handler: axum (`.route("/x", get(h))`) and
+/// Express (`app.get("/x", h)`).
+static ROUTE_REGISTRATION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?:\.route\(\s*"([^"]+)"\s*,\s*(?:[\w:]+::)?(?:get|post|put|delete|patch|any)\(\s*(?:[\w:]+::)?(\w+)\s*\)|\b(?:app|router|server|api)\.(?:get|post|put|delete|patch|all)\(\s*['"`]([^'"`]+)['"`]\s*,\s*(?:[\w.]+\s*,\s*)*(?:\w+\.)*([A-Za-z_$][\w$]*)\s*\))"#,
+    )
+    .expect("valid ROUTE_REGISTRATION_RE")
+});
+
+/// HTTP client call with a literal URL: `fetch(...)`, `axios.get(...)`,
+/// `requests.post(...)`, `client.get(format!(...))`, and similar.
+static REQUEST_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"\b(?:fetch|get|post|put|delete|patch|request)\(\s*(?:&?format!\(\s*)?f?["'`]([^"'`]+)["'`]"#,
+    )
+    .expect("valid REQUEST_RE")
+});
+
+/// Extensions of languages that implement functions declared in Rust `extern` blocks.
+const C_EXTENSIONS: &[&str] = &["c", "h", "cc", "cpp", "cxx", "hpp"];
+
+/// Extensions of Python sources.
+const PY_EXTENSIONS: &[&str] = &["py", "pyi"];
+
+/// Extensions of JavaScript and TypeScript sources.
+const JS_EXTENSIONS: &[&str] = &["js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts"];
+
+/// The binding a Rust function is exported through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Binding {
+    /// C ABI (`#[no_mangle]`, `extern "C"`), called by name from C/C++.
+    C,
+    /// `PyO3` (`#[pyfunction]`), called through the extension module.
+    Python,
+    /// `wasm-bindgen` or `napi-rs`, called through a JavaScript module.
+    Js,
+}
+
+/// A Rust function exported across a language boundary.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Export {
+    /// Rust function name.
+    pub function: String,
+    /// Name the other language calls it by.
+    pub name: String,
+    /// Binding the function is exported through.
+    pub binding: Binding,
+}
+
+/// A name bound by an import statement in Python, JavaScript, or TypeScript.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Import {
+    /// Local name the import binds.
+    pub local: String,
+    /// Imported module path or specifier.
+    pub module: String,
+    /// Member imported from the module (`from m import member`,
+    /// `import { member } from "m"`), or `None` when the module itself is bound.
+    pub member: Option<String>,
+}
+
+/// Cross-language connection points found in one file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Connectors {
+    /// Functions exported across a language boundary.
+    pub exports: Vec<Export>,
+    /// `PyO3` extension modules defined in the file (`#[pymodule]`).
+    pub python_modules: Vec<String>,
+    /// Names bound by imports, used to check that a call reaches an export
+    /// through its binding.
+    pub imports: Vec<Import>,
+    /// Call sites through imported names as `(caller, qualified callee)`
+    /// pairs (`mylib.checksum`); the parser's call list drops the qualifier.
+    pub calls: Vec<(String, String)>,
+    /// Functions declared in Rust `extern` blocks and implemented in another
+    /// language.
+    pub foreign: Vec<String>,
+    /// HTTP route handlers as `(handler, route template)` pairs.
+    pub routes: Vec<(String, String)>,
+    /// HTTP client call sites as `(caller, request path)` pairs.
+    pub requests: Vec<(String, String)>,
+}
+
+/// A function's name, graph node name, and 1-based inclusive line span.
+pub(super) struct Span<'a> {
+    /// Name as written in the source.
+    pub name: &'a str,
+    /// Name of the function's graph node (`Type::method` for methods).
+    pub key: &'a str,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Finds the connection points in one file.
+pub(super) fn extract(path: &str, lines: &[&str], spans: &[Span<'_>]) -> Connectors {
+    let mut connectors = Connectors::default();
+    let ext = extension(path);
+    if ext == "rs" {
+        (connectors.exports, connectors.python_modules) = rust_exports(lines, spans);
+        connectors.foreign = rust_foreign(lines);
+    } else if PY_EXTENSIONS.contains(&ext) {
+        connectors.imports = python_imports(lines);
+        connectors.calls = imported_calls(lines, spans, &connectors.imports);
+    } else if JS_EXTENSIONS.contains(&ext) {
+        connectors.imports = js_imports(lines);
+        connectors.calls = imported_calls(lines, spans, &connectors.imports);
+    }
+
+    for (idx, line) in lines.iter().enumerate() {
+        let line_no = idx + 1;
+        if let Some(caps) = ROUTE_ANNOTATION_RE.captures(line) {
+            let template = caps.iter().skip(1).flatten().next().map(|m| m.as_str());
+            if let (Some(template), Some(handler)) = (template, next_function(spans, line_no)) {
+                connectors
+                    .routes
+                    .push((handler.to_string(), template.to_string()));
+            }
+            continue;
+        }
+        if let Some(caps) = ROUTE_REGISTRATION_RE.captures(line) {
+            let template = caps.get(1).or_else(|| caps.get(3));
+            let handler = caps.get(2).or_else(|| caps.get(4));
+            if let (Some(template), Some(handler)) = (template, handler) {
+                connectors
+                    .routes
+                    .push((handler.as_str().to_string(), template.as_str().to_string()));
+            }
+            continue;
+        }
+        let Some(caller) = enclosing_function(spans, line_no) else {
+            continue;
+        };
+        for caps in REQUEST_RE.captures_iter(line) {
+            let url = &caps[1];
+            if path_segments(url).is_some() {
+                connectors
+                    .requests
+                    .push((caller.to_string(), url.to_string()));
+            }
+        }
+    }
+    connectors
+}
+
+/// Returns the exports and `PyO3` module names of a Rust file.
+///
+/// A function is exported when its attributes include `no_mangle`,
+/// `pyfunction`, `wasm_bindgen`, or `napi`, or its signature is `extern "C"`.
+/// `napi` exports are called by their camel-case name unless renamed.
+fn rust_exports(lines: &[&str], spans: &[Span<'_>]) -> (Vec<Export>, Vec<String>) {
+    let mut exports = Vec::new();
+    let mut modules = Vec::new();
+    for span in spans {
+        let start = span.start.saturating_sub(1);
+        let signature = format!("fn {}", span.name);
+        let Some(decl) =
+            (start..span.end.min(lines.len())).find(|&i| lines[i].contains(&signature))
+        else {
+            continue;
+        };
+        // Attributes between the span start and the signature, plus any the
+        // parser left above the span.
+        let above = lines[..start]
+            .iter()
+            .rev()
+            .take_while(|l| {
+                let l = l.trim_start();
+                l.starts_with("#[") || l.starts_with("//")
+            })
+            .count();
+        let attributes = lines[start - above..decl].join("\n");
+        let renamed = EXPORT_NAME_RE
+            .captures(&attributes)
+            .and_then(|caps| caps.get(1))
+            .map(|m| m.as_str().to_string());
+        if attributes.contains("pymodule") {
+            modules.push(renamed.unwrap_or_else(|| span.name.to_string()));
+            continue;
+        }
+        let binding = if attributes.contains("pyfunction") {
+            Binding::Python
+        } else if attributes.contains("wasm_bindgen") || attributes.contains("napi") {
+            Binding::Js
+        } else if attributes.contains("no_mangle") || lines[decl].contains("extern \"C\"") {
+            Binding::C
+        } else {
+            continue;
+        };
+        let name = renamed.unwrap_or_else(|| {
+            if attributes.contains("napi") {
+                camel_case(span.name)
+            } else {
+                span.name.to_string()
+            }
+        });
+        exports.push(Export {
+            function: span.key.to_string(),
+            name,
+            binding,
+        });
+    }
+    (exports, modules)
+}
+
+/// Converts a snake-case Rust name to the camel case `napi-rs` exports it as.
+fn camel_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' && !out.is_empty() {
+            upper = true;
+        } else if upper {
+            out.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Returns the names bound by the `import` and `from ... import` statements
+/// of a Python file.
+fn python_imports(lines: &[&str]) -> Vec<Import> {
+    let mut imports = Vec::new();
+    let mut idx = 0;
+    while idx < lines.len() {
+        let line = lines[idx];
+        idx += 1;
+        if let Some(caps) = PY_IMPORT_RE.captures(line) {
+            for item in caps[1].split(',') {
+                let (module, local) = split_alias(item, " as ");
+                imports.push(Import {
+                    local: local.to_string(),
+                    module: module.to_string(),
+                    member: None,
+                });
+            }
+        } else if let Some(caps) = PY_FROM_IMPORT_RE.captures(line) {
+            let module = caps[1].to_string();
+            let mut names = caps[2].to_string();
+            // A parenthesized list may continue on the following lines.
+            if line.contains('(') && !line.contains(')') {
+                while idx < lines.len() {
+                    let next = lines[idx];
+                    idx += 1;
+                    let end = next.find(')');
+                    names.push(',');
+                    names.push_str(&next[..end.unwrap_or(next.len())]);
+                    if end.is_some() {
+                        break;
+                    }
+                }
+            }
+            for item in names.split(',') {
+                let item = item.split('#').next().unwrap_or_default();
+                let (member, local) = split_alias(item, " as ");
+                if member.is_empty() || member == "*" {
+                    continue;
+                }
+                imports.push(Import {
+                    local: local.to_string(),
+                    module: module.clone(),
+                    member: Some(member.to_string()),
+                });
+            }
+        }
+    }
+    imports
+}
+
+/// Returns the names bound by the `import` statements and `require` calls of
+/// a JavaScript or TypeScript file.
+fn js_imports(lines: &[&str]) -> Vec<Import> {
+    let mut imports = Vec::new();
+    for line in lines {
+        let (clause, module) = if let Some(caps) = JS_IMPORT_RE.captures(line) {
+            (caps[1].to_string(), caps[2].to_string())
+        } else if let Some(caps) = JS_REQUIRE_RE.captures(line) {
+            (caps[1].to_string(), caps[2].to_string())
+        } else {
+            continue;
+        };
+        let (default, named) = match clause.find('{') {
+            Some(open) => (
+                clause[..open].trim().trim_end_matches(','),
+                clause[open + 1..].split('}').next().unwrap_or_default(),
+            ),
+            None => (clause.as_str(), ""),
+        };
+        for binding in default.split(',').map(str::trim).filter(|b| !b.is_empty()) {
+            let local = binding.strip_prefix("* as ").unwrap_or(binding).trim();
+            imports.push(Import {
+                local: local.to_string(),
+                module: module.clone(),
+                member: None,
+            });
+        }
+        for item in named.split(',') {
+            let item = item.trim().trim_start_matches("type ");
+            // `{ a as b }` in imports, `{ a: b }` in destructured requires
+            let (member, local) = if item.contains(':') {
+                split_alias(item, ":")
+            } else {
+                split_alias(item, " as ")
+            };
+            if member.is_empty() {
+                continue;
+            }
+            imports.push(Import {
+                local: local.to_string(),
+                module: module.clone(),
+                member: Some(member.to_string()),
+            });
+        }
+    }
+    imports
+}
+
+/// Returns the calls inside functions that go through an imported name, with
+/// their qualifier.
+fn imported_calls(lines: &[&str], spans: &[Span<'_>], imports: &[Import]) -> Vec<(String, String)> {
+    let mut calls = Vec::new();
+    for (idx, line) in lines.iter().enumerate() {
+        let Some(caller) = enclosing_function(spans, idx + 1) else {
+            continue;
+        };
+        for caps in QUALIFIED_CALL_RE.captures_iter(line) {
+            let qualified: String = caps[1].split_whitespace().collect();
+            if resolve_import(imports, &qualified).is_some() {
+                calls.push((caller.to_string(), qualified));
+            }
+        }
+    }
+    calls
+}
+
+/// Splits `name <sep> alias` into `(name, alias)`, the alias defaulting to the
+/// name.
+fn split_alias<'a>(item: &'a str, sep: &str) -> (&'a str, &'a str) {
+    let item = item.trim();
+    match item.split_once(sep) {
+        Some((name, alias)) => (name.trim(), alias.trim()),
+        None => (item, item),
+    }
+}
+
+/// Returns the functions declared in the `extern` blocks of a Rust file.
+fn rust_foreign(lines: &[&str]) -> Vec<String> {
+    let mut foreign = Vec::new();
+    let mut in_block = false;
+    for line in lines {
+        if in_block {
+            if line.trim() == "}" {
+                in_block = false;
+            } else if let Some(caps) = FOREIGN_FN_RE.captures(line) {
+                foreign.push(caps[1].to_string());
+            }
+        } else {
+            in_block = EXTERN_BLOCK_RE.is_match(line);
+        }
+    }
+    foreign
+}
+
+/// Returns the first function starting within ten lines after `line`, the
+/// target of a route attribute or decorator.
+fn next_function<'a>(spans: &[Span<'a>], line: usize) -> Option<&'a str> {
+    spans
+        .iter()
+        .filter(|s| s.start >= line && s.start <= line + 10)
+        .min_by_key(|s| s.start)
+        .map(|s| s.key)
+}
+
+/// Returns the innermost function whose span contains `line`.
+fn enclosing_function<'a>(spans: &[Span<'a>], line: usize) -> Option<&'a str> {
+    spans
+        .iter()
+        .filter(|s| s.start <= line && line <= s.end)
+        .min_by_key(|s| s.end - s.start)
+        .map(|s| s.key)
+}
+
+/// Splits a route template or request URL into path segments, `None` marking a
+/// parameter.
+///
+/// The scheme and host, a leading base-URL placeholder (`${API}/users`), and
+/// the query string are dropped. Returns `None` unless the rest is an absolute
+/// path with at least one literal segment.
+fn path_segments(raw: &str) -> Option<Vec<Option<&str>>> {
+    let mut path = raw.trim();
+    if let Some(pos) = path.find("://") {
+        let rest = &path[pos + 3..];
+        path = rest.find('/').map_or("", |slash| &rest[slash..]);
+    }
+    while let Some(rest) = path.strip_prefix("${").or_else(|| path.strip_prefix('{')) {
+        path = rest.find('}').map_or("", |end| &rest[end + 1..]);
+    }
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    if !path.starts_with('/') {
+        return None;
+    }
+    let segments: Vec<Option<&str>> = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            let param = s.starts_with(':') || s.starts_with('<') || s.contains('{') || s == "*";
+            (!param).then_some(s)
+        })
+        .collect();
+    segments.iter().any(Option::is_some).then_some(segments)
+}
+
+/// Returns `true` if a request path matches a route template segment by segment.
+fn route_matches(route: &[Option<&str>], request: &[Option<&str>]) -> bool {
+    route.len() == request.len()
+        && route
+            .iter()
+            .zip(request)
+            .all(|(r, q)| r.is_none() || q.is_none() || r == q)
+}
+
+fn extension(path: &str) -> &str {
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+}
+
+/// Resolves `callee` through the caller's imports to the module it reaches
+/// and the name it calls there, or `None` when no import binds it.
+fn resolve_import(imports: &[Import], callee: &str) -> Option<(String, String)> {
+    if let Some((qualifier, name)) = callee.rsplit_once('.') {
+        let import = imports.iter().find(|i| i.local == qualifier)?;
+        let module = match &import.member {
+            Some(member) => format!("{}.{member}", import.module),
+            None => import.module.clone(),
+        };
+        return Some((module, name.to_string()));
+    }
+    imports
+        .iter()
+        .find(|i| i.local == callee)
+        .and_then(|i| Some((i.module.clone(), i.member.clone()?)))
+}
+
+/// Returns the exported name and binding a call reaches from a file with
+/// extension `ext`, or `None` when the call does not go through a binding.
+///
+/// C/C++ callers call C ABI exports unqualified. Python callers must reach the
+/// name through an import of one of `python_modules`; JavaScript and
+/// TypeScript callers through an imported name or namespace.
+fn call_target(
+    ext: &str,
+    imports: &[Import],
+    python_modules: &HashSet<&str>,
+    callee: &str,
+) -> Option<(String, Binding)> {
+    if C_EXTENSIONS.contains(&ext) {
+        return (!callee.contains(['.', ':'])).then(|| (callee.to_string(), Binding::C));
+    }
+    let (module, name) = resolve_import(imports, callee)?;
+    if PY_EXTENSIONS.contains(&ext) {
+        let module = module.rsplit('.').next().unwrap_or_default();
+        python_modules
+            .contains(module)
+            .then_some((name, Binding::Python))
+    } else if JS_EXTENSIONS.contains(&ext) {
+        Some((name, Binding::Js))
+    } else {
+        None
+    }
+}
+
+/// Adds `Binds` and `Requests` edges from the call sites in `sources` to the
+/// exports, foreign definitions, and route handlers in `targets`.
+///
+/// A call matches an export only through the export's binding (see
+/// [`call_target`]); `PyO3` modules are looked up among `targets`. A Rust
+/// call matches a foreign definition by its last path segment when the
+/// caller's file declares the function in an `extern` block.
+pub(super) fn link_connectors(
+    graph: &mut GraphDb,
+    names: &HashMap<String, Vec<NodeIndex>>,
+    sources: &[(&str, &FileSymbols)],
+    targets: &[(&str, &FileSymbols)],
+) {
+    let mut exports: HashMap<&str, Vec<(NodeIndex, Binding)>> = HashMap::new();
+    let mut python_modules: HashSet<&str> = HashSet::new();
+    let mut routes: Vec<(Vec<Option<&str>>, NodeIndex)> = Vec::new();
+    let mut foreign_definitions: HashMap<&str, Vec<NodeIndex>> = HashMap::new();
+    for &(rel, symbols) in targets {
+        if C_EXTENSIONS.contains(&extension(rel)) {
+            for function in &symbols.functions {
+                if let Some(node) = function_in(graph, names, rel, function) {
+                    foreign_definitions.entry(function).or_default().push(node);
+                }
+            }
+        }
+        for export in &symbols.connectors.exports {
+            if let Some(node) = function_in(graph, names, rel, &export.function) {
+                exports
+                    .entry(&export.name)
+                    .or_default()
+                    .push((node, export.binding));
+            }
+        }
+        python_modules.extend(symbols.connectors.python_modules.iter().map(String::as_str));
+        for (handler, template) in &symbols.connectors.routes {
+            if let (Some(node), Some(segments)) = (
+                function_in(graph, names, rel, handler),
+                path_segments(template),
+            ) {
+                routes.push((segments, node));
+            }
+        }
+    }
+    let mut edges: Vec<(NodeIndex, NodeIndex, Edge)> = Vec::new();
+    for &(rel, symbols) in sources {
+        let ext = extension(rel);
+        let calls = if PY_EXTENSIONS.contains(&ext) || JS_EXTENSIONS.contains(&ext) {
+            &symbols.connectors.calls
+        } else {
+            &symbols.calls
+        };
+        for (caller, callee) in calls {
+            let exported = call_target(ext, &symbols.connectors.imports, &python_modules, callee)
+                .and_then(|(name, binding)| {
+                    exports
+                        .get(name.as_str())
+                        .map(|c| c.iter().filter(move |(_, b)| *b == binding))
+                })
+                .into_iter()
+                .flatten()
+                .map(|&(node, _)| node);
+            let short = callee.rsplit(['.', ':']).next().unwrap_or(callee);
+            let foreign = symbols
+                .connectors
+                .foreign
+                .iter()
+                .any(|f| f == short)
+                .then(|| foreign_definitions.get(short))
+                .flatten()
+                .into_iter()
+                .flatten()
+                .copied();
+            let targets: Vec<NodeIndex> = exported.chain(foreign).collect();
+            if targets.is_empty() {
+                continue;
+            }
+            if let Some(src) = function_in(graph, names, rel, caller) {
+                edges.extend(targets.into_iter().map(|dst| (src, dst, Edge::Binds)));
+            }
+        }
+
+        for (caller, url) in &symbols.connectors.requests {
+            let (Some(src), Some(request)) =
+                (function_in(graph, names, rel, caller), path_segments(url))
+            else {
+                continue;
+            };
+            edges.extend(
+                routes
+                    .iter()
+                    .filter(|(route, _)| route_matches(route, &request))
+                    .map(|&(_, dst)| (src, dst, Edge::Requests)),
+            );
+        }
+    }
+
+    for (src, dst, edge) in edges {
+        if src != dst {
+            super::repo::add_edge_once(graph, src, dst, edge);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spans<'a>(items: &[(&'a str, usize, usize)]) -> Vec<Span<'a>> {
+        items
+            .iter()
+            .map(|&(name, start, end)| Span {
+                name,
+                key: name,
+                start,
+                end,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_extract_rust_exports_and_foreign_declarations() {
+        let source = "#[no_mangle]\npub extern \"C\" fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n\n#[wasm_bindgen(js_name = parseConfig)]\npub fn parse_config() {}\n\nfn internal() {}\n\nextern \"C\" {\n    fn c_hash(data: *const u8) -> u32;\n}\n";
+        let lines: Vec<&str> = source.lines().collect();
+
+        let connectors = extract(
+            "src/lib.rs",
+            &lines,
+            &spans(&[("add", 1, 4), ("parse_config", 6, 7), ("internal", 9, 9)]),
+        );
+
+        let exports: Vec<(&str, &str, Binding)> = connectors
+            .exports
+            .iter()
+            .map(|e| (e.function.as_str(), e.name.as_str(), e.binding))
+            .collect();
+        assert_eq!(
+            exports,
+            vec![
+                ("add", "add", Binding::C),
+                ("parse_config", "parseConfig", Binding::Js),
+            ]
+        );
+        assert_eq!(connectors.foreign, vec!["c_hash".to_string()]);
+    }
+
+    #[test]
+    fn test_extract_pyo3_module_and_napi_names() {
+        let source = "#[pyfunction]\nfn checksum() {}\n\n#[pymodule]\nfn mylib(m: &Bound<'_, PyModule>) {}\n\n#[napi]\npub fn load_config() {}\n";
+        let lines: Vec<&str> = source.lines().collect();
+
+        let connectors = extract(
+            "src/lib.rs",
+            &lines,
+            &spans(&[("checksum", 1, 2), ("mylib", 4, 5), ("load_config", 7, 8)]),
+        );
+
+        let exports: Vec<(&str, Binding)> = connectors
+            .exports
+            .iter()
+            .map(|e| (e.name.as_str(), e.binding))
+            .collect();
+        assert_eq!(
+            exports,
+            vec![("checksum", Binding::Python), ("loadConfig", Binding::Js)]
+        );
+        assert_eq!(connectors.python_modules, vec!["mylib".to_string()]);
+    }
+
+    #[test]
+    fn test_calls_reach_exports_only_through_their_binding() {
+        let python = "import mylib\nimport other as o\nfrom pkg._native import (\n    checksum as cs,\n    verify,\n)\n";
+        let lines: Vec<&str> = python.lines().collect();
+        let imports = extract("scripts/run.py", &lines, &[]).imports;
+        let modules: HashSet<&str> = HashSet::from(["mylib", "_native"]);
+        let target = |callee: &str| call_target("py", &imports, &modules, callee);
+
+        assert_eq!(
+            target("mylib.checksum"),
+            Some(("checksum".to_string(), Binding::Python))
+        );
+        assert_eq!(
+            target("cs"),
+            Some(("checksum".to_string(), Binding::Python))
+        );
+        assert_eq!(target("o.checksum"), None);
+        assert_eq!(target("hashlib.checksum"), None);
+        assert_eq!(target("checksum"), None);
+
+        let js = "import init, { parseConfig as parse } from \"../pkg/mylib\";\nimport * as wasm from \"../pkg/mylib\";\nconst { loadConfig } = require(\"./index.node\");\n";
+        let lines: Vec<&str> = js.lines().collect();
+        let imports = extract("web/app.ts", &lines, &[]).imports;
+        let target = |callee: &str| call_target("ts", &imports, &modules, callee);
+
+        assert_eq!(
+            target("parse"),
+            Some(("parseConfig".to_string(), Binding::Js))
+        );
+        assert_eq!(target("wasm.add"), Some(("add".to_string(), Binding::Js)));
+        assert_eq!(
+            target("loadConfig"),
+            Some(("loadConfig".to_string(), Binding::Js))
+        );
+        assert_eq!(target("settings.add"), None);
+        assert_eq!(target("add"), None);
+
+        assert_eq!(
+            call_target("c", &[], &modules, "add"),
+            Some(("add".to_string(), Binding::C))
+        );
+        assert_eq!(call_target("c", &[], &modules, "lib.add"), None);
+    }
+
+    #[test]
+    fn test_extract_routes_and_requests() {
+        let server = "let app = Router::new()\n    .route(\"/api/users/:id\", get(get_user));\n\n#[post(\"/api/users\")]\nasync fn create_user() {}\n";
+        let lines: Vec<&str> = server.lines().collect();
+        let connectors = extract("src/server.rs", &lines, &spans(&[("create_user", 4, 5)]));
+        assert_eq!(
+            connectors.routes,
+            vec![
+                ("get_user".to_string(), "/api/users/:id".to_string()),
+                ("create_user".to_string(), "/api/users".to_string()),
+            ]
+        );
+
+        let client = "export async function loadUser(id) {\n  const res = await fetch(`${API_BASE}/api/users/${id}`);\n  const cfg = settings.get(\"theme\");\n  return res.json();\n}\n";
+        let lines: Vec<&str> = client.lines().collect();
+        let connectors = extract("web/user.ts", &lines, &spans(&[("loadUser", 1, 5)]));
+        assert_eq!(
+            connectors.requests,
+            vec![(
+                "loadUser".to_string(),
+                "${API_BASE}/api/users/${id}".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_path_segments_match_parameters() {
+        let route = path_segments("/api/users/{id}").unwrap();
+        assert!(route_matches(
+            &route,
+            &path_segments("https://example.com/api/users/42?full=1").unwrap()
+        ));
+        assert!(route_matches(
+            &route,
+            &path_segments("${BASE}/api/users/${id}").unwrap()
+        ));
+        assert!(!route_matches(
+            &route,
+            &path_segments("/api/users").unwrap()
+        ));
+        assert!(!route_matches(
+            &route,
+            &path_segments("/api/teams/7").unwrap()
+        ));
+        assert_eq!(path_segments("theme"), None);
+        assert_eq!(path_segments("/"), None);
+    }
+}
@@ -0,0 +1,177 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Graphviz DOT and Mermaid rendering of structural subgraphs.
+//!
+//! Both renderers emit one node per graph node (labelled with its kind, name,
+//! and file) and one edge per graph edge (labelled with the edge kind).
+//! `Contains` edges are omitted because file membership is already part of
+//! each node label.
+
+use std::fmt::Write as _;
+
+use petgraph::visit::EdgeRef as _;
+
+use super::{Edge, GraphDb, Node};
+
+/// Renders `graph` as a Graphviz DOT digraph.
+#[must_use]
+pub fn to_dot(graph: &GraphDb) -> String {
+    let mut out = String::from("digraph aptu {\n    rankdir=LR;\n    node [shape=box];\n");
+    for idx in graph.node_indices() {
+        let _ = writeln!(
+            out,
+            "    n{} [label=\"{}\"];",
+            idx.index(),
+            label(&graph[idx], "\\n", escape_dot)
+        );
+    }
+    for edge_ref in graph.edge_references() {
+        if matches!(edge_ref.weight(), Edge::Contains) {
+            continue;
+        }
+        let _ = writeln!(
+            out,
+            "    n{} -> n{} [label=\"{}\"];",
+            edge_ref.source().index(),
+            edge_ref.target().index(),
+            edge_label(*edge_ref.weight())
+        );
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Renders `graph` as a Mermaid flowchart.
+#[must_use]
+pub fn to_mermaid(graph: &GraphDb) -> String {
+    let mut out = String::from("flowchart LR\n");
+    for idx in graph.node_indices() {
+        let _ = writeln!(
+            out,
+            "    n{}[\"{}\"]",
+            idx.index(),
+            label(&graph[idx], "<br/>", escape_mermaid)
+        );
+    }
+    for edge_ref in graph.edge_references() {
+        if matches!(edge_ref.weight(), Edge::Contains) {
+            continue;
+        }
+        let _ = writeln!(
+            out,
+            "    n{} -->|{}| n{}",
+            edge_ref.source().index(),
+            edge_label(*edge_ref.weight()),
+            edge_ref.target().index()
+        );
+    }
+    out
+}
+
+/// Returns the short kind name of a node (`fn`, `struct`, `file`, ...).
+#[must_use]
+pub fn node_kind(node: &Node) -> &'static str {
+    match node {
+        Node::File { .. } => "file",
+        Node::Module { .. } => "module",
+        Node::Function { .. } => "fn",
+        Node::Struct { .. } => "struct",
+        Node::Enum { .. } => "enum",
+        Node::Trait { .. } => "trait",
+        Node::Impl { .. } => "impl",
+    }
+}
+
+/// Returns the lowercase label of an edge kind.
+#[must_use]
+pub fn edge_label(edge: Edge) -> &'static str {
+    match edge {
+        Edge::Contains => "contains",
+        Edge::Calls => "calls",
+        Edge::Imports => "imports",
+        Edge::Implements => "implements",
+        Edge::HasMethod => "has_method",
+        Edge::Modifies => "modifies",
+        Edge::Tests => "tests",
+        Edge::Binds => "binds",
+        Edge::Requests => "requests",
+    }
+}
+
+/// Builds a node label, escaping the name and path (but not `line_break`)
+/// with `escape`.
+fn label(node: &Node, line_break: &str, escape: fn(&str) -> String) -> String {
+    let head = format!("{} {}", node_kind(node), escape(node.name()));
+    if node.path().is_empty() || matches!(node, Node::File { .. }) {
+        head
+    } else {
+        format!("{head}{line_break}{}", escape(node.path()))
+    }
+}
+
+/// Escapes a DOT quoted string: backslashes first, so the escapes added for
+/// quotes are not themselves doubled.
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(s: &str) -> String {
+    s.replace('"', "#quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> GraphDb {
+        let mut graph = GraphDb::new();
+        let run = graph.add_node(Node::Function {
+            name: "run".to_string(),
+            path: "src/main.rs".to_string(),
+            visibility: "private".to_string(),
+        });
+        let parse = graph.add_node(Node::Function {
+            name: "parse".to_string(),
+            path: "src/lib.rs".to_string(),
+            visibility: "pub".to_string(),
+        });
+        graph.add_edge(run, parse, Edge::Calls);
+        graph
+    }
+
+    #[test]
+    fn test_to_dot_emits_nodes_and_labelled_edges() {
+        let dot = to_dot(&sample());
+
+        assert!(dot.starts_with("digraph aptu {"));
+        assert!(dot.contains("n0 [label=\"fn run\\nsrc/main.rs\"];"));
+        assert!(dot.contains("n0 -> n1 [label=\"calls\"];"));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+
+    #[test]
+    fn test_to_dot_escapes_backslashes_and_quotes() {
+        let mut graph = GraphDb::new();
+        graph.add_node(Node::Function {
+            name: "say\"hi\"".to_string(),
+            path: "src\\main.rs".to_string(),
+            visibility: "private".to_string(),
+        });
+
+        let dot = to_dot(&graph);
+
+        assert!(
+            dot.contains("n0 [label=\"fn say\\\"hi\\\"\\nsrc\\\\main.rs\"];"),
+            "{dot}"
+        );
+    }
+
+    #[test]
+    fn test_to_mermaid_emits_flowchart() {
+        let mermaid = to_mermaid(&sample());
+
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("n1[\"fn parse<br/>src/lib.rs\"]"));
+        assert!(mermaid.contains("n0 -->|calls| n1"));
+    }
+}
@@ -7,10 +7,19 @@
 //! produced by `aptu-coder-core`, caches the result on disk keyed by
 //! repository and commit SHA, and computes a bounded blast-radius subgraph
 //! around modified symbols for prompt injection.
+//!
+//! PR review uses the repository-wide graph from [`repo`], so callers in files
+//! the PR does not touch are part of the blast radius. [`connect`] links
+//! symbols across languages (FFI exports, bindings, HTTP routes) in that graph.
 
 pub mod builder;
 pub mod cache;
+#[cfg(all(feature = "ast-context", not(target_arch = "wasm32")))]
+pub mod connect;
+pub mod export;
 pub mod query;
+#[cfg(all(feature = "ast-context", not(target_arch = "wasm32")))]
+pub mod repo;
 
 pub use builder::build_from_analysis;
 pub use query::blast_radius;
@@ -18,7 +27,7 @@ pub use query::blast_radius;
 use serde::{Deserialize, Serialize};
 
 /// A node in the structural graph, representing a source-code symbol.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Node {
     /// A source file.
     File {
@@ -118,14 +127,19 @@ pub enum Edge {
     Calls,
     /// The source node imports the target node (module or item import).
     Imports,
-    /// The source node (impl) implements the target node (trait or type).
+    /// The source node (impl or type) implements the target node (trait or base type).
     Implements,
-    /// The source node (impl) has the target node as a method.
+    /// The source node (impl or type) has the target node as a method.
     HasMethod,
     /// The source node modifies the target node (ephemeral; never cached).
     Modifies,
     /// The source node (test function) tests the target node.
     Tests,
+    /// The source node calls the target node across a language boundary
+    /// (FFI export, generated binding, or foreign declaration).
+    Binds,
+    /// The source node sends an HTTP request routed to the target node.
+    Requests,
 }
 
 /// The structural graph database: a directed graph of `Node`s connected by `Edge`s.
@@ -2,9 +2,17 @@
 
 //! Graph queries: blast-radius BFS, ephemeral Modifies edges, and text rendering.
 //!
-//! The main entry point is [`blast_radius`], which performs a bounded BFS in both
-//! directions from a set of modified nodes over [`Edge::Calls`], [`Edge::Implements`],
-//! [`Edge::HasMethod`], and [`Edge::Tests`] edges, returning the induced subgraph.
+//! The main entry point is [`blast_radius`], which performs a depth-bounded BFS in
+//! both directions from a set of modified nodes over [`Edge::Calls`],
+//! [`Edge::Implements`], [`Edge::HasMethod`], and [`Edge::Tests`] edges, ranks the
+//! reached nodes (see [`rank_blast_radius`]), and returns the induced subgraph of
+//! the top-ranked ones. Cross-language [`Edge::Binds`] and [`Edge::Requests`]
+//! edges count as calls throughout, so queries span FFI and HTTP boundaries.
+//!
+//! [`resolve_target`], [`direct_callers`], [`direct_callees`], and
+//! [`shortest_path`] back the `aptu graph` query commands,
+//! [`affected_tests`] backs `aptu tests affected`, and [`relevant_symbols`]
+//! locates the code an issue talks about for `aptu issue plan`.
 
 use std::collections::{HashSet, VecDeque};
 
@@ -16,7 +24,8 @@ use super::{Edge, GraphDb, Node};
 
 /// Finds nodes matching the given symbol names and returns their indices. No edges are added.
 ///
-/// A symbol matches if its `Node::name()` exactly equals one of `modified_symbols`.
+/// A symbol matches if its `Node::name()`, or the method name after its
+/// `Type::` prefix, exactly equals one of `modified_symbols`.
 #[must_use]
 pub fn find_modified_nodes(graph: &mut GraphDb, modified_symbols: &[&str]) -> Vec<NodeIndex> {
     let symbol_set: HashSet<&str> = modified_symbols.iter().copied().collect();
@@ -26,24 +35,150 @@ pub fn find_modified_nodes(graph: &mut GraphDb, modified_symbols: &[&str]) -> Ve
         .node_indices()
         .filter(|&idx| {
             let name = graph[idx].name();
-            symbol_set.contains(name)
+            symbol_set.contains(name) || symbol_set.contains(bare_name(name))
         })
         .collect();
 
     matched
 }
 
+/// Score weight of hop distance (divided by the number of hops).
+const DISTANCE_WEIGHT: f64 = 4.0;
+/// Score weight of fan-in (multiplied by `ln(1 + callers)`).
+const FAN_IN_WEIGHT: f64 = 1.0;
+/// Score bonus for public symbols.
+const PUBLIC_BONUS: f64 = 1.0;
+/// Score bonus for functions with no [`Edge::Tests`] edge.
+const UNTESTED_BONUS: f64 = 0.5;
+/// Candidates ranked per kept node: the traversal stops discovering nodes
+/// once it has reached `max_nodes` times this many.
+const CANDIDATES_PER_NODE: usize = 10;
+
+/// A node reached by the blast-radius traversal, with its ranking inputs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedNode {
+    /// Index of the node in the full graph.
+    pub index: NodeIndex,
+    /// Hop distance from the nearest modified node (0 for modified nodes).
+    pub distance: usize,
+    /// Number of distinct functions calling this node in the full graph.
+    pub fan_in: usize,
+    /// Whether an [`Edge::Tests`] edge points at this node.
+    pub tested: bool,
+    /// Combined ranking score; higher is kept first.
+    pub score: f64,
+}
+
+/// Ranks the nodes within `max_depth` hops of `modified_nodes`.
+///
+/// The breadth-first traversal stops discovering nodes once it has reached
+/// `max_nodes` times [`CANDIDATES_PER_NODE`], so a hub in a repository-wide
+/// graph cannot pull in the whole repository; the nearest nodes are ranked.
+/// Traverses [`Edge::Calls`], [`Edge::Implements`], [`Edge::HasMethod`], and
+/// [`Edge::Tests`] edges in both directions. Each reached node is scored by
+/// proximity (closer is better), fan-in, public visibility, and missing test
+/// coverage, since an untested public function with many callers is where a
+/// change is most likely to break something unnoticed. Modified nodes come
+/// first, then the rest by descending score (ties broken by node index).
+#[must_use]
+pub fn rank_blast_radius(
+    graph: &GraphDb,
+    modified_nodes: &[NodeIndex],
+    max_nodes: usize,
+    max_depth: usize,
+) -> Vec<RankedNode> {
+    use std::collections::HashMap;
+
+    let max_candidates = max_nodes.saturating_mul(CANDIDATES_PER_NODE);
+
+    let mut distance: HashMap<NodeIndex, usize> = HashMap::new();
+    let mut queue: VecDeque<NodeIndex> = VecDeque::new();
+    for &node in modified_nodes {
+        if graph.node_weight(node).is_some() && !distance.contains_key(&node) {
+            distance.insert(node, 0);
+            queue.push_back(node);
+        }
+    }
+
+    while let Some(current) = queue.pop_front() {
+        let depth = distance[&current];
+        if depth >= max_depth {
+            continue;
+        }
+        if distance.len() >= max_candidates {
+            break;
+        }
+        let neighbours = graph
+            .edges_directed(current, Direction::Outgoing)
+            .filter(|e| is_dependency(*e.weight()))
+            .map(|e| e.target())
+            .chain(
+                graph
+                    .edges_directed(current, Direction::Incoming)
+                    .filter(|e| is_dependency(*e.weight()))
+                    .map(|e| e.source()),
+            )
+            .collect::<Vec<_>>();
+        for next in neighbours {
+            if distance.len() >= max_candidates {
+                break;
+            }
+            if let std::collections::hash_map::Entry::Vacant(entry) = distance.entry(next) {
+                entry.insert(depth + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    let mut ranked: Vec<RankedNode> = distance
+        .into_iter()
+        .map(|(index, distance)| {
+            let fan_in = graph
+                .edges_directed(index, Direction::Incoming)
+                .filter(|e| is_call(*e.weight()))
+                .map(|e| e.source())
+                .collect::<HashSet<_>>()
+                .len();
+            let tested = has_tests(graph, index);
+            let mut score = if distance == 0 {
+                f64::INFINITY
+            } else {
+                #[allow(clippy::cast_precision_loss)]
+                let hops = distance as f64;
+                DISTANCE_WEIGHT / hops
+            };
+            #[allow(clippy::cast_precision_loss)]
+            let callers = fan_in as f64;
+            score += FAN_IN_WEIGHT * callers.ln_1p();
+            if is_public(&graph[index]) {
+                score += PUBLIC_BONUS;
+            }
+            if matches!(graph[index], Node::Function { .. }) && !tested && !is_test(graph, index) {
+                score += UNTESTED_BONUS;
+            }
+            RankedNode {
+                index,
+                distance,
+                fan_in,
+                tested,
+                score,
+            }
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.index.cmp(&b.index)));
+    ranked
+}
+
 /// Computes the blast-radius subgraph from a set of `modified_nodes`.
 ///
-/// Performs a bounded BFS in both directions (callers and callees) from each
-/// node in `modified_nodes` over [`Edge::Calls`], [`Edge::Implements`],
-/// [`Edge::HasMethod`], and [`Edge::Tests`] edges. [`Edge::Contains`] and
-/// [`Edge::Modifies`] are excluded.
+/// Ranks the nodes within `max_depth` hops with [`rank_blast_radius`] and
+/// keeps the `max_nodes` highest-ranked ones (modified nodes first), so the
+/// cap drops the least relevant nodes rather than whatever a BFS happened to
+/// reach last. [`Edge::Contains`] and [`Edge::Modifies`] are not traversed.
 ///
-/// The returned graph contains at most `max_nodes` nodes (including the seed
-/// nodes). Traversal stops as soon as the cap is reached. `max_depth` caps the
-/// number of hop levels traversed from the seed nodes; whichever limit
-/// (`max_nodes` or `max_depth`) triggers first stops the BFS.
+/// When a kept node is covered by a test, one of its tests is kept alongside
+/// it (within the same cap), so [`render_subgraph_text`] only flags callers
+/// that really have no test.
 #[must_use]
 pub fn blast_radius(
     graph: &GraphDb,
@@ -55,50 +190,362 @@ pub fn blast_radius(
         return GraphDb::new();
     }
 
-    let relevant_edges = |e: &Edge| {
-        matches!(
-            e,
-            Edge::Calls | Edge::Implements | Edge::HasMethod | Edge::Tests
-        )
-    };
+    let ranked = rank_blast_radius(graph, modified_nodes, max_nodes, max_depth);
+    let mut kept: HashSet<NodeIndex> = HashSet::new();
+    for node in &ranked {
+        if kept.len() >= max_nodes {
+            break;
+        }
+        if kept.contains(&node.index) {
+            continue;
+        }
+        let testers: Vec<NodeIndex> = graph
+            .edges_directed(node.index, Direction::Incoming)
+            .filter(|e| matches!(e.weight(), Edge::Tests))
+            .map(|e| e.source())
+            .collect();
+        let tester = if testers.iter().any(|t| kept.contains(t)) {
+            None
+        } else {
+            testers.into_iter().min()
+        };
+        match tester {
+            Some(test) if kept.len() + 2 <= max_nodes => {
+                kept.insert(node.index);
+                kept.insert(test);
+            }
+            // A seed is kept even if its test does not fit.
+            Some(_) if node.distance == 0 => {
+                kept.insert(node.index);
+            }
+            // Kept without its test, the node would be flagged untested; let
+            // the next candidate have the slot instead.
+            Some(_) => {
+                tracing::debug!(
+                    node = graph[node.index].name(),
+                    "blast radius: no room for node and its test"
+                );
+            }
+            None => {
+                kept.insert(node.index);
+            }
+        }
+    }
+
+    build_induced_subgraph(graph, &kept)
+}
 
-    let mut visited: HashSet<NodeIndex> = HashSet::new();
-    let mut queue: VecDeque<(NodeIndex, usize)> = VecDeque::new();
+/// Returns `true` if any [`Edge::Tests`] edge points at `node`.
+fn has_tests(graph: &GraphDb, node: NodeIndex) -> bool {
+    graph
+        .edges_directed(node, Direction::Incoming)
+        .any(|e| matches!(e.weight(), Edge::Tests))
+}
 
-    for &node in modified_nodes {
-        if graph.node_weight(node).is_some() && visited.insert(node) {
-            queue.push_back((node, 0));
+/// Returns `true` if `node` has an outgoing call edge (see [`is_call`]).
+fn calls_any(graph: &GraphDb, node: NodeIndex) -> bool {
+    graph
+        .edges_directed(node, Direction::Outgoing)
+        .any(|e| is_call(*e.weight()))
+}
+
+/// Returns `true` if `node` is a test (it has an outgoing [`Edge::Tests`] edge).
+fn is_test(graph: &GraphDb, node: NodeIndex) -> bool {
+    graph
+        .edges_directed(node, Direction::Outgoing)
+        .any(|e| matches!(e.weight(), Edge::Tests))
+}
+
+/// Words too common to say where a change belongs.
+const STOP_WORDS: &[&str] = &[
+    "the", "and", "for", "with", "that", "this", "from", "when", "should", "not", "are", "was",
+    "but", "can", "has", "have", "into", "new", "get", "set", "add", "use", "all", "any", "its",
+    "issue", "error", "fix", "bug", "test", "tests", "none", "some", "self",
+];
+
+/// File stems too generic to point at a single module.
+const GENERIC_STEMS: &[&str] = &["mod", "lib", "main", "index", "__init__"];
+
+/// Splits text into lowercase words, breaking identifiers at `_` and at
+/// camelCase boundaries. Short and common words are dropped.
+fn words(text: &str) -> HashSet<String> {
+    let mut words = HashSet::new();
+    for token in text.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut word = String::new();
+        let mut prev_lower = false;
+        for c in token.chars() {
+            if c.is_ascii_uppercase() && prev_lower {
+                words.insert(std::mem::take(&mut word));
+            }
+            prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+            word.push(c.to_ascii_lowercase());
         }
+        words.insert(word);
     }
+    words.retain(|w| w.len() >= 3 && !STOP_WORDS.contains(&w.as_str()));
+    words
+}
 
-    while let Some((current, depth)) = queue.pop_front() {
-        if visited.len() >= max_nodes {
-            break;
+/// Returns `true` for names made of several words (`load_config`,
+/// `GraphDb`), which are specific enough to match on their own.
+fn is_compound(name: &str) -> bool {
+    name.contains('_')
+        || name
+            .chars()
+            .zip(name.chars().skip(1))
+            .any(|(a, b)| a.is_ascii_lowercase() && b.is_ascii_uppercase())
+}
+
+/// Finds the symbols a piece of text (such as an issue) is most likely about.
+///
+/// Each function, struct, enum, and trait is scored against `text`: naming a
+/// multi-word identifier outright scores highest, then mentioning the
+/// symbol's file, then each word of the symbol name that appears in the text.
+/// Symbols with a single word in common are not returned, nor are tests.
+/// Returns up to `limit` nodes, best first.
+#[must_use]
+pub fn relevant_symbols(graph: &GraphDb, text: &str, limit: usize) -> Vec<NodeIndex> {
+    let text_words = words(text);
+    let identifiers: HashSet<&str> = text
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .filter(|t| !t.is_empty())
+        .collect();
+
+    let mut scored: Vec<(usize, NodeIndex)> = graph
+        .node_indices()
+        .filter(|&idx| {
+            matches!(
+                graph[idx],
+                Node::Function { .. }
+                    | Node::Struct { .. }
+                    | Node::Enum { .. }
+                    | Node::Trait { .. }
+            ) && !is_test(graph, idx)
+        })
+        .filter_map(|idx| {
+            let node = &graph[idx];
+            let name = bare_name(node.name());
+            let mut score = 0;
+            if is_compound(name) && identifiers.contains(name) {
+                score += 10;
+            }
+            let path = std::path::Path::new(node.path());
+            let generic = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .is_some_and(|stem| GENERIC_STEMS.contains(&stem));
+            if !node.path().is_empty() && text.contains(node.path()) {
+                score += 4;
+            } else if let Some(file_name) = path.file_name().and_then(|n| n.to_str())
+                && !generic
+                && text.contains(file_name)
+            {
+                score += 3;
+            }
+            let shared = words(name)
+                .iter()
+                .filter(|w| text_words.contains(*w))
+                .count();
+            score += 2 * shared;
+            (score >= 4).then_some((score, idx))
+        })
+        .collect();
+
+    scored.sort_by(|(a_score, a), (b_score, b)| {
+        b_score
+            .cmp(a_score)
+            .then_with(|| graph[*a].path().cmp(graph[*b].path()))
+            .then_with(|| graph[*a].name().cmp(graph[*b].name()))
+    });
+    scored.into_iter().take(limit).map(|(_, idx)| idx).collect()
+}
+
+/// Returns `true` for symbols whose visibility starts with `pub`.
+fn is_public(node: &Node) -> bool {
+    match node {
+        Node::Function { visibility, .. }
+        | Node::Struct { visibility, .. }
+        | Node::Enum { visibility, .. }
+        | Node::Trait { visibility, .. } => visibility.starts_with("pub"),
+        _ => false,
+    }
+}
+
+/// Returns `true` for edges that carry behavioural dependencies (traversed by
+/// blast-radius and path queries).
+fn is_dependency(edge: Edge) -> bool {
+    matches!(
+        edge,
+        Edge::Calls
+            | Edge::Implements
+            | Edge::HasMethod
+            | Edge::Tests
+            | Edge::Binds
+            | Edge::Requests
+    )
+}
+
+/// Returns `true` for edges where the source calls the target, in the same
+/// language ([`Edge::Calls`]) or across a language boundary ([`Edge::Binds`],
+/// [`Edge::Requests`]).
+fn is_call(edge: Edge) -> bool {
+    matches!(edge, Edge::Calls | Edge::Binds | Edge::Requests)
+}
+
+/// Resolves a query target to graph nodes.
+///
+/// If `target` is the path of a file in the graph, returns every symbol
+/// defined in that file (`File` and `Module` nodes excluded). Otherwise
+/// returns every symbol node named `target`; a bare method name also matches
+/// methods keyed by their type (`Type::target`).
+#[must_use]
+pub fn resolve_target(graph: &GraphDb, target: &str) -> Vec<NodeIndex> {
+    let is_symbol = |node: &Node| !matches!(node, Node::File { .. } | Node::Module { .. });
+
+    let in_file: Vec<NodeIndex> = graph
+        .node_indices()
+        .filter(|&idx| graph[idx].path() == target && is_symbol(&graph[idx]))
+        .collect();
+    if !in_file.is_empty() {
+        return in_file;
+    }
+
+    graph
+        .node_indices()
+        .filter(|&idx| bare_name(graph[idx].name()) == target || graph[idx].name() == target)
+        .filter(|&idx| is_symbol(&graph[idx]))
+        .collect()
+}
+
+/// Strips the owning type from a method key (`Type::method` -> `method`).
+fn bare_name(name: &str) -> &str {
+    name.rsplit_once("::").map_or(name, |(_, method)| method)
+}
+
+/// Returns the subgraph of `nodes` plus the functions that call them directly.
+#[must_use]
+pub fn direct_callers(graph: &GraphDb, nodes: &[NodeIndex]) -> GraphDb {
+    direct_neighbors(graph, nodes, Direction::Incoming)
+}
+
+/// Returns the subgraph of `nodes` plus the functions they call directly.
+#[must_use]
+pub fn direct_callees(graph: &GraphDb, nodes: &[NodeIndex]) -> GraphDb {
+    direct_neighbors(graph, nodes, Direction::Outgoing)
+}
+
+/// Returns the subgraph of `nodes` plus their direct call neighbours (see
+/// [`is_call`]) in `direction`.
+fn direct_neighbors(graph: &GraphDb, nodes: &[NodeIndex], direction: Direction) -> GraphDb {
+    let mut set: HashSet<NodeIndex> = nodes.iter().copied().collect();
+    for &node in nodes {
+        for edge_ref in graph.edges_directed(node, direction) {
+            if is_call(*edge_ref.weight()) {
+                set.insert(match direction {
+                    Direction::Incoming => edge_ref.source(),
+                    Direction::Outgoing => edge_ref.target(),
+                });
+            }
         }
+    }
+    build_induced_subgraph(graph, &set)
+}
 
-        // Walk outgoing edges (callees).
+/// Finds a shortest dependency path from any node in `from` to any node in `to`.
+///
+/// Follows outgoing [`Edge::Calls`], [`Edge::Implements`], [`Edge::HasMethod`],
+/// [`Edge::Tests`], [`Edge::Binds`], and [`Edge::Requests`] edges, so a path
+/// from `a` to `b` means `a` (transitively) depends on `b`. Returns the nodes
+/// along the path, endpoints included, or `None` if `b` is not reachable from
+/// `a`.
+#[must_use]
+pub fn shortest_path(
+    graph: &GraphDb,
+    from: &[NodeIndex],
+    to: &[NodeIndex],
+) -> Option<Vec<NodeIndex>> {
+    use std::collections::HashMap;
+
+    let targets: HashSet<NodeIndex> = to.iter().copied().collect();
+    let mut previous: HashMap<NodeIndex, Option<NodeIndex>> = HashMap::new();
+    let mut queue: VecDeque<NodeIndex> = VecDeque::new();
+    for &start in from {
+        if previous.insert(start, None).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(current) = queue.pop_front() {
+        if targets.contains(&current) {
+            let mut path = vec![current];
+            let mut cursor = current;
+            while let Some(Some(prev)) = previous.get(&cursor) {
+                path.push(*prev);
+                cursor = *prev;
+            }
+            path.reverse();
+            return Some(path);
+        }
         for edge_ref in graph.edges_directed(current, Direction::Outgoing) {
-            if relevant_edges(edge_ref.weight()) {
-                let target = edge_ref.target();
-                if depth < max_depth && visited.len() < max_nodes && visited.insert(target) {
-                    queue.push_back((target, depth + 1));
-                }
+            if is_dependency(*edge_ref.weight()) && !previous.contains_key(&edge_ref.target()) {
+                previous.insert(edge_ref.target(), Some(current));
+                queue.push_back(edge_ref.target());
             }
         }
+    }
+    None
+}
+
+/// Returns the subgraph made of the nodes on `path` and the dependency edges
+/// between consecutive nodes.
+#[must_use]
+pub fn path_subgraph(graph: &GraphDb, path: &[NodeIndex]) -> GraphDb {
+    let mut sub = GraphDb::new();
+    let indices: Vec<NodeIndex> = path
+        .iter()
+        .map(|&idx| sub.add_node(graph[idx].clone()))
+        .collect();
+    for (pair, new_pair) in path.windows(2).zip(indices.windows(2)) {
+        if let Some(edge_ref) = graph
+            .edges_connecting(pair[0], pair[1])
+            .find(|e| is_dependency(*e.weight()))
+        {
+            sub.add_edge(new_pair[0], new_pair[1], *edge_ref.weight());
+        }
+    }
+    sub
+}
+
+/// Returns the tests that transitively exercise `modified_nodes`, in node order.
+///
+/// Walks incoming call (see [`is_call`]) and [`Edge::Tests`] edges without a
+/// depth cap, so a test reaching a modified function through any chain of
+/// helpers is selected, including one in another language. A test is a node
+/// with an outgoing [`Edge::Tests`] edge; a modified node that is itself a
+/// test is included.
+#[must_use]
+pub fn affected_tests(graph: &GraphDb, modified_nodes: &[NodeIndex]) -> Vec<NodeIndex> {
+    let mut seen: HashSet<NodeIndex> = HashSet::new();
+    let mut queue: VecDeque<NodeIndex> = VecDeque::new();
+    for &node in modified_nodes {
+        if graph.node_weight(node).is_some() && seen.insert(node) {
+            queue.push_back(node);
+        }
+    }
 
-        // Walk incoming edges (callers).
+    while let Some(current) = queue.pop_front() {
         for edge_ref in graph.edges_directed(current, Direction::Incoming) {
-            if relevant_edges(edge_ref.weight()) {
-                let source = edge_ref.source();
-                if depth < max_depth && visited.len() < max_nodes && visited.insert(source) {
-                    queue.push_back((source, depth + 1));
-                }
+            if (is_call(*edge_ref.weight()) || *edge_ref.weight() == Edge::Tests)
+                && seen.insert(edge_ref.source())
+            {
+                queue.push_back(edge_ref.source());
             }
         }
     }
 
-    // Build induced subgraph from visited nodes.
-    build_induced_subgraph(graph, &visited)
+    let mut tests: Vec<NodeIndex> = seen.into_iter().filter(|&n| is_test(graph, n)).collect();
+    tests.sort_unstable();
+    tests
 }
 
 /// Builds an induced subgraph containing only the nodes in `node_set` and
@@ -108,8 +555,10 @@ fn build_induced_subgraph(graph: &GraphDb, node_set: &HashSet<NodeIndex>) -> Gra
     let mut sub = GraphDb::new();
     let mut index_map: HashMap<NodeIndex, NodeIndex> = HashMap::new();
 
-    // Add nodes.
-    for &old_idx in node_set {
+    // Add nodes in original index order so output is deterministic.
+    let mut ordered: Vec<NodeIndex> = node_set.iter().copied().collect();
+    ordered.sort_unstable();
+    for old_idx in ordered {
         if let Some(weight) = graph.node_weight(old_idx) {
             let new_idx = sub.add_node(weight.clone());
             index_map.insert(old_idx, new_idx);
@@ -141,9 +590,16 @@ fn build_induced_subgraph(graph: &GraphDb, node_set: &HashSet<NodeIndex>) -> Gra
 ///
 /// Only `Function` nodes are listed by default; `Struct`, `Enum`, `Trait`, and
 /// `Impl` nodes appear without call/caller annotations. `File` and `Module`
-/// nodes are omitted (they are structural, not behavioural).
+/// nodes are omitted (they are structural, not behavioural). Cross-language
+/// neighbours are suffixed `(ffi)` or `(http)`.
+///
+/// Functions that call another node in the subgraph but are not the target
+/// of any [`Edge::Tests`] edge (and are not tests themselves) are flagged
+/// `[untested caller]`, pointing the reviewer at changes no test exercises.
+/// The `seeds` the subgraph was built around (such as the modified symbols)
+/// are not callers of the change and are never flagged.
 #[must_use]
-pub fn render_subgraph_text(subgraph: &GraphDb) -> String {
+pub fn render_subgraph_text(subgraph: &GraphDb, seeds: &[&Node]) -> String {
     use std::collections::{BTreeMap, BTreeSet, HashMap};
 
     // Build adjacency maps for calls and callers.
@@ -151,15 +607,20 @@ pub fn render_subgraph_text(subgraph: &GraphDb) -> String {
     let mut callers: HashMap<NodeIndex, BTreeSet<String>> = HashMap::new();
 
     for edge_ref in subgraph.edge_references() {
-        if matches!(edge_ref.weight(), Edge::Calls) {
+        if is_call(*edge_ref.weight()) {
+            let suffix = match edge_ref.weight() {
+                Edge::Binds => " (ffi)",
+                Edge::Requests => " (http)",
+                _ => "",
+            };
             calls
                 .entry(edge_ref.source())
                 .or_default()
-                .insert(subgraph[edge_ref.target()].name().to_string());
+                .insert(format!("{}{suffix}", subgraph[edge_ref.target()].name()));
             callers
                 .entry(edge_ref.target())
                 .or_default()
-                .insert(subgraph[edge_ref.source()].name().to_string());
+                .insert(format!("{}{suffix}", subgraph[edge_ref.source()].name()));
         }
         if matches!(edge_ref.weight(), Edge::HasMethod | Edge::Implements) {
             calls
@@ -197,6 +658,14 @@ pub fn render_subgraph_text(subgraph: &GraphDb) -> String {
                 c.iter().map(String::as_str).collect::<Vec<_>>().join(", ")
             ));
         }
+        if matches!(node, Node::Function { .. })
+            && !seeds.contains(&node)
+            && calls_any(subgraph, idx)
+            && !has_tests(subgraph, idx)
+            && !is_test(subgraph, idx)
+        {
+            parts.push("[untested caller]".to_string());
+        }
         by_file
             .entry(node.path().to_string())
             .or_default()
@@ -247,7 +716,7 @@ mod tests {
     #[test]
     fn test_blast_radius_returns_all_direct_callers_of_modified_node() {
         // Arrange: target has two callers.
-        let (graph, target, caller_a, caller_b) = two_caller_graph();
+        let (graph, target, _, _) = two_caller_graph();
 
         // Act
         let sub = blast_radius(&graph, &[target], 100, 10);
@@ -356,6 +825,114 @@ mod tests {
         assert_eq!(sub.node_count(), 0);
     }
 
+    #[test]
+    fn test_blast_radius_keeps_highest_ranked_nodes_under_cap() {
+        // Arrange: target has a public caller with three callers of its own
+        // and a private caller with none.
+        let (mut graph, target, caller_a, caller_b) = two_caller_graph();
+        if let Node::Function { visibility, .. } = &mut graph[caller_b] {
+            *visibility = "pub".to_string();
+        }
+        for i in 0..3 {
+            let upstream = graph.add_node(Node::Function {
+                name: format!("upstream{i}"),
+                path: "src/app.rs".to_string(),
+                visibility: "private".to_string(),
+            });
+            graph.add_edge(upstream, caller_b, Edge::Calls);
+        }
+
+        // Act
+        let ranked = rank_blast_radius(&graph, &[target], 2, 2);
+        let sub = blast_radius(&graph, &[target], 2, 2);
+
+        // Assert: the seed ranks first, then the busy public caller.
+        assert_eq!(ranked[0].index, target);
+        assert_eq!(ranked[1].index, caller_b);
+        assert_eq!(ranked[1].fan_in, 3);
+        assert!(
+            ranked
+                .iter()
+                .any(|n| n.index == caller_a && n.distance == 1)
+        );
+        let names: Vec<&str> = sub.node_weights().map(Node::name).collect();
+        assert_eq!(names, vec!["target", "caller_b"]);
+    }
+
+    #[test]
+    fn test_render_subgraph_text_flags_untested_callers() {
+        // Arrange: caller_a is covered by a test, caller_b is not.
+        let (mut graph, target, caller_a, _) = two_caller_graph();
+        let test_fn = graph.add_node(Node::Function {
+            name: "test_caller_a".to_string(),
+            path: "tests/lib.rs".to_string(),
+            visibility: "private".to_string(),
+        });
+        graph.add_edge(test_fn, caller_a, Edge::Tests);
+        graph.add_edge(test_fn, caller_a, Edge::Calls);
+
+        // Act: a cap of 3 keeps caller_a only together with its test.
+        let text = render_subgraph_text(&blast_radius(&graph, &[target], 100, 2), &[]);
+        let capped = blast_radius(&graph, &[target], 3, 2);
+
+        // Assert
+        let line = |name: &str| {
+            text.lines()
+                .find(|l| l.starts_with(&format!("fn {name} ")))
+                .unwrap_or_default()
+        };
+        assert!(line("caller_b").ends_with("[untested caller]"));
+        assert!(!line("caller_a").contains("untested"));
+        assert!(!line("test_caller_a").contains("untested"));
+        let names: Vec<&str> = capped.node_weights().map(Node::name).collect();
+        assert!(!names.contains(&"caller_a") || names.contains(&"test_caller_a"));
+    }
+
+    #[test]
+    fn test_render_subgraph_text_does_not_flag_seeds() {
+        // Arrange: the modified target calls a helper and has no test.
+        let (mut graph, target, _, _) = two_caller_graph();
+        let helper = graph.add_node(Node::Function {
+            name: "helper".to_string(),
+            path: "src/lib.rs".to_string(),
+            visibility: "private".to_string(),
+        });
+        graph.add_edge(target, helper, Edge::Calls);
+        let sub = blast_radius(&graph, &[target], 100, 2);
+
+        // Act
+        let text = render_subgraph_text(&sub, &[&graph[target]]);
+
+        // Assert
+        let target_line = text.lines().find(|l| l.starts_with("fn target ")).unwrap();
+        assert!(!target_line.contains("untested"), "{text}");
+        assert!(
+            text.contains("fn caller_a [calls: target] [untested caller]"),
+            "{text}"
+        );
+    }
+
+    #[test]
+    fn test_rank_blast_radius_caps_traversal_by_max_nodes() {
+        // Arrange: a hub with far more callers than the cap allows.
+        let (mut graph, target, _, _) = two_caller_graph();
+        for i in 0..100 {
+            let caller = graph.add_node(Node::Function {
+                name: format!("caller{i}"),
+                path: "src/app.rs".to_string(),
+                visibility: "private".to_string(),
+            });
+            graph.add_edge(caller, target, Edge::Calls);
+        }
+
+        // Act
+        let ranked = rank_blast_radius(&graph, &[target], 2, 5);
+
+        // Assert
+        assert_eq!(ranked.len(), 2 * CANDIDATES_PER_NODE);
+        assert_eq!(ranked[0].index, target);
+    }
+
     #[test]
     fn test_render_subgraph_text_contains_function_with_caller() {
         // Arrange: two-caller graph.
@@ -363,7 +940,7 @@ mod tests {
         let sub = blast_radius(&graph, &[target], 100, 10);
 
         // Act
-        let text = render_subgraph_text(&sub);
+        let text = render_subgraph_text(&sub, &[]);
 
         // Assert: rendered text contains the target function.
         assert!(text.contains("fn target"), "must render target function");
@@ -378,7 +955,7 @@ mod tests {
         let sub = blast_radius(&graph, &[], 100, 10);
 
         // Act
-        let text = render_subgraph_text(&sub);
+        let text = render_subgraph_text(&sub, &[]);
 
         // Assert: empty string, no leading newline.
         assert!(text.is_empty(), "empty graph must produce empty string");
@@ -406,4 +983,150 @@ mod tests {
         assert_eq!(matched.len(), 1);
         assert_eq!(graph.edge_count(), 0, "no sentinel edges should be added");
     }
+
+    #[test]
+    fn test_resolve_target_prefers_file_path_over_symbol_name() {
+        let (graph, _, _, _) = two_caller_graph();
+
+        assert_eq!(resolve_target(&graph, "src/lib.rs").len(), 3);
+        assert_eq!(resolve_target(&graph, "caller_a").len(), 1);
+        assert!(resolve_target(&graph, "missing").is_empty());
+    }
+
+    #[test]
+    fn test_direct_callers_and_callees_follow_call_direction() {
+        let (graph, target, caller_a, _) = two_caller_graph();
+
+        let incoming = direct_callers(&graph, &[target]);
+        let outgoing = direct_callees(&graph, &[caller_a]);
+
+        assert_eq!(incoming.node_count(), 3);
+        assert_eq!(incoming.edge_count(), 2);
+        let names: Vec<&str> = outgoing.node_weights().map(Node::name).collect();
+        assert_eq!(names.len(), 2);
+        assert!(names.contains(&"target"));
+    }
+
+    #[test]
+    fn test_shortest_path_follows_call_direction() {
+        let (mut graph, target, caller_a, caller_b) = two_caller_graph();
+        let leaf = graph.add_node(Node::Function {
+            name: "leaf".to_string(),
+            path: "src/leaf.rs".to_string(),
+            visibility: "private".to_string(),
+        });
+        graph.add_edge(target, leaf, Edge::Calls);
+
+        let path = shortest_path(&graph, &[caller_a], &[leaf]).expect("leaf is reachable");
+        let names: Vec<&str> = path.iter().map(|&i| graph[i].name()).collect();
+        assert_eq!(names, vec!["caller_a", "target", "leaf"]);
+        assert_eq!(path_subgraph(&graph, &path).edge_count(), 2);
+
+        assert!(shortest_path(&graph, &[leaf], &[caller_b]).is_none());
+    }
+
+    #[test]
+    fn test_affected_tests_follows_callers_transitively() {
+        // Arrange: one test reaches target through caller_a, another tests an
+        // unrelated function.
+        let (mut graph, target, caller_a, _) = two_caller_graph();
+        let unrelated = graph.add_node(Node::Function {
+            name: "unrelated".to_string(),
+            path: "src/other.rs".to_string(),
+            visibility: "private".to_string(),
+        });
+        let mut add_test = |name: &str, covered: NodeIndex| {
+            let test = graph.add_node(Node::Function {
+                name: name.to_string(),
+                path: "tests/lib.rs".to_string(),
+                visibility: "private".to_string(),
+            });
+            graph.add_edge(test, covered, Edge::Calls);
+            graph.add_edge(test, covered, Edge::Tests);
+            test
+        };
+        let test_caller_a = add_test("test_caller_a", caller_a);
+        let test_unrelated = add_test("test_unrelated", unrelated);
+
+        // Act
+        let affected = affected_tests(&graph, &[target]);
+
+        // Assert
+        assert_eq!(affected, vec![test_caller_a]);
+        assert_eq!(
+            affected_tests(&graph, &[test_unrelated]),
+            vec![test_unrelated]
+        );
+    }
+
+    #[test]
+    fn test_cross_language_edges_count_as_calls() {
+        // Arrange: a Python test calls a PyO3 binding of target.
+        let (mut graph, target, _, _) = two_caller_graph();
+        let binding = graph.add_node(Node::Function {
+            name: "verify".to_string(),
+            path: "scripts/verify.py".to_string(),
+            visibility: "pub".to_string(),
+        });
+        let py_test = graph.add_node(Node::Function {
+            name: "test_verify".to_string(),
+            path: "tests/test_verify.py".to_string(),
+            visibility: "pub".to_string(),
+        });
+        graph.add_edge(binding, target, Edge::Binds);
+        graph.add_edge(py_test, binding, Edge::Calls);
+        graph.add_edge(py_test, binding, Edge::Tests);
+
+        // Act
+        let affected = affected_tests(&graph, &[target]);
+        let text = render_subgraph_text(&direct_callers(&graph, &[target]), &[]);
+
+        // Assert
+        assert_eq!(affected, vec![py_test]);
+        assert!(text.contains("fn verify [calls: target (ffi)]"), "{text}");
+        assert!(text.contains("verify (ffi)"), "{text}");
+    }
+
+    #[test]
+    fn test_relevant_symbols_ranks_named_and_overlapping_symbols() {
+        // Arrange
+        let mut graph = GraphDb::new();
+        let mut add = |name: &str, path: &str| {
+            graph.add_node(Node::Function {
+                name: name.to_string(),
+                path: path.to_string(),
+                visibility: "pub".to_string(),
+            })
+        };
+        let forms = add("fetch_issue_forms", "src/github/templates.rs");
+        let labels = add("merge_labels", "src/github/issues.rs");
+        let check = add("check_templates", "src/github/templates.rs");
+        let parse = add("parse", "src/github/templates.rs");
+        let unrelated = add("render_sarif", "src/security/sarif.rs");
+        let test_fn = add("test_merge_labels", "src/github/issues.rs");
+        graph.add_edge(test_fn, labels, Edge::Tests);
+
+        // Act
+        let found = relevant_symbols(
+            &graph,
+            "`fetch_issue_forms` ignores templates.rs entries and merged labels are lost",
+            10,
+        );
+
+        // Assert: the named function first, then a file match with a shared
+        // word; a file match or one shared word alone, unrelated symbols, and
+        // tests are left out.
+        assert_eq!(found, vec![forms, check]);
+        assert!(!found.contains(&parse));
+        assert!(!found.contains(&unrelated));
+        assert!(!found.contains(&labels));
+    }
+
+    #[test]
+    fn test_words_splits_identifiers_and_drops_stop_words() {
+        let found = words("GraphDb load_config for the issue");
+        let mut found: Vec<&str> = found.iter().map(String::as_str).collect();
+        found.sort_unstable();
+        assert_eq!(found, vec!["config", "graph", "load"]);
+    }
 }
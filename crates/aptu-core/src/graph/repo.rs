@@ -0,0 +1,1322 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Repository-wide structural graph.
+//!
+//! Walks a local checkout, parses every supported source file once, and links
+//! call sites, tests, and trait implementations across files so blast-radius
+//! queries reach callers and tests that live outside the PR diff. Calls that
+//! cross a language boundary are linked by [`super::connect`]. Node paths are
+//! relative to the repository root.
+//!
+//! Parsing is the expensive part, so each file's symbols are kept in a
+//! [`FileIndex`] keyed by path and content hash. [`update_index`] re-parses
+//! only files whose content changed since the index was last updated (and
+//! drops deleted files), and [`graph_from_index`] assembles the `GraphDb` from
+//! the per-file records. PR review persists the index across commits (see
+//! [`super::cache::load_file_index`]), caches the assembled graph per base SHA,
+//! and then patches the PR's own files with [`apply_file_changes`].
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+
+use aptu_coder_core::SemanticAnalysis;
+use aptu_coder_core::language_for_extension;
+use aptu_coder_core::parser::SemanticExtractor;
+use ignore::WalkBuilder;
+use petgraph::Direction;
+use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef as _;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::debug;
+
+use super::connect::{Connectors, Span};
+use super::{Edge, GraphDb, Node};
+use crate::ai::types::PrFile;
+use crate::ast_context::{OwnedFunction, SourceFacts, owned_functions};
+
+/// Source files larger than this many bytes are not parsed (1 MiB).
+const MAX_SOURCE_FILE_SIZE: u64 = 1_048_576;
+
+/// Symbols extracted from one source file: the per-file subgraph.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileSymbols {
+    /// Hex SHA-256 of the file content the symbols were extracted from.
+    pub hash: String,
+    /// Functions and methods defined in the file; methods are named
+    /// `Type::method`.
+    pub functions: Vec<String>,
+    /// Imported module paths.
+    pub imports: Vec<String>,
+    /// Call sites as `(caller, callee)` name pairs.
+    pub calls: Vec<(String, String)>,
+    /// Functions that are test code: test files and `test_*` names, test
+    /// attributes such as `#[test]`, and functions inside test modules.
+    pub tests: Vec<String>,
+    /// Types defined in the file as `(kind, name)` pairs, where kind is
+    /// `struct`, `enum`, or `trait`.
+    pub types: Vec<(String, String)>,
+    /// Methods as `(type, function)` pairs, the function named as in
+    /// `functions`.
+    pub methods: Vec<(String, String)>,
+    /// Visibility of the functions and types that are not private, as
+    /// `(name, visibility)` pairs named as in `functions` and `types` (see
+    /// [`SourceFacts::visibility`]).
+    pub visibility: Vec<(String, String)>,
+    /// Trait implementations and base classes as `(type, trait)` name pairs.
+    pub implements: Vec<(String, String)>,
+    /// Cross-language exports, foreign declarations, HTTP routes, and HTTP
+    /// call sites (see [`super::connect`]).
+    pub connectors: Connectors,
+}
+
+impl FileSymbols {
+    fn from_semantic(path: &str, source: &str, hash: String, semantic: &SemanticAnalysis) -> Self {
+        let owned = owned_functions(semantic);
+        let keys: Vec<String> = owned.iter().map(OwnedFunction::qualified).collect();
+        let mut functions: Vec<String> = Vec::new();
+        for key in &keys {
+            if !functions.contains(key) {
+                functions.push(key.clone());
+            }
+        }
+
+        let facts = SourceFacts::extract(path, source);
+        let types = semantic
+            .classes
+            .iter()
+            .map(|c| (facts.kind(&c.name).to_string(), c.name.clone()))
+            .collect();
+        let methods = owned
+            .iter()
+            .zip(&keys)
+            .filter_map(|(f, key)| Some((f.owner?.to_string(), key.clone())))
+            .collect();
+        let visibility = owned
+            .iter()
+            .zip(&keys)
+            .map(|(f, key)| (key.clone(), facts.visibility(f.name, f.line, f.end_line)))
+            .chain(semantic.classes.iter().map(|c| {
+                (
+                    c.name.clone(),
+                    facts.visibility(&c.name, c.line, c.end_line),
+                )
+            }))
+            .filter(|(_, visibility)| visibility != "private")
+            .collect();
+        let mut tests: Vec<String> = Vec::new();
+        for (f, key) in owned.iter().zip(&keys) {
+            if facts.is_test(path, f.name, f.line, f.end_line) && !tests.contains(key) {
+                tests.push(key.clone());
+            }
+        }
+        let implements = semantic
+            .impl_traits
+            .iter()
+            .map(|i| (i.impl_type.clone(), i.trait_name.clone()))
+            .chain(
+                semantic
+                    .classes
+                    .iter()
+                    .flat_map(|c| c.inherits.iter().map(|base| (c.name.clone(), base.clone()))),
+            )
+            .collect();
+        let spans: Vec<Span<'_>> = owned
+            .iter()
+            .zip(&keys)
+            .map(|(f, key)| Span {
+                name: f.name,
+                key,
+                start: f.line,
+                end: f.end_line,
+            })
+            .collect();
+        let lines: Vec<&str> = source.lines().collect();
+        let connectors = super::connect::extract(path, &lines, &spans);
+        // Callers are reported by bare name; the span holding the call site
+        // says which type's method made it.
+        let calls = semantic
+            .calls
+            .iter()
+            .map(|c| {
+                let caller = spans
+                    .iter()
+                    .filter(|s| s.name == c.caller && (s.start..=s.end).contains(&c.line))
+                    .min_by_key(|s| s.end - s.start)
+                    .map_or_else(|| c.caller.clone(), |s| s.key.to_string());
+                (caller, c.callee.clone())
+            })
+            .chain(macro_calls(path, source, &spans))
+            .collect();
+
+        Self {
+            hash,
+            functions,
+            imports: semantic.imports.iter().map(|i| i.module.clone()).collect(),
+            calls,
+            tests,
+            types,
+            methods,
+            visibility,
+            implements,
+            connectors,
+        }
+    }
+}
+
+/// Tree-sitter query for `name(...)` inside a Rust macro's token tree.
+const MACRO_CALL_QUERY: &str = "(token_tree (identifier) @callee . (token_tree))";
+
+/// Returns the calls made inside Rust macro arguments as `(caller, callee)`
+/// pairs.
+///
+/// The parser leaves macro arguments as unparsed token trees, so the call in
+/// `assert_eq!(target(), 2)` is missing from its call list. Any identifier
+/// directly followed by a parenthesised group counts as a call; callees that
+/// name no defined function are dropped when calls are linked.
+fn macro_calls(path: &str, source: &str, spans: &[Span<'_>]) -> Vec<(String, String)> {
+    if language_of(path) != Some("rust") {
+        return Vec::new();
+    }
+    let captures = match aptu_coder_core::execute_query("rust", source, MACRO_CALL_QUERY) {
+        Ok(captures) => captures,
+        Err(e) => {
+            debug!("macro_calls: skipping {}: {}", path, e);
+            return Vec::new();
+        }
+    };
+    let mut calls: Vec<(String, String)> = Vec::new();
+    for capture in captures {
+        let line = capture.start_line + 1;
+        let Some(caller) = spans
+            .iter()
+            .filter(|s| (s.start..=s.end).contains(&line))
+            .min_by_key(|s| s.end - s.start)
+        else {
+            continue;
+        };
+        let call = (caller.key.to_string(), capture.text);
+        if !calls.contains(&call) {
+            calls.push(call);
+        }
+    }
+    calls
+}
+
+/// Per-file symbols for a repository, keyed by path relative to the root.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileIndex {
+    /// File records keyed by relative path.
+    pub files: BTreeMap<String, FileSymbols>,
+    /// Files that failed to parse, keyed by relative path, with the hash of
+    /// the content that failed. They are retried only once that content
+    /// changes.
+    pub failed: BTreeMap<String, String>,
+}
+
+/// What [`update_index`] had to do.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IndexUpdate {
+    /// Files parsed because they are new or their content changed.
+    pub parsed: usize,
+    /// Files whose cached symbols (or recorded parse failure) were reused.
+    pub reused: usize,
+    /// Files parsed because they are new or changed, but that failed to parse.
+    pub failed: usize,
+    /// Files dropped because they no longer exist.
+    pub removed: usize,
+}
+
+/// Builds a structural graph of every supported source file under `root`.
+///
+/// Equivalent to updating an empty [`FileIndex`] and assembling it. Returns an
+/// empty graph if `root` does not exist.
+#[must_use]
+pub fn build_repo_graph(root: &Path) -> GraphDb {
+    let mut index = FileIndex::default();
+    update_index(root, &mut index);
+    graph_from_index(&index)
+}
+
+/// Builds the graph for a local checkout, reusing its cached file index.
+///
+/// The index is stored under a `local/<path hash>` namespace (see
+/// [`super::cache::local_namespace`]) so repeated queries against the same
+/// working tree only re-parse files that changed since the last run. The
+/// working tree may be dirty, so the assembled graph itself is not cached.
+#[must_use]
+pub fn load_local_graph(root: &Path) -> GraphDb {
+    let (owner, repo) = super::cache::local_namespace(root);
+    let mut index = super::cache::load_file_index(&owner, &repo);
+    update_index(root, &mut index);
+    super::cache::persist_file_index(&owner, &repo, &index);
+    graph_from_index(&index)
+}
+
+/// Returns `true` when the checkout at `root` is at commit `sha` with no
+/// uncommitted or untracked changes, so a graph built from its working tree
+/// describes `sha` and may be cached under it.
+#[must_use]
+pub fn checkout_at(root: &Path, sha: &str) -> bool {
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(root)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    !sha.is_empty()
+        && git(&["rev-parse", "HEAD"]).is_some_and(|head| head == sha)
+        && git(&["status", "--porcelain"]).is_some_and(|status| status.is_empty())
+}
+
+/// Result of scanning one file in [`update_index`].
+enum Scanned {
+    /// The content hash matches the index (symbols or a recorded failure).
+    Unchanged,
+    /// The file was parsed.
+    Parsed(Box<FileSymbols>),
+    /// Parsing failed for the content with this hash.
+    Failed(String),
+}
+
+/// Brings `index` in line with the files currently under `root`.
+///
+/// The walk honours `.gitignore` and skips hidden directories. Files whose
+/// content hash matches the index are not re-parsed; new and changed files
+/// are parsed in parallel; files no longer present are removed. Unreadable
+/// and oversized files are skipped. Files that fail to parse are recorded in
+/// [`FileIndex::failed`] with their content hash, so unchanged content is not
+/// parsed again.
+pub fn update_index(root: &Path, index: &mut FileIndex) -> IndexUpdate {
+    let paths: Vec<String> = WalkBuilder::new(root)
+        .require_git(false)
+        .sort_by_file_path(Ord::cmp)
+        .build()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+        .filter(|entry| {
+            entry
+                .metadata()
+                .is_ok_and(|m| m.len() <= MAX_SOURCE_FILE_SIZE)
+        })
+        .filter_map(|entry| {
+            let rel = entry.path().strip_prefix(root).ok()?;
+            Some(rel.to_string_lossy().replace('\\', "/"))
+        })
+        .filter(|rel| language_of(rel).is_some())
+        .collect();
+
+    let scanned: Vec<(String, Scanned)> = paths
+        .into_par_iter()
+        .filter_map(|rel| {
+            let source = std::fs::read_to_string(root.join(&rel)).ok()?;
+            let hash = content_hash(&source);
+            if index.files.get(&rel).is_some_and(|f| f.hash == hash)
+                || index.failed.get(&rel).is_some_and(|h| *h == hash)
+            {
+                return Some((rel, Scanned::Unchanged));
+            }
+            let scanned = match parse(&rel, &source, hash.clone()) {
+                Some(symbols) => Scanned::Parsed(Box::new(symbols)),
+                None => Scanned::Failed(hash),
+            };
+            Some((rel, scanned))
+        })
+        .collect();
+
+    let mut update = IndexUpdate::default();
+    let present: HashSet<&str> = scanned.iter().map(|(rel, _)| rel.as_str()).collect();
+    let before = index.files.len() + index.failed.len();
+    index.files.retain(|rel, _| present.contains(rel.as_str()));
+    index.failed.retain(|rel, _| present.contains(rel.as_str()));
+    update.removed = before - index.files.len() - index.failed.len();
+
+    for (rel, scanned) in scanned {
+        match scanned {
+            Scanned::Unchanged => update.reused += 1,
+            Scanned::Parsed(symbols) => {
+                update.parsed += 1;
+                index.failed.remove(&rel);
+                index.files.insert(rel, *symbols);
+            }
+            Scanned::Failed(hash) => {
+                update.failed += 1;
+                index.files.remove(&rel);
+                index.failed.insert(rel, hash);
+            }
+        }
+    }
+
+    debug!(
+        parsed = update.parsed,
+        reused = update.reused,
+        failed = update.failed,
+        removed = update.removed,
+        "Repository file index updated"
+    );
+    update
+}
+
+/// Assembles a structural graph from per-file symbols.
+#[must_use]
+pub fn graph_from_index(index: &FileIndex) -> GraphDb {
+    let mut graph = GraphDb::new();
+    for (rel, symbols) in &index.files {
+        add_file(&mut graph, rel, symbols);
+    }
+    let names = name_index(&graph);
+    let types = type_index(&graph);
+    for (rel, symbols) in &index.files {
+        link_calls(&mut graph, &names, rel, symbols);
+        link_implements(&mut graph, &types, rel, symbols);
+    }
+    let files: Vec<(&str, &FileSymbols)> = index
+        .files
+        .iter()
+        .map(|(rel, symbols)| (rel.as_str(), symbols))
+        .collect();
+    super::connect::link_connectors(&mut graph, &names, &files, &files);
+
+    debug!(
+        files = index.files.len(),
+        nodes = graph.node_count(),
+        edges = graph.edge_count(),
+        "Repository graph assembled"
+    );
+    graph
+}
+
+/// Patches `graph` in place with the PR's changed files.
+///
+/// Every node belonging to a changed file is dropped and the file is
+/// re-parsed from `PrFile::full_content` when present, otherwise from disk
+/// under `root`. Removed files are only dropped, and a renamed file's nodes
+/// under its previous path are dropped too. Calls, tests, and trait
+/// implementations from unchanged files into a changed file are re-linked to
+/// the symbol of the same name in that file, so callers and tests outside the
+/// diff survive the update.
+/// Cross-language edges ([`Edge::Binds`], [`Edge::Requests`]) are re-linked the
+/// same way in both directions; an outbound one is kept while its source
+/// function still makes calls (or requests). Connectors between changed files
+/// are linked afresh.
+pub fn apply_file_changes(graph: &mut GraphDb, root: &Path, files: &[PrFile]) {
+    let renamed: HashMap<&str, &str> = files
+        .iter()
+        .filter_map(|f| Some((f.previous_filename.as_deref()?, f.filename.as_str())))
+        .collect();
+    let changed: HashSet<&str> = files
+        .iter()
+        .map(|f| f.filename.as_str())
+        .chain(renamed.keys().copied())
+        .collect();
+    if changed.is_empty() {
+        return;
+    }
+
+    // Remember inbound edges from unchanged files before dropping the nodes.
+    let inbound: Vec<(Node, String, String, Edge)> = graph
+        .edge_references()
+        .filter(|e| {
+            matches!(
+                e.weight(),
+                Edge::Calls | Edge::Tests | Edge::Implements | Edge::Binds | Edge::Requests
+            )
+        })
+        .filter(|e| {
+            changed.contains(graph[e.target()].path())
+                && !changed.contains(graph[e.source()].path())
+        })
+        .map(|e| {
+            let target = &graph[e.target()];
+            let path = target.path();
+            (
+                graph[e.source()].clone(),
+                renamed.get(path).copied().unwrap_or(path).to_string(),
+                target.name().to_string(),
+                *e.weight(),
+            )
+        })
+        .collect();
+    let outbound = outbound_connector_edges(graph, &changed)
+        .into_iter()
+        .map(|(rel, caller, target, edge)| {
+            let rel = renamed
+                .get(rel.as_str())
+                .map_or(rel, |new| (*new).to_string());
+            (rel, caller, target, edge)
+        })
+        .collect();
+
+    graph.retain_nodes(|g, idx| !changed.contains(g[idx].path()));
+
+    let parsed: Vec<(String, FileSymbols)> = files
+        .iter()
+        .filter(|f| f.status != "removed" && language_of(&f.filename).is_some())
+        .filter_map(|f| {
+            let source = match &f.full_content {
+                Some(content) => content.clone(),
+                None => std::fs::read_to_string(root.join(&f.filename)).ok()?,
+            };
+            let hash = content_hash(&source);
+            Some((f.filename.clone(), parse(&f.filename, &source, hash)?))
+        })
+        .collect();
+
+    for (rel, symbols) in &parsed {
+        add_file(graph, rel, symbols);
+    }
+    let names = name_index(graph);
+    let types = type_index(graph);
+    for (rel, symbols) in &parsed {
+        link_calls(graph, &names, rel, symbols);
+        link_implements(graph, &types, rel, symbols);
+    }
+    let files: Vec<(&str, &FileSymbols)> = parsed
+        .iter()
+        .map(|(rel, symbols)| (rel.as_str(), symbols))
+        .collect();
+    super::connect::link_connectors(graph, &names, &files, &files);
+
+    let positions: HashMap<Node, NodeIndex> = graph
+        .node_indices()
+        .map(|i| (graph[i].clone(), i))
+        .collect();
+    for (source, target_path, target, edge) in inbound {
+        let Some(&src) = positions.get(&source) else {
+            continue;
+        };
+        let index = if edge == Edge::Implements {
+            &types
+        } else {
+            &names
+        };
+        let dst = index
+            .get(&target)
+            .and_then(|c| c.iter().copied().find(|&i| graph[i].path() == target_path));
+        if let Some(dst) = dst {
+            add_edge_once(graph, src, dst, edge);
+        }
+    }
+
+    relink_outbound_connectors(graph, &names, &positions, &parsed, outbound);
+}
+
+/// A cross-language edge leaving a changed file: the source function's file
+/// and name, the target node, and the edge kind.
+type OutboundConnector = (String, String, Node, Edge);
+
+/// Collects the [`Edge::Binds`] and [`Edge::Requests`] edges from changed files
+/// into unchanged ones, before the changed files' nodes are dropped.
+fn outbound_connector_edges(graph: &GraphDb, changed: &HashSet<&str>) -> Vec<OutboundConnector> {
+    graph
+        .edge_references()
+        .filter(|e| matches!(e.weight(), Edge::Binds | Edge::Requests))
+        .filter(|e| {
+            changed.contains(graph[e.source()].path())
+                && !changed.contains(graph[e.target()].path())
+        })
+        .map(|e| {
+            let source = &graph[e.source()];
+            (
+                source.path().to_string(),
+                source.name().to_string(),
+                graph[e.target()].clone(),
+                *e.weight(),
+            )
+        })
+        .collect()
+}
+
+/// Restores the edges from [`outbound_connector_edges`] whose source function
+/// still makes calls (or requests) after the re-parse.
+fn relink_outbound_connectors(
+    graph: &mut GraphDb,
+    names: &HashMap<String, Vec<NodeIndex>>,
+    positions: &HashMap<Node, NodeIndex>,
+    parsed: &[(String, FileSymbols)],
+    outbound: Vec<OutboundConnector>,
+) {
+    for (rel, caller, target, edge) in outbound {
+        let Some((_, symbols)) = parsed.iter().find(|(r, _)| *r == rel) else {
+            continue;
+        };
+        let still_connects = if edge == Edge::Requests {
+            symbols
+                .connectors
+                .requests
+                .iter()
+                .any(|(c, _)| *c == caller)
+        } else {
+            symbols
+                .calls
+                .iter()
+                .chain(&symbols.connectors.calls)
+                .any(|(c, _)| *c == caller)
+        };
+        if !still_connects {
+            continue;
+        }
+        let src = function_in(graph, names, &rel, &caller);
+        let dst = positions.get(&target).copied();
+        if let (Some(src), Some(dst)) = (src, dst) {
+            add_edge_once(graph, src, dst, edge);
+        }
+    }
+}
+
+/// Returns the symbols a unified diff touches in the checkout at `root`.
+///
+/// The diff is split per file and each hunk is mapped onto the enclosing
+/// function, method, or class of the file as it exists under `root`, so the
+/// diff must describe the working tree (e.g. `git diff <rev>`). Deleted files
+/// contribute no symbols.
+#[must_use]
+pub fn diff_symbols(root: &Path, diff: &str) -> Vec<String> {
+    let files: Vec<PrFile> = split_diff(diff)
+        .into_iter()
+        .map(|mut file| {
+            if file.status != "removed" {
+                file.full_content = std::fs::read_to_string(root.join(&file.filename)).ok();
+            }
+            file
+        })
+        .collect();
+    crate::ai::review_context::derive_enclosing_symbols(root, &files, "")
+}
+
+/// Splits a multi-file unified diff into one [`PrFile`] per file, keyed by the
+/// new-side path (`+++ b/...`), or the old-side path for deletions.
+fn split_diff(diff: &str) -> Vec<PrFile> {
+    let mut files: Vec<PrFile> = Vec::new();
+    let mut lines = diff.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        // A removed line reading "-- ..." also starts with "--- "; only a
+        // line followed by "+++ " is a file header.
+        if let Some(old_path) = line.strip_prefix("--- ")
+            && let Some(new_path) = lines.next_if(|next| next.starts_with("+++ "))
+        {
+            let (filename, status) = match new_path[4..].strip_prefix("b/") {
+                Some(path) => (path, "modified"),
+                None => (old_path.strip_prefix("a/").unwrap_or(old_path), "removed"),
+            };
+            files.push(PrFile {
+                filename: filename.to_string(),
+                previous_filename: None,
+                status: status.to_string(),
+                additions: 0,
+                deletions: 0,
+                patch: Some(String::new()),
+                patch_truncated: false,
+                full_content: None,
+            });
+            continue;
+        }
+        if let Some(patch) = files.last_mut().and_then(|f| f.patch.as_mut()) {
+            patch.push_str(line);
+            patch.push('\n');
+        }
+    }
+
+    files
+}
+
+/// Returns the hex SHA-256 of `source`.
+fn content_hash(source: &str) -> String {
+    hex::encode(Sha256::digest(source.as_bytes()))
+}
+
+/// Returns the language name for `path`, or `None` if it is unsupported.
+fn language_of(path: &str) -> Option<&'static str> {
+    let ext = Path::new(path).extension().and_then(|e| e.to_str())?;
+    language_for_extension(ext)
+}
+
+/// Parses `source` for the language implied by `path`.
+fn parse(path: &str, source: &str, hash: String) -> Option<FileSymbols> {
+    let language = language_of(path)?;
+    match SemanticExtractor::extract(source, language, None, None) {
+        Ok(semantic) => Some(FileSymbols::from_semantic(path, source, hash, &semantic)),
+        Err(e) => {
+            debug!("repo graph: skipping {}: {}", path, e);
+            None
+        }
+    }
+}
+
+/// Adds the File node, its Function and type nodes, and its imported Module
+/// nodes for one file, plus the `HasMethod` edges between them.
+fn add_file(graph: &mut GraphDb, rel: &str, symbols: &FileSymbols) {
+    let file_idx = graph.add_node(Node::File {
+        name: rel.rsplit('/').next().unwrap_or(rel).to_string(),
+        path: rel.to_string(),
+    });
+
+    let visibility: HashMap<&str, &str> = symbols
+        .visibility
+        .iter()
+        .map(|(name, visibility)| (name.as_str(), visibility.as_str()))
+        .collect();
+    let visibility_of = |name: &str| visibility.get(name).unwrap_or(&"private").to_string();
+
+    let mut functions: HashMap<&str, NodeIndex> = HashMap::new();
+    for name in &symbols.functions {
+        let fn_idx = graph.add_node(Node::Function {
+            name: name.clone(),
+            path: rel.to_string(),
+            visibility: visibility_of(name),
+        });
+        graph.add_edge(file_idx, fn_idx, Edge::Contains);
+        functions.insert(name, fn_idx);
+    }
+
+    let mut types: HashMap<&str, NodeIndex> = HashMap::new();
+    for (kind, type_name) in &symbols.types {
+        let (name, path, visibility) =
+            (type_name.clone(), rel.to_string(), visibility_of(type_name));
+        let node = match kind.as_str() {
+            "trait" => Node::Trait {
+                name,
+                path,
+                visibility,
+            },
+            "enum" => Node::Enum {
+                name,
+                path,
+                visibility,
+            },
+            _ => Node::Struct {
+                name,
+                path,
+                visibility,
+            },
+        };
+        let type_idx = graph.add_node(node);
+        graph.add_edge(file_idx, type_idx, Edge::Contains);
+        types.entry(type_name).or_insert(type_idx);
+    }
+
+    for (type_name, method) in &symbols.methods {
+        if let (Some(&src), Some(&dst)) = (
+            types.get(type_name.as_str()),
+            functions.get(method.as_str()),
+        ) {
+            add_edge_once(graph, src, dst, Edge::HasMethod);
+        }
+    }
+
+    for module in &symbols.imports {
+        let module_idx = graph.add_node(Node::Module {
+            name: module.clone(),
+            path: rel.to_string(),
+        });
+        graph.add_edge(file_idx, module_idx, Edge::Imports);
+    }
+}
+
+/// Maps function names to their nodes, across all files.
+///
+/// Methods (`Type::method`) are also listed under their bare name, since call
+/// sites usually name only the method.
+fn name_index(graph: &GraphDb) -> HashMap<String, Vec<NodeIndex>> {
+    let mut index: HashMap<String, Vec<NodeIndex>> = HashMap::new();
+    for idx in graph.node_indices() {
+        if let Node::Function { name, .. } = &graph[idx] {
+            index.entry(name.clone()).or_default().push(idx);
+            if let Some((_, method)) = name.rsplit_once("::") {
+                index.entry(method.to_string()).or_default().push(idx);
+            }
+        }
+    }
+    index
+}
+
+/// Returns the node of function `name` defined in `rel`, or `None` when
+/// `rel` defines no such function or several (same-named methods of
+/// different types).
+pub(super) fn function_in(
+    graph: &GraphDb,
+    names: &HashMap<String, Vec<NodeIndex>>,
+    rel: &str,
+    name: &str,
+) -> Option<NodeIndex> {
+    let mut in_file = names
+        .get(name)?
+        .iter()
+        .copied()
+        .filter(|&i| graph[i].path() == rel);
+    match (in_file.next(), in_file.next()) {
+        (Some(only), None) => Some(only),
+        _ => None,
+    }
+}
+
+/// Maps struct, enum, and trait names to their nodes, across all files.
+fn type_index(graph: &GraphDb) -> HashMap<String, Vec<NodeIndex>> {
+    let mut index: HashMap<String, Vec<NodeIndex>> = HashMap::new();
+    for idx in graph.node_indices() {
+        if let Node::Struct { name, .. } | Node::Enum { name, .. } | Node::Trait { name, .. } =
+            &graph[idx]
+        {
+            index.entry(name.clone()).or_default().push(idx);
+        }
+    }
+    index
+}
+
+/// Resolves `name` in `index` from a reference in `rel`.
+///
+/// Prefers a definition in `rel`, then one in the same directory (module);
+/// otherwise the name must be defined exactly once in the repository.
+/// Ambiguous names resolve to nothing rather than to an arbitrary candidate.
+fn resolve(
+    graph: &GraphDb,
+    index: &HashMap<String, Vec<NodeIndex>>,
+    rel: &str,
+    name: &str,
+) -> Option<NodeIndex> {
+    let candidates = index.get(name)?;
+    if candidates.iter().any(|&i| graph[i].path() == rel) {
+        return function_in(graph, index, rel, name);
+    }
+    let dir = Path::new(rel).parent();
+    let mut same_dir = candidates
+        .iter()
+        .copied()
+        .filter(|&i| Path::new(graph[i].path()).parent() == dir);
+    match (same_dir.next(), same_dir.next()) {
+        (Some(only), None) => Some(only),
+        (Some(_), Some(_)) => None,
+        (None, _) => match candidates.as_slice() {
+            [only] => Some(*only),
+            _ => None,
+        },
+    }
+}
+
+/// Adds `Calls` and `Tests` edges for the call sites in one file.
+///
+/// The caller must be a function defined in `rel`. The callee is resolved by
+/// name (see [`resolve`]); calls to symbols not defined anywhere in the
+/// repository (standard library, dependencies) or defined ambiguously are
+/// dropped. A call from a test function also adds a `Tests` edge, unless the
+/// callee is a test helper in the same file.
+fn link_calls(
+    graph: &mut GraphDb,
+    names: &HashMap<String, Vec<NodeIndex>>,
+    rel: &str,
+    symbols: &FileSymbols,
+) {
+    for (caller, callee) in &symbols.calls {
+        let Some(src) = function_in(graph, names, rel, caller) else {
+            continue;
+        };
+        let Some(dst) = resolve(graph, names, rel, callee) else {
+            continue;
+        };
+        add_edge_once(graph, src, dst, Edge::Calls);
+
+        let callee_is_test =
+            graph[dst].path() == rel && symbols.tests.iter().any(|t| t == graph[dst].name());
+        if symbols.tests.contains(caller) && !callee_is_test {
+            add_edge_once(graph, src, dst, Edge::Tests);
+        }
+    }
+}
+
+/// Adds `Implements` edges from the types defined in `rel` to the traits and
+/// base classes they implement. Traits not defined anywhere in the repository
+/// are dropped.
+fn link_implements(
+    graph: &mut GraphDb,
+    types: &HashMap<String, Vec<NodeIndex>>,
+    rel: &str,
+    symbols: &FileSymbols,
+) {
+    for (type_name, base) in &symbols.implements {
+        let Some(src) = types
+            .get(type_name)
+            .and_then(|c| c.iter().copied().find(|&i| graph[i].path() == rel))
+        else {
+            continue;
+        };
+        if let Some(dst) = resolve(graph, types, rel, base)
+            && dst != src
+        {
+            add_edge_once(graph, src, dst, Edge::Implements);
+        }
+    }
+}
+
+/// Adds an edge unless an identical one already exists.
+pub(super) fn add_edge_once(graph: &mut GraphDb, src: NodeIndex, dst: NodeIndex, edge: Edge) {
+    let exists = graph
+        .edges_directed(src, Direction::Outgoing)
+        .any(|e| e.target() == dst && *e.weight() == edge);
+    if !exists {
+        graph.add_edge(src, dst, edge);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let path = root.join(rel);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn callers_of(graph: &GraphDb, name: &str) -> Vec<String> {
+        let mut callers: Vec<String> = graph
+            .node_indices()
+            .filter(|&i| graph[i].name() == name)
+            .flat_map(|i| graph.edges_directed(i, Direction::Incoming))
+            .filter(|e| matches!(e.weight(), Edge::Calls))
+            .map(|e| format!("{}:{}", graph[e.source()].path(), graph[e.source()].name()))
+            .collect();
+        callers.sort();
+        callers
+    }
+
+    fn pr_file(filename: &str, status: &str, content: Option<&str>) -> PrFile {
+        PrFile {
+            filename: filename.to_string(),
+            previous_filename: None,
+            status: status.to_string(),
+            additions: 0,
+            deletions: 0,
+            patch: None,
+            patch_truncated: false,
+            full_content: content.map(str::to_string),
+        }
+    }
+
+    fn fixture() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "src/lib.rs",
+            "pub fn target() -> u32 {\n    1\n}\n",
+        );
+        write(
+            dir.path(),
+            "src/user.rs",
+            "use crate::target;\n\npub fn uses_target() -> u32 {\n    target() + 1\n}\n",
+        );
+        dir
+    }
+
+    #[test]
+    fn test_build_repo_graph_links_calls_across_files() {
+        let dir = fixture();
+
+        let graph = build_repo_graph(dir.path());
+
+        assert_eq!(
+            callers_of(&graph, "target"),
+            vec!["src/user.rs:uses_target"]
+        );
+    }
+
+    #[test]
+    fn test_build_repo_graph_links_calls_inside_macros() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "src/lib.rs",
+            "pub fn target() -> u32 {\n    2\n}\n\n#[test]\nfn checks_target() {\n    assert_eq!(target(), 2);\n}\n",
+        );
+
+        let graph = build_repo_graph(dir.path());
+
+        assert_eq!(
+            callers_of(&graph, "target"),
+            vec!["src/lib.rs:checks_target"]
+        );
+    }
+
+    #[test]
+    fn test_checkout_at_requires_clean_head() {
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?} failed");
+            String::from_utf8(output.stdout).unwrap().trim().to_string()
+        };
+        git(&["init", "-q"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "user.name", "Test"]);
+        git(&["config", "commit.gpgsign", "false"]);
+        write(dir.path(), "src/lib.rs", "pub fn target() {}\n");
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "init"]);
+        let head = git(&["rev-parse", "HEAD"]);
+
+        assert!(checkout_at(dir.path(), &head));
+        assert!(!checkout_at(dir.path(), "0000000"));
+        assert!(!checkout_at(dir.path(), ""));
+
+        write(
+            dir.path(),
+            "src/lib.rs",
+            "pub fn target() -> u32 {\n    1\n}\n",
+        );
+        assert!(!checkout_at(dir.path(), &head));
+    }
+
+    #[test]
+    fn test_build_repo_graph_skips_ambiguous_callees() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "api/helper.rs", "pub fn helper() {}\n");
+        write(
+            dir.path(),
+            "api/handler.rs",
+            "pub fn handle() {\n    helper();\n}\n",
+        );
+        write(dir.path(), "cli/helper.rs", "pub fn helper() {}\n");
+        write(
+            dir.path(),
+            "web/page.rs",
+            "pub fn render() {\n    helper();\n}\n",
+        );
+
+        let graph = build_repo_graph(dir.path());
+
+        // The same directory disambiguates; a caller elsewhere gets no edge.
+        assert_eq!(callers_of(&graph, "helper"), vec!["api/handler.rs:handle"]);
+    }
+
+    #[test]
+    fn test_build_repo_graph_missing_root_is_empty() {
+        let graph = build_repo_graph(Path::new("/nonexistent/aptu/repo"));
+        assert_eq!(graph.node_count(), 0);
+    }
+
+    #[test]
+    fn test_apply_file_changes_keeps_callers_in_untouched_files() {
+        let dir = fixture();
+        let mut graph = build_repo_graph(dir.path());
+
+        let changed = pr_file(
+            "src/lib.rs",
+            "modified",
+            Some("pub fn target() -> u32 {\n    helper()\n}\n\nfn helper() -> u32 {\n    2\n}\n"),
+        );
+        apply_file_changes(&mut graph, dir.path(), &[changed]);
+
+        assert_eq!(
+            callers_of(&graph, "target"),
+            vec!["src/user.rs:uses_target"]
+        );
+        assert_eq!(callers_of(&graph, "helper"), vec!["src/lib.rs:target"]);
+    }
+
+    #[test]
+    fn test_apply_file_changes_moves_renamed_files() {
+        let dir = fixture();
+        let mut graph = build_repo_graph(dir.path());
+
+        let mut renamed = pr_file(
+            "src/core.rs",
+            "renamed",
+            Some("pub fn target() -> u32 {\n    1\n}\n"),
+        );
+        renamed.previous_filename = Some("src/lib.rs".to_string());
+        apply_file_changes(&mut graph, dir.path(), &[renamed]);
+
+        assert!(
+            graph
+                .node_indices()
+                .all(|i| graph[i].path() != "src/lib.rs")
+        );
+        let paths: Vec<&str> = graph
+            .node_indices()
+            .filter(|&i| graph[i].name() == "target")
+            .map(|i| graph[i].path())
+            .collect();
+        assert_eq!(paths, vec!["src/core.rs"]);
+        assert_eq!(
+            callers_of(&graph, "target"),
+            vec!["src/user.rs:uses_target"]
+        );
+    }
+
+    #[test]
+    fn test_apply_file_changes_drops_removed_files() {
+        let dir = fixture();
+        let mut graph = build_repo_graph(dir.path());
+
+        apply_file_changes(
+            &mut graph,
+            dir.path(),
+            &[pr_file("src/user.rs", "removed", None)],
+        );
+
+        assert!(graph.node_weights().all(|n| n.path() != "src/user.rs"));
+        assert!(callers_of(&graph, "target").is_empty());
+    }
+
+    #[test]
+    fn test_update_index_reparses_only_changed_files() {
+        let dir = fixture();
+        let mut index = FileIndex::default();
+
+        let first = update_index(dir.path(), &mut index);
+        assert_eq!((first.parsed, first.reused, first.removed), (2, 0, 0));
+
+        write(
+            dir.path(),
+            "src/lib.rs",
+            "pub fn target() -> u32 {\n    2\n}\n",
+        );
+        write(dir.path(), "src/new.rs", "fn fresh() {}\n");
+        let second = update_index(dir.path(), &mut index);
+        assert_eq!((second.parsed, second.reused, second.removed), (2, 1, 0));
+
+        std::fs::remove_file(dir.path().join("src/user.rs")).unwrap();
+        let third = update_index(dir.path(), &mut index);
+        assert_eq!((third.parsed, third.reused, third.removed), (0, 2, 1));
+
+        let graph = graph_from_index(&index);
+        assert!(graph.node_weights().all(|n| n.path() != "src/user.rs"));
+        assert!(graph.node_weights().any(|n| n.name() == "fresh"));
+    }
+
+    #[test]
+    fn test_update_index_records_parse_failures_by_hash() {
+        let dir = fixture();
+        let broken = "pub fn broken() {}\n";
+        write(dir.path(), "src/broken.rs", broken);
+        let mut index = FileIndex::default();
+        index
+            .failed
+            .insert("src/broken.rs".to_string(), content_hash(broken));
+
+        let first = update_index(dir.path(), &mut index);
+        assert_eq!((first.parsed, first.reused, first.failed), (2, 1, 0));
+        assert!(!index.files.contains_key("src/broken.rs"));
+
+        write(dir.path(), "src/broken.rs", "pub fn fixed() {}\n");
+        let second = update_index(dir.path(), &mut index);
+        assert_eq!((second.parsed, second.reused), (1, 2));
+        assert!(index.failed.is_empty());
+        assert!(index.files.contains_key("src/broken.rs"));
+
+        std::fs::remove_file(dir.path().join("src/broken.rs")).unwrap();
+        index
+            .failed
+            .insert("src/gone.rs".to_string(), "stale".to_string());
+        let third = update_index(dir.path(), &mut index);
+        assert_eq!(third.removed, 2);
+        assert!(index.failed.is_empty());
+    }
+
+    #[test]
+    fn test_build_repo_graph_ranks_public_callers_first() {
+        // Arrange: two otherwise identical callers; the private one has the
+        // lower node index, so only the visibility bonus can rank `api` first.
+        let dir = fixture();
+        write(
+            dir.path(),
+            "src/a.rs",
+            "fn internal() -> u32 {\n    crate::target()\n}\n",
+        );
+        write(
+            dir.path(),
+            "src/b.rs",
+            "pub fn api() -> u32 {\n    crate::target()\n}\n",
+        );
+        let graph = build_repo_graph(dir.path());
+        let find = |name: &str| {
+            graph
+                .node_indices()
+                .find(|&i| graph[i].name() == name)
+                .unwrap()
+        };
+
+        // Act
+        let ranked = super::super::query::rank_blast_radius(&graph, &[find("target")], 10, 1);
+
+        // Assert
+        let order: Vec<&str> = ranked.iter().map(|n| graph[n.index].name()).collect();
+        let position = |name: &str| order.iter().position(|n| *n == name).unwrap();
+        assert!(position("api") < position("internal"), "{order:?}");
+        assert!(matches!(
+            &graph[find("api")],
+            Node::Function { visibility, .. } if visibility == "pub"
+        ));
+        assert!(matches!(
+            &graph[find("target")],
+            Node::Function { visibility, .. } if visibility == "pub"
+        ));
+    }
+
+    fn edges_of(graph: &GraphDb, kind: Edge) -> Vec<String> {
+        let mut edges: Vec<String> = graph
+            .edge_references()
+            .filter(|e| *e.weight() == kind)
+            .map(|e| format!("{}->{}", graph[e.source()].name(), graph[e.target()].name()))
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn test_build_repo_graph_links_tests_to_what_they_call() {
+        let dir = fixture();
+        write(
+            dir.path(),
+            "src/user.rs",
+            "pub fn uses_target() -> u32 {\n    target() + 1\n}\n\n\
+             #[cfg(test)]\nmod tests {\n    use super::*;\n\n    \
+             fn setup() -> u32 {\n        uses_target()\n    }\n\n    \
+             #[test]\n    fn checks_target() {\n        let expected = target() + 1;\n        \
+             assert_eq!(setup(), expected);\n    }\n}\n",
+        );
+        write(
+            dir.path(),
+            "tests/it.py",
+            "from app import handler\n\ndef test_handler():\n    assert handler() == 1\n",
+        );
+        write(dir.path(), "app.py", "def handler():\n    return 1\n");
+
+        let graph = build_repo_graph(dir.path());
+
+        assert_eq!(
+            edges_of(&graph, Edge::Tests),
+            vec![
+                "checks_target->target",
+                "setup->uses_target",
+                "test_handler->handler"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_repo_graph_links_trait_impls_and_methods() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "src/shape.rs",
+            "pub trait Shape {\n    fn area(&self) -> f64;\n}\n",
+        );
+        write(
+            dir.path(),
+            "src/square.rs",
+            "pub struct Square {\n    side: f64,\n}\n\n\
+             impl Shape for Square {\n    fn area(&self) -> f64 {\n        self.side\n    }\n}\n",
+        );
+        let mut graph = build_repo_graph(dir.path());
+
+        assert_eq!(edges_of(&graph, Edge::Implements), vec!["Square->Shape"]);
+        assert_eq!(
+            edges_of(&graph, Edge::HasMethod),
+            vec!["Square->Square::area"]
+        );
+        assert!(
+            graph
+                .node_weights()
+                .any(|n| matches!(n, Node::Trait { name, .. } if name == "Shape"))
+        );
+
+        // Re-parsing the trait keeps the impl in the untouched file linked.
+        let changed = pr_file(
+            "src/shape.rs",
+            "modified",
+            Some(
+                "pub trait Shape {\n    fn area(&self) -> f64;\n    fn name(&self) -> String;\n}\n",
+            ),
+        );
+        apply_file_changes(&mut graph, dir.path(), &[changed]);
+        assert_eq!(edges_of(&graph, Edge::Implements), vec!["Square->Shape"]);
+    }
+
+    #[test]
+    fn test_build_repo_graph_links_across_languages() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "src/lib.rs",
+            "#[pyfunction]\n#[pyo3(name = \"checksum\")]\nfn py_checksum(data: &[u8]) -> u32 {\n    0\n}\n\n\
+             #[pymodule]\nfn mylib(m: &Bound<'_, PyModule>) -> PyResult<()> {\n    Ok(())\n}\n\n\
+             async fn get_user() -> String {\n    String::new()\n}\n\n\
+             pub fn router() -> Router {\n    Router::new().route(\"/api/users/:id\", get(get_user))\n}\n",
+        );
+        write(
+            dir.path(),
+            "scripts/verify.py",
+            "import mylib\nimport zlib\n\ndef verify(data):\n    return mylib.checksum(data)\n\n\
+             def crc(data):\n    return zlib.checksum(data)\n",
+        );
+        write(
+            dir.path(),
+            "web/user.ts",
+            "export async function loadUser(id: string) {\n  return fetch(`/api/users/${id}`);\n}\n",
+        );
+        let mut graph = build_repo_graph(dir.path());
+
+        assert_eq!(edges_of(&graph, Edge::Binds), vec!["verify->py_checksum"]);
+        assert_eq!(edges_of(&graph, Edge::Requests), vec!["loadUser->get_user"]);
+
+        // Re-parsing the Rust file keeps the callers in the other languages.
+        let changed = pr_file(
+            "src/lib.rs",
+            "modified",
+            Some(
+                "#[pyfunction]\n#[pyo3(name = \"checksum\")]\nfn py_checksum(data: &[u8]) -> u32 {\n    1\n}\n\n\
+                 #[pymodule]\nfn mylib(m: &Bound<'_, PyModule>) -> PyResult<()> {\n    Ok(())\n}\n\n\
+                 async fn get_user() -> String {\n    String::from(\"x\")\n}\n\n\
+                 pub fn router() -> Router {\n    Router::new().route(\"/api/users/:id\", get(get_user))\n}\n",
+            ),
+        );
+        apply_file_changes(&mut graph, dir.path(), &[changed]);
+        assert_eq!(edges_of(&graph, Edge::Binds), vec!["verify->py_checksum"]);
+        assert_eq!(edges_of(&graph, Edge::Requests), vec!["loadUser->get_user"]);
+    }
+
+    #[test]
+    fn test_diff_symbols_maps_hunks_per_file() {
+        let dir = fixture();
+        let diff = "diff --git a/src/lib.rs b/src/lib.rs\n\
+                    --- a/src/lib.rs\n+++ b/src/lib.rs\n\
+                    @@ -1,3 +1,3 @@\n pub fn target() -> u32 {\n-    0\n+    1\n }\n\
+                    diff --git a/src/old.rs b/src/old.rs\n\
+                    --- a/src/old.rs\n+++ /dev/null\n\
+                    @@ -1,1 +0,0 @@\n-fn gone() {}\n";
+
+        let files = split_diff(diff);
+        let symbols = diff_symbols(dir.path(), diff);
+
+        assert_eq!(
+            files
+                .iter()
+                .map(|f| (f.filename.as_str(), f.status.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("src/lib.rs", "modified"), ("src/old.rs", "removed")]
+        );
+        assert_eq!(symbols, vec!["target"]);
+    }
+
+    #[test]
+    fn test_graph_from_index_matches_fresh_build() {
+        let dir = fixture();
+        let mut index = FileIndex::default();
+        update_index(dir.path(), &mut index);
+
+        let assembled = graph_from_index(&index);
+        let fresh = build_repo_graph(dir.path());
+
+        assert_eq!(assembled.node_count(), fresh.node_count());
+        assert_eq!(assembled.edge_count(), fresh.edge_count());
+    }
+}
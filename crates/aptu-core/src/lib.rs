@@ -38,7 +38,9 @@ pub use config::{
 // ============================================================================
 
 pub use ai::types::{
-    IssueComment, IssueDetails, PrDetails, PrFile, PrReviewResponse, ReviewEvent, TriageResponse,
+    CodeSymbol, ImplementationPlan, IssueComment, IssueDetails, IssueSplitResponse, PlanStep,
+    PrDetails, PrFile, PrReviewResponse, PriorTriage, ReviewEvent, SubIssueTask, TemplateCheck,
+    TriageResponse,
 };
 pub use ai::{AiClient, AiModel, ModelProvider, ProviderConfig, all_providers, get_provider};
 
@@ -79,9 +81,10 @@ pub use repos::{CuratedRepo, RepoFilter};
 // ============================================================================
 
 pub use triage::{
-    APTU_SIGNATURE, TriageStatus, check_already_triaged, render_pr_review_comment_body,
-    render_pr_review_markdown, render_triage_markdown,
+    APTU_SIGNATURE, RetriageReason, TriageStatus, check_already_triaged, prior_triage,
+    render_pr_review_comment_body, render_pr_review_markdown, render_triage_markdown,
 };
+pub use triage_rules::{TriageRule, TriageRules};
 
 // ============================================================================
 // Bulk Processing
@@ -104,10 +107,11 @@ pub use utils::{
 pub use facade::format_issue;
 #[cfg(not(target_arch = "wasm32"))]
 pub use facade::{
-    add_custom_repo, analyze_issue, analyze_pr, apply_triage_labels, create_pr, discover_repos,
-    fetch_issue_for_triage, fetch_issues, fetch_pr_for_review, label_pr, list_curated_repos,
-    list_models, list_repos, post_issue, post_pr_review, post_triage_comment, remove_custom_repo,
-    revert_issue, revert_pr, validate_model,
+    add_custom_repo, analyze_issue, analyze_pr, apply_triage_labels, assign_issue, create_pr,
+    discover_repos, fetch_issue_for_plan, fetch_issue_for_triage, fetch_issues,
+    fetch_pr_for_review, label_pr, list_curated_repos, list_models, list_repos, post_issue,
+    post_pr_review, post_triage_comment, remove_custom_repo, revert_issue, revert_pr,
+    validate_model, validate_security_findings,
 };
 #[cfg(not(target_arch = "wasm32"))]
 pub use github::issues::ApplyResult;
@@ -121,8 +125,8 @@ pub use github::pulls::PrCreateResult;
 #[cfg(not(target_arch = "wasm32"))]
 pub use security::FindingCache;
 pub use security::{
-    Confidence, Finding, PatternEngine, SarifReport, SecurityConfig, SecurityScanner, Severity,
-    needs_security_scan,
+    CodeClimateReport, Confidence, Finding, GitLabSastReport, JUnitReport, PatternEngine,
+    SarifReport, SecurityConfig, SecurityScanner, Severity, needs_security_scan,
 };
 
 // ============================================================================
@@ -150,6 +154,7 @@ pub mod retry;
 pub mod sanitize;
 pub mod security;
 pub mod triage;
+pub mod triage_rules;
 pub mod utils;
 
 #[cfg(not(target_arch = "wasm32"))]
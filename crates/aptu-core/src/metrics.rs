@@ -89,6 +89,12 @@ pub struct ReviewContextRecord {
     pub finish_reasons: Vec<String>,
     /// Maximum prompt character budget from review config.
     pub max_prompt_chars: usize,
+    /// Files classified as non-behavioural (renames, moves, formatting, comments).
+    #[serde(default)]
+    pub non_behavioural_files: Vec<String>,
+    /// Whether the AI call was skipped because no file could change behaviour.
+    #[serde(default)]
+    pub ai_skipped: bool,
 }
 
 /// Append a PR review context record to the context JSONL file.
@@ -295,6 +301,8 @@ mod tests {
             prompt_chars_final: 5000,
             finish_reasons: vec!["stop".to_string()],
             max_prompt_chars: 120_000,
+            non_behavioural_files: vec![],
+            ai_skipped: false,
         };
 
         // Should not panic or error
@@ -328,6 +336,8 @@ mod tests {
             prompt_chars_final: 5000,
             finish_reasons: vec!["stop".to_string()],
             max_prompt_chars: 120_000,
+            non_behavioural_files: vec![],
+            ai_skipped: false,
         };
 
         write_context_jsonl_impl(&file_path_str, &record).unwrap();
@@ -367,6 +377,8 @@ mod tests {
             prompt_chars_final: 5000,
             finish_reasons: vec!["stop".to_string()],
             max_prompt_chars: 120_000,
+            non_behavioural_files: vec![],
+            ai_skipped: false,
         };
 
         let json = serde_json::to_string(&record).expect("serialization failed");
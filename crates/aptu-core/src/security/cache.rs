@@ -15,7 +15,7 @@ use tracing::instrument;
 #[cfg(not(target_arch = "wasm32"))]
 use crate::cache::{FileCache, FileCacheImpl};
 
-use super::ValidatedFinding;
+use super::{Finding, ValidatedFinding};
 
 /// A cached security finding with validation result.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -75,6 +75,31 @@ pub fn cache_key(
     hex::encode(hasher.finalize())
 }
 
+/// Normalizes a code snippet for cache keying.
+///
+/// Collapses runs of whitespace to a single space and trims the ends, so
+/// reindented or reformatted code reuses the same verdict.
+#[must_use]
+pub fn normalize_snippet(snippet: &str) -> String {
+    snippet.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Generate a cache key for an LLM validation verdict.
+///
+/// Keys on pattern, normalized snippet, and model only (via [`cache_key`]),
+/// so a verdict is reused wherever the same code appears, but a different
+/// model always gets a fresh verdict.
+#[must_use]
+pub fn verdict_cache_key(pattern_id: &str, matched_text: &str, model: &str) -> String {
+    cache_key(
+        "verdict",
+        model,
+        "",
+        pattern_id,
+        &normalize_snippet(matched_text),
+    )
+}
+
 /// Cache for security finding validation results.
 ///
 /// Wraps `FileCacheImpl` with a 7-day TTL for validated findings.
@@ -158,6 +183,45 @@ impl FindingCache {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+impl FindingCache {
+    /// Get a cached validation verdict for `finding` produced by `model`.
+    ///
+    /// The returned validated finding carries `finding` itself, so location
+    /// fields reflect the current scan rather than the scan that was cached.
+    #[instrument(skip(self, finding), fields(pattern_id = %finding.pattern_id))]
+    pub async fn get_verdict(
+        &self,
+        finding: &Finding,
+        model: &str,
+    ) -> Result<Option<ValidatedFinding>> {
+        let key = verdict_cache_key(&finding.pattern_id, &finding.matched_text, model);
+        Ok(self.cache.get(&key).await?.map(|cached| ValidatedFinding {
+            finding: finding.clone(),
+            ..cached.validated
+        }))
+    }
+
+    /// Cache a validation verdict under its model.
+    ///
+    /// Verdicts without a `model_version` (pattern-confidence fallbacks) are
+    /// not cached, so a later run retries the LLM.
+    #[instrument(skip(self, validated), fields(pattern_id = %validated.finding.pattern_id))]
+    pub async fn set_verdict(&self, validated: &ValidatedFinding) -> Result<()> {
+        let Some(model) = validated.model_version.as_deref() else {
+            return Ok(());
+        };
+        let key = verdict_cache_key(
+            &validated.finding.pattern_id,
+            &validated.finding.matched_text,
+            model,
+        );
+        self.cache
+            .set(&key, &CachedFinding::new(validated.clone()))
+            .await
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 impl Default for FindingCache {
     fn default() -> Self {
@@ -228,6 +292,7 @@ mod tests {
                 confidence: Confidence::Medium,
                 file_path: "src/test.rs".to_string(),
                 line_number: 42,
+                column: None,
                 matched_text: "test code".to_string(),
                 cwe: None,
             },
@@ -263,6 +328,70 @@ mod tests {
         cache.cache.remove(&key).await.ok();
     }
 
+    #[test]
+    fn test_verdict_cache_key_normalizes_whitespace_and_includes_model() {
+        let key = verdict_cache_key("pattern", "api_key =  \"x\"\n", "model-a");
+
+        assert_eq!(
+            key,
+            verdict_cache_key("pattern", "api_key = \"x\"", "model-a")
+        );
+        assert_ne!(
+            key,
+            verdict_cache_key("pattern", "api_key = \"x\"", "model-b")
+        );
+        assert_ne!(
+            key,
+            verdict_cache_key("other", "api_key = \"x\"", "model-a")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verdict_cache_round_trip_uses_current_location() {
+        let cache = FindingCache::new();
+        let matched_text = "verdict round trip 7f3a".to_string();
+        let cached_finding = Finding {
+            pattern_id: "test-pattern".to_string(),
+            file_path: "src/old.rs".to_string(),
+            line_number: 1,
+            matched_text: matched_text.clone(),
+            ..Finding::default()
+        };
+        let validated = ValidatedFinding {
+            finding: cached_finding,
+            is_valid: false,
+            reasoning: "Test data".to_string(),
+            model_version: Some("test-model".to_string()),
+        };
+        cache.set_verdict(&validated).await.expect("set verdict");
+
+        let moved = Finding {
+            pattern_id: "test-pattern".to_string(),
+            file_path: "src/new.rs".to_string(),
+            line_number: 9,
+            matched_text: format!("  {matched_text}"),
+            ..Finding::default()
+        };
+        let hit = cache
+            .get_verdict(&moved, "test-model")
+            .await
+            .expect("get verdict")
+            .expect("cache hit");
+        let miss = cache
+            .get_verdict(&moved, "other-model")
+            .await
+            .expect("get verdict");
+
+        assert_eq!(hit.finding.file_path, "src/new.rs");
+        assert!(!hit.is_valid);
+        assert_eq!(hit.reasoning, "Test data");
+        assert!(miss.is_none());
+
+        // Cleanup
+        let key = verdict_cache_key("test-pattern", &matched_text, "test-model");
+        cache.cache.remove(&key).await.ok();
+    }
+
     #[tokio::test]
     async fn test_finding_cache_miss() {
         let cache = FindingCache::new();
@@ -286,6 +415,7 @@ mod tests {
                 confidence: Confidence::High,
                 file_path: "src/file.rs".to_string(),
                 line_number: 10,
+                column: None,
                 matched_text: "code".to_string(),
                 cwe: None,
             },
@@ -329,6 +459,7 @@ mod tests {
                 confidence: Confidence::Low,
                 file_path: "test.rs".to_string(),
                 line_number: 1,
+                column: None,
                 matched_text: "test".to_string(),
                 cwe: Some("CWE-123".to_string()),
             },
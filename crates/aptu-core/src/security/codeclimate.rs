@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Code Climate JSON output support.
+//!
+//! Converts security findings to the Code Climate issue format consumed by
+//! GitLab Code Quality merge-request widgets and other Code Climate compatible tools.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::types::{Finding, PatternDefinition, Severity};
+
+/// A Code Climate report: a JSON array of issues.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CodeClimateReport {
+    /// Reported issues, one per finding.
+    pub issues: Vec<CodeClimateIssue>,
+}
+
+/// A single Code Climate issue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeClimateIssue {
+    /// Always `"issue"`.
+    #[serde(rename = "type")]
+    pub issue_type: String,
+    /// Rule identifier (pattern ID).
+    pub check_name: String,
+    /// Human-readable description.
+    pub description: String,
+    /// Remediation guidance, when the pattern provides it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<CodeClimateContent>,
+    /// Issue categories (always `Security`).
+    pub categories: Vec<String>,
+    /// Location of the issue.
+    pub location: CodeClimateLocation,
+    /// Severity (`info`, `minor`, `major`, `critical`, `blocker`).
+    pub severity: String,
+    /// Stable fingerprint for deduplication across runs.
+    pub fingerprint: String,
+}
+
+/// Markdown body describing the issue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeClimateContent {
+    /// Markdown text.
+    pub body: String,
+}
+
+/// File and line range of an issue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeClimateLocation {
+    /// File path relative to the project root.
+    pub path: String,
+    /// Line range.
+    pub lines: CodeClimateLines,
+}
+
+/// Line range of an issue (1-indexed, inclusive).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeClimateLines {
+    /// First line.
+    pub begin: usize,
+    /// Last line.
+    pub end: usize,
+}
+
+/// Maps a severity to a Code Climate severity.
+fn codeclimate_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "blocker",
+        Severity::High => "critical",
+        Severity::Medium => "major",
+        Severity::Low => "minor",
+    }
+}
+
+impl CodeClimateReport {
+    /// Build a Code Climate report, using `patterns` for remediation content.
+    #[must_use]
+    pub fn with_rules(findings: Vec<Finding>, patterns: &[PatternDefinition]) -> Self {
+        let by_id: HashMap<&str, &PatternDefinition> =
+            patterns.iter().map(|p| (p.id.as_str(), p)).collect();
+
+        let issues = findings
+            .into_iter()
+            .map(|f| {
+                let pattern = by_id.get(f.pattern_id.as_str());
+                let mut issue = CodeClimateIssue::from(f);
+                issue.content = pattern
+                    .and_then(|p| remediation_body(p))
+                    .map(|body| CodeClimateContent { body });
+                issue
+            })
+            .collect();
+
+        Self { issues }
+    }
+}
+
+/// Renders remediation and reference link as a markdown body.
+fn remediation_body(pattern: &PatternDefinition) -> Option<String> {
+    match (&pattern.remediation, &pattern.authority_url) {
+        (Some(r), Some(url)) => Some(format!("{r}\n\nReference: <{url}>")),
+        (Some(r), None) => Some(r.clone()),
+        (None, Some(url)) => Some(format!("Reference: <{url}>")),
+        (None, None) => None,
+    }
+}
+
+impl From<Finding> for CodeClimateIssue {
+    fn from(finding: Finding) -> Self {
+        let fingerprint = finding.fingerprint();
+        CodeClimateIssue {
+            issue_type: "issue".to_string(),
+            check_name: finding.pattern_id,
+            description: finding.description,
+            content: None,
+            categories: vec!["Security".to_string()],
+            location: CodeClimateLocation {
+                path: finding.file_path.trim_start_matches("./").to_string(),
+                lines: CodeClimateLines {
+                    begin: finding.line_number,
+                    end: finding.line_number,
+                },
+            },
+            severity: codeclimate_severity(finding.severity).to_string(),
+            fingerprint,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::types::Confidence;
+
+    fn finding() -> Finding {
+        Finding {
+            pattern_id: "hardcoded-api-key".to_string(),
+            description: "Hardcoded API key".to_string(),
+            severity: Severity::Critical,
+            confidence: Confidence::High,
+            file_path: "./src/config.rs".to_string(),
+            line_number: 7,
+            column: Some(5),
+            matched_text: "api_key = \"x\"".to_string(),
+            cwe: Some("CWE-798".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_issue_fields() {
+        let issue = CodeClimateIssue::from(finding());
+
+        assert_eq!(issue.issue_type, "issue");
+        assert_eq!(issue.check_name, "hardcoded-api-key");
+        assert_eq!(issue.severity, "blocker");
+        assert_eq!(issue.location.path, "src/config.rs");
+        assert_eq!(issue.location.lines.begin, 7);
+        assert_eq!(issue.fingerprint, finding().fingerprint());
+    }
+
+    #[test]
+    fn test_report_serializes_as_array_with_content() {
+        let pattern = PatternDefinition {
+            id: "hardcoded-api-key".to_string(),
+            description: "Hardcoded API key".to_string(),
+            pattern: "x".to_string(),
+            severity: Severity::Critical,
+            confidence: Confidence::High,
+            cwe: None,
+            remediation: Some("Use a secrets manager.".to_string()),
+            authority_url: None,
+            file_extensions: Vec::new(),
+        };
+        let report = CodeClimateReport::with_rules(vec![finding()], &[pattern]);
+        let json = serde_json::to_value(&report).unwrap();
+
+        assert!(json.is_array());
+        assert_eq!(json[0]["type"], "issue");
+        assert_eq!(json[0]["content"]["body"], "Use a secrets manager.");
+    }
+}
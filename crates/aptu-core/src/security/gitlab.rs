@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! GitLab SAST report output support.
+//!
+//! Converts security findings to the GitLab Secure report format
+//! (`gl-sast-report.json`, schema 15.x) so they appear in merge-request
+//! security widgets and the vulnerability report.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::types::{Finding, PatternDefinition, Severity};
+
+/// GitLab Secure report schema version emitted by this module.
+pub const GITLAB_SAST_SCHEMA_VERSION: &str = "15.0.7";
+
+/// Scanner identifier reported in `scan.scanner.id` and `scan.analyzer.id`.
+const SCANNER_ID: &str = "aptu-security-scanner";
+
+/// GitLab SAST report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabSastReport {
+    /// Report schema version.
+    pub version: String,
+    /// Scan metadata.
+    pub scan: GitLabScan,
+    /// Reported vulnerabilities, one per finding.
+    pub vulnerabilities: Vec<GitLabVulnerability>,
+}
+
+/// Scan metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabScan {
+    /// Analyzer that produced the report.
+    pub analyzer: GitLabTool,
+    /// Scanner that detected the vulnerabilities.
+    pub scanner: GitLabTool,
+    /// Scan type (always `sast`).
+    #[serde(rename = "type")]
+    pub scan_type: String,
+    /// Scan start time (`YYYY-MM-DDTHH:MM:SS`, UTC).
+    pub start_time: String,
+    /// Scan end time (`YYYY-MM-DDTHH:MM:SS`, UTC).
+    pub end_time: String,
+    /// Scan status (`success` or `failure`).
+    pub status: String,
+}
+
+/// Analyzer or scanner identity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabTool {
+    /// Unique identifier.
+    pub id: String,
+    /// Display name.
+    pub name: String,
+    /// Version.
+    pub version: String,
+    /// Vendor.
+    pub vendor: GitLabVendor,
+}
+
+/// Tool vendor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabVendor {
+    /// Vendor name.
+    pub name: String,
+}
+
+/// A single vulnerability.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabVulnerability {
+    /// Stable identifier (UUID derived from the finding fingerprint).
+    pub id: String,
+    /// Short name.
+    pub name: String,
+    /// Description.
+    pub description: String,
+    /// Severity (`Critical`, `High`, `Medium`, `Low`).
+    pub severity: String,
+    /// Remediation guidance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solution: Option<String>,
+    /// Rule and CWE identifiers.
+    pub identifiers: Vec<GitLabIdentifier>,
+    /// Location of the vulnerability.
+    pub location: GitLabLocation,
+}
+
+/// A vulnerability identifier (rule ID, CWE).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabIdentifier {
+    /// Identifier type (e.g. `cwe`, `aptu_rule_id`).
+    #[serde(rename = "type")]
+    pub identifier_type: String,
+    /// Human-readable name.
+    pub name: String,
+    /// Identifier value.
+    pub value: String,
+    /// Reference URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Source location of a vulnerability.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabLocation {
+    /// File path relative to the project root.
+    pub file: String,
+    /// First line (1-indexed).
+    pub start_line: usize,
+    /// Last line (1-indexed).
+    pub end_line: usize,
+}
+
+/// Maps a severity to a GitLab severity.
+fn gitlab_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "Critical",
+        Severity::High => "High",
+        Severity::Medium => "Medium",
+        Severity::Low => "Low",
+    }
+}
+
+/// Formats a timestamp the way the GitLab report schema expects.
+fn gitlab_time(time: DateTime<Utc>) -> String {
+    time.format("%Y-%m-%dT%H:%M:%S").to_string()
+}
+
+impl GitLabSastReport {
+    /// Build a GitLab SAST report, using `patterns` for solutions and reference URLs.
+    ///
+    /// Scan start and end times default to now; use [`Self::with_scan_times`]
+    /// to record the actual scan window.
+    #[must_use]
+    pub fn with_rules(findings: Vec<Finding>, patterns: &[PatternDefinition]) -> Self {
+        let by_id: HashMap<&str, &PatternDefinition> =
+            patterns.iter().map(|p| (p.id.as_str(), p)).collect();
+
+        let vulnerabilities = findings
+            .into_iter()
+            .map(|f| {
+                let pattern = by_id.get(f.pattern_id.as_str()).copied();
+                GitLabVulnerability::from_finding(&f, pattern)
+            })
+            .collect();
+
+        let tool = GitLabTool {
+            id: SCANNER_ID.to_string(),
+            name: "Aptu Security Scanner".to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            vendor: GitLabVendor {
+                name: "Aptu".to_string(),
+            },
+        };
+        let now = gitlab_time(Utc::now());
+
+        Self {
+            version: GITLAB_SAST_SCHEMA_VERSION.to_string(),
+            scan: GitLabScan {
+                analyzer: tool.clone(),
+                scanner: tool,
+                scan_type: "sast".to_string(),
+                start_time: now.clone(),
+                end_time: now,
+                status: "success".to_string(),
+            },
+            vulnerabilities,
+        }
+    }
+
+    /// Records the scan window.
+    #[must_use]
+    pub fn with_scan_times(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.scan.start_time = gitlab_time(start);
+        self.scan.end_time = gitlab_time(end);
+        self
+    }
+}
+
+impl GitLabVulnerability {
+    fn from_finding(finding: &Finding, pattern: Option<&PatternDefinition>) -> Self {
+        let fingerprint = finding.fingerprint();
+        let mut hash = [0u8; 16];
+        if let Ok(bytes) = hex::decode(&fingerprint[..32]) {
+            hash.copy_from_slice(&bytes);
+        }
+        let id = uuid::Builder::from_sha1_bytes(hash).into_uuid().to_string();
+
+        let mut identifiers = vec![GitLabIdentifier {
+            identifier_type: "aptu_rule_id".to_string(),
+            name: format!("Aptu rule {}", finding.pattern_id),
+            value: finding.pattern_id.clone(),
+            url: None,
+        }];
+        if let Some(cwe) = &finding.cwe {
+            let number = cwe.strip_prefix("CWE-").unwrap_or(cwe);
+            identifiers.push(GitLabIdentifier {
+                identifier_type: "cwe".to_string(),
+                name: cwe.clone(),
+                value: number.to_string(),
+                url: Some(format!(
+                    "https://cwe.mitre.org/data/definitions/{number}.html"
+                )),
+            });
+        }
+
+        GitLabVulnerability {
+            id,
+            name: finding.description.clone(),
+            description: pattern
+                .map_or_else(|| finding.description.clone(), |p| p.description.clone()),
+            severity: gitlab_severity(finding.severity).to_string(),
+            solution: pattern.and_then(|p| p.remediation.clone()),
+            identifiers,
+            location: GitLabLocation {
+                file: finding.file_path.trim_start_matches("./").to_string(),
+                start_line: finding.line_number,
+                end_line: finding.line_number,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::types::Confidence;
+
+    fn finding() -> Finding {
+        Finding {
+            pattern_id: "sql-injection-concat".to_string(),
+            description: "Potential SQL injection".to_string(),
+            severity: Severity::High,
+            confidence: Confidence::Medium,
+            file_path: "src/db.rs".to_string(),
+            line_number: 12,
+            column: None,
+            matched_text: "query(".to_string(),
+            cwe: Some("CWE-89".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_vulnerability_fields() {
+        let report = GitLabSastReport::with_rules(vec![finding()], &[]);
+        let vuln = &report.vulnerabilities[0];
+
+        assert_eq!(report.version, GITLAB_SAST_SCHEMA_VERSION);
+        assert_eq!(report.scan.scan_type, "sast");
+        assert_eq!(vuln.severity, "High");
+        assert_eq!(vuln.location.file, "src/db.rs");
+        assert_eq!(vuln.location.start_line, 12);
+        assert_eq!(vuln.identifiers.len(), 2);
+        assert_eq!(vuln.identifiers[1].identifier_type, "cwe");
+        assert_eq!(vuln.identifiers[1].value, "89");
+    }
+
+    #[test]
+    fn test_vulnerability_id_is_stable_uuid() {
+        let a = GitLabSastReport::with_rules(vec![finding()], &[]);
+        let b = GitLabSastReport::with_rules(vec![finding()], &[]);
+        let id = &a.vulnerabilities[0].id;
+
+        assert_eq!(id, &b.vulnerabilities[0].id);
+        assert!(uuid::Uuid::parse_str(id).is_ok());
+    }
+
+    #[test]
+    fn test_scan_times_format() {
+        let start = DateTime::parse_from_rfc3339("2026-03-04T05:06:07Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let report = GitLabSastReport::with_rules(vec![], &[]).with_scan_times(start, start);
+
+        assert_eq!(report.scan.start_time, "2026-03-04T05:06:07");
+        assert!(report.vulnerabilities.is_empty());
+    }
+}
@@ -0,0 +1,192 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Git-aware scanning: staged changes and commit history.
+//!
+//! Both modes shell out to `git` and feed the resulting unified diffs to
+//! [`SecurityScanner::scan_diff`], so only added lines are scanned. Scanning a
+//! commit range looks at every commit individually, which catches secrets that
+//! were added in one commit and removed in a later one.
+
+use std::path::Path;
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+use super::scanner::SecurityScanner;
+use super::types::Finding;
+use crate::error::AptuError;
+
+/// Findings introduced by a single commit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitFindings {
+    /// Full commit SHA.
+    pub commit: String,
+    /// Commit subject line.
+    pub summary: String,
+    /// Findings on lines added by this commit.
+    pub findings: Vec<Finding>,
+}
+
+/// Diff options that keep output parseable by `scan_diff` regardless of user config.
+const DIFF_ARGS: &[&str] = &[
+    "--no-color",
+    "--no-ext-diff",
+    "--src-prefix=a/",
+    "--dst-prefix=b/",
+];
+
+/// Run git in `repo` and return stdout, mapping failures to `AptuError::SecurityScan`.
+fn git(repo: &Path, args: &[&str]) -> crate::Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(repo)
+        .output()
+        .map_err(|e| AptuError::SecurityScan {
+            message: format!("failed to run git: {e}"),
+        })?;
+    if !output.status.success() {
+        return Err(AptuError::SecurityScan {
+            message: format!(
+                "git {} failed: {}",
+                args.first().unwrap_or(&""),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        });
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+impl SecurityScanner {
+    /// Scans changes staged in the index (`git diff --cached`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `AptuError::SecurityScan` if git cannot be run or `repo` is not
+    /// a git work tree.
+    pub fn scan_staged(&self, repo: &Path) -> crate::Result<Vec<Finding>> {
+        let mut args = vec!["diff", "--cached"];
+        args.extend_from_slice(DIFF_ARGS);
+        let diff = git(repo, &args)?;
+        Ok(self.scan_diff(&diff))
+    }
+
+    /// Scans every commit in `since..HEAD`, oldest first.
+    ///
+    /// Each non-merge commit is diffed against its parent, so a secret added
+    /// and later removed is still reported against the commit that added it.
+    /// Commits without findings are omitted from the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AptuError::SecurityScan` if git cannot be run or `since` does
+    /// not resolve to a revision.
+    pub fn scan_commit_range(
+        &self,
+        repo: &Path,
+        since: &str,
+    ) -> crate::Result<Vec<CommitFindings>> {
+        let range = format!("{since}..HEAD");
+        let commits = git(repo, &["rev-list", "--reverse", "--no-merges", &range])?;
+
+        let mut results = Vec::new();
+        for commit in commits.lines().filter(|c| !c.is_empty()) {
+            let mut args = vec!["show", "--format=%s%n"];
+            args.extend_from_slice(DIFF_ARGS);
+            args.push(commit);
+            let show = git(repo, &args)?;
+            let (summary, diff) = show.split_once('\n').unwrap_or((show.as_str(), ""));
+
+            let findings = self.scan_diff(diff);
+            if !findings.is_empty() {
+                results.push(CommitFindings {
+                    commit: commit.to_string(),
+                    summary: summary.trim().to_string(),
+                    findings,
+                });
+            }
+        }
+
+        tracing::debug!(
+            range = %range,
+            commits = commits.lines().count(),
+            commits_with_findings = results.len(),
+            "Commit range scan complete"
+        );
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::ignore::SecurityConfig;
+
+    const SECRET: &str = "let api_key = \"sk-1234567890abcdefghijklmnopqrstuvwxyz\";\n";
+
+    fn scanner() -> SecurityScanner {
+        SecurityScanner::with_config(SecurityConfig::empty())
+    }
+
+    fn run(repo: &Path, args: &[&str]) -> String {
+        git(repo, args).expect("git command succeeds")
+    }
+
+    fn init_repo() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        run(dir.path(), &["init", "-q"]);
+        run(dir.path(), &["config", "user.email", "test@example.com"]);
+        run(dir.path(), &["config", "user.name", "Test"]);
+        run(dir.path(), &["config", "commit.gpgsign", "false"]);
+        std::fs::write(dir.path().join("README.md"), "hello\n").unwrap();
+        run(dir.path(), &["add", "."]);
+        run(dir.path(), &["commit", "-q", "-m", "initial"]);
+        dir
+    }
+
+    #[test]
+    fn test_scan_staged_reports_only_staged_lines() {
+        let repo = init_repo();
+        std::fs::write(
+            repo.path().join("config.rs"),
+            format!("fn main() {{}}\n{SECRET}"),
+        )
+        .unwrap();
+        std::fs::write(repo.path().join("unstaged.rs"), SECRET).unwrap();
+        run(repo.path(), &["add", "config.rs"]);
+
+        let findings = scanner().scan_staged(repo.path()).unwrap();
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].file_path, "config.rs");
+        assert_eq!(findings[0].line_number, 2);
+    }
+
+    #[test]
+    fn test_scan_commit_range_catches_removed_secret() {
+        let repo = init_repo();
+        std::fs::write(repo.path().join("config.rs"), SECRET).unwrap();
+        run(repo.path(), &["add", "."]);
+        run(repo.path(), &["commit", "-q", "-m", "add config"]);
+        std::fs::write(repo.path().join("config.rs"), "fn main() {}\n").unwrap();
+        run(repo.path(), &["commit", "-q", "-am", "remove secret"]);
+
+        let results = scanner().scan_commit_range(repo.path(), "HEAD~2").unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].summary, "add config");
+        assert_eq!(results[0].findings[0].file_path, "config.rs");
+        assert_eq!(results[0].findings[0].line_number, 1);
+    }
+
+    #[test]
+    fn test_scan_commit_range_rejects_unknown_revision() {
+        let repo = init_repo();
+
+        let err = scanner()
+            .scan_commit_range(repo.path(), "does-not-exist")
+            .unwrap_err();
+
+        assert!(matches!(err, AptuError::SecurityScan { .. }));
+    }
+}
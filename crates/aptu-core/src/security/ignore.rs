@@ -284,6 +284,7 @@ mod tests {
             confidence: Confidence::Low,
             file_path: "src/main.rs".to_string(),
             line_number: 1,
+            column: None,
             matched_text: "test".to_string(),
             cwe: None,
         };
@@ -305,6 +306,7 @@ mod tests {
             confidence: Confidence::Low,
             file_path: "test/fixtures/data.rs".to_string(),
             line_number: 1,
+            column: None,
             matched_text: "test".to_string(),
             cwe: None,
         };
@@ -326,6 +328,7 @@ mod tests {
             confidence: Confidence::High,
             file_path: "src/main.rs".to_string(),
             line_number: 42,
+            column: None,
             matched_text: "code".to_string(),
             cwe: Some("CWE-123".to_string()),
         };
@@ -348,6 +351,7 @@ mod tests {
             confidence: Confidence::Low,
             file_path: "test/unit/test.rs".to_string(),
             line_number: 1,
+            column: None,
             matched_text: "test".to_string(),
             cwe: None,
         };
@@ -361,6 +365,7 @@ mod tests {
             confidence: Confidence::Low,
             file_path: "src/test.rs".to_string(),
             line_number: 1,
+            column: None,
             matched_text: "test".to_string(),
             cwe: None,
         };
@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! `JUnit` XML output support.
+//!
+//! Renders security findings as a `JUnit` test suite so scans show up in CI
+//! test dashboards (Jenkins, GitLab test reports). Each finding is a failed
+//! test case; a clean scan produces a single passing test case.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use super::types::{Finding, PatternDefinition};
+
+/// Test suite name used for all findings.
+const SUITE_NAME: &str = "aptu-security-scanner";
+
+/// A `JUnit` report with one test suite.
+#[derive(Debug, Clone, Default)]
+pub struct JUnitReport {
+    /// Test cases, one per finding.
+    pub test_cases: Vec<JUnitTestCase>,
+    /// Scan duration in seconds.
+    pub time_seconds: f64,
+}
+
+/// A single failed test case describing a finding.
+#[derive(Debug, Clone)]
+pub struct JUnitTestCase {
+    /// Test class name (the file path).
+    pub classname: String,
+    /// Test name (`pattern_id` at `file:line`).
+    pub name: String,
+    /// Failure message (finding description).
+    pub message: String,
+    /// Failure type (severity).
+    pub failure_type: String,
+    /// Failure body with location, CWE, and remediation.
+    pub details: String,
+}
+
+impl JUnitReport {
+    /// Build a `JUnit` report, using `patterns` for remediation text.
+    #[must_use]
+    pub fn with_rules(findings: Vec<Finding>, patterns: &[PatternDefinition]) -> Self {
+        let by_id: HashMap<&str, &PatternDefinition> =
+            patterns.iter().map(|p| (p.id.as_str(), p)).collect();
+
+        let test_cases = findings
+            .into_iter()
+            .map(|f| {
+                let mut details = format!("{}:{}", f.file_path, f.line_number);
+                if let Some(cwe) = &f.cwe {
+                    let _ = write!(details, "\n{cwe}");
+                }
+                if let Some(remediation) = by_id
+                    .get(f.pattern_id.as_str())
+                    .and_then(|p| p.remediation.as_deref())
+                {
+                    let _ = write!(details, "\nRemediation: {remediation}");
+                }
+                JUnitTestCase {
+                    name: format!("{} at {}:{}", f.pattern_id, f.file_path, f.line_number),
+                    classname: f.file_path,
+                    message: f.description,
+                    failure_type: f.severity.as_str().to_string(),
+                    details,
+                }
+            })
+            .collect();
+
+        Self {
+            test_cases,
+            time_seconds: 0.0,
+        }
+    }
+
+    /// Records the scan duration.
+    #[must_use]
+    pub fn with_duration(mut self, duration: std::time::Duration) -> Self {
+        self.time_seconds = duration.as_secs_f64();
+        self
+    }
+
+    /// Serializes the report as `JUnit` XML.
+    #[must_use]
+    pub fn to_xml(&self) -> String {
+        let failures = self.test_cases.len();
+        let tests = failures.max(1);
+        let time = format!("{:.3}", self.time_seconds);
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"{SUITE_NAME}\" tests=\"{tests}\" failures=\"{failures}\" time=\"{time}\">"
+        );
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{SUITE_NAME}\" tests=\"{tests}\" failures=\"{failures}\" errors=\"0\" skipped=\"0\" time=\"{time}\">"
+        );
+
+        if self.test_cases.is_empty() {
+            let _ = writeln!(
+                xml,
+                "    <testcase classname=\"{SUITE_NAME}\" name=\"no security findings\" time=\"0\"/>"
+            );
+        }
+        for case in &self.test_cases {
+            let _ = writeln!(
+                xml,
+                "    <testcase classname=\"{}\" name=\"{}\" time=\"0\">",
+                escape_xml(&case.classname),
+                escape_xml(&case.name)
+            );
+            let _ = writeln!(
+                xml,
+                "      <failure message=\"{}\" type=\"{}\">{}</failure>",
+                escape_xml(&case.message),
+                escape_xml(&case.failure_type),
+                escape_xml(&case.details)
+            );
+            let _ = writeln!(xml, "    </testcase>");
+        }
+
+        let _ = writeln!(xml, "  </testsuite>");
+        let _ = writeln!(xml, "</testsuites>");
+        xml
+    }
+}
+
+/// Escapes text for use in XML attribute values and element content.
+///
+/// Characters that are not allowed in XML 1.0 are dropped.
+fn escape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\n' => out.push_str("&#10;"),
+            '\t' | '\r' => out.push(c),
+            c if (c as u32) < 0x20 => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::types::{Confidence, Severity};
+
+    fn finding() -> Finding {
+        Finding {
+            pattern_id: "xss-innerhtml".to_string(),
+            description: "Assignment to innerHTML with <script> risk".to_string(),
+            severity: Severity::Medium,
+            confidence: Confidence::Medium,
+            file_path: "web/app.js".to_string(),
+            line_number: 3,
+            column: None,
+            matched_text: "innerHTML =".to_string(),
+            cwe: Some("CWE-79".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_findings_render_as_failures() {
+        let xml = JUnitReport::with_rules(vec![finding()], &[]).to_xml();
+
+        assert!(xml.contains("tests=\"1\" failures=\"1\""));
+        assert!(xml.contains("classname=\"web/app.js\""));
+        assert!(xml.contains("name=\"xss-innerhtml at web/app.js:3\""));
+        assert!(xml.contains("message=\"Assignment to innerHTML with &lt;script&gt; risk\""));
+        assert!(xml.contains("type=\"medium\""));
+        assert!(xml.contains("web/app.js:3&#10;CWE-79</failure>"));
+    }
+
+    #[test]
+    fn test_clean_scan_renders_passing_case() {
+        let xml = JUnitReport::default().to_xml();
+
+        assert!(xml.contains("tests=\"1\" failures=\"0\""));
+        assert!(xml.contains("name=\"no security findings\""));
+        assert!(!xml.contains("<failure"));
+    }
+
+    #[test]
+    fn test_escape_xml_drops_control_characters() {
+        assert_eq!(escape_xml("a\u{1}b&\"c\""), "ab&amp;&quot;c&quot;");
+    }
+}
@@ -4,22 +4,35 @@
 //!
 //! Provides pattern-based security scanning for pull requests and code changes.
 //! Uses regex patterns to detect common vulnerabilities like hardcoded secrets,
-//! SQL injection, XSS, and other OWASP/CWE issues.
+//! SQL injection, XSS, and other OWASP/CWE issues. Findings can be exported as
+//! SARIF, Code Climate JSON, GitLab SAST JSON, or `JUnit` XML.
 
 pub mod cache;
+pub mod codeclimate;
 pub mod detection;
+pub mod gitlab;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod history;
 pub mod ignore;
+pub mod junit;
 pub mod patterns;
 pub mod sarif;
 pub mod scanner;
 pub mod types;
 pub mod validator;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod walk;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub use cache::FindingCache;
 pub use cache::{CachedFinding, cache_key};
+pub use codeclimate::CodeClimateReport;
 pub use detection::needs_security_scan;
+pub use gitlab::GitLabSastReport;
+#[cfg(not(target_arch = "wasm32"))]
+pub use history::CommitFindings;
 pub use ignore::SecurityConfig;
+pub use junit::JUnitReport;
 pub use patterns::PatternEngine;
 pub use sarif::SarifReport;
 pub use scanner::SecurityScanner;
@@ -27,3 +40,5 @@ pub use types::{
     Confidence, Finding, PatternDefinition, Severity, ValidatedFinding, ValidationResult,
 };
 pub use validator::SecurityValidator;
+#[cfg(not(target_arch = "wasm32"))]
+pub use walk::{DirectoryScan, ScanOptions, ScanStats};
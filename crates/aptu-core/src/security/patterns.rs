@@ -97,6 +97,7 @@ impl PatternEngine {
                         confidence: compiled.definition.confidence,
                         file_path: file_path.to_string(),
                         line_number: line_num + 1,
+                        column: Some(line[..mat.start()].chars().count() + 1),
                         matched_text: mat.as_str().to_string(),
                         cwe: compiled.definition.cwe.clone(),
                     });
@@ -163,6 +164,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_finding_column_counts_code_points() {
+        let engine = PatternEngine::global();
+        let code = r#"/* é */ let api_key = "sk-1234567890abcdefghijklmnopqrstuvwxyz";"#;
+
+        let findings = engine.scan(code, "test.rs");
+        let finding = findings
+            .iter()
+            .find(|f| f.pattern_id == "hardcoded-api-key")
+            .expect("Should detect API key");
+        assert_eq!(finding.column, Some(13));
+    }
+
     #[test]
     fn test_sql_injection_detection() {
         let engine = PatternEngine::global();
@@ -368,6 +382,7 @@ mod tests {
             confidence: Confidence::High,
             file_path: "src/config.rs".to_string(),
             line_number: 1,
+            column: None,
             matched_text: "api_key = \"sk-abc\"".to_string(),
             cwe: Some("CWE-798".to_string()),
         };
@@ -4,6 +4,7 @@
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 /// Severity level of a security finding.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
@@ -63,6 +64,9 @@ pub struct Finding {
     pub file_path: String,
     /// Line number in the file (1-indexed).
     pub line_number: usize,
+    /// Column of the first matched character (1-indexed, in Unicode code points).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
     /// The matched code snippet.
     pub matched_text: String,
     /// Optional CWE identifier (e.g., "CWE-798").
@@ -70,6 +74,20 @@ pub struct Finding {
     pub cwe: Option<String>,
 }
 
+impl Finding {
+    /// Stable fingerprint for deduplication across report formats.
+    ///
+    /// Hex-encoded SHA-256 of `file_path:line_number:pattern_id`.
+    #[must_use]
+    pub fn fingerprint(&self) -> String {
+        let input = format!(
+            "{}:{}:{}",
+            self.file_path, self.line_number, self.pattern_id
+        );
+        hex::encode(Sha256::digest(input.as_bytes()))
+    }
+}
+
 /// Pattern definition for security scanning.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PatternDefinition {
@@ -138,6 +156,7 @@ mod tests {
             confidence: Confidence::High,
             file_path: "src/config.rs".to_string(),
             line_number: 42,
+            column: None,
             matched_text: "api_key = \"sk-1234567890\"".to_string(),
             cwe: Some("CWE-798".to_string()),
         };
@@ -327,6 +327,7 @@ mod tests {
             confidence: Confidence::High,
             file_path: "test.rs".to_string(),
             line_number: 1,
+            column: None,
             matched_text: "test".to_string(),
             cwe: None,
         };
@@ -345,6 +346,7 @@ mod tests {
             confidence: Confidence::Low,
             file_path: "test.rs".to_string(),
             line_number: 1,
+            column: None,
             matched_text: "test".to_string(),
             cwe: None,
         };
@@ -0,0 +1,324 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Parallel directory scanning.
+//!
+//! Walks a file or directory tree honouring `.gitignore` and `.aptuignore`,
+//! skips binary and oversized files, and scans the remaining files in
+//! parallel with the pattern engine.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use ignore::WalkBuilder;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::scanner::SecurityScanner;
+use super::types::Finding;
+
+/// Default maximum size of a file to scan (1 MiB).
+pub const DEFAULT_MAX_FILE_SIZE: u64 = 1_048_576;
+
+/// Aptu-specific ignore file, using `.gitignore` syntax.
+pub const APTU_IGNORE_FILE: &str = ".aptuignore";
+
+/// Number of leading bytes inspected for NUL bytes when detecting binaries.
+const BINARY_SNIFF_LEN: usize = 8192;
+
+/// Options for [`SecurityScanner::scan_path`].
+#[derive(Debug, Clone)]
+pub struct ScanOptions {
+    /// Files larger than this many bytes are skipped.
+    pub max_file_size: u64,
+    /// Honour `.gitignore`, `.ignore`, and git exclude files.
+    /// `.aptuignore` is always honoured.
+    pub respect_gitignore: bool,
+    /// Path prefixes to exclude (matched against the walked path string).
+    pub exclude: Vec<String>,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self {
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
+            respect_gitignore: true,
+            exclude: Vec::new(),
+        }
+    }
+}
+
+/// Counters and timing for a directory scan.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScanStats {
+    /// Files whose content was scanned.
+    pub files_scanned: usize,
+    /// Files skipped because they look binary or are not valid UTF-8.
+    pub files_skipped_binary: usize,
+    /// Files skipped because they exceed `max_file_size`.
+    pub files_skipped_size: usize,
+    /// Files skipped because they could not be read.
+    pub files_skipped_unreadable: usize,
+    /// Total bytes of scanned content.
+    pub bytes_scanned: u64,
+    /// Wall-clock duration of the walk and scan.
+    #[serde(with = "duration_millis")]
+    pub elapsed: Duration,
+}
+
+/// Result of scanning a file or directory tree.
+#[derive(Debug, Clone, Default)]
+pub struct DirectoryScan {
+    /// Findings, ordered by file path then line.
+    pub findings: Vec<Finding>,
+    /// Scan statistics.
+    pub stats: ScanStats,
+}
+
+/// Outcome of scanning a single file.
+enum FileOutcome {
+    Scanned { findings: Vec<Finding>, bytes: u64 },
+    Binary,
+    TooLarge,
+    Unreadable,
+}
+
+impl SecurityScanner {
+    /// Scans a file or directory tree in parallel.
+    ///
+    /// Files are discovered with a gitignore-aware walker (hidden files are
+    /// included, `.git` is skipped), then read and scanned on the rayon
+    /// thread pool. Each file goes through [`SecurityScanner::scan_file`], so
+    /// configured ignore paths and patterns still apply.
+    #[must_use]
+    pub fn scan_path(&self, root: &Path, options: &ScanOptions) -> DirectoryScan {
+        let started = Instant::now();
+
+        let mut builder = WalkBuilder::new(root);
+        builder
+            .hidden(false)
+            .follow_links(false)
+            .require_git(false)
+            .git_ignore(options.respect_gitignore)
+            .git_exclude(options.respect_gitignore)
+            .git_global(options.respect_gitignore)
+            .ignore(options.respect_gitignore)
+            .parents(options.respect_gitignore)
+            .add_custom_ignore_filename(APTU_IGNORE_FILE)
+            .sort_by_file_path(Ord::cmp)
+            .filter_entry(|entry| entry.file_name() != ".git");
+
+        let files: Vec<_> = builder
+            .build()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+            .map(ignore::DirEntry::into_path)
+            .filter(|path| {
+                let path_str = path.to_string_lossy();
+                !options
+                    .exclude
+                    .iter()
+                    .any(|prefix| path_str.starts_with(prefix.as_str()))
+            })
+            .collect();
+
+        let outcomes: Vec<FileOutcome> = files
+            .par_iter()
+            .map(|path| self.scan_one(path, options.max_file_size))
+            .collect();
+
+        let mut scan = DirectoryScan::default();
+        for outcome in outcomes {
+            match outcome {
+                FileOutcome::Scanned { findings, bytes } => {
+                    scan.stats.files_scanned += 1;
+                    scan.stats.bytes_scanned += bytes;
+                    scan.findings.extend(findings);
+                }
+                FileOutcome::Binary => scan.stats.files_skipped_binary += 1,
+                FileOutcome::TooLarge => scan.stats.files_skipped_size += 1,
+                FileOutcome::Unreadable => scan.stats.files_skipped_unreadable += 1,
+            }
+        }
+        scan.stats.elapsed = started.elapsed();
+
+        tracing::debug!(
+            files_scanned = scan.stats.files_scanned,
+            skipped_binary = scan.stats.files_skipped_binary,
+            skipped_size = scan.stats.files_skipped_size,
+            elapsed_ms = scan.stats.elapsed.as_millis(),
+            "Directory scan complete"
+        );
+
+        scan
+    }
+
+    fn scan_one(&self, path: &Path, max_file_size: u64) -> FileOutcome {
+        let Ok(file) = File::open(path) else {
+            return FileOutcome::Unreadable;
+        };
+        match file.metadata() {
+            Ok(meta) if meta.len() > max_file_size => return FileOutcome::TooLarge,
+            Ok(_) => {}
+            Err(_) => return FileOutcome::Unreadable,
+        }
+
+        // Read at most one byte past the limit in case the file grew after stat.
+        let mut bytes = Vec::new();
+        if file
+            .take(max_file_size.saturating_add(1))
+            .read_to_end(&mut bytes)
+            .is_err()
+        {
+            return FileOutcome::Unreadable;
+        }
+        if bytes.len() as u64 > max_file_size {
+            return FileOutcome::TooLarge;
+        }
+        if is_binary(&bytes) {
+            return FileOutcome::Binary;
+        }
+        let Ok(content) = String::from_utf8(bytes) else {
+            return FileOutcome::Binary;
+        };
+
+        let path_str = path.to_string_lossy();
+        FileOutcome::Scanned {
+            bytes: content.len() as u64,
+            findings: self.scan_file(&content, &path_str),
+        }
+    }
+}
+
+/// Returns `true` if the leading bytes contain a NUL byte.
+fn is_binary(bytes: &[u8]) -> bool {
+    bytes[..bytes.len().min(BINARY_SNIFF_LEN)].contains(&0)
+}
+
+/// Serializes a [`Duration`] as integer milliseconds.
+mod duration_millis {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_u64(u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Duration, D::Error> {
+        u64::deserialize(d).map(Duration::from_millis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::ignore::SecurityConfig;
+
+    const SECRET: &str = "let api_key = \"sk-1234567890abcdefghijklmnopqrstuvwxyz\";\n";
+
+    fn scanner() -> SecurityScanner {
+        SecurityScanner::with_config(SecurityConfig::empty())
+    }
+
+    #[test]
+    fn test_scan_path_finds_secrets_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("src/b.rs"), SECRET).unwrap();
+        std::fs::write(dir.path().join("src/a.rs"), SECRET).unwrap();
+        std::fs::write(dir.path().join("src/clean.rs"), "fn main() {}\n").unwrap();
+
+        let scan = scanner().scan_path(dir.path(), &ScanOptions::default());
+
+        assert_eq!(scan.stats.files_scanned, 3);
+        assert_eq!(scan.findings.len(), 2);
+        assert!(scan.findings[0].file_path.ends_with("a.rs"));
+        assert!(scan.findings[1].file_path.ends_with("b.rs"));
+    }
+
+    #[test]
+    fn test_scan_path_honours_gitignore_and_aptuignore() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(".gitignore"), "generated.rs\n").unwrap();
+        std::fs::write(dir.path().join(APTU_IGNORE_FILE), "fixtures/\n").unwrap();
+        std::fs::create_dir(dir.path().join("fixtures")).unwrap();
+        std::fs::write(dir.path().join("generated.rs"), SECRET).unwrap();
+        std::fs::write(dir.path().join("fixtures/key.rs"), SECRET).unwrap();
+        std::fs::write(dir.path().join("kept.rs"), SECRET).unwrap();
+
+        let scan = scanner().scan_path(dir.path(), &ScanOptions::default());
+        assert_eq!(scan.findings.len(), 1);
+        assert!(scan.findings[0].file_path.ends_with("kept.rs"));
+
+        let options = ScanOptions {
+            respect_gitignore: false,
+            ..ScanOptions::default()
+        };
+        let scan = scanner().scan_path(dir.path(), &options);
+        assert_eq!(scan.findings.len(), 2, ".aptuignore applies regardless");
+    }
+
+    #[test]
+    fn test_scan_path_includes_hidden_files_but_not_git_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join(".git")).unwrap();
+        std::fs::write(dir.path().join(".git/config"), SECRET).unwrap();
+        std::fs::write(dir.path().join(".env.rs"), SECRET).unwrap();
+
+        let scan = scanner().scan_path(dir.path(), &ScanOptions::default());
+
+        assert_eq!(scan.findings.len(), 1);
+        assert!(scan.findings[0].file_path.ends_with(".env.rs"));
+    }
+
+    #[test]
+    fn test_scan_path_skips_binary_and_oversized_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("blob.bin"), b"key\0sk-1234").unwrap();
+        std::fs::write(dir.path().join("big.rs"), SECRET.repeat(10)).unwrap();
+        std::fs::write(dir.path().join("small.rs"), SECRET).unwrap();
+
+        let options = ScanOptions {
+            max_file_size: SECRET.len() as u64,
+            ..ScanOptions::default()
+        };
+        let scan = scanner().scan_path(dir.path(), &options);
+
+        assert_eq!(scan.stats.files_scanned, 1);
+        assert_eq!(scan.stats.files_skipped_binary, 1);
+        assert_eq!(scan.stats.files_skipped_size, 1);
+        assert_eq!(scan.stats.bytes_scanned, SECRET.len() as u64);
+        assert_eq!(scan.findings.len(), 1);
+    }
+
+    #[test]
+    fn test_scan_path_applies_exclude_prefixes() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("skip")).unwrap();
+        std::fs::write(dir.path().join("skip/key.rs"), SECRET).unwrap();
+        std::fs::write(dir.path().join("key.rs"), SECRET).unwrap();
+
+        let options = ScanOptions {
+            exclude: vec![dir.path().join("skip").to_string_lossy().into_owned()],
+            ..ScanOptions::default()
+        };
+        let scan = scanner().scan_path(dir.path(), &options);
+
+        assert_eq!(scan.stats.files_scanned, 1);
+        assert_eq!(scan.findings.len(), 1);
+    }
+
+    #[test]
+    fn test_scan_path_accepts_single_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("key.rs");
+        std::fs::write(&file, SECRET).unwrap();
+
+        let scan = scanner().scan_path(&file, &ScanOptions::default());
+
+        assert_eq!(scan.stats.files_scanned, 1);
+        assert_eq!(scan.findings.len(), 1);
+    }
+}
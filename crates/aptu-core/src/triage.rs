@@ -3,16 +3,41 @@
 //! Triage status detection for GitHub issues.
 //!
 //! This module provides utilities to check whether an issue has already been triaged,
-//! either through labels or Aptu-generated comments.
+//! either through labels or Aptu-generated comments, and whether it changed enough
+//! since the last Aptu triage to warrant another pass.
 
-use crate::ai::types::{IssueDetails, PrReviewComment, PrReviewResponse, TriageResponse};
+use crate::ai::types::{
+    IssueComment, IssueDetails, PrReviewComment, PrReviewResponse, PriorTriage, TriageResponse,
+};
 use crate::utils::is_priority_label;
-use std::fmt::Write;
+use chrono::{DateTime, FixedOffset};
+use std::fmt::{self, Write};
 use tracing::debug;
 
 /// Signature string used to identify Aptu-generated triage comments
 pub const APTU_SIGNATURE: &str = "Generated by Aptu";
 
+/// Heading that opens every rendered triage comment.
+const TRIAGE_HEADING: &str = "## Triage Summary";
+
+/// Why an issue with an Aptu triage comment should be triaged again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetriageReason {
+    /// The issue body was edited after the triage comment.
+    BodyEdited,
+    /// The issue author commented after the triage comment.
+    AuthorReplied,
+}
+
+impl fmt::Display for RetriageReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BodyEdited => write!(f, "issue was edited"),
+            Self::AuthorReplied => write!(f, "author replied"),
+        }
+    }
+}
+
 /// Status of whether an issue has already been triaged
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TriageStatus {
@@ -24,6 +49,8 @@ pub struct TriageStatus {
     pub has_aptu_comment: bool,
     /// List of labels that indicate triage status
     pub label_names: Vec<String>,
+    /// Set when the issue changed after the latest Aptu triage comment.
+    pub retriage: Option<RetriageReason>,
 }
 
 impl TriageStatus {
@@ -40,13 +67,16 @@ impl TriageStatus {
             has_priority_label,
             has_aptu_comment,
             label_names,
+            retriage: None,
         }
     }
 
-    /// Check if the issue has been triaged (has both type and priority labels, or Aptu comment).
+    /// Check if the issue has been triaged (has both type and priority labels, or Aptu comment)
+    /// and has not changed since.
     #[must_use]
     pub fn is_triaged(&self) -> bool {
-        (self.has_type_label && self.has_priority_label) || self.has_aptu_comment
+        ((self.has_type_label && self.has_priority_label) || self.has_aptu_comment)
+            && self.retriage.is_none()
     }
 }
 
@@ -148,6 +178,7 @@ pub fn render_triage_markdown(triage: &TriageResponse) -> String {
     let labels: Vec<String> = triage
         .suggested_labels
         .iter()
+        .chain(&triage.forced_labels)
         .map(|l| format!("`{l}`"))
         .collect();
     output.push_str(&render_list_section_markdown(
@@ -178,6 +209,15 @@ pub fn render_triage_markdown(triage: &TriageResponse) -> String {
     // Complexity assessment
     render_complexity_markdown(&mut output, triage);
 
+    // Suggested assignees (plain logins, so posting the triage pings nobody)
+    if !triage.suggested_assignees.is_empty() {
+        output.push_str("### Suggested Assignees\n\n");
+        for assignee in &triage.suggested_assignees {
+            let _ = writeln!(output, "- **{}** - {}", assignee.login, assignee.reason);
+        }
+        output.push('\n');
+    }
+
     // Questions
     output.push_str(&render_list_section_markdown(
         "Clarifying Questions",
@@ -250,7 +290,8 @@ pub fn render_triage_markdown(triage: &TriageResponse) -> String {
 /// Check if an issue has already been triaged.
 ///
 /// Returns `TriageStatus` indicating whether the issue has both type and priority labels,
-/// or has an Aptu-generated comment.
+/// or has an Aptu-generated comment. If the body was edited or the author replied after
+/// the latest Aptu triage comment, `retriage` says why the issue needs another pass.
 pub fn check_already_triaged(issue: &IssueDetails) -> TriageStatus {
     let has_type_label = issue.labels.iter().any(|label| is_type_label(label));
     let has_priority_label = issue.labels.iter().any(|label| is_priority_label(label));
@@ -263,8 +304,7 @@ pub fn check_already_triaged(issue: &IssueDetails) -> TriageStatus {
         .collect();
 
     // Check for Aptu signature in comments
-    let has_aptu_comment = issue
-        .comments
+    let has_aptu_comment = all_comments(issue)
         .iter()
         .any(|comment| comment.body.contains(APTU_SIGNATURE));
 
@@ -278,12 +318,64 @@ pub fn check_already_triaged(issue: &IssueDetails) -> TriageStatus {
         );
     }
 
-    TriageStatus::new(
+    let mut status = TriageStatus::new(
         has_type_label,
         has_priority_label,
         has_aptu_comment,
         label_names,
-    )
+    );
+    status.retriage = latest_triage_comment(issue).and_then(|c| retriage_reason(issue, c));
+    if let Some(reason) = status.retriage {
+        debug!(reason = %reason, "Issue changed since last triage");
+    }
+    status
+}
+
+/// Returns the latest Aptu triage comment on an issue, for use as
+/// [`IssueDetails::prior_triage`] when re-triaging.
+#[must_use]
+pub fn prior_triage(issue: &IssueDetails) -> Option<PriorTriage> {
+    latest_triage_comment(issue).map(|c| PriorTriage {
+        comment_id: c.id,
+        body: c.body.clone(),
+    })
+}
+
+/// Every comment on the issue when the full history was fetched, otherwise
+/// the comments in the prompt window.
+fn all_comments(issue: &IssueDetails) -> &[IssueComment] {
+    issue.comment_history.as_deref().unwrap_or(&issue.comments)
+}
+
+/// Finds the most recent comment rendered by [`render_triage_markdown`].
+fn latest_triage_comment(issue: &IssueDetails) -> Option<&IssueComment> {
+    all_comments(issue)
+        .iter()
+        .rev()
+        .find(|c| c.body.contains(APTU_SIGNATURE) && c.body.contains(TRIAGE_HEADING))
+}
+
+/// Compares the issue's edit time and the author's comments against the
+/// triage comment's last update (it is edited in place on re-triage).
+fn retriage_reason(issue: &IssueDetails, triage: &IssueComment) -> Option<RetriageReason> {
+    let parse =
+        |ts: Option<&str>| ts.and_then(|t| DateTime::<FixedOffset>::parse_from_rfc3339(t).ok());
+    let triaged_at =
+        parse(triage.updated_at.as_deref()).or_else(|| parse(triage.created_at.as_deref()))?;
+
+    if parse(issue.last_edited_at.as_deref()).is_some_and(|edited| edited > triaged_at) {
+        return Some(RetriageReason::BodyEdited);
+    }
+
+    let author = issue.author.as_deref().filter(|a| !a.is_empty())?;
+    all_comments(issue)
+        .iter()
+        .any(|c| {
+            c.author == author
+                && !c.body.contains(APTU_SIGNATURE)
+                && parse(c.created_at.as_deref()).is_some_and(|created| created > triaged_at)
+        })
+        .then_some(RetriageReason::AuthorReplied)
 }
 
 /// Formats an inline PR review comment body.
@@ -421,6 +513,8 @@ mod tests {
             id: 1,
             author: "aptu-bot".to_string(),
             body: "This looks good. Generated by Aptu".to_string(),
+            created_at: None,
+            updated_at: None,
         }];
         let issue = create_test_issue(vec![], comments);
         let status = check_already_triaged(&issue);
@@ -438,6 +532,8 @@ mod tests {
             id: 2,
             author: "aptu-bot".to_string(),
             body: "Generated by Aptu".to_string(),
+            created_at: None,
+            updated_at: None,
         }];
         let issue = create_test_issue(labels, comments);
         let status = check_already_triaged(&issue);
@@ -453,6 +549,8 @@ mod tests {
             id: 3,
             author: "other-bot".to_string(),
             body: "Generated by AnotherTool".to_string(),
+            created_at: None,
+            updated_at: None,
         }];
         let issue = create_test_issue(vec![], comments);
         let status = check_already_triaged(&issue);
@@ -460,6 +558,88 @@ mod tests {
         assert!(!status.has_aptu_comment);
     }
 
+    fn triage_comment(updated_at: &str) -> IssueComment {
+        IssueComment {
+            id: 10,
+            author: "maintainer".to_string(),
+            body: format!("## Triage Summary\n\nCrash on start.\n\n---\n*{APTU_SIGNATURE}*\n"),
+            created_at: Some("2025-01-01T00:00:00Z".to_string()),
+            updated_at: Some(updated_at.to_string()),
+        }
+    }
+
+    fn author_comment(created_at: &str) -> IssueComment {
+        IssueComment {
+            id: 11,
+            author: "reporter".to_string(),
+            body: "It happens on 2.0 too.".to_string(),
+            created_at: Some(created_at.to_string()),
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_retriage_when_author_replies_after_triage() {
+        let mut issue = create_test_issue(
+            vec![],
+            vec![
+                triage_comment("2025-01-02T00:00:00Z"),
+                author_comment("2025-01-03T00:00:00Z"),
+            ],
+        );
+        issue.author = Some("reporter".to_string());
+
+        let status = check_already_triaged(&issue);
+        assert!(status.has_aptu_comment);
+        assert_eq!(status.retriage, Some(RetriageReason::AuthorReplied));
+        assert!(!status.is_triaged());
+        assert_eq!(prior_triage(&issue).map(|p| p.comment_id), Some(10));
+    }
+
+    #[test]
+    fn test_triage_found_in_comment_history_outside_prompt_window() {
+        let mut issue = create_test_issue(vec![], vec![author_comment("2025-01-01T00:00:00Z")]);
+        issue.author = Some("reporter".to_string());
+        issue.comment_history = Some(vec![
+            author_comment("2025-01-01T00:00:00Z"),
+            triage_comment("2025-01-02T00:00:00Z"),
+            author_comment("2025-01-03T00:00:00Z"),
+        ]);
+
+        let status = check_already_triaged(&issue);
+        assert!(status.has_aptu_comment);
+        assert_eq!(status.retriage, Some(RetriageReason::AuthorReplied));
+        assert_eq!(prior_triage(&issue).map(|p| p.comment_id), Some(10));
+    }
+
+    #[test]
+    fn test_retriage_when_body_edited_after_triage() {
+        let mut issue = create_test_issue(vec![], vec![triage_comment("2025-01-02T00:00:00Z")]);
+        issue.last_edited_at = Some("2025-01-05T00:00:00Z".to_string());
+
+        let status = check_already_triaged(&issue);
+        assert_eq!(status.retriage, Some(RetriageReason::BodyEdited));
+        assert!(!status.is_triaged());
+    }
+
+    #[test]
+    fn test_no_retriage_when_changes_predate_updated_triage() {
+        // The triage comment was edited in place after the reply and the edit
+        let mut issue = create_test_issue(
+            vec![],
+            vec![
+                author_comment("2025-01-03T00:00:00Z"),
+                triage_comment("2025-01-04T00:00:00Z"),
+            ],
+        );
+        issue.author = Some("reporter".to_string());
+        issue.last_edited_at = Some("2025-01-03T12:00:00Z".to_string());
+
+        let status = check_already_triaged(&issue);
+        assert_eq!(status.retriage, None);
+        assert!(status.is_triaged());
+    }
+
     #[test]
     fn test_irrelevant_labels() {
         let labels = vec!["component: ui".to_string(), "needs-review".to_string()];
@@ -502,6 +682,9 @@ mod tests {
             status_note: None,
             contributor_guidance: None,
             complexity: None,
+            suggested_assignees: vec![],
+            forced_labels: vec![],
+            applied_rules: vec![],
         };
 
         let markdown = render_triage_markdown(&triage);
@@ -525,6 +708,9 @@ mod tests {
             status_note: None,
             contributor_guidance: None,
             complexity: None,
+            suggested_assignees: vec![],
+            forced_labels: vec![],
+            applied_rules: vec![],
         };
 
         let markdown = render_triage_markdown(&triage);
@@ -546,6 +732,9 @@ mod tests {
             status_note: None,
             contributor_guidance: None,
             complexity: None,
+            suggested_assignees: vec![],
+            forced_labels: vec![],
+            applied_rules: vec![],
         };
 
         let markdown = render_triage_markdown(&triage);
@@ -720,6 +909,9 @@ mod tests {
                 affected_areas: vec!["crates/aptu-core/src/retry.rs".to_string()],
                 recommendation: Some("Decompose into sub-issues".to_string()),
             }),
+            suggested_assignees: vec![],
+            forced_labels: vec![],
+            applied_rules: vec![],
         };
 
         // Act
@@ -743,4 +935,24 @@ mod tests {
             "markdown must contain recommendation"
         );
     }
+
+    #[test]
+    fn test_render_triage_markdown_suggested_assignees_without_mentions() {
+        use crate::ai::types::SuggestedAssignee;
+
+        let triage = TriageResponse {
+            summary: "Retry bug".to_string(),
+            suggested_assignees: vec![SuggestedAssignee {
+                login: "alice".to_string(),
+                reason: "code owner of `crates/aptu-core/src/retry.rs`".to_string(),
+            }],
+            ..Default::default()
+        };
+
+        let markdown = render_triage_markdown(&triage);
+
+        assert!(markdown.contains("### Suggested Assignees"));
+        assert!(markdown.contains("- **alice** - code owner of `crates/aptu-core/src/retry.rs`"));
+        assert!(!markdown.contains("@alice"));
+    }
 }
@@ -0,0 +1,512 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Declarative triage rules applied before and after the model.
+//!
+//! Rules live in `~/.config/aptu/triage-rules.toml` as `[[rule]]` tables.
+//! Each rule has conditions, all of which must hold for it to match:
+//!
+//! - `repos`: `owner/repo` names the rule is limited to
+//! - `title` / `body`: regexes matched against the issue text
+//! - `author_association`: e.g. `["NONE", "FIRST_TIME_CONTRIBUTOR"]`
+//! - `paths`: CODEOWNERS-style patterns matched against file paths mentioned
+//!   in the body
+//! - `suggested_labels`: labels the model suggested (matched after the model
+//!   runs, so such rules cannot skip it)
+//!
+//! and actions:
+//!
+//! - `add_labels`: force labels, including maintainer-only ones
+//! - `forbid_labels`: never apply these labels, even when forced
+//! - `map_labels`: rename model-suggested labels (`{ "bug" = "type: bug" }`)
+//! - `milestone`, `forbid_milestones`, `map_milestones`: the same for milestones
+//! - `skip_ai`: triage from the rules alone, with `summary` as the comment text
+//!
+//! Rules run in file order; when several set a milestone the last one wins.
+
+use std::collections::BTreeMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+
+use regex::Regex;
+use serde::Deserialize;
+#[cfg(not(target_arch = "wasm32"))]
+use tracing::{debug, instrument};
+
+use crate::ai::types::{IssueDetails, TriageResponse};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::config::config_dir;
+use crate::error::AptuError;
+use crate::github::owners::pattern_regex;
+
+/// Summary used for `skip_ai` rules that do not set one.
+const DEFAULT_RULE_SUMMARY: &str = "Triaged by repository rules.";
+
+/// Returns the path to the triage rules file.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn rules_file_path() -> PathBuf {
+    config_dir().join("triage-rules.toml")
+}
+
+/// A set of triage rules, in file order.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TriageRules {
+    /// The rules, from `[[rule]]` tables.
+    #[serde(default, rename = "rule")]
+    pub rules: Vec<TriageRule>,
+}
+
+/// A single triage rule: conditions plus the actions taken when they all hold.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TriageRule {
+    /// Rule name, shown in output and the triage comment.
+    pub name: String,
+    /// Repositories (`owner/repo`) the rule applies to; empty means all.
+    pub repos: Vec<String>,
+    /// Regex the issue title must match.
+    pub title: Option<String>,
+    /// Regex the issue body must match.
+    pub body: Option<String>,
+    /// Author associations, one of which the author must have.
+    pub author_association: Vec<String>,
+    /// Path patterns, one of which a path mentioned in the body must match.
+    pub paths: Vec<String>,
+    /// Labels, one of which the model must have suggested.
+    pub suggested_labels: Vec<String>,
+    /// Labels to apply regardless of the model.
+    pub add_labels: Vec<String>,
+    /// Labels never to apply.
+    pub forbid_labels: Vec<String>,
+    /// Renames for model-suggested labels.
+    pub map_labels: BTreeMap<String, String>,
+    /// Milestone to set regardless of the model.
+    pub milestone: Option<String>,
+    /// Milestones never to set.
+    pub forbid_milestones: Vec<String>,
+    /// Renames for the model-suggested milestone.
+    pub map_milestones: BTreeMap<String, String>,
+    /// Skip the AI call and triage from the rules alone.
+    pub skip_ai: bool,
+    /// Summary posted when `skip_ai` triages the issue.
+    pub summary: Option<String>,
+    #[serde(skip)]
+    title_re: Option<Regex>,
+    #[serde(skip)]
+    body_re: Option<Regex>,
+    #[serde(skip)]
+    path_res: Vec<Regex>,
+}
+
+impl TriageRules {
+    /// Parses and validates rules from TOML.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the TOML is invalid, a rule has no name, a regex or
+    /// path pattern does not compile, or a `skip_ai` rule depends on
+    /// `suggested_labels`.
+    pub fn parse(content: &str) -> crate::Result<Self> {
+        let mut rules: Self = toml::from_str(content).map_err(|e| AptuError::Config {
+            message: format!("Failed to parse triage rules TOML: {e}"),
+        })?;
+        for rule in &mut rules.rules {
+            rule.compile()?;
+        }
+        Ok(rules)
+    }
+
+    /// Reads rules from [`rules_file_path`].
+    ///
+    /// Returns an empty rule set if the file does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read or parsed.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[instrument]
+    pub fn load() -> crate::Result<Self> {
+        let path = rules_file_path();
+        if !path.exists() {
+            debug!("Triage rules file does not exist: {:?}", path);
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path).map_err(|e| AptuError::Config {
+            message: format!("Failed to read triage rules file: {e}"),
+        })?;
+        let rules = Self::parse(&content)?;
+        debug!("Read {} triage rules", rules.rules.len());
+        Ok(rules)
+    }
+
+    /// Returns `true` if there are no rules.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns a rules-only triage if a matching rule skips the AI call.
+    ///
+    /// The returned triage still needs [`TriageRules::apply`] to fill in
+    /// labels and milestone.
+    #[must_use]
+    pub fn skip_ai_triage(&self, issue: &IssueDetails) -> Option<TriageResponse> {
+        let paths = mentioned_paths(&issue.body);
+        let rule = self
+            .rules
+            .iter()
+            .find(|r| r.skip_ai && r.matches(issue, &paths, None))?;
+        Some(TriageResponse {
+            summary: rule
+                .summary
+                .clone()
+                .unwrap_or_else(|| DEFAULT_RULE_SUMMARY.to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// Applies matching rules to a triage, in place.
+    ///
+    /// Maps, forbids, and forces labels and milestone, and records the names of
+    /// the rules that matched in `applied_rules`. Forced labels go into
+    /// `forced_labels` so they bypass the maintainer-only and priority checks
+    /// when applied; forbidden labels are dropped from both lists.
+    pub fn apply(&self, issue: &IssueDetails, triage: &mut TriageResponse) {
+        let paths = mentioned_paths(&issue.body);
+        let matched: Vec<&TriageRule> = self
+            .rules
+            .iter()
+            .filter(|r| r.matches(issue, &paths, Some(&triage.suggested_labels)))
+            .collect();
+        if matched.is_empty() {
+            return;
+        }
+
+        for rule in &matched {
+            for label in &mut triage.suggested_labels {
+                if let Some(to) = lookup(&rule.map_labels, label) {
+                    label.clone_from(to);
+                }
+            }
+            if let Some(milestone) = triage.suggested_milestone.as_mut()
+                && let Some(to) = lookup(&rule.map_milestones, milestone)
+            {
+                milestone.clone_from(to);
+            }
+            for label in &rule.add_labels {
+                push_unique(&mut triage.forced_labels, label);
+            }
+            if let Some(milestone) = &rule.milestone {
+                triage.suggested_milestone = Some(milestone.clone());
+            }
+        }
+
+        let forbidden = |label: &String| {
+            matched
+                .iter()
+                .any(|r| contains_ignore_case(&r.forbid_labels, label))
+        };
+        triage.forced_labels.retain(|l| !forbidden(l));
+        let forced = triage.forced_labels.clone();
+        let mut suggested = Vec::new();
+        for label in &triage.suggested_labels {
+            if !forbidden(label) && !contains_ignore_case(&forced, label) {
+                push_unique(&mut suggested, label);
+            }
+        }
+        triage.suggested_labels = suggested;
+
+        if triage.suggested_milestone.as_ref().is_some_and(|m| {
+            matched
+                .iter()
+                .any(|r| contains_ignore_case(&r.forbid_milestones, m))
+        }) {
+            triage.suggested_milestone = None;
+        }
+
+        for rule in matched {
+            push_unique(&mut triage.applied_rules, &rule.name);
+        }
+    }
+}
+
+impl TriageRule {
+    /// Validates the rule and compiles its patterns.
+    fn compile(&mut self) -> crate::Result<()> {
+        let invalid = |message: String| AptuError::Config { message };
+        if self.name.trim().is_empty() {
+            return Err(invalid("Triage rule is missing a name".to_string()));
+        }
+        if self.skip_ai && !self.suggested_labels.is_empty() {
+            return Err(invalid(format!(
+                "Triage rule '{}' cannot skip the AI call and match on suggested_labels",
+                self.name
+            )));
+        }
+        let compile = |field: &str, pattern: &str| {
+            Regex::new(pattern).map_err(|e| {
+                invalid(format!(
+                    "Triage rule '{}' has an invalid {field} regex: {e}",
+                    self.name
+                ))
+            })
+        };
+        self.title_re = self
+            .title
+            .as_deref()
+            .map(|p| compile("title", p))
+            .transpose()?;
+        self.body_re = self
+            .body
+            .as_deref()
+            .map(|p| compile("body", p))
+            .transpose()?;
+        self.path_res = self
+            .paths
+            .iter()
+            .map(|p| {
+                pattern_regex(p).ok_or_else(|| {
+                    invalid(format!(
+                        "Triage rule '{}' has an invalid path pattern '{p}'",
+                        self.name
+                    ))
+                })
+            })
+            .collect::<crate::Result<_>>()?;
+        Ok(())
+    }
+
+    /// Returns `true` if every condition holds. Rules that match on
+    /// `suggested_labels` never match when `suggested` is `None`.
+    fn matches(
+        &self,
+        issue: &IssueDetails,
+        paths: &[String],
+        suggested: Option<&[String]>,
+    ) -> bool {
+        let full_name = format!("{}/{}", issue.owner, issue.repo);
+        (self.repos.is_empty() || contains_ignore_case(&self.repos, &full_name))
+            && self
+                .title_re
+                .as_ref()
+                .is_none_or(|re| re.is_match(&issue.title))
+            && self
+                .body_re
+                .as_ref()
+                .is_none_or(|re| re.is_match(&issue.body))
+            && (self.author_association.is_empty()
+                || issue
+                    .author_association
+                    .as_ref()
+                    .is_some_and(|a| contains_ignore_case(&self.author_association, a)))
+            && (self.path_res.is_empty()
+                || paths
+                    .iter()
+                    .any(|p| self.path_res.iter().any(|re| re.is_match(p))))
+            && (self.suggested_labels.is_empty()
+                || suggested.is_some_and(|labels| {
+                    labels
+                        .iter()
+                        .any(|l| contains_ignore_case(&self.suggested_labels, l))
+                }))
+    }
+}
+
+/// Extracts repository-relative file paths mentioned in an issue body, such
+/// as `src/main.rs`, `crates/core/` or `lib.rs:42`. URLs are ignored.
+#[must_use]
+pub fn mentioned_paths(body: &str) -> Vec<String> {
+    let mut paths = Vec::new();
+    for token in body.split(|c: char| c.is_whitespace() || "`'\"()[]<>,;".contains(c)) {
+        if token.contains("://") {
+            continue;
+        }
+        let token = token.split(':').next().unwrap_or_default();
+        let token = token.trim_end_matches('.').trim_start_matches("./");
+        let token = token.trim_start_matches('/');
+        let looks_like_path = token.contains('/')
+            || token.rsplit_once('.').is_some_and(|(stem, ext)| {
+                !stem.is_empty()
+                    && (1..=8).contains(&ext.len())
+                    && ext.chars().all(|c| c.is_ascii_alphanumeric())
+                    && ext.chars().any(|c| c.is_ascii_alphabetic())
+            });
+        if looks_like_path
+            && token
+                .chars()
+                .all(|c| c.is_alphanumeric() || "/._-".contains(c))
+        {
+            push_unique(&mut paths, token);
+        }
+    }
+    paths
+}
+
+/// Looks up `key` in `map`, ignoring case.
+fn lookup<'a>(map: &'a BTreeMap<String, String>, key: &str) -> Option<&'a String> {
+    map.iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(key))
+        .map(|(_, v)| v)
+}
+
+fn contains_ignore_case(list: &[String], value: &str) -> bool {
+    list.iter().any(|l| l.eq_ignore_ascii_case(value))
+}
+
+fn push_unique(list: &mut Vec<String>, value: &str) {
+    if !contains_ignore_case(list, value) {
+        list.push(value.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(title: &str, body: &str, association: Option<&str>) -> IssueDetails {
+        let mut issue = IssueDetails::builder()
+            .owner("block".to_string())
+            .repo("goose".to_string())
+            .number(1)
+            .title(title.to_string())
+            .body(body.to_string())
+            .url("https://github.com/block/goose/issues/1".to_string())
+            .build();
+        issue.author_association = association.map(str::to_string);
+        issue
+    }
+
+    fn triage(labels: &[&str], milestone: Option<&str>) -> TriageResponse {
+        TriageResponse {
+            suggested_labels: labels.iter().map(|l| (*l).to_string()).collect(),
+            suggested_milestone: milestone.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parse_rejects_invalid_rules() {
+        assert!(TriageRules::parse("[[rule]]\ntitle = \"x\"").is_err());
+        assert!(TriageRules::parse("[[rule]]\nname = \"r\"\ntitle = \"(\"").is_err());
+        assert!(
+            TriageRules::parse(
+                "[[rule]]\nname = \"r\"\nskip_ai = true\nsuggested_labels = [\"bug\"]"
+            )
+            .is_err()
+        );
+        assert!(TriageRules::parse("[[rule]]\nname = \"r\"\ntypo = 1").is_err());
+        assert!(TriageRules::parse("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn skip_ai_rule_matches_title_and_association() {
+        let rules = TriageRules::parse(
+            r#"
+            [[rule]]
+            name = "dependency bumps"
+            title = "^Bump "
+            author_association = ["none"]
+            skip_ai = true
+            summary = "Automated dependency update."
+            add_labels = ["dependencies"]
+            "#,
+        )
+        .unwrap();
+
+        let bump = issue("Bump serde from 1.0 to 1.1", "", Some("NONE"));
+        let mut triage = rules.skip_ai_triage(&bump).expect("rule should skip AI");
+        assert_eq!(triage.summary, "Automated dependency update.");
+        rules.apply(&bump, &mut triage);
+        assert_eq!(triage.forced_labels, vec!["dependencies"]);
+        assert_eq!(triage.applied_rules, vec!["dependency bumps"]);
+
+        let member = issue("Bump serde from 1.0 to 1.1", "", Some("MEMBER"));
+        assert!(rules.skip_ai_triage(&member).is_none());
+        assert!(
+            rules
+                .skip_ai_triage(&issue("Crash on start", "", Some("NONE")))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn apply_maps_forces_and_forbids_labels() {
+        let rules = TriageRules::parse(
+            r#"
+            [[rule]]
+            name = "label names"
+            map_labels = { "bug" = "type: bug" }
+            forbid_labels = ["wontfix"]
+
+            [[rule]]
+            name = "docs"
+            paths = ["docs/", "*.md"]
+            add_labels = ["documentation", "good first issue", "wontfix"]
+            milestone = "Docs"
+            "#,
+        )
+        .unwrap();
+
+        let docs = issue("Typo", "See `docs/setup.md` line 3.", None);
+        let mut result = triage(&["bug", "wontfix", "documentation"], Some("v1.0"));
+        rules.apply(&docs, &mut result);
+        assert_eq!(result.suggested_labels, vec!["type: bug"]);
+        assert_eq!(
+            result.forced_labels,
+            vec!["documentation", "good first issue"]
+        );
+        assert_eq!(result.suggested_milestone.as_deref(), Some("Docs"));
+        assert_eq!(result.applied_rules, vec!["label names", "docs"]);
+
+        let code = issue("Crash", "Panics in src/main.rs:42", None);
+        let mut result = triage(&["bug"], None);
+        rules.apply(&code, &mut result);
+        assert_eq!(result.suggested_labels, vec!["type: bug"]);
+        assert!(result.forced_labels.is_empty());
+        assert_eq!(result.applied_rules, vec!["label names"]);
+    }
+
+    #[test]
+    fn apply_matches_model_labels_and_milestones() {
+        let rules = TriageRules::parse(
+            r#"
+            [[rule]]
+            name = "security"
+            repos = ["block/goose"]
+            suggested_labels = ["security"]
+            add_labels = ["needs-triage"]
+            forbid_milestones = ["Backlog"]
+            map_milestones = { "next" = "v2.0" }
+            "#,
+        )
+        .unwrap();
+
+        let report = issue("Token leak", "", None);
+        assert!(rules.skip_ai_triage(&report).is_none());
+
+        let mut result = triage(&["Security"], Some("Backlog"));
+        rules.apply(&report, &mut result);
+        assert_eq!(result.forced_labels, vec!["needs-triage"]);
+        assert!(result.suggested_milestone.is_none());
+
+        let mut result = triage(&["security"], Some("next"));
+        rules.apply(&report, &mut result);
+        assert_eq!(result.suggested_milestone.as_deref(), Some("v2.0"));
+
+        let mut result = triage(&["bug"], Some("Backlog"));
+        rules.apply(&report, &mut result);
+        assert!(result.applied_rules.is_empty());
+        assert_eq!(result.suggested_milestone.as_deref(), Some("Backlog"));
+    }
+
+    #[test]
+    fn mentioned_paths_finds_files_not_prose() {
+        let body = "Fails in `crates/aptu-core/src/lib.rs:10` and ./README.md, \
+                    see https://example.com/a/b.html. Version 1.2 e.g. broken.";
+        let paths = mentioned_paths(body);
+        assert!(paths.contains(&"crates/aptu-core/src/lib.rs".to_string()));
+        assert!(paths.contains(&"README.md".to_string()));
+        assert!(!paths.iter().any(|p| p.contains("example.com")));
+        assert!(!paths.contains(&"1.2".to_string()));
+    }
+}
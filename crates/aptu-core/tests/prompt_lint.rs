@@ -7,8 +7,9 @@
 //! therefore validate the exact strings the AI receives, not a copy.
 
 use aptu_core::ai::prompts::{
-    TOOLING_CONTEXT, build_create_system_prompt, build_pr_label_system_prompt,
-    build_pr_review_system_prompt, build_triage_system_prompt,
+    TOOLING_CONTEXT, build_create_system_prompt, build_dedupe_system_prompt,
+    build_plan_system_prompt, build_pr_label_system_prompt, build_pr_review_system_prompt,
+    build_split_system_prompt, build_stale_system_prompt, build_triage_system_prompt,
 };
 use aptu_core::ai::provider::AiProvider;
 use aptu_core::ai::types::{IssueDetails, PrDetails, PrFile};
@@ -55,6 +56,10 @@ fn all_system_prompts() -> Vec<(&'static str, String)> {
         ("create", build_create_system_prompt(TOOLING_CONTEXT)),
         ("pr_review", build_pr_review_system_prompt(TOOLING_CONTEXT)),
         ("pr_label", build_pr_label_system_prompt(TOOLING_CONTEXT)),
+        ("dedupe", build_dedupe_system_prompt(TOOLING_CONTEXT)),
+        ("stale", build_stale_system_prompt(TOOLING_CONTEXT)),
+        ("split", build_split_system_prompt(TOOLING_CONTEXT)),
+        ("plan", build_plan_system_prompt(TOOLING_CONTEXT)),
     ]
 }
 
@@ -180,6 +185,7 @@ fn all_user_prompts_contain_schema() {
         url: "https://github.com/test/repo/pull/1".to_string(),
         files: vec![PrFile {
             filename: "src/lib.rs".to_string(),
+            previous_filename: None,
             status: "modified".to_string(),
             additions: 5,
             deletions: 2,
@@ -189,6 +195,7 @@ fn all_user_prompts_contain_schema() {
         }],
         labels: vec![],
         head_sha: String::new(),
+        base_sha: String::new(),
         review_comments: vec![],
         instructions: None,
         dep_enrichments: vec![],
@@ -213,6 +220,8 @@ fn all_user_prompts_contain_schema() {
         estimated_size: 0,
         graph_context: String::new(),
         graph_cache_hit: false,
+        symbol_changes: String::new(),
+        non_behavioural_files: Vec::new(),
     };
     let pr_review_user = aptu_core::ai::prompts::build_pr_review_user_prompt(&mut ctx);
     assert!(
@@ -249,6 +258,7 @@ mod fetch_file_contents_tests {
             url: "https://github.com/test/repo/pull/1".to_string(),
             files: vec![PrFile {
                 filename: "src/lib.rs".to_string(),
+                previous_filename: None,
                 status: "modified".to_string(),
                 additions: 5,
                 deletions: 2,
@@ -258,6 +268,7 @@ mod fetch_file_contents_tests {
             }],
             labels: vec![],
             head_sha: String::new(),
+            base_sha: String::new(),
             review_comments: vec![],
             instructions: None,
             dep_enrichments: vec![],
@@ -295,6 +306,7 @@ mod fetch_file_contents_tests {
         let pr = PrDetails {
             files: vec![PrFile {
                 filename: "huge.rs".to_string(),
+                previous_filename: None,
                 status: "modified".to_string(),
                 additions: 100,
                 deletions: 0,
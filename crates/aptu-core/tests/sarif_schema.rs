@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Schema validation tests for SARIF output.
+//!
+//! These tests validate reports produced by `SarifReport` against the SARIF 2.1.0
+//! JSON schema bundled under `tests/sarif_fixtures`, so that field names, enums,
+//! and numeric bounds stay acceptable to GitHub Code Scanning.
+//!
+//! The bundled file is a trimmed subset of the OASIS schema (see its
+//! `description`) and does not establish conformance; replace it with the
+//! official schema, unmodified, by running `just sarif-schema`.
+
+use std::path::Path;
+
+use aptu_core::security::sarif::SarifInvocation;
+use aptu_core::{PatternEngine, SarifReport, SecurityScanner};
+use chrono::Utc;
+use serde_json::Value;
+
+/// Bundled SARIF 2.1.0 schema.
+const SARIF_SCHEMA: &str = include_str!("sarif_fixtures/sarif-schema-2.1.0.json");
+
+/// Test fixture: `hardcoded_secrets.rs`
+const HARDCODED_SECRETS_FIXTURE: &str =
+    include_str!("../../../tests/security_fixtures/vulnerable/hardcoded_secrets.rs");
+
+/// Asserts that `report` is valid against the bundled schema, listing every violation.
+fn assert_schema_valid(report: &SarifReport) {
+    let schema: Value = serde_json::from_str(SARIF_SCHEMA).expect("schema fixture is valid JSON");
+    let validator = jsonschema::options()
+        .should_validate_formats(true)
+        .build(&schema)
+        .expect("schema fixture compiles");
+    let instance = serde_json::to_value(report).expect("report serializes");
+
+    let errors: Vec<String> = validator
+        .iter_errors(&instance)
+        .map(|e| format!("{}: {e}", e.instance_path()))
+        .collect();
+    assert!(
+        errors.is_empty(),
+        "SARIF report violates schema:\n{}",
+        errors.join("\n")
+    );
+}
+
+#[test]
+fn test_empty_report_is_schema_valid() {
+    assert_schema_valid(&SarifReport::from(vec![]));
+}
+
+#[test]
+fn test_report_with_rules_is_schema_valid() {
+    let scanner = SecurityScanner::new();
+    let findings = scanner.scan_file(HARDCODED_SECRETS_FIXTURE, "./src/secrets.rs");
+    assert!(!findings.is_empty(), "fixture should produce findings");
+
+    let patterns = PatternEngine::global().definitions();
+    let now = Utc::now();
+    let report = SarifReport::with_rules(findings, &patterns)
+        .with_source_root(Path::new("/work/my repo"))
+        .with_invocation(&SarifInvocation::completed(now, now))
+        .with_automation_id("aptu-scan-security/");
+
+    assert_schema_valid(&report);
+
+    let result = &report.runs[0].results[0];
+    let region = &result.locations[0].physical_location.region;
+    assert!(result.rule_index.is_some());
+    assert!(region.start_column.is_some());
+    assert!(region.snippet.is_some());
+}